@@ -1,22 +1,69 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use sqlx::SqlitePool;
 
 use crate::{
-  app::{error::AppError, state::AppState},
+  app::{error::AppError, session::Session, state::AppState},
   application::patients::{
+    acknowledge_critical_alert::AcknowledgeCriticalAlertUseCase, amend_result::AmendResultUseCase,
+    cancel_attendance::CancelAttendanceUseCase,
     complete_attendance::CompleteAttendanceUseCase, create_attendance::CreateAttendanceUseCase,
-    create_patient::CreatePatientUseCase, get_patient_record::GetPatientRecordUseCase,
-    list_attendance_queue::ListAttendanceQueueUseCase, list_exam_catalog::ListExamCatalogUseCase,
+    create_exam_catalog_item::CreateExamCatalogItemUseCase,
+    create_exam_category::CreateExamCategoryUseCase, create_patient::CreatePatientUseCase,
+    deactivate_exam_catalog_item::DeactivateExamCatalogItemUseCase,
+    delete_attendance::DeleteAttendanceUseCase, delete_patient::DeletePatientUseCase,
+    enter_exam_results::EnterExamResultsUseCase,
+    find_duplicate_patients::FindDuplicatePatientsUseCase,
+    get_exam_delta_check::GetExamDeltaCheckUseCase, get_patient_record::GetPatientRecordUseCase,
+    list_attendance_queue::ListAttendanceQueueUseCase,
+    list_attendance_status_history::ListAttendanceStatusHistoryUseCase,
+    list_exam_catalog::ListExamCatalogUseCase, list_exam_categories::ListExamCategoriesUseCase,
+    list_exam_reference_ranges::ListExamReferenceRangesUseCase,
     list_patients::ListPatientsUseCase,
+    list_pending_critical_alerts::ListPendingCriticalAlertsUseCase,
+    list_result_history::ListResultHistoryUseCase,
+    merge_patients::MergePatientsUseCase,
+    release_attendance::ReleaseAttendanceUseCase, restore_attendance::RestoreAttendanceUseCase,
+    restore_patient::RestorePatientUseCase, set_exam_delta_check::SetExamDeltaCheckUseCase,
+    set_exam_panel_analytes::SetExamPanelAnalytesUseCase,
+    set_exam_reference_ranges::SetExamReferenceRangesUseCase,
+    transition_attendance::TransitionAttendanceUseCase, update_attendance::UpdateAttendanceUseCase,
+    update_exam_catalog_item::UpdateExamCatalogItemUseCase, update_patient::UpdatePatientUseCase,
+  },
+  application::audit::{
+    list_audit_log::ListAuditLogUseCase, verify_audit_chain::VerifyAuditChainUseCase,
+  },
+  application::reports::generate_lab_report::GenerateLabReportUseCase,
+  application::requesters::{
+    create_requester::CreateRequesterUseCase, list_requesters::ListRequestersUseCase,
+    search_requesters::SearchRequestersUseCase, set_requester_active::SetRequesterActiveUseCase,
+    update_requester::UpdateRequesterUseCase,
+  },
+  application::users::{
+    create_initial_admin::CreateInitialAdminUseCase, create_user::CreateUserUseCase,
+    list_users::ListUsersUseCase, login::LoginUseCase,
+    needs_initial_setup::NeedsInitialSetupUseCase, set_user_active::SetUserActiveUseCase,
   },
   infra::{
+    clock::system_clock::SystemClock,
     db::sqlite::{create_sqlite_pool, run_migrations},
-    repositories::patients_sqlite::PatientsSqliteRepository,
+    reports::{lab_report_pdf::PdfLabReportRenderer, report_files::FsReportFileStore},
+    repositories::{
+      audit_sqlite::{seal_legacy_audit_entries, AuditSqliteRepository},
+      patients_sqlite::PatientsSqliteRepository,
+      reports_sqlite::ReportsSqliteRepository,
+      requesters_sqlite::RequestersSqliteRepository,
+      users_sqlite::UsersSqliteRepository,
+    },
+    security::argon2_hasher::Argon2PasswordHasher,
   },
 };
 
-pub async fn compose(db_path: &str) -> Result<AppState, AppError> {
+const LAB_NAME: &str = "Laboratorio Joao Paulo II";
+/// How many days ahead of today an attendance may be scheduled.
+const ATTENDANCE_MAX_DAYS_AHEAD: i64 = 30;
+
+pub async fn compose(db_path: &str, reports_dir: &Path) -> Result<AppState, AppError> {
   // 1) Pool
   let pool: SqlitePool = create_sqlite_pool(db_path)
     .await
@@ -26,27 +73,151 @@ pub async fn compose(db_path: &str) -> Result<AppState, AppError> {
   run_migrations(&pool)
     .await
     .map_err(|e| AppError::Database(format!("failed to run migrations: {e}")))?;
+  seal_legacy_audit_entries(&pool)
+    .await
+    .map_err(|e| AppError::Database(format!("failed to seal audit log: {e}")))?;
 
   // 3) Repository (concreto, infra)
-  let repo = Arc::new(PatientsSqliteRepository::new(pool));
+  let repo = Arc::new(PatientsSqliteRepository::new(pool.clone()));
+  let reports_repo = Arc::new(ReportsSqliteRepository::new(pool.clone()));
+  let requesters_repo = Arc::new(RequestersSqliteRepository::new(pool.clone()));
+  let users_repo = Arc::new(UsersSqliteRepository::new(pool.clone()));
+  let audit_repo = Arc::new(AuditSqliteRepository::new(pool));
+  let password_hasher = Arc::new(Argon2PasswordHasher::new());
+  let report_renderer = Arc::new(PdfLabReportRenderer::new(LAB_NAME));
+  let report_files = Arc::new(FsReportFileStore::new(reports_dir));
 
   // 4) Use case (application)
   let create_patient_use_case = Arc::new(CreatePatientUseCase::new(repo.clone()));
+  let update_patient_use_case = Arc::new(UpdatePatientUseCase::new(repo.clone()));
   let list_patients_use_case = Arc::new(ListPatientsUseCase::new(repo.clone()));
+  let find_duplicate_patients_use_case =
+    Arc::new(FindDuplicatePatientsUseCase::new(repo.clone()));
+  let merge_patients_use_case = Arc::new(MergePatientsUseCase::new(repo.clone()));
+  let delete_patient_use_case = Arc::new(DeletePatientUseCase::new(repo.clone()));
+  let restore_patient_use_case = Arc::new(RestorePatientUseCase::new(repo.clone()));
+  let delete_attendance_use_case = Arc::new(DeleteAttendanceUseCase::new(repo.clone()));
+  let restore_attendance_use_case = Arc::new(RestoreAttendanceUseCase::new(repo.clone()));
   let get_patient_record_use_case = Arc::new(GetPatientRecordUseCase::new(repo.clone()));
   let list_exam_catalog_use_case = Arc::new(ListExamCatalogUseCase::new(repo.clone()));
-  let create_attendance_use_case = Arc::new(CreateAttendanceUseCase::new(repo.clone()));
+  let create_attendance_use_case = Arc::new(CreateAttendanceUseCase::new(
+    repo.clone(),
+    requesters_repo.clone(),
+    Arc::new(SystemClock::new()),
+    ATTENDANCE_MAX_DAYS_AHEAD,
+  ));
+  let update_attendance_use_case = Arc::new(UpdateAttendanceUseCase::new(
+    repo.clone(),
+    repo.clone(),
+    requesters_repo.clone(),
+  ));
   let list_attendance_queue_use_case = Arc::new(ListAttendanceQueueUseCase::new(repo.clone()));
-  let complete_attendance_use_case = Arc::new(CompleteAttendanceUseCase::new(repo));
+  let complete_attendance_use_case = Arc::new(CompleteAttendanceUseCase::new(repo.clone()));
+  let cancel_attendance_use_case = Arc::new(CancelAttendanceUseCase::new(repo.clone()));
+  let list_exam_categories_use_case = Arc::new(ListExamCategoriesUseCase::new(repo.clone()));
+  let create_exam_category_use_case = Arc::new(CreateExamCategoryUseCase::new(repo.clone()));
+  let create_exam_catalog_item_use_case = Arc::new(CreateExamCatalogItemUseCase::new(repo.clone()));
+  let update_exam_catalog_item_use_case = Arc::new(UpdateExamCatalogItemUseCase::new(repo.clone()));
+  let deactivate_exam_catalog_item_use_case =
+    Arc::new(DeactivateExamCatalogItemUseCase::new(repo.clone()));
+  let list_exam_reference_ranges_use_case =
+    Arc::new(ListExamReferenceRangesUseCase::new(repo.clone()));
+  let set_exam_reference_ranges_use_case =
+    Arc::new(SetExamReferenceRangesUseCase::new(repo.clone()));
+  let get_exam_delta_check_use_case = Arc::new(GetExamDeltaCheckUseCase::new(repo.clone()));
+  let set_exam_delta_check_use_case = Arc::new(SetExamDeltaCheckUseCase::new(repo.clone()));
+  let set_exam_panel_analytes_use_case = Arc::new(SetExamPanelAnalytesUseCase::new(repo.clone()));
+  let transition_attendance_use_case = Arc::new(TransitionAttendanceUseCase::new(repo.clone()));
+  let list_attendance_status_history_use_case =
+    Arc::new(ListAttendanceStatusHistoryUseCase::new(repo.clone()));
+  let enter_exam_results_use_case = Arc::new(EnterExamResultsUseCase::new(repo.clone()));
+  let release_attendance_use_case = Arc::new(ReleaseAttendanceUseCase::new(
+    repo.clone(),
+    users_repo.clone(),
+    password_hasher.clone(),
+  ));
+  let amend_result_use_case = Arc::new(AmendResultUseCase::new(
+    repo.clone(),
+    reports_repo.clone(),
+    report_renderer.clone(),
+    report_files.clone(),
+  ));
+  let list_result_history_use_case = Arc::new(ListResultHistoryUseCase::new(repo.clone()));
+  let list_pending_critical_alerts_use_case =
+    Arc::new(ListPendingCriticalAlertsUseCase::new(repo.clone()));
+  let acknowledge_critical_alert_use_case = Arc::new(AcknowledgeCriticalAlertUseCase::new(repo));
+  let list_requesters_use_case = Arc::new(ListRequestersUseCase::new(requesters_repo.clone()));
+  let search_requesters_use_case = Arc::new(SearchRequestersUseCase::new(requesters_repo.clone()));
+  let create_requester_use_case = Arc::new(CreateRequesterUseCase::new(requesters_repo.clone()));
+  let update_requester_use_case = Arc::new(UpdateRequesterUseCase::new(requesters_repo.clone()));
+  let set_requester_active_use_case = Arc::new(SetRequesterActiveUseCase::new(requesters_repo));
+  let generate_lab_report_use_case = Arc::new(GenerateLabReportUseCase::new(
+    reports_repo,
+    report_renderer,
+    report_files,
+  ));
+  let login_use_case = Arc::new(LoginUseCase::new(users_repo.clone(), password_hasher.clone()));
+  let needs_initial_setup_use_case = Arc::new(NeedsInitialSetupUseCase::new(users_repo.clone()));
+  let create_initial_admin_use_case = Arc::new(CreateInitialAdminUseCase::new(
+    users_repo.clone(),
+    password_hasher.clone(),
+  ));
+  let list_users_use_case = Arc::new(ListUsersUseCase::new(users_repo.clone()));
+  let create_user_use_case = Arc::new(CreateUserUseCase::new(users_repo.clone(), password_hasher));
+  let set_user_active_use_case = Arc::new(SetUserActiveUseCase::new(users_repo));
+  let list_audit_log_use_case = Arc::new(ListAuditLogUseCase::new(audit_repo.clone()));
+  let verify_audit_chain_use_case = Arc::new(VerifyAuditChainUseCase::new(audit_repo));
 
   // 5) State
   Ok(AppState {
+    session: Arc::new(Session::new()),
     create_patient_use_case,
+    update_patient_use_case,
     list_patients_use_case,
+    find_duplicate_patients_use_case,
+    merge_patients_use_case,
+    delete_patient_use_case,
+    restore_patient_use_case,
+    delete_attendance_use_case,
+    restore_attendance_use_case,
     get_patient_record_use_case,
     list_exam_catalog_use_case,
     create_attendance_use_case,
+    update_attendance_use_case,
     list_attendance_queue_use_case,
     complete_attendance_use_case,
+    cancel_attendance_use_case,
+    list_exam_categories_use_case,
+    create_exam_category_use_case,
+    create_exam_catalog_item_use_case,
+    update_exam_catalog_item_use_case,
+    deactivate_exam_catalog_item_use_case,
+    list_exam_reference_ranges_use_case,
+    set_exam_reference_ranges_use_case,
+    get_exam_delta_check_use_case,
+    set_exam_delta_check_use_case,
+    set_exam_panel_analytes_use_case,
+    transition_attendance_use_case,
+    list_attendance_status_history_use_case,
+    enter_exam_results_use_case,
+    release_attendance_use_case,
+    amend_result_use_case,
+    list_result_history_use_case,
+    list_pending_critical_alerts_use_case,
+    acknowledge_critical_alert_use_case,
+    list_requesters_use_case,
+    search_requesters_use_case,
+    create_requester_use_case,
+    update_requester_use_case,
+    set_requester_active_use_case,
+    generate_lab_report_use_case,
+    login_use_case,
+    needs_initial_setup_use_case,
+    create_initial_admin_use_case,
+    list_users_use_case,
+    create_user_use_case,
+    set_user_active_use_case,
+    list_audit_log_use_case,
+    verify_audit_chain_use_case,
   })
 }
@@ -1,6 +1,35 @@
 #[derive(Debug)]
 pub enum AppError {
-  Validation(String),
+  /// The input is invalid; `field` names the input field at fault (`cpf`, `items[2]`, ...), so
+  /// forms can highlight it.
+  Validation {
+    field: Option<String>,
+    message: String,
+  },
   Database(String),
   Unexpected(String),
+  /// The current user (or the lack of one) may not perform the action.
+  Forbidden(String),
+  /// The record the action refers to does not exist.
+  NotFound(String),
+  /// The action clashes with the current state (duplicate key, concurrent change, ...).
+  Conflict(String),
+}
+
+impl AppError {
+  /// Validation error that is not about a single input field.
+  pub fn validation(message: String) -> Self {
+    Self::Validation {
+      field: None,
+      message,
+    }
+  }
+
+  /// Validation error about the input field `field`.
+  pub fn invalid(field: impl Into<String>, message: String) -> Self {
+    Self::Validation {
+      field: Some(field.into()),
+      message,
+    }
+  }
 }
@@ -0,0 +1,50 @@
+use std::sync::RwLock;
+
+use crate::{app::error::AppError, domain::users::dto::UserView};
+
+/// User logged in on this desktop instance; one window, one session.
+#[derive(Default)]
+pub struct Session {
+  current: RwLock<Option<UserView>>,
+}
+
+impl Session {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn start(&self, user: UserView) {
+    *self.current.write().unwrap_or_else(|e| e.into_inner()) = Some(user);
+  }
+
+  pub fn end(&self) {
+    *self.current.write().unwrap_or_else(|e| e.into_inner()) = None;
+  }
+
+  /// Replaces the cached user when `user` is the one logged in, so a deactivation or role change
+  /// applies to the open session instead of waiting for the next login.
+  pub fn refresh(&self, user: &UserView) {
+    let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+    if current
+      .as_ref()
+      .is_some_and(|logged_in| logged_in.id == user.id)
+    {
+      *current = Some(user.clone());
+    }
+  }
+
+  pub fn current_user(&self) -> Option<UserView> {
+    self
+      .current
+      .read()
+      .unwrap_or_else(|e| e.into_inner())
+      .clone()
+  }
+
+  /// The logged-in user, or `Forbidden` when nobody is logged in.
+  pub fn require_user(&self) -> Result<UserView, AppError> {
+    self
+      .current_user()
+      .ok_or_else(|| AppError::Forbidden("login required".into()))
+  }
+}
@@ -1,19 +1,97 @@
 use std::sync::Arc;
 
 use crate::application::patients::{
+  acknowledge_critical_alert::AcknowledgeCriticalAlertUseCase, amend_result::AmendResultUseCase,
+  cancel_attendance::CancelAttendanceUseCase,
   complete_attendance::CompleteAttendanceUseCase, create_attendance::CreateAttendanceUseCase,
-  create_patient::CreatePatientUseCase, get_patient_record::GetPatientRecordUseCase,
-  list_attendance_queue::ListAttendanceQueueUseCase, list_exam_catalog::ListExamCatalogUseCase,
+  create_exam_catalog_item::CreateExamCatalogItemUseCase,
+  create_exam_category::CreateExamCategoryUseCase, create_patient::CreatePatientUseCase,
+  deactivate_exam_catalog_item::DeactivateExamCatalogItemUseCase,
+  delete_attendance::DeleteAttendanceUseCase, delete_patient::DeletePatientUseCase,
+  enter_exam_results::EnterExamResultsUseCase,
+  find_duplicate_patients::FindDuplicatePatientsUseCase,
+  get_exam_delta_check::GetExamDeltaCheckUseCase, get_patient_record::GetPatientRecordUseCase,
+  list_attendance_queue::ListAttendanceQueueUseCase,
+  list_attendance_status_history::ListAttendanceStatusHistoryUseCase,
+  list_exam_catalog::ListExamCatalogUseCase, list_exam_categories::ListExamCategoriesUseCase,
+  list_exam_reference_ranges::ListExamReferenceRangesUseCase,
   list_patients::ListPatientsUseCase,
+  list_pending_critical_alerts::ListPendingCriticalAlertsUseCase,
+  list_result_history::ListResultHistoryUseCase,
+  merge_patients::MergePatientsUseCase,
+  release_attendance::ReleaseAttendanceUseCase, restore_attendance::RestoreAttendanceUseCase,
+  restore_patient::RestorePatientUseCase, set_exam_delta_check::SetExamDeltaCheckUseCase,
+  set_exam_panel_analytes::SetExamPanelAnalytesUseCase,
+  set_exam_reference_ranges::SetExamReferenceRangesUseCase,
+  transition_attendance::TransitionAttendanceUseCase, update_attendance::UpdateAttendanceUseCase,
+  update_exam_catalog_item::UpdateExamCatalogItemUseCase, update_patient::UpdatePatientUseCase,
 };
+use crate::application::audit::{
+  list_audit_log::ListAuditLogUseCase, verify_audit_chain::VerifyAuditChainUseCase,
+};
+use crate::application::reports::generate_lab_report::GenerateLabReportUseCase;
+use crate::application::requesters::{
+  create_requester::CreateRequesterUseCase, list_requesters::ListRequestersUseCase,
+  search_requesters::SearchRequestersUseCase, set_requester_active::SetRequesterActiveUseCase,
+  update_requester::UpdateRequesterUseCase,
+};
+use crate::application::users::{
+  create_initial_admin::CreateInitialAdminUseCase, create_user::CreateUserUseCase,
+  list_users::ListUsersUseCase, login::LoginUseCase,
+  needs_initial_setup::NeedsInitialSetupUseCase, set_user_active::SetUserActiveUseCase,
+};
+
+use super::session::Session;
 
 #[derive(Clone)]
 pub struct AppState {
+  pub session: Arc<Session>,
   pub create_patient_use_case: Arc<CreatePatientUseCase>,
+  pub update_patient_use_case: Arc<UpdatePatientUseCase>,
   pub list_patients_use_case: Arc<ListPatientsUseCase>,
+  pub find_duplicate_patients_use_case: Arc<FindDuplicatePatientsUseCase>,
+  pub merge_patients_use_case: Arc<MergePatientsUseCase>,
+  pub delete_patient_use_case: Arc<DeletePatientUseCase>,
+  pub restore_patient_use_case: Arc<RestorePatientUseCase>,
+  pub delete_attendance_use_case: Arc<DeleteAttendanceUseCase>,
+  pub restore_attendance_use_case: Arc<RestoreAttendanceUseCase>,
   pub get_patient_record_use_case: Arc<GetPatientRecordUseCase>,
   pub list_exam_catalog_use_case: Arc<ListExamCatalogUseCase>,
   pub create_attendance_use_case: Arc<CreateAttendanceUseCase>,
+  pub update_attendance_use_case: Arc<UpdateAttendanceUseCase>,
   pub list_attendance_queue_use_case: Arc<ListAttendanceQueueUseCase>,
   pub complete_attendance_use_case: Arc<CompleteAttendanceUseCase>,
+  pub cancel_attendance_use_case: Arc<CancelAttendanceUseCase>,
+  pub list_exam_categories_use_case: Arc<ListExamCategoriesUseCase>,
+  pub create_exam_category_use_case: Arc<CreateExamCategoryUseCase>,
+  pub create_exam_catalog_item_use_case: Arc<CreateExamCatalogItemUseCase>,
+  pub update_exam_catalog_item_use_case: Arc<UpdateExamCatalogItemUseCase>,
+  pub deactivate_exam_catalog_item_use_case: Arc<DeactivateExamCatalogItemUseCase>,
+  pub list_exam_reference_ranges_use_case: Arc<ListExamReferenceRangesUseCase>,
+  pub set_exam_reference_ranges_use_case: Arc<SetExamReferenceRangesUseCase>,
+  pub get_exam_delta_check_use_case: Arc<GetExamDeltaCheckUseCase>,
+  pub set_exam_delta_check_use_case: Arc<SetExamDeltaCheckUseCase>,
+  pub set_exam_panel_analytes_use_case: Arc<SetExamPanelAnalytesUseCase>,
+  pub transition_attendance_use_case: Arc<TransitionAttendanceUseCase>,
+  pub list_attendance_status_history_use_case: Arc<ListAttendanceStatusHistoryUseCase>,
+  pub enter_exam_results_use_case: Arc<EnterExamResultsUseCase>,
+  pub release_attendance_use_case: Arc<ReleaseAttendanceUseCase>,
+  pub amend_result_use_case: Arc<AmendResultUseCase>,
+  pub list_result_history_use_case: Arc<ListResultHistoryUseCase>,
+  pub list_pending_critical_alerts_use_case: Arc<ListPendingCriticalAlertsUseCase>,
+  pub acknowledge_critical_alert_use_case: Arc<AcknowledgeCriticalAlertUseCase>,
+  pub list_requesters_use_case: Arc<ListRequestersUseCase>,
+  pub search_requesters_use_case: Arc<SearchRequestersUseCase>,
+  pub create_requester_use_case: Arc<CreateRequesterUseCase>,
+  pub update_requester_use_case: Arc<UpdateRequesterUseCase>,
+  pub set_requester_active_use_case: Arc<SetRequesterActiveUseCase>,
+  pub generate_lab_report_use_case: Arc<GenerateLabReportUseCase>,
+  pub login_use_case: Arc<LoginUseCase>,
+  pub needs_initial_setup_use_case: Arc<NeedsInitialSetupUseCase>,
+  pub create_initial_admin_use_case: Arc<CreateInitialAdminUseCase>,
+  pub list_users_use_case: Arc<ListUsersUseCase>,
+  pub create_user_use_case: Arc<CreateUserUseCase>,
+  pub set_user_active_use_case: Arc<SetUserActiveUseCase>,
+  pub list_audit_log_use_case: Arc<ListAuditLogUseCase>,
+  pub verify_audit_chain_use_case: Arc<VerifyAuditChainUseCase>,
 }
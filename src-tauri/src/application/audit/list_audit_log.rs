@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    audit::{
+      dto::{AuditEntryView, ListAuditLogInput},
+      errors::AuditRepositoryError,
+      ports::AuditRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 500;
+
+pub struct ListAuditLogUseCase {
+  repo: Arc<dyn AuditRepository>,
+}
+
+impl ListAuditLogUseCase {
+  pub fn new(repo: Arc<dyn AuditRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: ListAuditLogInput,
+  ) -> Result<Vec<AuditEntryView>, AppError> {
+    authorize(actor, Permission::ViewAuditLog)?;
+
+    for (field, value) in [("from_date", &input.from_date), ("to_date", &input.to_date)] {
+      if let Some(date) = value {
+        if !is_date_only(date) {
+          return Err(AppError::invalid(
+            field,
+            format!("{field} must be YYYY-MM-DD"),
+          ));
+        }
+      }
+    }
+    if let (Some(from), Some(to)) = (&input.from_date, &input.to_date) {
+      if from > to {
+        return Err(AppError::invalid(
+          "from_date",
+          "from_date must not be after to_date".into(),
+        ));
+      }
+    }
+    let limit = input.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+      return Err(AppError::invalid(
+        "limit",
+        format!("limit must be between 1 and {MAX_LIMIT}"),
+      ));
+    }
+
+    self
+      .repo
+      .list_audit_log(ListAuditLogInput {
+        entity_name: normalize(input.entity_name),
+        entity_id: normalize(input.entity_id),
+        performed_by_user_id: normalize(input.performed_by_user_id),
+        from_date: input.from_date,
+        to_date: input.to_date,
+        limit: Some(limit),
+      })
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn normalize(value: Option<String>) -> Option<String> {
+  value
+    .map(|raw| raw.trim().to_string())
+    .filter(|value| !value.is_empty())
+}
+
+fn map_repo_error(err: AuditRepositoryError) -> AppError {
+  match err {
+    AuditRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch audit log".into())
+    }
+  }
+}
+
+fn is_date_only(value: &str) -> bool {
+  let bytes = value.as_bytes();
+  if bytes.len() != 10 {
+    return false;
+  }
+  bytes[0].is_ascii_digit()
+    && bytes[1].is_ascii_digit()
+    && bytes[2].is_ascii_digit()
+    && bytes[3].is_ascii_digit()
+    && bytes[4] == b'-'
+    && bytes[5].is_ascii_digit()
+    && bytes[6].is_ascii_digit()
+    && bytes[7] == b'-'
+    && bytes[8].is_ascii_digit()
+    && bytes[9].is_ascii_digit()
+}
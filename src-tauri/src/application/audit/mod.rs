@@ -0,0 +1,2 @@
+pub mod list_audit_log;
+pub mod verify_audit_chain;
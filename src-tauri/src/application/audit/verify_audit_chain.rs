@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    audit::{
+      dto::AuditChainVerificationView, errors::AuditRepositoryError, hash_chain::verify_chain,
+      ports::AuditRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct VerifyAuditChainUseCase {
+  repo: Arc<dyn AuditRepository>,
+}
+
+impl VerifyAuditChainUseCase {
+  pub fn new(repo: Arc<dyn AuditRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(&self, actor: &UserView) -> Result<AuditChainVerificationView, AppError> {
+    authorize(actor, Permission::ViewAuditLog)?;
+
+    let entries = self.repo.list_audit_chain().await.map_err(map_repo_error)?;
+
+    Ok(verify_chain(&entries))
+  }
+}
+
+fn map_repo_error(err: AuditRepositoryError) -> AppError {
+  match err {
+    AuditRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch audit log".into())
+    }
+  }
+}
@@ -0,0 +1,23 @@
+use crate::{
+  app::error::AppError,
+  domain::users::{dto::UserView, permission::Permission, role::Role},
+};
+
+/// Central permission check; every use case calls it before touching data.
+pub fn authorize(actor: &UserView, permission: Permission) -> Result<(), AppError> {
+  if !actor.is_active {
+    return Err(AppError::Forbidden("user is inactive".into()));
+  }
+
+  let role = Role::parse(&actor.role)
+    .map_err(|_| AppError::Forbidden(format!("unknown role: {}", actor.role)))?;
+  if !role.allows(permission) {
+    return Err(AppError::Forbidden(format!(
+      "role {} is not allowed to {}",
+      role.as_str(),
+      permission.as_str()
+    )));
+  }
+
+  Ok(())
+}
@@ -1 +1,6 @@
+pub mod audit;
+pub mod authorization;
 pub mod patients;
+pub mod reports;
+pub mod requesters;
+pub mod users;
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{AcknowledgeCriticalAlertInput, CriticalAlertView},
+      errors::PatientRepositoryError,
+      ports::CriticalAlertRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+/// How the requester was told about the panic value.
+const CONTACT_METHODS: [&str; 4] = ["phone", "in_person", "email", "message"];
+const MAX_CONTACT_NAME_LEN: usize = 120;
+const MAX_NOTE_LEN: usize = 500;
+
+pub struct AcknowledgeCriticalAlertUseCase {
+  repo: Arc<dyn CriticalAlertRepository>,
+}
+
+impl AcknowledgeCriticalAlertUseCase {
+  pub fn new(repo: Arc<dyn CriticalAlertRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Records that the requester was notified of a panic value: who was told, how, and (as the
+  /// actor) by whom. An acknowledged alert cannot be acknowledged again.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: AcknowledgeCriticalAlertInput,
+  ) -> Result<CriticalAlertView, AppError> {
+    authorize(actor, Permission::EnterResults)?;
+
+    input.alert_id = input.alert_id.trim().to_string();
+    input.contact_name = input.contact_name.trim().to_string();
+    input.contact_method = input.contact_method.trim().to_lowercase();
+    validate_input(&input)?;
+
+    self
+      .repo
+      .acknowledge_critical_alert(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn validate_input(input: &AcknowledgeCriticalAlertInput) -> Result<(), AppError> {
+  if input.alert_id.is_empty() {
+    return Err(AppError::invalid("alert_id", "alert_id is required".into()));
+  }
+  if input.contact_name.is_empty() {
+    return Err(AppError::invalid(
+      "contact_name",
+      "contact_name is required".into(),
+    ));
+  }
+  if input.contact_name.chars().count() > MAX_CONTACT_NAME_LEN {
+    return Err(AppError::invalid(
+      "contact_name",
+      format!("contact_name must have at most {MAX_CONTACT_NAME_LEN} characters"),
+    ));
+  }
+  if !CONTACT_METHODS.contains(&input.contact_method.as_str()) {
+    return Err(AppError::invalid(
+      "contact_method",
+      format!(
+        "contact_method must be one of {}",
+        CONTACT_METHODS.join(", ")
+      ),
+    ));
+  }
+  if let Some(note) = &input.note {
+    if note.trim().chars().count() > MAX_NOTE_LEN {
+      return Err(AppError::invalid(
+        "note",
+        format!("note must have at most {MAX_NOTE_LEN} characters"),
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to acknowledge critical alert".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("critical alert not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("critical alert was already acknowledged".into())
+    }
+  }
+}
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::{authorization::authorize, reports::generate_lab_report::issue_lab_report},
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{AmendResultInput, AmendResultView},
+      errors::PatientRepositoryError,
+      ports::AttendanceRepository,
+      reference_range::{age_in_years, compute_result_flag},
+    },
+    reports::{
+      dto::GeneratedLabReportView,
+      errors::ReportRepositoryError,
+      ports::{LabReportRenderer, ReportFileStore, ReportRepository},
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+const MAX_RESULT_FLAG_LEN: usize = 20;
+const MAX_REASON_LEN: usize = 500;
+
+pub struct AmendResultUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+  reports: Arc<dyn ReportRepository>,
+  renderer: Arc<dyn LabReportRenderer>,
+  files: Arc<dyn ReportFileStore>,
+}
+
+impl AmendResultUseCase {
+  pub fn new(
+    repo: Arc<dyn AttendanceRepository>,
+    reports: Arc<dyn ReportRepository>,
+    renderer: Arc<dyn LabReportRenderer>,
+    files: Arc<dyn ReportFileStore>,
+  ) -> Self {
+    Self {
+      repo,
+      reports,
+      renderer,
+      files,
+    }
+  }
+
+  /// Corrects a released result, keeping the replaced value in the result history. When a laudo
+  /// was already generated, a new version is issued right away, marked as "retificado"; if that
+  /// fails the amendment still stands and the failure comes back in `report_error`.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: AmendResultInput,
+  ) -> Result<AmendResultView, AppError> {
+    authorize(actor, Permission::ReleaseResults)?;
+
+    input.attendance_id = input.attendance_id.trim().to_string();
+    input.reason = input.reason.trim().to_string();
+    validate_input(&input)?;
+
+    let context = self
+      .repo
+      .get_result_entry_context(input.attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let released = AttendanceStatus::parse(&context.status)
+      .map(|status| status.allows_report())
+      .unwrap_or(false);
+    if !released {
+      return Err(AppError::validation(format!(
+        "results can only be amended after release; attendance is {}",
+        context.status
+      )));
+    }
+
+    let exam_item_id = input.exam_item_id.trim();
+    let Some(item_context) = context
+      .items
+      .iter()
+      .find(|candidate| candidate.exam_item_id == exam_item_id)
+    else {
+      return Err(AppError::invalid(
+        "exam_item_id",
+        format!("exam_item_id {exam_item_id} does not belong to attendance"),
+      ));
+    };
+    let age_years = age_in_years(&context.patient_birth_date, &context.exam_date);
+    if let Some(flag) = compute_result_flag(
+      &input.result_value,
+      &item_context.reference_ranges,
+      &context.patient_sex,
+      age_years,
+    ) {
+      input.result_flag = Some(flag.as_str().to_string());
+    }
+
+    let reason = input.reason.clone();
+    let entry = self
+      .repo
+      .amend_result(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)?;
+
+    // The amendment is already committed; a failed reissue must not hide it from the caller.
+    let (report, report_error) = match self.reissue_report(&entry.exam_id, actor, &reason).await {
+      Ok(report) => (report, None),
+      Err(err) => (
+        None,
+        Some(format!(
+          "result amended, but the retificado laudo could not be issued: {}; generate it again",
+          message_of(&err)
+        )),
+      ),
+    };
+
+    Ok(AmendResultView {
+      entry,
+      report,
+      report_error,
+    })
+  }
+
+  /// Issues the next laudo version when one was already generated; `None` otherwise.
+  async fn reissue_report(
+    &self,
+    attendance_id: &str,
+    actor: &UserView,
+    reason: &str,
+  ) -> Result<Option<GeneratedLabReportView>, AppError> {
+    let source = self
+      .reports
+      .get_lab_report_source(attendance_id.to_string())
+      .await
+      .map_err(map_report_repo_error)?;
+    if source.current_report_version.is_none() {
+      return Ok(None);
+    }
+
+    issue_lab_report(
+      self.reports.as_ref(),
+      self.renderer.as_ref(),
+      self.files.as_ref(),
+      &source,
+      actor,
+      Some(format!("Retificado: {reason}")),
+    )
+    .await
+    .map(Some)
+  }
+}
+
+fn validate_input(input: &AmendResultInput) -> Result<(), AppError> {
+  if input.attendance_id.is_empty() {
+    return Err(AppError::invalid(
+      "attendance_id",
+      "attendance_id is required".into(),
+    ));
+  }
+  if input.exam_item_id.trim().is_empty() {
+    return Err(AppError::invalid(
+      "exam_item_id",
+      "exam_item_id is required".into(),
+    ));
+  }
+  if input.result_value.trim().is_empty() {
+    return Err(AppError::invalid(
+      "result_value",
+      "result_value is required".into(),
+    ));
+  }
+  if let Some(flag) = &input.result_flag {
+    if flag.trim().chars().count() > MAX_RESULT_FLAG_LEN {
+      return Err(AppError::invalid(
+        "result_flag",
+        format!("result_flag must have at most {MAX_RESULT_FLAG_LEN} characters"),
+      ));
+    }
+  }
+  if input.reason.is_empty() {
+    return Err(AppError::invalid("reason", "reason is required".into()));
+  }
+  if input.reason.chars().count() > MAX_REASON_LEN {
+    return Err(AppError::invalid(
+      "reason",
+      format!("reason must have at most {MAX_REASON_LEN} characters"),
+    ));
+  }
+
+  Ok(())
+}
+
+fn message_of(err: &AppError) -> &str {
+  match err {
+    AppError::Validation { message, .. }
+    | AppError::Database(message)
+    | AppError::Unexpected(message)
+    | AppError::Forbidden(message)
+    | AppError::NotFound(message)
+    | AppError::Conflict(message) => message,
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => AppError::Database("failed to amend result".into()),
+    PatientRepositoryError::NotFound => {
+      AppError::NotFound("attendance or exam item not found".into())
+    }
+    // The item was not released, or another amendment of the same value won.
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("result was changed by someone else".into())
+    }
+  }
+}
+
+fn map_report_repo_error(err: ReportRepositoryError) -> AppError {
+  match err {
+    ReportRepositoryError::PersistenceError => {
+      AppError::Database("failed to load report source".into())
+    }
+    ReportRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    ReportRepositoryError::Conflict => {
+      AppError::Conflict("conflict while loading report source".into())
+    }
+  }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{AttendanceQueueItemView, AttendanceStatusChange, CancelAttendanceInput},
+      errors::{PatientDomainError, PatientRepositoryError},
+      ports::AttendanceRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+const MAX_REASON_LEN: usize = 500;
+
+pub struct CancelAttendanceUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+}
+
+impl CancelAttendanceUseCase {
+  pub fn new(repo: Arc<dyn AttendanceRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Cancels an attendance whose results were not released yet, keeping who cancelled and why.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CancelAttendanceInput,
+  ) -> Result<AttendanceQueueItemView, AppError> {
+    authorize(actor, Permission::ManageAttendances)?;
+
+    let attendance_id = input.attendance_id.trim().to_string();
+    if attendance_id.is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+    let reason = input.reason.trim();
+    if reason.is_empty() {
+      return Err(AppError::invalid("reason", "reason is required".into()));
+    }
+    if reason.chars().count() > MAX_REASON_LEN {
+      return Err(AppError::invalid(
+        "reason",
+        format!("reason must have at most {MAX_REASON_LEN} characters"),
+      ));
+    }
+
+    let current = self
+      .repo
+      .get_attendance(attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let current_status = AttendanceStatus::parse(&current.status).map_err(map_domain_error)?;
+    match current_status {
+      AttendanceStatus::Cancelled => {
+        return Err(AppError::validation(
+          "attendance is already cancelled".into(),
+        ));
+      }
+      AttendanceStatus::Released | AttendanceStatus::Delivered => {
+        return Err(AppError::validation(
+          "attendance cannot be cancelled after its results were released".into(),
+        ));
+      }
+      _ => {}
+    }
+    let target = current_status
+      .transition_to(AttendanceStatus::Cancelled)
+      .map_err(map_domain_error)?;
+
+    self
+      .repo
+      .cancel_attendance(AttendanceStatusChange {
+        attendance_id,
+        from_status: current_status.as_str().to_string(),
+        to_status: target.as_str().to_string(),
+        changed_by_user_id: Some(actor.id.clone()),
+        note: Some(reason.to_string()),
+      })
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_domain_error(err: PatientDomainError) -> AppError {
+  match err {
+    PatientDomainError::UnknownAttendanceStatus(status) => {
+      AppError::validation(format!("unknown attendance status: {status}"))
+    }
+    PatientDomainError::InvalidStatusTransition { from, to } => {
+      AppError::validation(format!("cannot move attendance from {from} to {to}"))
+    }
+    other => AppError::Unexpected(format!("{other:?}")),
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to cancel attendance".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("attendance status was changed by someone else".into())
+    }
+  }
+}
@@ -2,10 +2,14 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::{AttendanceQueueItemView, CompleteAttendanceInput},
-    errors::PatientRepositoryError,
-    ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{AttendanceQueueItemView, CompleteAttendanceInput},
+      errors::PatientRepositoryError,
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -18,12 +22,25 @@ impl CompleteAttendanceUseCase {
     Self { repo }
   }
 
-  pub async fn execute(&self, input: CompleteAttendanceInput) -> Result<AttendanceQueueItemView, AppError> {
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CompleteAttendanceInput,
+  ) -> Result<AttendanceQueueItemView, AppError> {
+    authorize(actor, Permission::ManageAttendances)?;
+
     if input.attendance_id.trim().is_empty() {
-      return Err(AppError::Validation("attendance_id is required".into()));
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
     }
 
-    self.repo.complete_attendance(input).await.map_err(map_repo_error)
+    self
+      .repo
+      .complete_attendance(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
   }
 }
 
@@ -32,9 +49,9 @@ fn map_repo_error(err: PatientRepositoryError) -> AppError {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to complete attendance".into())
     }
-    PatientRepositoryError::NotFound => AppError::Database("attendance not found".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
     PatientRepositoryError::Conflict => {
-      AppError::Database("conflict while completing attendance".into())
+      AppError::Conflict("only waiting attendances can be completed".into())
     }
   }
 }
@@ -1,47 +1,306 @@
-use std::sync::Arc;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::{CreateAttendanceInput, PatientRecordEntryView},
-    errors::PatientRepositoryError,
-    ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      calendar::CalendarDate,
+      dto::{
+        CreateAttendanceInput, CreateAttendanceItemInput, ExamCatalogItemView,
+        PatientRecordEntryView,
+      },
+      duplicates::fold_accent,
+      errors::PatientRepositoryError,
+      ports::{Clock, PatientRepository},
+    },
+    requesters::{errors::RequesterRepositoryError, ports::RequesterRepository},
+    users::{dto::UserView, permission::Permission},
   },
 };
 
 pub struct CreateAttendanceUseCase {
   repo: Arc<dyn PatientRepository>,
+  requesters: Arc<dyn RequesterRepository>,
+  clock: Arc<dyn Clock>,
+  max_days_ahead: i64,
 }
 
 impl CreateAttendanceUseCase {
-  pub fn new(repo: Arc<dyn PatientRepository>) -> Self {
-    Self { repo }
+  /// `max_days_ahead` is how far past today an attendance may be scheduled; past dates are always
+  /// accepted, since old paper records get typed in late.
+  pub fn new(
+    repo: Arc<dyn PatientRepository>,
+    requesters: Arc<dyn RequesterRepository>,
+    clock: Arc<dyn Clock>,
+    max_days_ahead: i64,
+  ) -> Self {
+    Self {
+      repo,
+      requesters,
+      clock,
+      max_days_ahead,
+    }
   }
 
   pub async fn execute(
     &self,
+    actor: &UserView,
     input: CreateAttendanceInput,
   ) -> Result<PatientRecordEntryView, AppError> {
-    if input.patient_id.trim().is_empty() {
-      return Err(AppError::Validation("patient_id is required".into()));
-    }
-    if input.exam_date.trim().is_empty() {
-      return Err(AppError::Validation("exam_date is required".into()));
+    authorize(actor, Permission::ManageAttendances)?;
+
+    let patient_id = input.patient_id.trim().to_string();
+    if patient_id.is_empty() {
+      return Err(AppError::invalid(
+        "patient_id",
+        "patient_id is required".into(),
+      ));
     }
+    let exam_date = self.validate_exam_date(&input.exam_date)?;
     if input.items.is_empty() {
-      return Err(AppError::Validation("items is required".into()));
+      return Err(AppError::invalid("items", "items is required".into()));
+    }
+    if let Some(status) = input.status.as_deref().filter(|s| !s.trim().is_empty()) {
+      let is_initial = AttendanceStatus::parse(status)
+        .map(|s| s.is_initial())
+        .unwrap_or(false);
+      if !is_initial {
+        return Err(AppError::invalid(
+          "status",
+          "status must be waiting or collected when creating an attendance".into(),
+        ));
+      }
     }
 
-    self.repo.create_attendance(input).await.map_err(map_repo_error)
+    self.ensure_active_patient(&patient_id).await?;
+    if let Some(requester_id) = input.requester_id.as_deref().map(str::trim) {
+      if !requester_id.is_empty() {
+        ensure_active_requester(self.requesters.as_ref(), requester_id).await?;
+      }
+    }
+    let catalog = self
+      .repo
+      .list_exam_catalog(false)
+      .await
+      .map_err(map_repo_error)?;
+    let items = resolve_catalog_items(&catalog, input.items, "items", &HashSet::new())?;
+
+    self
+      .repo
+      .create_attendance(
+        CreateAttendanceInput {
+          patient_id,
+          exam_date: exam_date.to_string(),
+          items,
+          ..input
+        },
+        Some(actor.id.clone()),
+      )
+      .await
+      .map_err(map_repo_error)
+  }
+
+  fn validate_exam_date(&self, value: &str) -> Result<CalendarDate, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+      return Err(AppError::invalid(
+        "exam_date",
+        "exam_date is required".into(),
+      ));
+    }
+    let exam_date = CalendarDate::parse(value).ok_or_else(|| {
+      AppError::invalid(
+        "exam_date",
+        "exam_date must be a valid YYYY-MM-DD date".into(),
+      )
+    })?;
+
+    let latest = self.clock.today().add_days(self.max_days_ahead);
+    if exam_date > latest {
+      return Err(AppError::invalid(
+        "exam_date",
+        format!("exam_date must not be later than {latest}"),
+      ));
+    }
+    Ok(exam_date)
+  }
+
+  async fn ensure_active_patient(&self, patient_id: &str) -> Result<(), AppError> {
+    let patient = self
+      .repo
+      .find_patient(patient_id.to_string())
+      .await
+      .map_err(map_repo_error)?;
+
+    match patient {
+      Some(patient) if patient.deleted_at.is_none() => Ok(()),
+      Some(_) => Err(AppError::invalid(
+        "patient_id",
+        "patient_id points at a deleted patient".into(),
+      )),
+      None => Err(AppError::NotFound("patient not found".into())),
+    }
   }
 }
 
+/// New attendances may only point at requesters still in use; old ones keep theirs.
+pub async fn ensure_active_requester(
+  requesters: &dyn RequesterRepository,
+  requester_id: &str,
+) -> Result<(), AppError> {
+  let requester = requesters
+    .find_requester(requester_id.to_string())
+    .await
+    .map_err(map_requester_error)?;
+
+  match requester {
+    Some(requester) if requester.is_active => Ok(()),
+    Some(_) => Err(AppError::invalid(
+      "requester_id",
+      "requester_id points at an inactive requester".into(),
+    )),
+    None => Err(AppError::invalid(
+      "requester_id",
+      "requester_id does not match any requester".into(),
+    )),
+  }
+}
+
+/// Links every item to an active catalog entry, by `catalog_item_id` or else by name (case and
+/// accents ignored), and rejects the same exam requested twice or already in `requested`
+/// (catalog and panel ids of the items the attendance keeps). A panel also counts as each of its
+/// analytes, so "Glicose" and a panel that includes it cannot be requested together. Errors point
+/// at `field[index]`.
+pub fn resolve_catalog_items(
+  catalog: &[ExamCatalogItemView],
+  items: Vec<CreateAttendanceItemInput>,
+  field: &str,
+  requested: &HashSet<String>,
+) -> Result<Vec<CreateAttendanceItemInput>, AppError> {
+  let mut first_index: HashMap<String, usize> = HashMap::new();
+  let mut resolved = Vec::with_capacity(items.len());
+  for (index, item) in items.into_iter().enumerate() {
+    let entry = find_catalog_entry(catalog, &item, field, index)?;
+    let covered: Vec<&String> = std::iter::once(&entry.id)
+      .chain(&entry.analyte_ids)
+      .collect();
+    if covered.iter().any(|id| requested.contains(*id)) {
+      return Err(AppError::invalid(
+        format!("{field}[{index}]"),
+        format!(
+          "{field}[{index}]: {} is already requested in this attendance",
+          entry.name
+        ),
+      ));
+    }
+    if let Some(first) = covered.iter().find_map(|id| first_index.get(*id)) {
+      return Err(AppError::invalid(
+        format!("{field}[{index}]"),
+        format!(
+          "{field}[{index}]: {} is already requested in {field}[{first}]",
+          entry.name
+        ),
+      ));
+    }
+    for id in covered {
+      first_index.insert(id.clone(), index);
+    }
+
+    resolved.push(CreateAttendanceItemInput {
+      catalog_item_id: Some(entry.id.clone()),
+      ..item
+    });
+  }
+  Ok(resolved)
+}
+
+fn find_catalog_entry<'a>(
+  catalog: &'a [ExamCatalogItemView],
+  item: &CreateAttendanceItemInput,
+  field: &str,
+  index: usize,
+) -> Result<&'a ExamCatalogItemView, AppError> {
+  let catalog_item_id = item
+    .catalog_item_id
+    .as_deref()
+    .map(str::trim)
+    .unwrap_or_default();
+  if !catalog_item_id.is_empty() {
+    return catalog
+      .iter()
+      .find(|entry| entry.id == catalog_item_id)
+      .ok_or_else(|| {
+        AppError::invalid(
+          format!("{field}[{index}]"),
+          format!("{field}[{index}]: catalog_item_id does not match an active catalog entry"),
+        )
+      });
+  }
+
+  let key = catalog_key(&item.name);
+  if key.is_empty() {
+    return Err(AppError::invalid(
+      format!("{field}[{index}]"),
+      format!("{field}[{index}]: name or catalog_item_id is required"),
+    ));
+  }
+  let mut matches = catalog
+    .iter()
+    .filter(|entry| catalog_key(&entry.name) == key);
+  match (matches.next(), matches.next()) {
+    (Some(entry), None) => Ok(entry),
+    (None, _) => Err(AppError::invalid(
+      format!("{field}[{index}]"),
+      format!(
+        "{field}[{index}]: {} does not match any active catalog entry",
+        item.name.trim()
+      ),
+    )),
+    (Some(_), Some(_)) => Err(AppError::invalid(
+      format!("{field}[{index}]"),
+      format!(
+        "{field}[{index}]: {} matches more than one catalog entry; send catalog_item_id",
+        item.name.trim()
+      ),
+    )),
+  }
+}
+
+/// Lowercase, accents folded, whitespace collapsed: `" GLICOSE  em jejum"` == `"Glicose em Jejum"`.
+fn catalog_key(name: &str) -> String {
+  name
+    .to_lowercase()
+    .chars()
+    .map(fold_accent)
+    .collect::<String>()
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
 fn map_repo_error(err: PatientRepositoryError) -> AppError {
   match err {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to create attendance".into())
     }
-    PatientRepositoryError::NotFound => AppError::Database("patient not found".into()),
-    PatientRepositoryError::Conflict => AppError::Database("conflict while creating attendance".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
+    PatientRepositoryError::Conflict => AppError::Conflict("conflict while creating attendance".into()),
+  }
+}
+
+fn map_requester_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch requester".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requester not found".into()),
+    RequesterRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching requester".into())
+    }
   }
 }
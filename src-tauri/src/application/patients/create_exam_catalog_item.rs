@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{CreateExamCatalogItemInput, ExamCatalogItemView},
+      errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct CreateExamCatalogItemUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl CreateExamCatalogItemUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CreateExamCatalogItemInput,
+  ) -> Result<ExamCatalogItemView, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    if input.name.trim().is_empty() {
+      return Err(AppError::invalid("name", "name is required".into()));
+    }
+    if input.category_id.trim().is_empty() {
+      return Err(AppError::invalid(
+        "category_id",
+        "category_id is required".into(),
+      ));
+    }
+    if input.price_cents < 0 {
+      return Err(AppError::invalid(
+        "price_cents",
+        "price_cents must not be negative".into(),
+      ));
+    }
+
+    self
+      .repo
+      .create_exam_catalog_item(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to persist exam catalog item".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam category not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while saving exam catalog item".into())
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{CreateExamCategoryInput, ExamCategoryView},
+      errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct CreateExamCategoryUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl CreateExamCategoryUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CreateExamCategoryInput,
+  ) -> Result<ExamCategoryView, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    let id = input.id.trim();
+    if id.is_empty() {
+      return Err(AppError::invalid("id", "id is required".into()));
+    }
+    if !id
+      .chars()
+      .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+      return Err(AppError::invalid(
+        "id",
+        "id must contain only lowercase letters, digits or hyphens".into(),
+      ));
+    }
+    if input.title.trim().is_empty() {
+      return Err(AppError::invalid("title", "title is required".into()));
+    }
+
+    self
+      .repo
+      .create_exam_category(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to persist exam category".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam category not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while saving exam category".into())
+    }
+  }
+}
@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::{CreatePatientInput, PatientView},
-    entity::Patient,
-    errors::{PatientDomainError, PatientRepositoryError},
-    ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      cpf::Cpf,
+      dto::{CreatePatientInput, PatientView},
+      entity::Patient,
+      errors::{PatientDomainError, PatientRepositoryError},
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -19,12 +24,18 @@ impl CreatePatientUseCase {
     Self { repo }
   }
 
-  pub async fn execute(&self, input: CreatePatientInput) -> Result<PatientView, AppError> {
-    validate_create_input(&input)?;
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CreatePatientInput,
+  ) -> Result<PatientView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let input = validate_create_input(input)?;
 
     let persisted = self
       .repo
-      .insert(input)
+      .insert(input, Some(actor.id.clone()))
       .await
       .map_err(map_repo_error)?;
 
@@ -45,20 +56,40 @@ impl CreatePatientUseCase {
   }
 }
 
-fn validate_create_input(input: &CreatePatientInput) -> Result<(), AppError> {
-  if input.full_name.trim().is_empty() {
-    return Err(AppError::Validation("full_name is required".into()));
-  }
-  if input.cpf.trim().is_empty() {
-    return Err(AppError::Validation("cpf is required".into()));
+fn validate_create_input(input: CreatePatientInput) -> Result<CreatePatientInput, AppError> {
+  let cpf = validate_patient_fields(&input.full_name, &input.cpf)?;
+
+  Ok(CreatePatientInput { cpf, ..input })
+}
+
+/// Checks shared by creation and `update_patient`. Returns the CPF in canonical form, so the
+/// UNIQUE constraint sees `123.456.789-09` and `12345678909` as the same patient.
+pub(crate) fn validate_patient_fields(full_name: &str, cpf: &str) -> Result<String, AppError> {
+  if full_name.trim().is_empty() {
+    return Err(AppError::invalid(
+      "full_name",
+      "full_name is required".into(),
+    ));
   }
-  Ok(())
+  let cpf = Cpf::parse(cpf).map_err(map_domain_error)?;
+
+  Ok(cpf.into_inner())
 }
 
 fn map_domain_error(err: PatientDomainError) -> AppError {
   match err {
-    PatientDomainError::FullNameRequired => AppError::Validation("full_name is required".into()),
-    PatientDomainError::CpfRequired => AppError::Validation("cpf is required".into()),
+    PatientDomainError::FullNameRequired => {
+      AppError::invalid("full_name", "full_name is required".into())
+    }
+    PatientDomainError::CpfRequired => AppError::invalid("cpf", "cpf is required".into()),
+    PatientDomainError::InvalidCpf(msg) => AppError::invalid("cpf", msg),
+    PatientDomainError::UnknownAttendanceStatus(status) => {
+      AppError::validation(format!("unknown attendance status: {status}"))
+    }
+    PatientDomainError::InvalidStatusTransition { from, to } => {
+      AppError::validation(format!("cannot move attendance from {from} to {to}"))
+    }
+    PatientDomainError::InvalidReferenceRange(msg) => AppError::validation(msg),
   }
 }
 
@@ -67,8 +98,8 @@ fn map_repo_error(err: PatientRepositoryError) -> AppError {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to persist patient".into())
     }
-    PatientRepositoryError::Conflict => AppError::Database("conflict while saving patient".into()),
-    PatientRepositoryError::NotFound => AppError::Database("patient not found".into()),
+    PatientRepositoryError::Conflict => AppError::Conflict("cpf already registered".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
   }
 }
 
@@ -83,5 +114,6 @@ fn to_view(p: Patient) -> PatientView {
     address: p.address,
     created_at: p.created_at,
     updated_at: p.updated_at,
+    deleted_at: p.deleted_at,
   }
 }
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{DeactivateExamCatalogItemInput, ExamCatalogItemView},
+      errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct DeactivateExamCatalogItemUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl DeactivateExamCatalogItemUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: DeactivateExamCatalogItemInput,
+  ) -> Result<ExamCatalogItemView, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    if input.id.trim().is_empty() {
+      return Err(AppError::invalid("id", "id is required".into()));
+    }
+
+    self
+      .repo
+      .deactivate_exam_catalog_item(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to deactivate exam catalog item".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while deactivating exam catalog item".into())
+    }
+  }
+}
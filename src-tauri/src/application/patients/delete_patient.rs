@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{PatientDeletionInput, PatientView},
+      entity::Patient,
+      errors::PatientRepositoryError,
+      ports::RecordDeletionRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct DeletePatientUseCase {
+  repo: Arc<dyn RecordDeletionRepository>,
+}
+
+impl DeletePatientUseCase {
+  pub fn new(repo: Arc<dyn RecordDeletionRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: PatientDeletionInput,
+  ) -> Result<PatientView, AppError> {
+    authorize(actor, Permission::DeleteRecords)?;
+
+    let patient_id = input.patient_id.trim().to_string();
+    if patient_id.is_empty() {
+      return Err(AppError::invalid(
+        "patient_id",
+        "patient_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .set_patient_deleted(patient_id, true, Some(actor.id.clone()))
+      .await
+      .map(to_view)
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to delete patient".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
+    PatientRepositoryError::Conflict => AppError::Conflict("patient is already deleted".into()),
+  }
+}
+
+fn to_view(p: Patient) -> PatientView {
+  PatientView {
+    id: p.id,
+    full_name: p.full_name,
+    cpf: p.cpf,
+    birth_date: p.birth_date,
+    sex: p.sex,
+    phone: p.phone,
+    address: p.address,
+    created_at: p.created_at,
+    updated_at: p.updated_at,
+    deleted_at: p.deleted_at,
+  }
+}
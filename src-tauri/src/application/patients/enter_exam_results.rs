@@ -0,0 +1,166 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{DeltaCheckView, EnterExamResultsInput, EnterExamResultsView},
+      errors::PatientRepositoryError,
+      ports::AttendanceRepository,
+      reference_range::{age_in_years, compute_result_flag},
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+const MAX_RESULT_FLAG_LEN: usize = 20;
+
+pub struct EnterExamResultsUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+}
+
+impl EnterExamResultsUseCase {
+  pub fn new(repo: Arc<dyn AttendanceRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Saves the results and flags them against the structured reference ranges of each catalog
+  /// exam. A manually informed `result_flag` is kept only when no range applies to the patient.
+  /// Each result is also compared with the patient's previous released value of the same exam.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: EnterExamResultsInput,
+  ) -> Result<EnterExamResultsView, AppError> {
+    authorize(actor, Permission::EnterResults)?;
+
+    validate_input(&input)?;
+
+    let context = self
+      .repo
+      .get_result_entry_context(input.attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let accepts_results = AttendanceStatus::parse(&context.status)
+      .map(|status| status.accepts_results())
+      .unwrap_or(false);
+    if !accepts_results {
+      return Err(AppError::validation(format!(
+        "results cannot be entered while attendance is {}",
+        context.status
+      )));
+    }
+
+    let age_years = age_in_years(&context.patient_birth_date, &context.exam_date);
+    let mut delta_checks = Vec::new();
+    for item in &mut input.items {
+      let exam_item_id = item.exam_item_id.trim();
+      let Some(item_context) = context
+        .items
+        .iter()
+        .find(|candidate| candidate.exam_item_id == exam_item_id)
+      else {
+        return Err(AppError::validation(format!(
+          "exam_item_id {exam_item_id} does not belong to attendance"
+        )));
+      };
+
+      if let Some(flag) = compute_result_flag(
+        &item.result_value,
+        &item_context.reference_ranges,
+        &context.patient_sex,
+        age_years,
+      ) {
+        item.result_flag = Some(flag.as_str().to_string());
+      }
+
+      if let Some(previous) = &item_context.previous_result {
+        let current_value = item.result_value.trim();
+        let change = item_context
+          .delta_check
+          .compare(&previous.result_value, current_value);
+        delta_checks.push(DeltaCheckView {
+          exam_item_id: exam_item_id.to_string(),
+          previous_attendance_id: previous.attendance_id.clone(),
+          previous_exam_date: previous.exam_date.clone(),
+          previous_value: previous.result_value.clone(),
+          current_value: current_value.to_string(),
+          absolute_change: change.as_ref().map(|change| change.absolute_change),
+          percent_change: change.as_ref().and_then(|change| change.percent_change),
+          exceeds_threshold: change.is_some_and(|change| change.exceeds_threshold),
+        });
+      }
+    }
+
+    let entry = self
+      .repo
+      .enter_exam_results(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)?;
+
+    Ok(EnterExamResultsView {
+      entry,
+      delta_checks,
+    })
+  }
+}
+
+fn validate_input(input: &EnterExamResultsInput) -> Result<(), AppError> {
+  if input.attendance_id.trim().is_empty() {
+    return Err(AppError::invalid(
+      "attendance_id",
+      "attendance_id is required".into(),
+    ));
+  }
+  if input.items.is_empty() {
+    return Err(AppError::invalid("items", "items is required".into()));
+  }
+
+  let mut seen = HashSet::new();
+  for item in &input.items {
+    let exam_item_id = item.exam_item_id.trim();
+    if exam_item_id.is_empty() {
+      return Err(AppError::invalid(
+        "exam_item_id",
+        "exam_item_id is required".into(),
+      ));
+    }
+    if !seen.insert(exam_item_id) {
+      return Err(AppError::validation(format!(
+        "exam_item_id {exam_item_id} is repeated"
+      )));
+    }
+    if item.result_value.trim().is_empty() {
+      return Err(AppError::invalid(
+        "result_value",
+        "result_value is required".into(),
+      ));
+    }
+    if let Some(flag) = &item.result_flag {
+      if flag.trim().chars().count() > MAX_RESULT_FLAG_LEN {
+        return Err(AppError::invalid(
+          "result_flag",
+          format!("result_flag must have at most {MAX_RESULT_FLAG_LEN} characters"),
+        ));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to save exam results".into())
+    }
+    PatientRepositoryError::NotFound => {
+      AppError::NotFound("attendance or exam item not found".into())
+    }
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while saving exam results".into())
+    }
+  }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{DuplicatePatientsView, FindDuplicatePatientsInput, PatientPageQuery, PatientView},
+      duplicates::{find_duplicates, DEFAULT_MIN_SCORE},
+      entity::Patient,
+      errors::PatientRepositoryError,
+      pagination::{PageCursor, PatientSort, MAX_PAGE_LIMIT},
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct FindDuplicatePatientsUseCase {
+  repo: Arc<dyn PatientRepository>,
+}
+
+impl FindDuplicatePatientsUseCase {
+  pub fn new(repo: Arc<dyn PatientRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: FindDuplicatePatientsInput,
+  ) -> Result<Vec<DuplicatePatientsView>, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let min_score = input.min_score.unwrap_or(DEFAULT_MIN_SCORE);
+    if !(0..=100).contains(&min_score) {
+      return Err(AppError::invalid(
+        "min_score",
+        "min_score must be between 0 and 100".into(),
+      ));
+    }
+    let patient_id = input
+      .patient_id
+      .map(|id| id.trim().to_string())
+      .filter(|id| !id.is_empty());
+
+    // Duplicates can sit anywhere in the list, so walk every page.
+    let mut patients = Vec::new();
+    let sort = PatientSort::default();
+    let mut after = None;
+    loop {
+      let page = self
+        .repo
+        .list(PatientPageQuery {
+          query: None,
+          include_deleted: false,
+          sort,
+          after,
+          limit: MAX_PAGE_LIMIT,
+        })
+        .await
+        .map_err(map_repo_error)?;
+      patients.extend(page.items);
+      after = match page.next_cursor {
+        Some(cursor) => PageCursor::decode(&cursor, sort.as_str(), sort.cursor_keys()),
+        None => None,
+      };
+      if after.is_none() {
+        break;
+      }
+    }
+    if let Some(id) = &patient_id {
+      if !patients.iter().any(|p| &p.id == id) {
+        return Err(AppError::NotFound("patient not found".into()));
+      }
+    }
+
+    Ok(
+      find_duplicates(&patients, patient_id.as_deref(), min_score)
+        .into_iter()
+        .map(|pair| DuplicatePatientsView {
+          patient: to_view(pair.patient),
+          duplicate: to_view(pair.duplicate),
+          score: pair.score.score,
+          reasons: pair.score.reasons,
+        })
+        .collect(),
+    )
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch patients".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching patients".into())
+    }
+  }
+}
+
+fn to_view(p: &Patient) -> PatientView {
+  PatientView {
+    id: p.id.clone(),
+    full_name: p.full_name.clone(),
+    cpf: p.cpf.clone(),
+    birth_date: p.birth_date.clone(),
+    sex: p.sex.clone(),
+    phone: p.phone.clone(),
+    address: p.address.clone(),
+    created_at: p.created_at.clone(),
+    updated_at: p.updated_at.clone(),
+    deleted_at: p.deleted_at.clone(),
+  }
+}
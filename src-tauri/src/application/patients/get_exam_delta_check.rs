@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      delta_check::DeltaCheckRule, errors::PatientRepositoryError, ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct GetExamDeltaCheckUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl GetExamDeltaCheckUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    catalog_item_id: String,
+  ) -> Result<DeltaCheckRule, AppError> {
+    authorize(actor, Permission::ViewCatalog)?;
+
+    let catalog_item_id = catalog_item_id.trim().to_string();
+    if catalog_item_id.is_empty() {
+      return Err(AppError::invalid(
+        "catalog_item_id",
+        "catalog_item_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .get_exam_delta_check(catalog_item_id)
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch delta check".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching delta check".into())
+    }
+  }
+}
@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::PatientRecordView, errors::PatientRepositoryError, ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::PatientRecordView, errors::PatientRepositoryError, ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -16,14 +20,27 @@ impl GetPatientRecordUseCase {
     Self { repo }
   }
 
-  pub async fn execute(&self, patient_id: String) -> Result<PatientRecordView, AppError> {
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    patient_id: String,
+    include_deleted: bool,
+  ) -> Result<PatientRecordView, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+    if include_deleted {
+      authorize(actor, Permission::DeleteRecords)?;
+    }
+
     if patient_id.trim().is_empty() {
-      return Err(AppError::Validation("patient_id is required".into()));
+      return Err(AppError::invalid(
+        "patient_id",
+        "patient_id is required".into(),
+      ));
     }
 
     self
       .repo
-      .get_patient_record(patient_id)
+      .get_patient_record(patient_id, include_deleted)
       .await
       .map_err(map_repo_error)
   }
@@ -34,9 +51,9 @@ fn map_repo_error(err: PatientRepositoryError) -> AppError {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to fetch patient record".into())
     }
-    PatientRepositoryError::NotFound => AppError::Database("patient not found".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
     PatientRepositoryError::Conflict => {
-      AppError::Database("conflict while fetching patient record".into())
+      AppError::Conflict("conflict while fetching patient record".into())
     }
   }
 }
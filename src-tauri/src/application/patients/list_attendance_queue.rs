@@ -2,10 +2,16 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::{AttendanceQueueItemView, AttendanceQueueQueryInput},
-    errors::PatientRepositoryError,
-    ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{AttendanceQueueItemView, AttendanceQueuePageQuery, AttendanceQueueQueryInput},
+      errors::PatientRepositoryError,
+      pagination::{resolve_page_limit, AttendanceSort, PageCursor, PageView, MAX_PAGE_LIMIT},
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -20,31 +26,89 @@ impl ListAttendanceQueueUseCase {
 
   pub async fn execute(
     &self,
+    actor: &UserView,
     input: AttendanceQueueQueryInput,
-  ) -> Result<Vec<AttendanceQueueItemView>, AppError> {
-    if let Some(date) = &input.date {
+  ) -> Result<PageView<AttendanceQueueItemView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+    let include_deleted = input.include_deleted.unwrap_or(false);
+    if include_deleted {
+      authorize(actor, Permission::DeleteRecords)?;
+    }
+    let date = non_blank(input.date);
+    let status = non_blank(input.status);
+
+    if let Some(date) = &date {
       if !is_date_only(date) {
-        return Err(AppError::Validation("date must be YYYY-MM-DD".into()));
+        return Err(AppError::invalid("date", "date must be YYYY-MM-DD".into()));
       }
     }
-    if let Some(status) = &input.status {
-      if status != "waiting" && status != "completed" {
-        return Err(AppError::Validation("status must be waiting or completed".into()));
+    if let Some(status) = &status {
+      if AttendanceStatus::parse(status).is_err() {
+        let allowed: Vec<&str> = AttendanceStatus::ALL.iter().map(|s| s.as_str()).collect();
+        return Err(AppError::invalid(
+          "status",
+          format!("status must be one of {}", allowed.join(", ")),
+        ));
       }
     }
 
-    self.repo.list_attendance_queue(input).await.map_err(map_repo_error)
+    let sort = match input.sort.as_deref() {
+      Some(value) => AttendanceSort::parse(value).ok_or_else(|| {
+        let allowed: Vec<&str> = AttendanceSort::ALL.iter().map(|s| s.as_str()).collect();
+        AppError::invalid(
+          "sort",
+          format!("sort must be one of {}", allowed.join(", ")),
+        )
+      })?,
+      None => AttendanceSort::default(),
+    };
+    let limit = resolve_page_limit(input.limit).ok_or_else(|| {
+      AppError::invalid(
+        "limit",
+        format!("limit must be between 1 and {MAX_PAGE_LIMIT}"),
+      )
+    })?;
+    let include_cancelled = input.include_cancelled.unwrap_or(false)
+      || status.as_deref() == Some(AttendanceStatus::Cancelled.as_str());
+    let after = match input.cursor.as_deref() {
+      Some(cursor) => Some(
+        PageCursor::decode(cursor, sort.as_str(), sort.cursor_keys())
+          .ok_or_else(|| AppError::invalid("cursor", "cursor is invalid for this sort".into()))?,
+      ),
+      None => None,
+    };
+
+    self
+      .repo
+      .list_attendance_queue(AttendanceQueuePageQuery {
+        date,
+        status,
+        query: non_blank(input.query),
+        include_deleted,
+        include_cancelled,
+        sort,
+        after,
+        limit,
+      })
+      .await
+      .map_err(map_repo_error)
   }
 }
 
+fn non_blank(value: Option<String>) -> Option<String> {
+  value
+    .map(|value| value.trim().to_string())
+    .filter(|value| !value.is_empty())
+}
+
 fn map_repo_error(err: PatientRepositoryError) -> AppError {
   match err {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to fetch attendance queue".into())
     }
-    PatientRepositoryError::NotFound => AppError::Database("attendance not found".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
     PatientRepositoryError::Conflict => {
-      AppError::Database("conflict while fetching attendance queue".into())
+      AppError::Conflict("conflict while fetching attendance queue".into())
     }
   }
 }
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::AttendanceStatusHistoryView, errors::PatientRepositoryError, ports::AttendanceRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListAttendanceStatusHistoryUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+}
+
+impl ListAttendanceStatusHistoryUseCase {
+  pub fn new(repo: Arc<dyn AttendanceRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+
+    if attendance_id.trim().is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .list_attendance_status_history(attendance_id)
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch attendance status history".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching attendance status history".into())
+    }
+  }
+}
@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::ExamCatalogItemView, errors::PatientRepositoryError, ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::ExamCatalogItemView, errors::PatientRepositoryError, ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -16,8 +20,18 @@ impl ListExamCatalogUseCase {
     Self { repo }
   }
 
-  pub async fn execute(&self) -> Result<Vec<ExamCatalogItemView>, AppError> {
-    self.repo.list_exam_catalog().await.map_err(map_repo_error)
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, AppError> {
+    authorize(actor, Permission::ViewCatalog)?;
+
+    self
+      .repo
+      .list_exam_catalog(include_inactive)
+      .await
+      .map_err(map_repo_error)
   }
 }
 
@@ -26,9 +40,9 @@ fn map_repo_error(err: PatientRepositoryError) -> AppError {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to fetch exam catalog".into())
     }
-    PatientRepositoryError::NotFound => AppError::Database("exam catalog not found".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog not found".into()),
     PatientRepositoryError::Conflict => {
-      AppError::Database("conflict while fetching exam catalog".into())
+      AppError::Conflict("conflict while fetching exam catalog".into())
     }
   }
 }
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::ExamCategoryView, errors::PatientRepositoryError, ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListExamCategoriesUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl ListExamCategoriesUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(&self, actor: &UserView) -> Result<Vec<ExamCategoryView>, AppError> {
+    authorize(actor, Permission::ViewCatalog)?;
+
+    self.repo.list_exam_categories().await.map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch exam categories".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam category not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching exam categories".into())
+    }
+  }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      errors::PatientRepositoryError, ports::ExamCatalogRepository, reference_range::ReferenceRange,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListExamReferenceRangesUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl ListExamReferenceRangesUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    catalog_item_id: String,
+  ) -> Result<Vec<ReferenceRange>, AppError> {
+    authorize(actor, Permission::ViewCatalog)?;
+
+    let catalog_item_id = catalog_item_id.trim().to_string();
+    if catalog_item_id.is_empty() {
+      return Err(AppError::invalid(
+        "catalog_item_id",
+        "catalog_item_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .list_exam_reference_ranges(catalog_item_id)
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch reference ranges".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching reference ranges".into())
+    }
+  }
+}
@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use crate::{
   app::error::AppError,
-  domain::patients::{
-    dto::PatientView,
-    entity::Patient,
-    errors::PatientRepositoryError,
-    ports::PatientRepository,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{ListPatientsInput, PatientPageQuery, PatientView},
+      entity::Patient,
+      errors::PatientRepositoryError,
+      pagination::{resolve_page_limit, PageCursor, PageView, PatientSort, MAX_PAGE_LIMIT},
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
   },
 };
 
@@ -19,9 +24,70 @@ impl ListPatientsUseCase {
     Self { repo }
   }
 
-  pub async fn execute(&self, query: Option<String>) -> Result<Vec<PatientView>, AppError> {
-    let patients = self.repo.list(query).await.map_err(map_repo_error)?;
-    Ok(patients.into_iter().map(to_view).collect())
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: ListPatientsInput,
+  ) -> Result<PageView<PatientView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+    let include_deleted = input.include_deleted.unwrap_or(false);
+    if include_deleted {
+      authorize(actor, Permission::DeleteRecords)?;
+    }
+
+    let query = input
+      .query
+      .map(|query| query.trim().to_string())
+      .filter(|query| !query.is_empty());
+    let sort = match input.sort.as_deref() {
+      Some(value) => PatientSort::parse(value).ok_or_else(|| {
+        let allowed: Vec<&str> = PatientSort::ALL.iter().map(|s| s.as_str()).collect();
+        AppError::invalid(
+          "sort",
+          format!("sort must be one of {}", allowed.join(", ")),
+        )
+      })?,
+      // A search is best read best-match first.
+      None if query.is_some() => PatientSort::Relevance,
+      None => PatientSort::default(),
+    };
+    if sort == PatientSort::Relevance && query.is_none() {
+      return Err(AppError::invalid(
+        "sort",
+        "sort relevance requires a query".into(),
+      ));
+    }
+    let limit = resolve_page_limit(input.limit).ok_or_else(|| {
+      AppError::invalid(
+        "limit",
+        format!("limit must be between 1 and {MAX_PAGE_LIMIT}"),
+      )
+    })?;
+    let after = match input.cursor.as_deref() {
+      Some(cursor) => Some(
+        PageCursor::decode(cursor, sort.as_str(), sort.cursor_keys())
+          .filter(|cursor| sort.accepts(cursor))
+          .ok_or_else(|| AppError::invalid("cursor", "cursor is invalid for this sort".into()))?,
+      ),
+      None => None,
+    };
+
+    let page = self
+      .repo
+      .list(PatientPageQuery {
+        query,
+        include_deleted,
+        sort,
+        after,
+        limit,
+      })
+      .await
+      .map_err(map_repo_error)?;
+    Ok(PageView {
+      items: page.items.into_iter().map(to_view).collect(),
+      next_cursor: page.next_cursor,
+      total: page.total,
+    })
   }
 }
 
@@ -30,8 +96,8 @@ fn map_repo_error(err: PatientRepositoryError) -> AppError {
     PatientRepositoryError::PersistenceError => {
       AppError::Database("failed to fetch patients".into())
     }
-    PatientRepositoryError::Conflict => AppError::Database("conflict while fetching patients".into()),
-    PatientRepositoryError::NotFound => AppError::Database("patient not found".into()),
+    PatientRepositoryError::Conflict => AppError::Conflict("conflict while fetching patients".into()),
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
   }
 }
 
@@ -46,5 +112,6 @@ fn to_view(p: Patient) -> PatientView {
     address: p.address,
     created_at: p.created_at,
     updated_at: p.updated_at,
+    deleted_at: p.deleted_at,
   }
 }
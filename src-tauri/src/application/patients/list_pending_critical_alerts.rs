@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::CriticalAlertView, errors::PatientRepositoryError, ports::CriticalAlertRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListPendingCriticalAlertsUseCase {
+  repo: Arc<dyn CriticalAlertRepository>,
+}
+
+impl ListPendingCriticalAlertsUseCase {
+  pub fn new(repo: Arc<dyn CriticalAlertRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Panic values still waiting for someone to notify the requester, oldest first.
+  pub async fn execute(&self, actor: &UserView) -> Result<Vec<CriticalAlertView>, AppError> {
+    authorize(actor, Permission::EnterResults)?;
+
+    self
+      .repo
+      .list_pending_critical_alerts()
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch critical alerts".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("critical alerts not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching critical alerts".into())
+    }
+  }
+}
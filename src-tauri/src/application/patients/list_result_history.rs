@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::ResultHistoryView, errors::PatientRepositoryError, ports::AttendanceRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListResultHistoryUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+}
+
+impl ListResultHistoryUseCase {
+  pub fn new(repo: Arc<dyn AttendanceRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+
+    if exam_item_id.trim().is_empty() {
+      return Err(AppError::invalid(
+        "exam_item_id",
+        "exam_item_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .list_result_history(exam_item_id)
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch result history".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching result history".into())
+    }
+  }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{MergePatientsInput, MergePatientsResultView},
+      errors::PatientRepositoryError,
+      ports::PatientMergeRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct MergePatientsUseCase {
+  repo: Arc<dyn PatientMergeRepository>,
+}
+
+impl MergePatientsUseCase {
+  pub fn new(repo: Arc<dyn PatientMergeRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: MergePatientsInput,
+  ) -> Result<MergePatientsResultView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let surviving_patient_id = input.surviving_patient_id.trim().to_string();
+    let merged_patient_id = input.merged_patient_id.trim().to_string();
+    if surviving_patient_id.is_empty() {
+      return Err(AppError::invalid(
+        "surviving_patient_id",
+        "surviving_patient_id is required".into(),
+      ));
+    }
+    if merged_patient_id.is_empty() {
+      return Err(AppError::invalid(
+        "merged_patient_id",
+        "merged_patient_id is required".into(),
+      ));
+    }
+    if surviving_patient_id == merged_patient_id {
+      return Err(AppError::invalid(
+        "merged_patient_id",
+        "merged_patient_id must differ from surviving_patient_id".into(),
+      ));
+    }
+
+    self
+      .repo
+      .merge_patients(
+        MergePatientsInput {
+          surviving_patient_id,
+          merged_patient_id,
+        },
+        Some(actor.id.clone()),
+      )
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to merge patients".into())
+    }
+    PatientRepositoryError::NotFound => {
+      AppError::NotFound("patient not found or already merged".into())
+    }
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while merging patients".into())
+    }
+  }
+}
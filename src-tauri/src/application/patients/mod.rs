@@ -1,7 +1,34 @@
+pub mod acknowledge_critical_alert;
+pub mod amend_result;
+pub mod cancel_attendance;
 pub mod complete_attendance;
 pub mod create_attendance;
+pub mod create_exam_catalog_item;
+pub mod create_exam_category;
 pub mod create_patient;
+pub mod deactivate_exam_catalog_item;
+pub mod delete_attendance;
+pub mod delete_patient;
+pub mod enter_exam_results;
+pub mod find_duplicate_patients;
+pub mod get_exam_delta_check;
 pub mod get_patient_record;
 pub mod list_attendance_queue;
+pub mod list_attendance_status_history;
 pub mod list_exam_catalog;
+pub mod list_exam_categories;
+pub mod list_exam_reference_ranges;
 pub mod list_patients;
+pub mod list_pending_critical_alerts;
+pub mod list_result_history;
+pub mod merge_patients;
+pub mod release_attendance;
+pub mod restore_attendance;
+pub mod restore_patient;
+pub mod set_exam_delta_check;
+pub mod set_exam_panel_analytes;
+pub mod set_exam_reference_ranges;
+pub mod transition_attendance;
+pub mod update_attendance;
+pub mod update_exam_catalog_item;
+pub mod update_patient;
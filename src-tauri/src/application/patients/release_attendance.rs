@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{AttendanceQueueItemView, AttendanceStatusChange, ReleaseAttendanceInput},
+      errors::{PatientDomainError, PatientRepositoryError},
+      ports::AttendanceRepository,
+    },
+    users::{
+      dto::UserView,
+      errors::UserRepositoryError,
+      permission::Permission,
+      ports::{PasswordHasher, UserRepository},
+    },
+  },
+};
+
+pub struct ReleaseAttendanceUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+  users: Arc<dyn UserRepository>,
+  hasher: Arc<dyn PasswordHasher>,
+}
+
+impl ReleaseAttendanceUseCase {
+  pub fn new(
+    repo: Arc<dyn AttendanceRepository>,
+    users: Arc<dyn UserRepository>,
+    hasher: Arc<dyn PasswordHasher>,
+  ) -> Self {
+    Self {
+      repo,
+      users,
+      hasher,
+    }
+  }
+
+  /// Technical validation: once every item has a result and every critical value was
+  /// acknowledged, the biochemist re-enters their password to sign the attendance. Signed items
+  /// no longer accept result changes.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: ReleaseAttendanceInput,
+  ) -> Result<AttendanceQueueItemView, AppError> {
+    authorize(actor, Permission::ReleaseResults)?;
+
+    let attendance_id = input.attendance_id.trim().to_string();
+    if attendance_id.is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+    if input.password.is_empty() {
+      return Err(AppError::invalid("password", "password is required".into()));
+    }
+
+    let context = self
+      .repo
+      .get_attendance_edit_context(attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let current_status = AttendanceStatus::parse(&context.status).map_err(map_domain_error)?;
+    let target = current_status
+      .transition_to(AttendanceStatus::Released)
+      .map_err(map_domain_error)?;
+    if context.items.is_empty() {
+      return Err(AppError::validation("attendance has no exam items".into()));
+    }
+    if let Some(pending) = context.items.iter().find(|item| !item.has_result) {
+      return Err(AppError::invalid(
+        "items",
+        format!("items: {} has no result yet", pending.exam_item_id),
+      ));
+    }
+    if let Some(critical) = context
+      .items
+      .iter()
+      .find(|item| item.has_pending_critical_alert)
+    {
+      return Err(AppError::invalid(
+        "items",
+        format!(
+          "items: {} has an unacknowledged critical value",
+          critical.exam_item_id
+        ),
+      ));
+    }
+
+    self.verify_signature(actor, &input.password).await?;
+
+    self
+      .repo
+      .release_attendance(AttendanceStatusChange {
+        attendance_id,
+        from_status: current_status.as_str().to_string(),
+        to_status: target.as_str().to_string(),
+        changed_by_user_id: Some(actor.id.clone()),
+        note: input.note,
+      })
+      .await
+      .map_err(map_repo_error)
+  }
+
+  /// The password must belong to the signed-in user, who must still be active.
+  async fn verify_signature(&self, actor: &UserView, password: &str) -> Result<(), AppError> {
+    let credentials = self
+      .users
+      .find_credentials_by_username(actor.username.clone())
+      .await
+      .map_err(map_user_repo_error)?;
+    let signed = credentials.is_some_and(|credentials| {
+      credentials.user.id == actor.id
+        && credentials.user.is_active
+        && self.hasher.verify(password, &credentials.password_hash)
+    });
+    if !signed {
+      return Err(AppError::invalid(
+        "password",
+        "password: does not match the signed-in user".into(),
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn map_domain_error(err: PatientDomainError) -> AppError {
+  match err {
+    PatientDomainError::UnknownAttendanceStatus(status) => {
+      AppError::validation(format!("unknown attendance status: {status}"))
+    }
+    PatientDomainError::InvalidStatusTransition { from, to } => {
+      AppError::validation(format!("cannot move attendance from {from} to {to}"))
+    }
+    other => AppError::Unexpected(format!("{other:?}")),
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to release attendance".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    // The status moved, a result was cleared or a critical value appeared since the attendance
+    // was read.
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("attendance was changed by someone else".into())
+    }
+  }
+}
+
+fn map_user_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to fetch user".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("user not found".into()),
+    UserRepositoryError::Conflict => AppError::Conflict("conflict while fetching user".into()),
+  }
+}
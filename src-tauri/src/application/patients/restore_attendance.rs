@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{AttendanceDeletionInput, AttendanceQueueItemView},
+      errors::PatientRepositoryError,
+      ports::RecordDeletionRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct RestoreAttendanceUseCase {
+  repo: Arc<dyn RecordDeletionRepository>,
+}
+
+impl RestoreAttendanceUseCase {
+  pub fn new(repo: Arc<dyn RecordDeletionRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: AttendanceDeletionInput,
+  ) -> Result<AttendanceQueueItemView, AppError> {
+    authorize(actor, Permission::DeleteRecords)?;
+
+    let attendance_id = input.attendance_id.trim().to_string();
+    if attendance_id.is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .set_attendance_deleted(attendance_id, false, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to restore attendance".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    PatientRepositoryError::Conflict => AppError::Conflict("attendance is not deleted".into()),
+  }
+}
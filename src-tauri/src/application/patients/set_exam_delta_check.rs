@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      delta_check::DeltaCheckRule, dto::SetExamDeltaCheckInput, errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct SetExamDeltaCheckUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl SetExamDeltaCheckUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Replaces the delta-check thresholds of the catalog item; both unset turns the warning off.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: SetExamDeltaCheckInput,
+  ) -> Result<DeltaCheckRule, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    input.catalog_item_id = input.catalog_item_id.trim().to_string();
+    if input.catalog_item_id.is_empty() {
+      return Err(AppError::invalid(
+        "catalog_item_id",
+        "catalog_item_id is required".into(),
+      ));
+    }
+    for (field, threshold) in [
+      ("absolute_threshold", input.rule.absolute_threshold),
+      ("percent_threshold", input.rule.percent_threshold),
+    ] {
+      if threshold.is_some_and(|value| !value.is_finite() || value <= 0.0) {
+        return Err(AppError::invalid(
+          field,
+          format!("{field} must be greater than zero"),
+        ));
+      }
+    }
+
+    self
+      .repo
+      .set_exam_delta_check(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to save delta check".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while saving delta check".into())
+    }
+  }
+}
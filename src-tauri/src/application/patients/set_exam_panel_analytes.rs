@@ -0,0 +1,83 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{ExamCatalogItemView, SetExamPanelAnalytesInput},
+      errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct SetExamPanelAnalytesUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl SetExamPanelAnalytesUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Replaces the analytes of a panel, in report order; an empty list turns it back into a
+  /// single exam. Attendances already created keep the items they were expanded into.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: SetExamPanelAnalytesInput,
+  ) -> Result<ExamCatalogItemView, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    input.panel_id = input.panel_id.trim().to_string();
+    if input.panel_id.is_empty() {
+      return Err(AppError::invalid("panel_id", "panel_id is required".into()));
+    }
+
+    let mut first_index: HashMap<String, usize> = HashMap::new();
+    for (index, analyte_id) in input.analyte_ids.iter_mut().enumerate() {
+      *analyte_id = analyte_id.trim().to_string();
+      if analyte_id.is_empty() {
+        return Err(AppError::invalid(
+          format!("analyte_ids[{index}]"),
+          format!("analyte_ids[{index}] is required"),
+        ));
+      }
+      if *analyte_id == input.panel_id {
+        return Err(AppError::invalid(
+          format!("analyte_ids[{index}]"),
+          format!("analyte_ids[{index}]: a panel cannot contain itself"),
+        ));
+      }
+      if let Some(first) = first_index.get(analyte_id.as_str()) {
+        return Err(AppError::invalid(
+          format!("analyte_ids[{index}]"),
+          format!("analyte_ids[{index}]: {analyte_id} is already listed in analyte_ids[{first}]"),
+        ));
+      }
+      first_index.insert(analyte_id.clone(), index);
+    }
+
+    self
+      .repo
+      .set_exam_panel_analytes(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to save panel analytes".into())
+    }
+    PatientRepositoryError::NotFound => {
+      AppError::NotFound("exam catalog item or analyte not found".into())
+    }
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("panels cannot contain other panels".into())
+    }
+  }
+}
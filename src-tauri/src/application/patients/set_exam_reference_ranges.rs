@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::SetExamReferenceRangesInput,
+      errors::{PatientDomainError, PatientRepositoryError},
+      ports::ExamCatalogRepository,
+      reference_range::ReferenceRange,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct SetExamReferenceRangesUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl SetExamReferenceRangesUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// Replaces every reference range of the catalog item with `input.ranges`.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    mut input: SetExamReferenceRangesInput,
+  ) -> Result<Vec<ReferenceRange>, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    input.catalog_item_id = input.catalog_item_id.trim().to_string();
+    if input.catalog_item_id.is_empty() {
+      return Err(AppError::invalid(
+        "catalog_item_id",
+        "catalog_item_id is required".into(),
+      ));
+    }
+
+    for (index, range) in input.ranges.iter_mut().enumerate() {
+      range.sex = range.sex.take().map(|sex| sex.trim().to_uppercase()).filter(|sex| !sex.is_empty());
+      range.validate().map_err(|err| {
+        AppError::invalid(
+          format!("ranges[{index}]"),
+          format!("ranges[{index}]: {}", domain_message(err)),
+        )
+      })?;
+    }
+
+    self
+      .repo
+      .set_exam_reference_ranges(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn domain_message(err: PatientDomainError) -> String {
+  match err {
+    PatientDomainError::InvalidReferenceRange(msg) => msg,
+    other => format!("{other:?}"),
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to save reference ranges".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("exam catalog item not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while saving reference ranges".into())
+    }
+  }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{AttendanceQueueItemView, AttendanceStatusChange, TransitionAttendanceInput},
+      errors::{PatientDomainError, PatientRepositoryError},
+      ports::AttendanceRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct TransitionAttendanceUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+}
+
+impl TransitionAttendanceUseCase {
+  pub fn new(repo: Arc<dyn AttendanceRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: TransitionAttendanceInput,
+  ) -> Result<AttendanceQueueItemView, AppError> {
+    if input.attendance_id.trim().is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+    if input.to_status.trim().is_empty() {
+      return Err(AppError::invalid(
+        "to_status",
+        "to_status is required".into(),
+      ));
+    }
+
+    let target = AttendanceStatus::parse(&input.to_status).map_err(map_domain_error)?;
+    authorize(actor, permission_for(target)?)?;
+
+    let current = self
+      .repo
+      .get_attendance(input.attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let current_status = AttendanceStatus::parse(&current.status).map_err(map_domain_error)?;
+
+    current_status
+      .transition_to(target)
+      .map_err(map_domain_error)?;
+
+    self
+      .repo
+      .change_attendance_status(AttendanceStatusChange {
+        attendance_id: input.attendance_id,
+        from_status: current_status.as_str().to_string(),
+        to_status: target.as_str().to_string(),
+        changed_by_user_id: Some(actor.id.clone()),
+        note: input.note,
+      })
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+/// Moving samples through the bench needs `EnterResults`; front-desk steps (collect, deliver) need
+/// `ManageAttendances`. Release and cancellation are refused here: their own use cases sign the
+/// results or record the reason.
+fn permission_for(target: AttendanceStatus) -> Result<Permission, AppError> {
+  match target {
+    AttendanceStatus::InAnalysis | AttendanceStatus::AwaitingValidation => {
+      Ok(Permission::EnterResults)
+    }
+    AttendanceStatus::Waiting | AttendanceStatus::Collected | AttendanceStatus::Delivered => {
+      Ok(Permission::ManageAttendances)
+    }
+    AttendanceStatus::Cancelled => Err(AppError::invalid(
+      "to_status",
+      "to_status must not be cancelled; use cancel_attendance, which records the reason".into(),
+    )),
+    AttendanceStatus::Released => Err(AppError::invalid(
+      "to_status",
+      "to_status must not be released; use release_attendance, which signs the results".into(),
+    )),
+  }
+}
+
+fn map_domain_error(err: PatientDomainError) -> AppError {
+  match err {
+    PatientDomainError::UnknownAttendanceStatus(status) => {
+      AppError::validation(format!("unknown attendance status: {status}"))
+    }
+    PatientDomainError::InvalidStatusTransition { from, to } => {
+      AppError::validation(format!("cannot move attendance from {from} to {to}"))
+    }
+    other => AppError::Unexpected(format!("{other:?}")),
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to change attendance status".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("attendance status was changed by someone else".into())
+    }
+  }
+}
@@ -0,0 +1,197 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+  app::error::AppError,
+  application::{
+    authorization::authorize,
+    patients::create_attendance::{ensure_active_requester, resolve_catalog_items},
+  },
+  domain::{
+    patients::{
+      attendance_status::AttendanceStatus,
+      dto::{
+        AttendanceEditContext, AttendanceUpdate, PatientRecordEntryView, UpdateAttendanceInput,
+      },
+      errors::PatientRepositoryError,
+      ports::{AttendanceRepository, PatientRepository},
+    },
+    requesters::ports::RequesterRepository,
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct UpdateAttendanceUseCase {
+  repo: Arc<dyn AttendanceRepository>,
+  patients: Arc<dyn PatientRepository>,
+  requesters: Arc<dyn RequesterRepository>,
+}
+
+impl UpdateAttendanceUseCase {
+  pub fn new(
+    repo: Arc<dyn AttendanceRepository>,
+    patients: Arc<dyn PatientRepository>,
+    requesters: Arc<dyn RequesterRepository>,
+  ) -> Self {
+    Self {
+      repo,
+      patients,
+      requesters,
+    }
+  }
+
+  /// Edits requester, procedure type, delivery and notes while the attendance is open. Items may
+  /// only be added or removed until the first result is entered.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: UpdateAttendanceInput,
+  ) -> Result<PatientRecordEntryView, AppError> {
+    authorize(actor, Permission::ManageAttendances)?;
+
+    let attendance_id = input.attendance_id.trim().to_string();
+    if attendance_id.is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+
+    let context = self
+      .repo
+      .get_attendance_edit_context(attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let is_open = AttendanceStatus::parse(&context.status)
+      .map(|status| status.is_open())
+      .unwrap_or(false);
+    if !is_open {
+      return Err(AppError::validation(format!(
+        "attendance cannot be edited while {}",
+        context.status
+      )));
+    }
+
+    let requester_id = input
+      .requester_id
+      .as_deref()
+      .map(str::trim)
+      .filter(|id| !id.is_empty());
+    // Keeping a requester deactivated after the attendance was created is fine.
+    if let Some(requester_id) = requester_id {
+      if context.requester_id.as_deref() != Some(requester_id) {
+        ensure_active_requester(self.requesters.as_ref(), requester_id).await?;
+      }
+    }
+
+    let changes_items = !input.add_items.is_empty() || !input.remove_item_ids.is_empty();
+    if changes_items && context.items.iter().any(|item| item.has_result) {
+      return Err(AppError::validation(
+        "items cannot change once a result has been entered".into(),
+      ));
+    }
+    let remove_item_ids = validate_removals(&context, &input.remove_item_ids)?;
+    let add_items = if input.add_items.is_empty() {
+      Vec::new()
+    } else {
+      let kept: HashSet<String> = context
+        .items
+        .iter()
+        .filter(|item| !remove_item_ids.contains(&item.exam_item_id))
+        .flat_map(|item| item.catalog_item_id.iter().chain(&item.panel_id).cloned())
+        .collect();
+      let catalog = self
+        .patients
+        .list_exam_catalog(false)
+        .await
+        .map_err(map_repo_error)?;
+      resolve_catalog_items(&catalog, input.add_items, "add_items", &kept)?
+    };
+    if context.items.len() - remove_item_ids.len() + add_items.len() == 0 {
+      return Err(AppError::invalid(
+        "items",
+        "items must keep at least one exam".into(),
+      ));
+    }
+
+    self
+      .repo
+      .update_attendance(
+        AttendanceUpdate {
+          attendance_id,
+          expected_status: context.status,
+          requester_id: requester_id.map(str::to_string),
+          procedure_type: input.procedure_type,
+          delivered_to: input.delivered_to,
+          notes: input.notes,
+          add_items,
+          remove_item_ids,
+        },
+        Some(actor.id.clone()),
+      )
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn validate_removals(
+  context: &AttendanceEditContext,
+  remove_item_ids: &[String],
+) -> Result<Vec<String>, AppError> {
+  let mut removals: Vec<String> = Vec::with_capacity(remove_item_ids.len());
+  for (index, exam_item_id) in remove_item_ids.iter().enumerate() {
+    let exam_item_id = exam_item_id.trim();
+    let Some(item) = context
+      .items
+      .iter()
+      .find(|item| item.exam_item_id == exam_item_id)
+    else {
+      return Err(AppError::invalid(
+        format!("remove_item_ids[{index}]"),
+        format!("remove_item_ids[{index}]: {exam_item_id} does not belong to attendance"),
+      ));
+    };
+    if removals.iter().any(|id| id == exam_item_id) {
+      return Err(AppError::invalid(
+        format!("remove_item_ids[{index}]"),
+        format!("remove_item_ids[{index}]: {exam_item_id} is repeated"),
+      ));
+    }
+    removals.push(item.exam_item_id.clone());
+  }
+  // A panel is removed as a whole: dropping one analyte would leave it half requested.
+  for (index, exam_item_id) in removals.iter().enumerate() {
+    let panel_id = context
+      .items
+      .iter()
+      .find(|item| &item.exam_item_id == exam_item_id)
+      .and_then(|item| item.panel_id.as_deref());
+    let Some(panel_id) = panel_id else {
+      continue;
+    };
+    let kept_sibling = context.items.iter().any(|item| {
+      item.panel_id.as_deref() == Some(panel_id) && !removals.contains(&item.exam_item_id)
+    });
+    if kept_sibling {
+      return Err(AppError::invalid(
+        format!("remove_item_ids[{index}]"),
+        format!(
+          "remove_item_ids[{index}]: {exam_item_id} is part of a panel; remove all of its analytes"
+        ),
+      ));
+    }
+  }
+  Ok(removals)
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to update attendance".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    // The status moved or a result was entered since the attendance was read.
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("attendance was changed by someone else".into())
+    }
+  }
+}
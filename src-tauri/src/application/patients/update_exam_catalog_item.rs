@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::{
+      dto::{ExamCatalogItemView, UpdateExamCatalogItemInput},
+      errors::PatientRepositoryError,
+      ports::ExamCatalogRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct UpdateExamCatalogItemUseCase {
+  repo: Arc<dyn ExamCatalogRepository>,
+}
+
+impl UpdateExamCatalogItemUseCase {
+  pub fn new(repo: Arc<dyn ExamCatalogRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: UpdateExamCatalogItemInput,
+  ) -> Result<ExamCatalogItemView, AppError> {
+    authorize(actor, Permission::ManageCatalog)?;
+
+    if input.id.trim().is_empty() {
+      return Err(AppError::invalid("id", "id is required".into()));
+    }
+    if input.name.trim().is_empty() {
+      return Err(AppError::invalid("name", "name is required".into()));
+    }
+    if input.category_id.trim().is_empty() {
+      return Err(AppError::invalid(
+        "category_id",
+        "category_id is required".into(),
+      ));
+    }
+    if input.price_cents < 0 {
+      return Err(AppError::invalid(
+        "price_cents",
+        "price_cents must not be negative".into(),
+      ));
+    }
+
+    self
+      .repo
+      .update_exam_catalog_item(input, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to update exam catalog item".into())
+    }
+    PatientRepositoryError::NotFound => {
+      AppError::NotFound("exam catalog item or category not found".into())
+    }
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("conflict while updating exam catalog item".into())
+    }
+  }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::{authorization::authorize, patients::create_patient::validate_patient_fields},
+  domain::{
+    patients::{
+      dto::{PatientView, UpdatePatientInput},
+      entity::Patient,
+      errors::PatientRepositoryError,
+      ports::PatientRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct UpdatePatientUseCase {
+  repo: Arc<dyn PatientRepository>,
+}
+
+impl UpdatePatientUseCase {
+  pub fn new(repo: Arc<dyn PatientRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: UpdatePatientInput,
+  ) -> Result<PatientView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    if input.id.trim().is_empty() {
+      return Err(AppError::invalid("id", "id is required".into()));
+    }
+    if input.expected_updated_at.trim().is_empty() {
+      return Err(AppError::invalid(
+        "expected_updated_at",
+        "expected_updated_at is required".into(),
+      ));
+    }
+    let cpf = validate_patient_fields(&input.full_name, &input.cpf)?;
+
+    let patient = self
+      .repo
+      .update_patient(
+        UpdatePatientInput {
+          id: input.id.trim().to_string(),
+          cpf,
+          ..input
+        },
+        Some(actor.id.clone()),
+      )
+      .await
+      .map_err(map_repo_error)?;
+
+    Ok(to_view(patient))
+  }
+}
+
+fn map_repo_error(err: PatientRepositoryError) -> AppError {
+  match err {
+    PatientRepositoryError::PersistenceError => {
+      AppError::Database("failed to update patient".into())
+    }
+    PatientRepositoryError::NotFound => AppError::NotFound("patient not found".into()),
+    // Either a stale `expected_updated_at` or a CPF that belongs to another patient.
+    PatientRepositoryError::Conflict => {
+      AppError::Conflict("patient was changed by someone else or cpf already registered".into())
+    }
+  }
+}
+
+fn to_view(p: Patient) -> PatientView {
+  PatientView {
+    id: p.id,
+    full_name: p.full_name,
+    cpf: p.cpf,
+    birth_date: p.birth_date,
+    sex: p.sex,
+    phone: p.phone,
+    address: p.address,
+    created_at: p.created_at,
+    updated_at: p.updated_at,
+    deleted_at: p.deleted_at,
+  }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    patients::attendance_status::AttendanceStatus,
+    reports::{
+      dto::{
+        GenerateLabReportInput, GeneratedLabReportView, LabReportSource, RecordPdfReportInput,
+      },
+      errors::{ReportRepositoryError, ReportStorageError},
+      ports::{LabReportRenderer, ReportFileStore, ReportRepository},
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+const MAX_NOTE_LEN: usize = 500;
+
+pub struct GenerateLabReportUseCase {
+  repo: Arc<dyn ReportRepository>,
+  renderer: Arc<dyn LabReportRenderer>,
+  files: Arc<dyn ReportFileStore>,
+}
+
+impl GenerateLabReportUseCase {
+  pub fn new(
+    repo: Arc<dyn ReportRepository>,
+    renderer: Arc<dyn LabReportRenderer>,
+    files: Arc<dyn ReportFileStore>,
+  ) -> Self {
+    Self {
+      repo,
+      renderer,
+      files,
+    }
+  }
+
+  /// Renders the laudo, writes it as a new versioned file and records the version in
+  /// `pdf_reports`. Earlier versions stay on disk untouched.
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: GenerateLabReportInput,
+  ) -> Result<GeneratedLabReportView, AppError> {
+    authorize(actor, Permission::GenerateReports)?;
+
+    let attendance_id = input.attendance_id.trim().to_string();
+    if attendance_id.is_empty() {
+      return Err(AppError::invalid(
+        "attendance_id",
+        "attendance_id is required".into(),
+      ));
+    }
+    let note = input
+      .note
+      .map(|note| note.trim().to_string())
+      .filter(|note| !note.is_empty());
+    if note.as_ref().is_some_and(|note| note.chars().count() > MAX_NOTE_LEN) {
+      return Err(AppError::invalid(
+        "note",
+        format!("note must have at most {MAX_NOTE_LEN} characters"),
+      ));
+    }
+
+    let source = self
+      .repo
+      .get_lab_report_source(attendance_id.clone())
+      .await
+      .map_err(map_repo_error)?;
+    let allows_report = AttendanceStatus::parse(&source.entry.status)
+      .map(|status| status.allows_report())
+      .unwrap_or(false);
+    if !allows_report {
+      return Err(AppError::validation(format!(
+        "report cannot be generated while attendance is {}",
+        source.entry.status
+      )));
+    }
+
+    issue_lab_report(
+      self.repo.as_ref(),
+      self.renderer.as_ref(),
+      self.files.as_ref(),
+      &source,
+      actor,
+      note,
+    )
+    .await
+  }
+}
+
+/// Renders and stores the next version of the laudo of `source` and records it. Shared with
+/// `amend_result`, which reissues the laudo after a correction.
+pub async fn issue_lab_report(
+  repo: &dyn ReportRepository,
+  renderer: &dyn LabReportRenderer,
+  files: &dyn ReportFileStore,
+  source: &LabReportSource,
+  actor: &UserView,
+  note: Option<String>,
+) -> Result<GeneratedLabReportView, AppError> {
+  let attendance_id = source.entry.exam_id.clone();
+  let report_version = source.current_report_version.unwrap_or(0) + 1;
+  let content = renderer.render(source, report_version);
+  let file_name = format!("laudo-{attendance_id}-v{report_version}.pdf");
+  // The file keeps a staged name until the version is recorded, so a concurrent issue of the
+  // same version never overwrites the winner's laudo and a failed insert leaves nothing behind.
+  let staged_name = files
+    .stage(&file_name, &content)
+    .map_err(map_storage_error)?;
+
+  let recorded = repo
+    .record_pdf_report(RecordPdfReportInput {
+      attendance_id,
+      report_version,
+      generated_by_user_id: Some(actor.id.clone()),
+      note,
+      amended: source.amended,
+    })
+    .await;
+  let report = match recorded {
+    Ok(report) => report,
+    Err(err) => {
+      files.discard(&staged_name);
+      return Err(map_repo_error(err));
+    }
+  };
+  let file_path = files
+    .promote(&staged_name, &file_name)
+    .map_err(map_storage_error)?;
+
+  Ok(GeneratedLabReportView { report, file_path })
+}
+
+fn map_storage_error(err: ReportStorageError) -> AppError {
+  match err {
+    ReportStorageError::InvalidFileName(name) => {
+      AppError::Unexpected(format!("invalid report file name: {name}"))
+    }
+    ReportStorageError::Io(msg) => AppError::Unexpected(format!("failed to write report file: {msg}")),
+  }
+}
+
+fn map_repo_error(err: ReportRepositoryError) -> AppError {
+  match err {
+    ReportRepositoryError::PersistenceError => {
+      AppError::Database("failed to record report".into())
+    }
+    ReportRepositoryError::NotFound => AppError::NotFound("attendance not found".into()),
+    ReportRepositoryError::Conflict => {
+      AppError::Conflict("report was generated by someone else; try again".into())
+    }
+  }
+}
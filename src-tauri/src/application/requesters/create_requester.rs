@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    requesters::{
+      dto::{CreateRequesterInput, NewRequester, RequesterView},
+      errors::{RequesterDomainError, RequesterRepositoryError},
+      ports::RequesterRepository,
+      registry::CouncilRegistry,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct CreateRequesterUseCase {
+  repo: Arc<dyn RequesterRepository>,
+}
+
+impl CreateRequesterUseCase {
+  pub fn new(repo: Arc<dyn RequesterRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CreateRequesterInput,
+  ) -> Result<RequesterView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let requester = validate_requester(
+      &input.name,
+      input.council_type.as_deref(),
+      input.council_number.as_deref(),
+      input.council_state.as_deref(),
+    )?;
+
+    self
+      .repo
+      .insert_requester(requester, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+/// Trimmed name plus the canonical council registry, shared by create and update.
+pub fn validate_requester(
+  name: &str,
+  council_type: Option<&str>,
+  council_number: Option<&str>,
+  council_state: Option<&str>,
+) -> Result<NewRequester, AppError> {
+  let name = name.trim();
+  if name.is_empty() {
+    return Err(map_domain_error(RequesterDomainError::NameRequired));
+  }
+  let registry = CouncilRegistry::parse(council_type, council_number, council_state)
+    .map_err(map_domain_error)?;
+
+  Ok(NewRequester {
+    name: name.to_string(),
+    registry,
+  })
+}
+
+fn map_domain_error(err: RequesterDomainError) -> AppError {
+  match err {
+    RequesterDomainError::NameRequired => AppError::invalid("name", "name is required".into()),
+    RequesterDomainError::InvalidRegistry { field, message } => AppError::invalid(field, message),
+  }
+}
+
+fn map_repo_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to create requester".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requester not found".into()),
+    RequesterRepositoryError::Conflict => AppError::Conflict(
+      "another requester is already registered with this council registration".into(),
+    ),
+  }
+}
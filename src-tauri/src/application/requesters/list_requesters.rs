@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    requesters::{
+      dto::RequesterView, errors::RequesterRepositoryError, ports::RequesterRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct ListRequestersUseCase {
+  repo: Arc<dyn RequesterRepository>,
+}
+
+impl ListRequestersUseCase {
+  pub fn new(repo: Arc<dyn RequesterRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+
+    self
+      .repo
+      .list_requesters(include_inactive)
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to fetch requesters".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requesters not found".into()),
+    RequesterRepositoryError::Conflict => {
+      AppError::Conflict("conflict while fetching requesters".into())
+    }
+  }
+}
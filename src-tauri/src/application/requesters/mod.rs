@@ -0,0 +1,5 @@
+pub mod create_requester;
+pub mod list_requesters;
+pub mod search_requesters;
+pub mod set_requester_active;
+pub mod update_requester;
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    requesters::{
+      dto::{RequesterView, SearchRequestersInput},
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+      search::{search_requesters, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT},
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+/// Requester autocomplete for the attendance form; only active requesters are offered.
+pub struct SearchRequestersUseCase {
+  repo: Arc<dyn RequesterRepository>,
+}
+
+impl SearchRequestersUseCase {
+  pub fn new(repo: Arc<dyn RequesterRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: SearchRequestersInput,
+  ) -> Result<Vec<RequesterView>, AppError> {
+    authorize(actor, Permission::ViewRecords)?;
+
+    let limit = input.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+      return Err(AppError::invalid(
+        "limit",
+        format!("limit must be between 1 and {MAX_SEARCH_LIMIT}"),
+      ));
+    }
+
+    let requesters = self
+      .repo
+      .list_requesters(false)
+      .await
+      .map_err(map_repo_error)?;
+
+    Ok(search_requesters(
+      &requesters,
+      input.query.as_deref().unwrap_or_default(),
+      limit as usize,
+    ))
+  }
+}
+
+fn map_repo_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to search requesters".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requesters not found".into()),
+    RequesterRepositoryError::Conflict => {
+      AppError::Conflict("conflict while searching requesters".into())
+    }
+  }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::{
+    requesters::{
+      dto::{RequesterView, SetRequesterActiveInput},
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+/// Requesters are never deleted, since past attendances point at them. Deactivating one hides
+/// it from the autocomplete and from new attendances.
+pub struct SetRequesterActiveUseCase {
+  repo: Arc<dyn RequesterRepository>,
+}
+
+impl SetRequesterActiveUseCase {
+  pub fn new(repo: Arc<dyn RequesterRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: SetRequesterActiveInput,
+  ) -> Result<RequesterView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let requester_id = input.requester_id.trim().to_string();
+    if requester_id.is_empty() {
+      return Err(AppError::invalid(
+        "requester_id",
+        "requester_id is required".into(),
+      ));
+    }
+
+    self
+      .repo
+      .set_requester_active(requester_id, input.is_active, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to update requester".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requester not found".into()),
+    RequesterRepositoryError::Conflict => {
+      AppError::Conflict("conflict while updating requester".into())
+    }
+  }
+}
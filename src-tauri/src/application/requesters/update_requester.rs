@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::{authorization::authorize, requesters::create_requester::validate_requester},
+  domain::{
+    requesters::{
+      dto::{RequesterView, UpdateRequesterInput},
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+    },
+    users::{dto::UserView, permission::Permission},
+  },
+};
+
+pub struct UpdateRequesterUseCase {
+  repo: Arc<dyn RequesterRepository>,
+}
+
+impl UpdateRequesterUseCase {
+  pub fn new(repo: Arc<dyn RequesterRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: UpdateRequesterInput,
+  ) -> Result<RequesterView, AppError> {
+    authorize(actor, Permission::RegisterPatients)?;
+
+    let requester_id = input.id.trim().to_string();
+    if requester_id.is_empty() {
+      return Err(AppError::invalid("id", "id is required".into()));
+    }
+    let requester = validate_requester(
+      &input.name,
+      input.council_type.as_deref(),
+      input.council_number.as_deref(),
+      input.council_state.as_deref(),
+    )?;
+
+    self
+      .repo
+      .update_requester(requester_id, requester, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: RequesterRepositoryError) -> AppError {
+  match err {
+    RequesterRepositoryError::PersistenceError => {
+      AppError::Database("failed to update requester".into())
+    }
+    RequesterRepositoryError::NotFound => AppError::NotFound("requester not found".into()),
+    RequesterRepositoryError::Conflict => AppError::Conflict(
+      "another requester is already registered with this council registration".into(),
+    ),
+  }
+}
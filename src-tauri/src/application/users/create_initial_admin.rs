@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  domain::users::{
+    dto::{CreateInitialAdminInput, NewUser, UserView},
+    errors::{PasswordHashError, UserRepositoryError},
+    ports::{PasswordHasher, UserRepository},
+    role::Role,
+  },
+};
+
+pub const MIN_PASSWORD_LEN: usize = 8;
+const MAX_USERNAME_LEN: usize = 50;
+
+pub struct CreateInitialAdminUseCase {
+  repo: Arc<dyn UserRepository>,
+  hasher: Arc<dyn PasswordHasher>,
+}
+
+impl CreateInitialAdminUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>, hasher: Arc<dyn PasswordHasher>) -> Self {
+    Self { repo, hasher }
+  }
+
+  /// First-run flow: creates the admin account; refused once any user exists.
+  pub async fn execute(&self, input: CreateInitialAdminInput) -> Result<UserView, AppError> {
+    let name = input.name.trim().to_string();
+    let cpf = input.cpf.trim().to_string();
+    let username = input.username.trim().to_lowercase();
+
+    if name.is_empty() {
+      return Err(AppError::invalid("name", "name is required".into()));
+    }
+    if cpf.is_empty() {
+      return Err(AppError::invalid("cpf", "cpf is required".into()));
+    }
+    validate_username(&username)?;
+    validate_password(&input.password)?;
+
+    let password_hash = self
+      .hasher
+      .hash(&input.password)
+      .map_err(map_hash_error)?;
+
+    self
+      .repo
+      .insert_first_user(NewUser {
+        name,
+        cpf,
+        username,
+        password_hash,
+        role: Role::Admin.as_str().to_string(),
+      })
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+pub fn validate_username(username: &str) -> Result<(), AppError> {
+  if username.is_empty() {
+    return Err(AppError::invalid("username", "username is required".into()));
+  }
+  if username.chars().count() > MAX_USERNAME_LEN {
+    return Err(AppError::invalid(
+      "username",
+      format!("username must have at most {MAX_USERNAME_LEN} characters"),
+    ));
+  }
+  let allowed = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-');
+  if !username.chars().all(allowed) {
+    return Err(AppError::invalid(
+      "username",
+      "username may only contain letters, digits, '.', '_' and '-'".into(),
+    ));
+  }
+  Ok(())
+}
+
+pub fn validate_password(password: &str) -> Result<(), AppError> {
+  if password.chars().count() < MIN_PASSWORD_LEN {
+    return Err(AppError::invalid(
+      "password",
+      format!("password must have at least {MIN_PASSWORD_LEN} characters"),
+    ));
+  }
+  Ok(())
+}
+
+fn map_hash_error(err: PasswordHashError) -> AppError {
+  match err {
+    PasswordHashError::HashFailed(msg) => {
+      AppError::Unexpected(format!("failed to hash password: {msg}"))
+    }
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => {
+      AppError::Database("failed to create initial admin".into())
+    }
+    UserRepositoryError::NotFound => AppError::NotFound("user not found".into()),
+    UserRepositoryError::Conflict => {
+      AppError::Conflict("initial setup was already completed".into())
+    }
+  }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::{
+    authorization::authorize,
+    users::create_initial_admin::{validate_password, validate_username},
+  },
+  domain::users::{
+    dto::{CreateUserInput, NewUser, UserView},
+    errors::{PasswordHashError, UserRepositoryError},
+    permission::Permission,
+    ports::{PasswordHasher, UserRepository},
+    role::Role,
+  },
+};
+
+pub struct CreateUserUseCase {
+  repo: Arc<dyn UserRepository>,
+  hasher: Arc<dyn PasswordHasher>,
+}
+
+impl CreateUserUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>, hasher: Arc<dyn PasswordHasher>) -> Self {
+    Self { repo, hasher }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: CreateUserInput,
+  ) -> Result<UserView, AppError> {
+    authorize(actor, Permission::ManageUsers)?;
+
+    let name = input.name.trim().to_string();
+    let cpf = input.cpf.trim().to_string();
+    let username = input.username.trim().to_lowercase();
+
+    if name.is_empty() {
+      return Err(AppError::invalid("name", "name is required".into()));
+    }
+    if cpf.is_empty() {
+      return Err(AppError::invalid("cpf", "cpf is required".into()));
+    }
+    validate_username(&username)?;
+    validate_password(&input.password)?;
+    let role = Role::parse(&input.role.to_lowercase()).map_err(|_| {
+      AppError::invalid(
+        "role",
+        "role must be one of admin, reception, bench, biochemist".into(),
+      )
+    })?;
+
+    let password_hash = self.hasher.hash(&input.password).map_err(map_hash_error)?;
+
+    self
+      .repo
+      .insert_user(
+        NewUser {
+          name,
+          cpf,
+          username,
+          password_hash,
+          role: role.as_str().to_string(),
+        },
+        Some(actor.id.clone()),
+      )
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_hash_error(err: PasswordHashError) -> AppError {
+  match err {
+    PasswordHashError::HashFailed(msg) => {
+      AppError::Unexpected(format!("failed to hash password: {msg}"))
+    }
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to create user".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("user not found".into()),
+    UserRepositoryError::Conflict => {
+      AppError::Conflict("username or cpf already registered".into())
+    }
+  }
+}
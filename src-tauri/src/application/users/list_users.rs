@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::users::{
+    dto::UserView, errors::UserRepositoryError, permission::Permission, ports::UserRepository,
+  },
+};
+
+pub struct ListUsersUseCase {
+  repo: Arc<dyn UserRepository>,
+}
+
+impl ListUsersUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(&self, actor: &UserView) -> Result<Vec<UserView>, AppError> {
+    authorize(actor, Permission::ManageUsers)?;
+
+    self.repo.list_users().await.map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to fetch users".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("users not found".into()),
+    UserRepositoryError::Conflict => AppError::Conflict("conflict while fetching users".into()),
+  }
+}
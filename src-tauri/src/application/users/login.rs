@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  domain::users::{
+    dto::{LoginInput, UserView},
+    errors::UserRepositoryError,
+    ports::{PasswordHasher, UserRepository},
+  },
+};
+
+pub struct LoginUseCase {
+  repo: Arc<dyn UserRepository>,
+  hasher: Arc<dyn PasswordHasher>,
+}
+
+impl LoginUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>, hasher: Arc<dyn PasswordHasher>) -> Self {
+    Self { repo, hasher }
+  }
+
+  pub async fn execute(&self, input: LoginInput) -> Result<UserView, AppError> {
+    let username = input.username.trim().to_lowercase();
+    if username.is_empty() {
+      return Err(AppError::invalid("username", "username is required".into()));
+    }
+    if input.password.is_empty() {
+      return Err(AppError::invalid("password", "password is required".into()));
+    }
+
+    let credentials = self
+      .repo
+      .find_credentials_by_username(username)
+      .await
+      .map_err(map_repo_error)?;
+
+    // Unknown user and wrong password answer the same, so usernames cannot be probed.
+    let Some(credentials) = credentials else {
+      return Err(AppError::validation("invalid username or password".into()));
+    };
+    if !self.hasher.verify(&input.password, &credentials.password_hash) {
+      return Err(AppError::validation("invalid username or password".into()));
+    }
+    if !credentials.user.is_active {
+      return Err(AppError::validation("user is inactive".into()));
+    }
+
+    Ok(credentials.user)
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to fetch user".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("user not found".into()),
+    UserRepositoryError::Conflict => AppError::Conflict("conflict while fetching user".into()),
+  }
+}
@@ -0,0 +1,6 @@
+pub mod create_initial_admin;
+pub mod create_user;
+pub mod list_users;
+pub mod login;
+pub mod needs_initial_setup;
+pub mod set_user_active;
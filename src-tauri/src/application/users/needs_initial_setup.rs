@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  domain::users::{errors::UserRepositoryError, ports::UserRepository},
+};
+
+pub struct NeedsInitialSetupUseCase {
+  repo: Arc<dyn UserRepository>,
+}
+
+impl NeedsInitialSetupUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>) -> Self {
+    Self { repo }
+  }
+
+  /// `true` while no user exists, i.e. the first-run admin still has to be created.
+  pub async fn execute(&self) -> Result<bool, AppError> {
+    let total = self.repo.count_users().await.map_err(map_repo_error)?;
+    Ok(total == 0)
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to count users".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("users not found".into()),
+    UserRepositoryError::Conflict => AppError::Conflict("conflict while counting users".into()),
+  }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::users::{
+    dto::{SetUserActiveInput, UserView},
+    errors::UserRepositoryError,
+    permission::Permission,
+    ports::UserRepository,
+  },
+};
+
+pub struct SetUserActiveUseCase {
+  repo: Arc<dyn UserRepository>,
+}
+
+impl SetUserActiveUseCase {
+  pub fn new(repo: Arc<dyn UserRepository>) -> Self {
+    Self { repo }
+  }
+
+  pub async fn execute(
+    &self,
+    actor: &UserView,
+    input: SetUserActiveInput,
+  ) -> Result<UserView, AppError> {
+    authorize(actor, Permission::ManageUsers)?;
+
+    let user_id = input.user_id.trim().to_string();
+    if user_id.is_empty() {
+      return Err(AppError::invalid("user_id", "user_id is required".into()));
+    }
+    // An admin deactivating themself could leave the lab without any admin.
+    if !input.is_active && user_id == actor.id {
+      return Err(AppError::validation(
+        "you cannot deactivate your own user".into(),
+      ));
+    }
+
+    self
+      .repo
+      .set_user_active(user_id, input.is_active, Some(actor.id.clone()))
+      .await
+      .map_err(map_repo_error)
+  }
+}
+
+fn map_repo_error(err: UserRepositoryError) -> AppError {
+  match err {
+    UserRepositoryError::PersistenceError => AppError::Database("failed to update user".into()),
+    UserRepositoryError::NotFound => AppError::NotFound("user not found".into()),
+    UserRepositoryError::Conflict => AppError::Conflict("conflict while updating user".into()),
+  }
+}
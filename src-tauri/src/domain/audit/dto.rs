@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListAuditLogInput {
+  pub entity_name: Option<String>,
+  pub entity_id: Option<String>,
+  pub performed_by_user_id: Option<String>,
+  /// Inclusive `YYYY-MM-DD` bounds on `performed_at`.
+  pub from_date: Option<String>,
+  pub to_date: Option<String>,
+  pub limit: Option<i64>,
+}
+
+/// One audit row. `before_json`/`after_json` hold the JSON snapshot of the entity around
+/// the change (`None` before a create).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntryView {
+  pub id: String,
+  pub entity_name: String,
+  pub entity_id: String,
+  pub action: String,
+  pub performed_by_user_id: Option<String>,
+  pub performed_at: String,
+  pub before_json: Option<String>,
+  pub after_json: Option<String>,
+}
+
+/// Raw `audit_log` row with its chain columns, as read for verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditChainEntry {
+  pub id: String,
+  pub sequence: Option<i64>,
+  pub entity_name: String,
+  pub entity_id: String,
+  pub action: String,
+  pub performed_by_user_id: Option<String>,
+  pub performed_at: String,
+  pub before_json: Option<String>,
+  pub after_json: Option<String>,
+  pub prev_hash: Option<String>,
+  pub entry_hash: Option<String>,
+}
+
+/// Result of `verify_audit_chain`. When valid, `last_entry_hash` can be written down (e.g. in an
+/// inspection report) to later prove that no entry was removed from the end of the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditChainVerificationView {
+  pub is_valid: bool,
+  /// Entries verified before the first broken link, or all of them when the chain is valid.
+  pub checked_entries: i64,
+  pub first_broken_entry_id: Option<String>,
+  pub first_broken_sequence: Option<i64>,
+  pub reason: Option<String>,
+  pub last_entry_hash: Option<String>,
+}
@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditRepositoryError {
+  PersistenceError,
+}
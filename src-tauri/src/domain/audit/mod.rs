@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod errors;
+pub mod hash_chain;
+pub mod ports;
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use super::{
+  dto::{AuditChainEntry, AuditEntryView, ListAuditLogInput},
+  errors::AuditRepositoryError,
+};
+
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+  async fn list_audit_log(
+    &self,
+    input: ListAuditLogInput,
+  ) -> Result<Vec<AuditEntryView>, AuditRepositoryError>;
+  /// Every entry ordered by `sequence` (unsealed rows first).
+  async fn list_audit_chain(&self) -> Result<Vec<AuditChainEntry>, AuditRepositoryError>;
+}
@@ -1,2 +1,5 @@
+pub mod audit;
 pub mod patients;
-
+pub mod reports;
+pub mod requesters;
+pub mod users;
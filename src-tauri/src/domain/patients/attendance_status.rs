@@ -0,0 +1,111 @@
+use super::errors::PatientDomainError;
+
+/// Lifecycle of an attendance (`exams.status`), from reception to delivery of the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttendanceStatus {
+  Waiting,
+  Collected,
+  InAnalysis,
+  AwaitingValidation,
+  Released,
+  Delivered,
+  Cancelled,
+}
+
+impl AttendanceStatus {
+  pub const ALL: [AttendanceStatus; 7] = [
+    AttendanceStatus::Waiting,
+    AttendanceStatus::Collected,
+    AttendanceStatus::InAnalysis,
+    AttendanceStatus::AwaitingValidation,
+    AttendanceStatus::Released,
+    AttendanceStatus::Delivered,
+    AttendanceStatus::Cancelled,
+  ];
+
+  pub fn parse(value: &str) -> Result<Self, PatientDomainError> {
+    match value.trim() {
+      "waiting" => Ok(AttendanceStatus::Waiting),
+      "collected" => Ok(AttendanceStatus::Collected),
+      "in_analysis" => Ok(AttendanceStatus::InAnalysis),
+      "awaiting_validation" => Ok(AttendanceStatus::AwaitingValidation),
+      "released" => Ok(AttendanceStatus::Released),
+      "delivered" => Ok(AttendanceStatus::Delivered),
+      "cancelled" => Ok(AttendanceStatus::Cancelled),
+      other => Err(PatientDomainError::UnknownAttendanceStatus(other.to_string())),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AttendanceStatus::Waiting => "waiting",
+      AttendanceStatus::Collected => "collected",
+      AttendanceStatus::InAnalysis => "in_analysis",
+      AttendanceStatus::AwaitingValidation => "awaiting_validation",
+      AttendanceStatus::Released => "released",
+      AttendanceStatus::Delivered => "delivered",
+      AttendanceStatus::Cancelled => "cancelled",
+    }
+  }
+
+  /// Statuses reachable from `self` in a single step.
+  pub fn next_statuses(&self) -> &'static [AttendanceStatus] {
+    match self {
+      AttendanceStatus::Waiting => &[AttendanceStatus::Collected, AttendanceStatus::Cancelled],
+      AttendanceStatus::Collected => &[AttendanceStatus::InAnalysis, AttendanceStatus::Cancelled],
+      AttendanceStatus::InAnalysis => {
+        &[AttendanceStatus::AwaitingValidation, AttendanceStatus::Cancelled]
+      }
+      AttendanceStatus::AwaitingValidation => &[
+        AttendanceStatus::Released,
+        AttendanceStatus::InAnalysis,
+        AttendanceStatus::Cancelled,
+      ],
+      AttendanceStatus::Released => &[AttendanceStatus::Delivered],
+      AttendanceStatus::Delivered | AttendanceStatus::Cancelled => &[],
+    }
+  }
+
+  pub fn can_transition_to(&self, next: AttendanceStatus) -> bool {
+    self.next_statuses().contains(&next)
+  }
+
+  pub fn transition_to(self, next: AttendanceStatus) -> Result<AttendanceStatus, PatientDomainError> {
+    if self.can_transition_to(next) {
+      Ok(next)
+    } else {
+      Err(PatientDomainError::InvalidStatusTransition {
+        from: self.as_str().to_string(),
+        to: next.as_str().to_string(),
+      })
+    }
+  }
+
+  /// Results can only be typed while the samples are on the bench.
+  pub fn accepts_results(&self) -> bool {
+    matches!(
+      self,
+      AttendanceStatus::Collected
+        | AttendanceStatus::InAnalysis
+        | AttendanceStatus::AwaitingValidation
+    )
+  }
+
+  /// A laudo can only be printed once its results were released.
+  pub fn allows_report(&self) -> bool {
+    matches!(self, AttendanceStatus::Released | AttendanceStatus::Delivered)
+  }
+
+  /// Reception may still edit the attendance until its results are released.
+  pub fn is_open(&self) -> bool {
+    !matches!(
+      self,
+      AttendanceStatus::Released | AttendanceStatus::Delivered | AttendanceStatus::Cancelled
+    )
+  }
+
+  /// Statuses an attendance may be created with.
+  pub fn is_initial(&self) -> bool {
+    matches!(self, AttendanceStatus::Waiting | AttendanceStatus::Collected)
+  }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// A calendar day (`YYYY-MM-DD`), without time or timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+  year: i64,
+  month: u32,
+  day: u32,
+}
+
+impl CalendarDate {
+  /// Strict `YYYY-MM-DD`; `None` for other shapes and for days the month does not have.
+  pub fn parse(value: &str) -> Option<Self> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+      return None;
+    }
+    let year = value[0..4].parse::<i64>().ok()?;
+    let month = value[5..7].parse::<u32>().ok()?;
+    let day = value[8..10].parse::<u32>().ok()?;
+    let all_digits = value
+      .char_indices()
+      .all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit());
+
+    (all_digits && (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+      .then_some(Self { year, month, day })
+  }
+
+  /// Day `days` after 1970-01-01 (negative goes back).
+  pub fn from_unix_days(days: i64) -> Self {
+    // Inverse of `unix_days`, after Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    Self { year, month, day }
+  }
+
+  /// Days since 1970-01-01.
+  pub fn unix_days(&self) -> i64 {
+    let year = self.year - i64::from(self.month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(self.month);
+    let doy =
+      (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+  }
+
+  pub fn add_days(&self, days: i64) -> Self {
+    Self::from_unix_days(self.unix_days() + days)
+  }
+}
+
+impl fmt::Display for CalendarDate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+  }
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
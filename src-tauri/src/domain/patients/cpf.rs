@@ -0,0 +1,72 @@
+use std::fmt;
+
+use super::errors::PatientDomainError;
+
+/// Brazilian CPF in canonical form: the 11 digits, without punctuation. `Display` prints the
+/// usual `000.000.000-00` mask.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cpf(String);
+
+impl Cpf {
+  /// Accepts the number with or without the mask (`.`, `-`, spaces) and checks both check digits.
+  pub fn parse(value: &str) -> Result<Self, PatientDomainError> {
+    let value = value.trim();
+    if value.is_empty() {
+      return Err(PatientDomainError::CpfRequired);
+    }
+    if !value
+      .chars()
+      .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ' '))
+    {
+      return Err(invalid("cpf must contain only digits, '.' and '-'"));
+    }
+
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 11 {
+      return Err(invalid("cpf must have 11 digits"));
+    }
+    if digits.iter().all(|d| *d == digits[0]) {
+      return Err(invalid("cpf must not repeat a single digit"));
+    }
+    if check_digit(&digits[..9]) != digits[9] || check_digit(&digits[..10]) != digits[10] {
+      return Err(invalid("cpf check digits do not match"));
+    }
+
+    Ok(Self(digits.iter().map(|d| d.to_string()).collect()))
+  }
+
+  /// Digits only, as stored in `patients.cpf`.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  pub fn into_inner(self) -> String {
+    self.0
+  }
+}
+
+impl fmt::Display for Cpf {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let d = &self.0;
+    write!(f, "{}.{}.{}-{}", &d[0..3], &d[3..6], &d[6..9], &d[9..11])
+  }
+}
+
+/// Mod-11 check digit over `digits`, with weights counting down to 2.
+fn check_digit(digits: &[u32]) -> u32 {
+  let weight_start = digits.len() as u32 + 1;
+  let sum: u32 = digits
+    .iter()
+    .enumerate()
+    .map(|(i, d)| d * (weight_start - i as u32))
+    .sum();
+
+  match sum % 11 {
+    0 | 1 => 0,
+    rest => 11 - rest,
+  }
+}
+
+fn invalid(message: &str) -> PatientDomainError {
+  PatientDomainError::InvalidCpf(message.to_string())
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use super::reference_range::parse_numeric;
+
+/// Delta-check thresholds of a catalog exam. A new result is flagged when it moves from the
+/// patient's previous released value by at least either threshold; unset thresholds never flag.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeltaCheckRule {
+  pub absolute_threshold: Option<f64>,
+  /// Change relative to the previous value, in percent (50.0 flags a result that halves).
+  pub percent_threshold: Option<f64>,
+}
+
+/// How a numeric result moved from the previous one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaChange {
+  pub absolute_change: f64,
+  /// `None` when the previous value is zero.
+  pub percent_change: Option<f64>,
+  pub exceeds_threshold: bool,
+}
+
+impl DeltaCheckRule {
+  /// Compares `current` with `previous`; `None` when either value is not numeric.
+  pub fn compare(&self, previous: &str, current: &str) -> Option<DeltaChange> {
+    let previous = parse_numeric(previous)?;
+    let current = parse_numeric(current)?;
+
+    let absolute_change = current - previous;
+    let percent_change = (previous != 0.0).then(|| absolute_change / previous.abs() * 100.0);
+    let exceeds_absolute = self
+      .absolute_threshold
+      .is_some_and(|threshold| absolute_change.abs() >= threshold);
+    let exceeds_percent = match (self.percent_threshold, percent_change) {
+      (Some(threshold), Some(percent)) => percent.abs() >= threshold,
+      _ => false,
+    };
+
+    Some(DeltaChange {
+      absolute_change,
+      percent_change,
+      exceeds_threshold: exceeds_absolute || exceeds_percent,
+    })
+  }
+}
@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::reports::dto::GeneratedLabReportView;
+
+use super::{
+  delta_check::DeltaCheckRule,
+  pagination::{AttendanceSort, PageCursor, PatientSort},
+  reference_range::ReferenceRange,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePatientInput {
   pub full_name: String,
@@ -10,6 +18,20 @@ pub struct CreatePatientInput {
   pub address: String,
 }
 
+/// Demographic fix-up of an existing patient. `expected_updated_at` is the `updated_at` the client
+/// last saw; the update is rejected if the record changed since then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatePatientInput {
+  pub id: String,
+  pub full_name: String,
+  pub cpf: String,
+  pub birth_date: String,
+  pub sex: String,
+  pub phone: String,
+  pub address: String,
+  pub expected_updated_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatientView {
   pub id: String,
@@ -21,6 +43,74 @@ pub struct PatientView {
   pub address: String,
   pub created_at: String,
   pub updated_at: String,
+  pub deleted_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListPatientsInput {
+  pub query: Option<String>,
+  /// Also list soft-deleted patients.
+  pub include_deleted: Option<bool>,
+  /// `created_at` (newest first), `name` or `relevance` (best match first, requires `query`).
+  /// Defaults to `relevance` when `query` is set and to `created_at` otherwise.
+  pub sort: Option<String>,
+  /// `next_cursor` of the previous page, issued for the same `sort`.
+  pub cursor: Option<String>,
+  /// 1..=200; defaults to 50.
+  pub limit: Option<i64>,
+}
+
+/// Validated `list_patients` query handed to the repository.
+#[derive(Debug, Clone)]
+pub struct PatientPageQuery {
+  pub query: Option<String>,
+  pub include_deleted: bool,
+  pub sort: PatientSort,
+  pub after: Option<PageCursor>,
+  pub limit: i64,
+}
+
+/// Target of `delete_patient` / `restore_patient`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatientDeletionInput {
+  pub patient_id: String,
+}
+
+/// Target of `delete_attendance` / `restore_attendance`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceDeletionInput {
+  pub attendance_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FindDuplicatePatientsInput {
+  /// Only pairs involving this patient (e.g. right after registering it).
+  pub patient_id: Option<String>,
+  /// 0..=100; defaults to `duplicates::DEFAULT_MIN_SCORE`.
+  pub min_score: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicatePatientsView {
+  pub patient: PatientView,
+  pub duplicate: PatientView,
+  pub score: i64,
+  /// `similar_name`, `same_birth_date`, `same_phone`.
+  pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergePatientsInput {
+  pub surviving_patient_id: String,
+  /// Record whose attendances move to the survivor; it is kept only as a tombstone.
+  pub merged_patient_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergePatientsResultView {
+  pub patient: PatientView,
+  pub merged_patient_id: String,
+  pub moved_attendances: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,11 +125,84 @@ pub struct CreateAttendanceInput {
   pub items: Vec<CreateAttendanceItemInput>,
 }
 
+/// Replaces the editable fields of an open attendance; blank optional fields are cleared.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAttendanceInput {
+  pub attendance_id: String,
+  pub requester_id: Option<String>,
+  pub procedure_type: Option<String>,
+  pub delivered_to: Option<String>,
+  pub notes: Option<String>,
+  /// Exams to add, resolved against the active catalog like in `create_attendance`.
+  #[serde(default)]
+  pub add_items: Vec<CreateAttendanceItemInput>,
+  /// `exam_item_id`s to drop from the attendance.
+  #[serde(default)]
+  pub remove_item_ids: Vec<String>,
+}
+
+/// What `update_attendance` needs to know about an attendance before changing it.
+#[derive(Debug, Clone)]
+pub struct AttendanceEditContext {
+  pub attendance_id: String,
+  pub status: String,
+  pub requester_id: Option<String>,
+  pub items: Vec<AttendanceEditItemContext>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttendanceEditItemContext {
+  pub exam_item_id: String,
+  pub catalog_item_id: Option<String>,
+  /// Panel the item was ordered in; its analytes are added and removed together.
+  pub panel_id: Option<String>,
+  pub has_result: bool,
+  /// The item has a critical value nobody acknowledged yet.
+  pub has_pending_critical_alert: bool,
+}
+
+/// Validated attendance edit handed to the repository; the update only applies while the
+/// attendance is still in `expected_status`.
+#[derive(Debug, Clone)]
+pub struct AttendanceUpdate {
+  pub attendance_id: String,
+  pub expected_status: String,
+  pub requester_id: Option<String>,
+  pub procedure_type: Option<String>,
+  pub delivered_to: Option<String>,
+  pub notes: Option<String>,
+  pub add_items: Vec<CreateAttendanceItemInput>,
+  pub remove_item_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AttendanceQueueQueryInput {
   pub date: Option<String>,
   pub status: Option<String>,
   pub query: Option<String>,
+  /// Also list soft-deleted attendances and attendances of soft-deleted patients.
+  pub include_deleted: Option<bool>,
+  /// Also list cancelled attendances; implied by `status: "cancelled"`.
+  pub include_cancelled: Option<bool>,
+  /// `exam_date` (default, newest first), `created_at` or `name`.
+  pub sort: Option<String>,
+  /// `next_cursor` of the previous page, issued for the same `sort`.
+  pub cursor: Option<String>,
+  /// 1..=200 attendances; defaults to 50.
+  pub limit: Option<i64>,
+}
+
+/// Validated `list_attendance_queue` query handed to the repository. Blank filters are `None`.
+#[derive(Debug, Clone)]
+pub struct AttendanceQueuePageQuery {
+  pub date: Option<String>,
+  pub status: Option<String>,
+  pub query: Option<String>,
+  pub include_deleted: bool,
+  pub include_cancelled: bool,
+  pub sort: AttendanceSort,
+  pub after: Option<PageCursor>,
+  pub limit: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +215,7 @@ pub struct AttendanceQueueItemView {
   pub status: String,
   pub exam_names: Vec<String>,
   pub updated_at: String,
+  pub deleted_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,8 +223,180 @@ pub struct CompleteAttendanceInput {
   pub attendance_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelAttendanceInput {
+  pub attendance_id: String,
+  pub reason: String,
+}
+
+/// Technical validation: the biochemist signs every item of the attendance with their password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAttendanceInput {
+  pub attendance_id: String,
+  pub password: String,
+  pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionAttendanceInput {
+  pub attendance_id: String,
+  pub to_status: String,
+  pub note: Option<String>,
+}
+
+/// Compare-and-set status change handed to the repository once the transition was validated.
+#[derive(Debug, Clone)]
+pub struct AttendanceStatusChange {
+  pub attendance_id: String,
+  pub from_status: String,
+  pub to_status: String,
+  pub changed_by_user_id: Option<String>,
+  pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceStatusHistoryView {
+  pub id: String,
+  pub attendance_id: String,
+  pub from_status: Option<String>,
+  pub to_status: String,
+  pub changed_by_user_id: Option<String>,
+  pub changed_at: String,
+  pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnterExamResultsInput {
+  pub attendance_id: String,
+  pub items: Vec<ExamItemResultInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExamItemResultInput {
+  pub exam_item_id: String,
+  pub result_value: String,
+  pub result_flag: Option<String>,
+}
+
+/// Saved results plus how each one moved from the patient's previous released value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnterExamResultsView {
+  pub entry: PatientRecordEntryView,
+  pub delta_checks: Vec<DeltaCheckView>,
+}
+
+/// Comparison of a result with the most recent released value of the same catalog exam.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaCheckView {
+  pub exam_item_id: String,
+  pub previous_attendance_id: String,
+  pub previous_exam_date: String,
+  pub previous_value: String,
+  pub current_value: String,
+  /// `None` when either value is not numeric.
+  pub absolute_change: Option<f64>,
+  pub percent_change: Option<f64>,
+  /// The change reached a threshold configured with `set_exam_delta_check`.
+  pub exceeds_threshold: bool,
+}
+
+/// Correction of a released result; the replaced value goes to the result history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendResultInput {
+  pub attendance_id: String,
+  pub exam_item_id: String,
+  pub result_value: String,
+  pub result_flag: Option<String>,
+  pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendResultView {
+  pub entry: PatientRecordEntryView,
+  /// The laudo reissued as "retificado"; `None` when no laudo had been generated yet or the
+  /// reissue failed.
+  pub report: Option<GeneratedLabReportView>,
+  /// Why the reissue failed; the amendment itself was saved, so the laudo must be generated again.
+  pub report_error: Option<String>,
+}
+
+/// A result value that was replaced by an amendment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultHistoryView {
+  pub id: String,
+  pub exam_item_id: String,
+  pub result_version: i64,
+  pub result_value: Option<String>,
+  pub result_flag: Option<String>,
+  pub reason: String,
+  pub amended_by_user_id: Option<String>,
+  pub amended_at: String,
+}
+
+/// Notification of a panic value (result flagged LL/HH) to the requester. Pending while
+/// `acknowledged_at` is `None`; the attendance cannot be released until then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalAlertView {
+  pub id: String,
+  pub attendance_id: String,
+  pub exam_item_id: String,
+  pub patient_id: String,
+  pub patient_name: String,
+  pub exam_name: String,
+  pub result_value: String,
+  pub result_flag: String,
+  pub unit: Option<String>,
+  pub requester_name: Option<String>,
+  pub detected_at: String,
+  pub detected_by_user_id: Option<String>,
+  pub acknowledged_at: Option<String>,
+  pub acknowledged_by_user_id: Option<String>,
+  /// Who was told, e.g. the requesting physician or the nurse on duty.
+  pub contact_name: Option<String>,
+  /// phone | in_person | email | message
+  pub contact_method: Option<String>,
+  pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcknowledgeCriticalAlertInput {
+  pub alert_id: String,
+  pub contact_name: String,
+  pub contact_method: String,
+  pub note: Option<String>,
+}
+
+/// What result entry needs to know about an attendance to validate and flag results.
+#[derive(Debug, Clone)]
+pub struct ResultEntryContext {
+  pub attendance_id: String,
+  pub status: String,
+  pub exam_date: String,
+  pub patient_sex: String,
+  pub patient_birth_date: String,
+  pub items: Vec<ResultEntryItemContext>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResultEntryItemContext {
+  pub exam_item_id: String,
+  pub catalog_item_id: Option<String>,
+  pub reference_ranges: Vec<ReferenceRange>,
+  pub delta_check: DeltaCheckRule,
+  pub previous_result: Option<PreviousResult>,
+}
+
+/// Most recent released value of the same catalog exam in an earlier attendance of the patient.
+#[derive(Debug, Clone)]
+pub struct PreviousResult {
+  pub attendance_id: String,
+  pub exam_date: String,
+  pub result_value: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAttendanceItemInput {
+  pub catalog_item_id: Option<String>,
   pub name: String,
   pub unit: Option<String>,
   pub method: Option<String>,
@@ -74,6 +410,69 @@ pub struct ExamCatalogItemView {
   pub category_id: String,
   pub category_title: String,
   pub price_cents: i64,
+  pub unit: Option<String>,
+  pub method: Option<String>,
+  pub reference_range: Option<String>,
+  pub is_active: bool,
+  /// Active analytes of a panel, in report order; empty for a single exam.
+  pub analyte_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExamCategoryView {
+  pub id: String,
+  pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateExamCategoryInput {
+  pub id: String,
+  pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateExamCatalogItemInput {
+  pub name: String,
+  pub category_id: String,
+  pub price_cents: i64,
+  pub unit: Option<String>,
+  pub method: Option<String>,
+  pub reference_range: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateExamCatalogItemInput {
+  pub id: String,
+  pub name: String,
+  pub category_id: String,
+  pub price_cents: i64,
+  pub unit: Option<String>,
+  pub method: Option<String>,
+  pub reference_range: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeactivateExamCatalogItemInput {
+  pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetExamReferenceRangesInput {
+  pub catalog_item_id: String,
+  pub ranges: Vec<ReferenceRange>,
+}
+
+/// Replaces the analytes of a panel; an empty list turns it back into a single exam.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetExamPanelAnalytesInput {
+  pub panel_id: String,
+  pub analyte_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetExamDeltaCheckInput {
+  pub catalog_item_id: String,
+  pub rule: DeltaCheckRule,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +488,7 @@ pub struct PatientRecordEntryView {
   pub status: String,
   pub requester_name: Option<String>,
   pub items: Vec<PatientRecordExamItemView>,
+  pub deleted_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,5 +500,12 @@ pub struct PatientRecordExamItemView {
   pub reference_range: Option<String>,
   pub result_value: Option<String>,
   pub result_flag: Option<String>,
+  /// The result was signed in `release_attendance`; typing a value alone does not make it
+  /// reportable.
   pub report_available: bool,
+  /// Starts at 1 and grows with each `amend_result`; earlier values are in the result history.
+  pub result_version: i64,
+  /// Panel the item was ordered in, for grouping its analytes; `None` for a single exam.
+  pub panel_id: Option<String>,
+  pub panel_name: Option<String>,
 }
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use super::entity::Patient;
+
+pub const REASON_SIMILAR_NAME: &str = "similar_name";
+pub const REASON_SAME_BIRTH_DATE: &str = "same_birth_date";
+pub const REASON_SAME_PHONE: &str = "same_phone";
+
+/// Score a pair needs to be reported when the caller does not choose one. A matching name alone
+/// (50) is not enough: it must come with the birth date or the phone.
+pub const DEFAULT_MIN_SCORE: i64 = 60;
+
+const NAME_WEIGHT: f64 = 50.0;
+const BIRTH_DATE_WEIGHT: i64 = 30;
+const PHONE_WEIGHT: i64 = 20;
+const SIMILAR_NAME_THRESHOLD: f64 = 0.7;
+/// Phones are compared on their last digits, so `+55 11 98888-7777` matches `98888-7777`.
+const PHONE_SUFFIX_LEN: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateScore {
+  /// 0..=100: up to 50 for the name, 30 for the birth date, 20 for the phone.
+  pub score: i64,
+  pub reasons: Vec<String>,
+}
+
+pub fn score_duplicate(a: &Patient, b: &Patient) -> DuplicateScore {
+  let mut score = 0;
+  let mut reasons = Vec::new();
+
+  let similarity = name_similarity(&a.full_name, &b.full_name);
+  score += (similarity * NAME_WEIGHT).round() as i64;
+  if similarity >= SIMILAR_NAME_THRESHOLD {
+    reasons.push(REASON_SIMILAR_NAME.to_string());
+  }
+
+  let birth_date = date_part(&a.birth_date);
+  if !birth_date.is_empty() && birth_date == date_part(&b.birth_date) {
+    score += BIRTH_DATE_WEIGHT;
+    reasons.push(REASON_SAME_BIRTH_DATE.to_string());
+  }
+
+  if let (Some(phone_a), Some(phone_b)) = (phone_suffix(&a.phone), phone_suffix(&b.phone)) {
+    if phone_a == phone_b {
+      score += PHONE_WEIGHT;
+      reasons.push(REASON_SAME_PHONE.to_string());
+    }
+  }
+
+  DuplicateScore { score, reasons }
+}
+
+/// Dice coefficient over character bigrams of the normalized names (0.0..=1.0). Tolerates typos
+/// and a missing middle name better than comparing whole words.
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+  let a = normalize_name(a);
+  let b = normalize_name(b);
+  if a.is_empty() || b.is_empty() {
+    return 0.0;
+  }
+  if a == b {
+    return 1.0;
+  }
+
+  let bigrams_a = bigrams(&a);
+  let bigrams_b = bigrams(&b);
+  let mut unmatched_b: HashMap<(char, char), usize> = HashMap::new();
+  for bigram in &bigrams_b {
+    *unmatched_b.entry(*bigram).or_default() += 1;
+  }
+
+  let mut shared = 0;
+  for bigram in &bigrams_a {
+    if let Some(count) = unmatched_b.get_mut(bigram).filter(|count| **count > 0) {
+      *count -= 1;
+      shared += 1;
+    }
+  }
+
+  let total = bigrams_a.len() + bigrams_b.len();
+  if total == 0 {
+    return 0.0;
+  }
+  (2 * shared) as f64 / total as f64
+}
+
+/// Lowercase, accents folded, connectives (`da`, `de`, `dos`, ...) dropped, single spaces.
+pub fn normalize_name(name: &str) -> String {
+  let folded: String = name.to_lowercase().chars().map(fold_accent).collect();
+  folded
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty() && !matches!(*word, "da" | "de" | "do" | "das" | "dos" | "e"))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+pub fn fold_accent(c: char) -> char {
+  match c {
+    'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+    'é' | 'è' | 'ê' | 'ë' => 'e',
+    'í' | 'ì' | 'î' | 'ï' => 'i',
+    'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+    'ú' | 'ù' | 'û' | 'ü' => 'u',
+    'ç' => 'c',
+    'ñ' => 'n',
+    other => other,
+  }
+}
+
+fn bigrams(value: &str) -> Vec<(char, char)> {
+  let chars: Vec<char> = value.chars().collect();
+  chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+fn date_part(value: &str) -> &str {
+  let value = value.trim();
+  value.get(0..10).unwrap_or(value)
+}
+
+fn phone_suffix(phone: &str) -> Option<String> {
+  let digits: Vec<char> = phone.chars().filter(char::is_ascii_digit).collect();
+  (digits.len() >= PHONE_SUFFIX_LEN)
+    .then(|| digits[digits.len() - PHONE_SUFFIX_LEN..].iter().collect())
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicatePair<'a> {
+  pub patient: &'a Patient,
+  pub duplicate: &'a Patient,
+  pub score: DuplicateScore,
+}
+
+/// Pairs scoring at least `min_score`, best first. Only patients sharing the birth date or the
+/// phone are compared (the name alone never reaches the default threshold). With `patient_id`,
+/// only pairs involving that patient are returned, with it as `patient`.
+pub fn find_duplicates<'a>(
+  patients: &'a [Patient],
+  patient_id: Option<&str>,
+  min_score: i64,
+) -> Vec<DuplicatePair<'a>> {
+  let mut blocks: HashMap<String, Vec<usize>> = HashMap::new();
+  for (index, patient) in patients.iter().enumerate() {
+    let birth_date = date_part(&patient.birth_date);
+    if !birth_date.is_empty() {
+      blocks
+        .entry(format!("birth:{birth_date}"))
+        .or_default()
+        .push(index);
+    }
+    if let Some(phone) = phone_suffix(&patient.phone) {
+      blocks
+        .entry(format!("phone:{phone}"))
+        .or_default()
+        .push(index);
+    }
+  }
+
+  let mut pairs: Vec<(usize, usize)> = blocks
+    .values()
+    .flat_map(|members| {
+      members
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| members[i + 1..].iter().map(move |b| (*a, *b)))
+    })
+    .collect();
+  pairs.sort_unstable();
+  pairs.dedup();
+
+  let mut found: Vec<DuplicatePair<'a>> = pairs
+    .into_iter()
+    .filter_map(|(a, b)| {
+      let (a, b) = (&patients[a], &patients[b]);
+      let (patient, duplicate) = match patient_id {
+        Some(id) if a.id == id => (a, b),
+        Some(id) if b.id == id => (b, a),
+        Some(_) => return None,
+        None => (a, b),
+      };
+      let score = score_duplicate(patient, duplicate);
+      (score.score >= min_score).then_some(DuplicatePair {
+        patient,
+        duplicate,
+        score,
+      })
+    })
+    .collect();
+
+  found.sort_by(|x, y| {
+    y.score
+      .score
+      .cmp(&x.score.score)
+      .then_with(|| x.patient.full_name.cmp(&y.patient.full_name))
+      .then_with(|| x.duplicate.full_name.cmp(&y.duplicate.full_name))
+  });
+  found
+}
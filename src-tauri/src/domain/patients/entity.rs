@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use super::errors::PatientDomainError;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Patient {
   pub id: String,
   pub full_name: String,
@@ -11,6 +13,8 @@ pub struct Patient {
   pub address: String,
   pub created_at: String,
   pub updated_at: String,
+  /// Set while the patient is soft-deleted.
+  pub deleted_at: Option<String>,
 }
 
 impl Patient {
@@ -42,6 +46,7 @@ impl Patient {
       address,
       created_at,
       updated_at,
+      deleted_at: None,
     })
   }
 }
@@ -2,6 +2,10 @@
 pub enum PatientDomainError {
   FullNameRequired,
   CpfRequired,
+  InvalidCpf(String),
+  UnknownAttendanceStatus(String),
+  InvalidStatusTransition { from: String, to: String },
+  InvalidReferenceRange(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,4 +1,11 @@
+pub mod attendance_status;
+pub mod calendar;
+pub mod cpf;
+pub mod delta_check;
+pub mod duplicates;
 pub mod dto;
 pub mod entity;
 pub mod errors;
+pub mod pagination;
 pub mod ports;
+pub mod reference_range;
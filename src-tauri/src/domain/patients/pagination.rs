@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 50;
+pub const MAX_PAGE_LIMIT: i64 = 200;
+
+/// One page of a keyset-paginated listing. `next_cursor` is `None` on the last page; `total`
+/// counts every row matching the filters, not just this page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageView<T> {
+  pub items: Vec<T>,
+  pub next_cursor: Option<String>,
+  pub total: i64,
+}
+
+/// Order of `list_patients`. `created_at` is newest first, `name` is alphabetical and
+/// `relevance` (only with a search query) puts the best name matches first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatientSort {
+  #[default]
+  CreatedAt,
+  Name,
+  Relevance,
+}
+
+impl PatientSort {
+  pub const ALL: [PatientSort; 3] = [
+    PatientSort::CreatedAt,
+    PatientSort::Name,
+    PatientSort::Relevance,
+  ];
+
+  pub fn parse(value: &str) -> Option<Self> {
+    match value.trim() {
+      "created_at" => Some(PatientSort::CreatedAt),
+      "name" => Some(PatientSort::Name),
+      "relevance" => Some(PatientSort::Relevance),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      PatientSort::CreatedAt => "created_at",
+      PatientSort::Name => "name",
+      PatientSort::Relevance => "relevance",
+    }
+  }
+
+  /// Values a cursor carries for this order: the sort columns, then the id as tie-breaker.
+  /// `relevance` sorts on the match tier and the name length, both integers.
+  pub fn cursor_keys(&self) -> usize {
+    match self {
+      PatientSort::CreatedAt | PatientSort::Name => 2,
+      PatientSort::Relevance => 3,
+    }
+  }
+
+  pub fn accepts(&self, cursor: &PageCursor) -> bool {
+    match self {
+      PatientSort::CreatedAt | PatientSort::Name => true,
+      PatientSort::Relevance => cursor.keys[..2]
+        .iter()
+        .all(|key| key.parse::<i64>().is_ok()),
+    }
+  }
+}
+
+/// Order of `list_attendance_queue`. `exam_date` and `created_at` are newest first, `name`
+/// (patient name) is alphabetical.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AttendanceSort {
+  #[default]
+  ExamDate,
+  CreatedAt,
+  Name,
+}
+
+impl AttendanceSort {
+  pub const ALL: [AttendanceSort; 3] = [
+    AttendanceSort::ExamDate,
+    AttendanceSort::CreatedAt,
+    AttendanceSort::Name,
+  ];
+
+  pub fn parse(value: &str) -> Option<Self> {
+    match value.trim() {
+      "exam_date" => Some(AttendanceSort::ExamDate),
+      "created_at" => Some(AttendanceSort::CreatedAt),
+      "name" => Some(AttendanceSort::Name),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AttendanceSort::ExamDate => "exam_date",
+      AttendanceSort::CreatedAt => "created_at",
+      AttendanceSort::Name => "name",
+    }
+  }
+
+  /// `exam_date` ties are broken by `created_at` and then the id; the others only by the id.
+  pub fn cursor_keys(&self) -> usize {
+    match self {
+      AttendanceSort::ExamDate => 3,
+      AttendanceSort::CreatedAt | AttendanceSort::Name => 2,
+    }
+  }
+}
+
+/// Sort key values of the last row of a page; the next page starts strictly after them. Clients
+/// get it as an opaque string (hex of a small JSON document) and must send it back untouched
+/// with the same `sort`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+  pub sort: String,
+  pub keys: Vec<String>,
+}
+
+impl PageCursor {
+  pub fn new(sort: &str, keys: Vec<String>) -> Self {
+    Self {
+      sort: sort.to_string(),
+      keys,
+    }
+  }
+
+  pub fn encode(&self) -> String {
+    let json = serde_json::to_string(self).unwrap_or_default();
+    json.bytes().map(|byte| format!("{byte:02x}")).collect()
+  }
+
+  /// `None` when `value` is not a cursor issued for `sort` with `key_count` values.
+  pub fn decode(value: &str, sort: &str, key_count: usize) -> Option<Self> {
+    let value = value.trim();
+    if !value.len().is_multiple_of(2) || !value.is_ascii() {
+      return None;
+    }
+    let bytes = (0..value.len())
+      .step_by(2)
+      .map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+      .collect::<Option<Vec<u8>>>()?;
+    let cursor: PageCursor = serde_json::from_slice(&bytes).ok()?;
+    (cursor.sort == sort && cursor.keys.len() == key_count).then_some(cursor)
+  }
+}
+
+/// `limit` defaulted and checked against `1..=MAX_PAGE_LIMIT`; `None` when out of range.
+pub fn resolve_page_limit(limit: Option<i64>) -> Option<i64> {
+  let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+  (1..=MAX_PAGE_LIMIT).contains(&limit).then_some(limit)
+}
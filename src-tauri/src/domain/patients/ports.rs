@@ -1,34 +1,232 @@
 use async_trait::async_trait;
 
 use super::{
+  calendar::CalendarDate,
+  delta_check::DeltaCheckRule,
   dto::{
-    AttendanceQueueItemView, AttendanceQueueQueryInput, CompleteAttendanceInput,
-    CreateAttendanceInput, CreatePatientInput, ExamCatalogItemView, PatientRecordEntryView,
-    PatientRecordView,
+    AcknowledgeCriticalAlertInput, AmendResultInput, AttendanceEditContext,
+    AttendanceQueueItemView, AttendanceQueuePageQuery, AttendanceStatusChange,
+    AttendanceStatusHistoryView, AttendanceUpdate, CompleteAttendanceInput, CreateAttendanceInput,
+    CreateExamCatalogItemInput, CreateExamCategoryInput, CreatePatientInput, CriticalAlertView,
+    DeactivateExamCatalogItemInput, EnterExamResultsInput, ExamCatalogItemView, ExamCategoryView,
+    MergePatientsInput, MergePatientsResultView, PatientPageQuery, PatientRecordEntryView,
+    PatientRecordView, ResultEntryContext, ResultHistoryView, SetExamDeltaCheckInput,
+    SetExamPanelAnalytesInput, SetExamReferenceRangesInput, UpdateExamCatalogItemInput,
+    UpdatePatientInput,
   },
   entity::Patient,
   errors::PatientRepositoryError,
+  pagination::PageView,
+  reference_range::ReferenceRange,
 };
 
 #[async_trait]
 pub trait PatientRepository: Send + Sync {
-  async fn insert(&self, input: CreatePatientInput) -> Result<Patient, PatientRepositoryError>;
-  async fn list(&self, query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError>;
+  async fn insert(
+    &self,
+    input: CreatePatientInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError>;
+  /// Soft-deleted patients are skipped unless `include_deleted`; merged ones always are.
+  async fn list(
+    &self,
+    query: PatientPageQuery,
+  ) -> Result<PageView<Patient>, PatientRepositoryError>;
+  /// Compare-and-set on `updated_at`: `Conflict` if it no longer matches `expected_updated_at`.
+  async fn update_patient(
+    &self,
+    input: UpdatePatientInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError>;
+  /// Soft-deleted patients come back with `deleted_at` set; merged ones are `None`.
+  async fn find_patient(
+    &self,
+    patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError>;
+  /// `NotFound` for a soft-deleted patient and no soft-deleted entries, unless `include_deleted`.
   async fn get_patient_record(
     &self,
     patient_id: String,
+    include_deleted: bool,
   ) -> Result<PatientRecordView, PatientRepositoryError>;
-  async fn list_exam_catalog(&self) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError>;
+  async fn list_exam_catalog(
+    &self,
+    include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError>;
   async fn create_attendance(
     &self,
     input: CreateAttendanceInput,
+    performed_by_user_id: Option<String>,
   ) -> Result<PatientRecordEntryView, PatientRepositoryError>;
+  /// Pages over attendances: each item carries all of its exam names whatever the page size.
   async fn list_attendance_queue(
     &self,
-    input: AttendanceQueueQueryInput,
-  ) -> Result<Vec<AttendanceQueueItemView>, PatientRepositoryError>;
+    query: AttendanceQueuePageQuery,
+  ) -> Result<PageView<AttendanceQueueItemView>, PatientRepositoryError>;
   async fn complete_attendance(
     &self,
     input: CompleteAttendanceInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+}
+
+#[async_trait]
+pub trait ExamCatalogRepository: Send + Sync {
+  async fn list_exam_categories(&self) -> Result<Vec<ExamCategoryView>, PatientRepositoryError>;
+  async fn create_exam_category(
+    &self,
+    input: CreateExamCategoryInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCategoryView, PatientRepositoryError>;
+  async fn create_exam_catalog_item(
+    &self,
+    input: CreateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError>;
+  async fn update_exam_catalog_item(
+    &self,
+    input: UpdateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError>;
+  async fn deactivate_exam_catalog_item(
+    &self,
+    input: DeactivateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError>;
+  async fn list_exam_reference_ranges(
+    &self,
+    catalog_item_id: String,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError>;
+  async fn set_exam_reference_ranges(
+    &self,
+    input: SetExamReferenceRangesInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError>;
+  async fn get_exam_delta_check(
+    &self,
+    catalog_item_id: String,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError>;
+  async fn set_exam_delta_check(
+    &self,
+    input: SetExamDeltaCheckInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError>;
+  /// `NotFound` if the panel or an analyte is missing or the analyte is inactive; `Conflict` if
+  /// an analyte is itself a panel or the panel is an analyte of another one (panels do not nest).
+  async fn set_exam_panel_analytes(
+    &self,
+    input: SetExamPanelAnalytesInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError>;
+}
+
+#[async_trait]
+pub trait AttendanceRepository: Send + Sync {
+  async fn get_attendance(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+  async fn change_attendance_status(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+  /// Status change to `cancelled` that also stores who cancelled and why (`change.note`).
+  async fn cancel_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+  /// Status change to `released` that signs the attendance and every item with
+  /// `change.changed_by_user_id`; `Conflict` if an item still has no result or an
+  /// unacknowledged critical alert.
+  async fn release_attendance(
+    &self,
+    change: AttendanceStatusChange,
   ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+  async fn list_attendance_status_history(
+    &self,
+    attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError>;
+  async fn get_result_entry_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError>;
+  async fn enter_exam_results(
+    &self,
+    input: EnterExamResultsInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError>;
+  /// Replaces a released result, moving the previous value to the result history in the same
+  /// transaction; `Conflict` if the item was not released.
+  async fn amend_result(
+    &self,
+    input: AmendResultInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError>;
+  /// Replaced values of an item, oldest first; `NotFound` if the item does not exist.
+  async fn list_result_history(
+    &self,
+    exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError>;
+  /// `NotFound` for a soft-deleted attendance or one of a soft-deleted patient.
+  async fn get_attendance_edit_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError>;
+  /// `Conflict` if the status moved away from `expected_status`, or if items change after a
+  /// result was entered.
+  async fn update_attendance(
+    &self,
+    update: AttendanceUpdate,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError>;
+}
+
+/// Critical alerts are opened by `enter_exam_results` and `amend_result` when the stored flag is
+/// LL/HH, in the same transaction as the result.
+#[async_trait]
+pub trait CriticalAlertRepository: Send + Sync {
+  /// Unacknowledged alerts of attendances that were not soft-deleted, oldest first.
+  async fn list_pending_critical_alerts(
+    &self,
+  ) -> Result<Vec<CriticalAlertView>, PatientRepositoryError>;
+  /// `NotFound` if the alert does not exist, `Conflict` if it was already acknowledged.
+  async fn acknowledge_critical_alert(
+    &self,
+    input: AcknowledgeCriticalAlertInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<CriticalAlertView, PatientRepositoryError>;
+}
+
+#[async_trait]
+pub trait PatientMergeRepository: Send + Sync {
+  /// In one transaction: moves every exam of `merged_patient_id` to `surviving_patient_id` and
+  /// tombstones the merged record. `NotFound` if either patient is missing or already merged.
+  async fn merge_patients(
+    &self,
+    input: MergePatientsInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<MergePatientsResultView, PatientRepositoryError>;
+}
+
+/// Soft delete: rows stay in place (and in `audit_log`), only `deleted_at`/`deleted_by` change.
+/// `NotFound` if the row is missing, `Conflict` if it is already in the requested state.
+#[async_trait]
+pub trait RecordDeletionRepository: Send + Sync {
+  async fn set_patient_deleted(
+    &self,
+    patient_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError>;
+  async fn set_attendance_deleted(
+    &self,
+    attendance_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError>;
+}
+
+/// Source of the current date, so date rules can be tested against a fixed day.
+pub trait Clock: Send + Sync {
+  fn today(&self) -> CalendarDate;
 }
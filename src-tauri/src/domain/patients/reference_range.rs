@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::PatientDomainError;
+
+/// Structured reference range of a catalog exam. A catalog exam may have several ranges,
+/// narrowed by patient sex and age; the most specific applicable one is used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceRange {
+  pub sex: Option<String>,
+  pub age_min_years: Option<i64>,
+  pub age_max_years: Option<i64>,
+  pub low: Option<f64>,
+  pub high: Option<f64>,
+  pub critical_low: Option<f64>,
+  pub critical_high: Option<f64>,
+  pub unit: Option<String>,
+  /// Expected answer of qualitative exams (e.g. "Negativo").
+  pub expected_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultFlag {
+  Low,
+  High,
+  CriticalLow,
+  CriticalHigh,
+  Normal,
+  OutOfRange,
+}
+
+impl ResultFlag {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ResultFlag::Low => "L",
+      ResultFlag::High => "H",
+      ResultFlag::CriticalLow => "LL",
+      ResultFlag::CriticalHigh => "HH",
+      ResultFlag::Normal => "normal",
+      ResultFlag::OutOfRange => "out_of_range",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<ResultFlag> {
+    match value {
+      "L" => Some(ResultFlag::Low),
+      "H" => Some(ResultFlag::High),
+      "LL" => Some(ResultFlag::CriticalLow),
+      "HH" => Some(ResultFlag::CriticalHigh),
+      "normal" => Some(ResultFlag::Normal),
+      "out_of_range" => Some(ResultFlag::OutOfRange),
+      _ => None,
+    }
+  }
+
+  pub fn is_critical(&self) -> bool {
+    matches!(self, ResultFlag::CriticalLow | ResultFlag::CriticalHigh)
+  }
+}
+
+impl ReferenceRange {
+  pub fn validate(&self) -> Result<(), PatientDomainError> {
+    let invalid = |msg: &str| Err(PatientDomainError::InvalidReferenceRange(msg.to_string()));
+
+    if let Some(sex) = &self.sex {
+      if sex != "F" && sex != "M" {
+        return invalid("sex must be F or M");
+      }
+    }
+    if self.age_min_years.is_some_and(|age| age < 0) || self.age_max_years.is_some_and(|age| age < 0) {
+      return invalid("age bounds must not be negative");
+    }
+    if let (Some(min), Some(max)) = (self.age_min_years, self.age_max_years) {
+      if min > max {
+        return invalid("age_min_years must not exceed age_max_years");
+      }
+    }
+    if self.low.is_none()
+      && self.high.is_none()
+      && self.critical_low.is_none()
+      && self.critical_high.is_none()
+      && self.expected_text.as_deref().is_none_or(|text| text.trim().is_empty())
+    {
+      return invalid("a numeric bound or expected_text is required");
+    }
+    if let (Some(low), Some(high)) = (self.low, self.high) {
+      if low > high {
+        return invalid("low must not exceed high");
+      }
+    }
+    if let (Some(critical_low), Some(low)) = (self.critical_low, self.low) {
+      if critical_low > low {
+        return invalid("critical_low must not exceed low");
+      }
+    }
+    if let (Some(critical_high), Some(high)) = (self.critical_high, self.high) {
+      if critical_high < high {
+        return invalid("critical_high must not be below high");
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn applies_to(&self, sex: &str, age_years: Option<i64>) -> bool {
+    if let Some(range_sex) = &self.sex {
+      if !range_sex.eq_ignore_ascii_case(sex.trim()) {
+        return false;
+      }
+    }
+    if self.age_min_years.is_none() && self.age_max_years.is_none() {
+      return true;
+    }
+    let Some(age) = age_years else {
+      return false;
+    };
+    self.age_min_years.is_none_or(|min| age >= min) && self.age_max_years.is_none_or(|max| age <= max)
+  }
+
+  fn specificity(&self) -> u8 {
+    let sex = if self.sex.is_some() { 2 } else { 0 };
+    let age = if self.age_min_years.is_some() || self.age_max_years.is_some() {
+      1
+    } else {
+      0
+    };
+    sex + age
+  }
+
+  fn has_numeric_bounds(&self) -> bool {
+    self.low.is_some() || self.high.is_some() || self.critical_low.is_some() || self.critical_high.is_some()
+  }
+
+  pub fn evaluate(&self, value: &str) -> ResultFlag {
+    match parse_numeric(value) {
+      Some(number) if self.has_numeric_bounds() => {
+        if self.critical_low.is_some_and(|limit| number < limit) {
+          ResultFlag::CriticalLow
+        } else if self.critical_high.is_some_and(|limit| number > limit) {
+          ResultFlag::CriticalHigh
+        } else if self.low.is_some_and(|limit| number < limit) {
+          ResultFlag::Low
+        } else if self.high.is_some_and(|limit| number > limit) {
+          ResultFlag::High
+        } else {
+          ResultFlag::Normal
+        }
+      }
+      _ => match &self.expected_text {
+        Some(expected) if expected.trim().to_lowercase() == value.trim().to_lowercase() => {
+          ResultFlag::Normal
+        }
+        _ => ResultFlag::OutOfRange,
+      },
+    }
+  }
+}
+
+/// Picks the most specific range that applies to the patient (sex-specific beats age-specific,
+/// which beats a generic range).
+pub fn select_reference_range<'a>(
+  ranges: &'a [ReferenceRange],
+  sex: &str,
+  age_years: Option<i64>,
+) -> Option<&'a ReferenceRange> {
+  ranges
+    .iter()
+    .filter(|range| range.applies_to(sex, age_years))
+    .fold(None, |best: Option<&ReferenceRange>, range| match best {
+      Some(current) if current.specificity() >= range.specificity() => Some(current),
+      _ => Some(range),
+    })
+}
+
+pub fn compute_result_flag(
+  value: &str,
+  ranges: &[ReferenceRange],
+  sex: &str,
+  age_years: Option<i64>,
+) -> Option<ResultFlag> {
+  select_reference_range(ranges, sex, age_years).map(|range| range.evaluate(value))
+}
+
+/// Whole years between `birth_date` and `on_date` (both `YYYY-MM-DD`, time suffix ignored).
+pub fn age_in_years(birth_date: &str, on_date: &str) -> Option<i64> {
+  let (birth_year, birth_month, birth_day) = parse_date(birth_date)?;
+  let (year, month, day) = parse_date(on_date)?;
+
+  let mut age = year - birth_year;
+  if (month, day) < (birth_month, birth_day) {
+    age -= 1;
+  }
+
+  (age >= 0).then_some(age)
+}
+
+fn parse_date(value: &str) -> Option<(i64, i64, i64)> {
+  let date = value.trim().get(0..10)?;
+  let mut parts = date.split('-');
+  let year = parts.next()?.parse().ok()?;
+  let month = parts.next()?.parse().ok()?;
+  let day = parts.next()?.parse().ok()?;
+  Some((year, month, day))
+}
+
+pub(crate) fn parse_numeric(value: &str) -> Option<f64> {
+  value.trim().replace(',', ".").parse::<f64>().ok().filter(|n| n.is_finite())
+}
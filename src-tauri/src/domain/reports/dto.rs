@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::patients::dto::{PatientRecordEntryView, PatientView};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateLabReportInput {
+  pub attendance_id: String,
+  pub note: Option<String>,
+}
+
+/// Everything a laudo shows, loaded in one go from the attendance.
+#[derive(Debug, Clone)]
+pub struct LabReportSource {
+  pub patient: PatientView,
+  pub entry: PatientRecordEntryView,
+  /// Version of the last generated report, `None` when the attendance never had one.
+  pub current_report_version: Option<i64>,
+  /// Some result was amended after release, so the laudo is printed as "retificado".
+  pub amended: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordPdfReportInput {
+  pub attendance_id: String,
+  pub report_version: i64,
+  pub generated_by_user_id: Option<String>,
+  pub note: Option<String>,
+  pub amended: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfReportView {
+  pub id: String,
+  pub attendance_id: String,
+  pub report_version: i64,
+  pub generated_by_user_id: Option<String>,
+  pub generated_at: String,
+  pub note: Option<String>,
+  pub is_amended: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedLabReportView {
+  pub report: PdfReportView,
+  pub file_path: String,
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportRepositoryError {
+  PersistenceError,
+
+  NotFound,
+
+  Conflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportStorageError {
+  InvalidFileName(String),
+
+  Io(String),
+}
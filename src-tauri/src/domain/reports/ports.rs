@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use super::{
+  dto::{LabReportSource, PdfReportView, RecordPdfReportInput},
+  errors::{ReportRepositoryError, ReportStorageError},
+};
+
+#[async_trait]
+pub trait ReportRepository: Send + Sync {
+  async fn get_lab_report_source(
+    &self,
+    attendance_id: String,
+  ) -> Result<LabReportSource, ReportRepositoryError>;
+  /// Inserts version 1 or bumps the existing row; fails with `Conflict` when
+  /// `input.report_version` is not the successor of the stored version.
+  async fn record_pdf_report(
+    &self,
+    input: RecordPdfReportInput,
+  ) -> Result<PdfReportView, ReportRepositoryError>;
+}
+
+pub trait LabReportRenderer: Send + Sync {
+  fn render(&self, source: &LabReportSource, report_version: i64) -> Vec<u8>;
+}
+
+pub trait ReportFileStore: Send + Sync {
+  /// Stores the file and returns its absolute path.
+  fn save(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError>;
+  /// Stores the file under a temporary name no other call gets and returns that name; it only
+  /// shows up as `file_name` once promoted.
+  fn stage(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError>;
+  /// Renames a staged file to `file_name` and returns its absolute path.
+  fn promote(&self, staged_name: &str, file_name: &str) -> Result<String, ReportStorageError>;
+  /// Removes a staged file that will not be promoted; a missing file is not an error.
+  fn discard(&self, staged_name: &str);
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::registry::CouncilRegistry;
+
+/// Council fields are all-or-nothing: either the three are filled or the three are blank.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRequesterInput {
+  pub name: String,
+  pub council_type: Option<String>,
+  pub council_number: Option<String>,
+  pub council_state: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRequesterInput {
+  pub id: String,
+  pub name: String,
+  pub council_type: Option<String>,
+  pub council_number: Option<String>,
+  pub council_state: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRequesterActiveInput {
+  pub requester_id: String,
+  pub is_active: bool,
+}
+
+/// Autocomplete query: words match the start of name words, digits the start of the council
+/// number, and `CRM`/`SP`-style tokens the council type or state. A blank query lists everyone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchRequestersInput {
+  pub query: Option<String>,
+  pub limit: Option<i64>,
+}
+
+/// `council_*` are `None` for requesters registered before the council data existed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequesterView {
+  pub id: String,
+  pub name: String,
+  pub council_type: Option<String>,
+  pub council_number: Option<String>,
+  pub council_state: Option<String>,
+  pub is_active: bool,
+  pub created_at: String,
+  pub updated_at: String,
+}
+
+/// Validated requester data, ready to be written.
+#[derive(Debug, Clone)]
+pub struct NewRequester {
+  pub name: String,
+  pub registry: Option<CouncilRegistry>,
+}
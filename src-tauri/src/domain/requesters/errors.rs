@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequesterDomainError {
+  NameRequired,
+  /// `field` is the council input at fault.
+  InvalidRegistry {
+    field: &'static str,
+    message: String,
+  },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequesterRepositoryError {
+  PersistenceError,
+
+  NotFound,
+
+  Conflict,
+}
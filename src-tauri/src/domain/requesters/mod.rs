@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod errors;
+pub mod ports;
+pub mod registry;
+pub mod search;
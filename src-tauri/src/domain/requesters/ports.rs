@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use super::{
+  dto::{NewRequester, RequesterView},
+  errors::RequesterRepositoryError,
+};
+
+#[async_trait]
+pub trait RequesterRepository: Send + Sync {
+  async fn list_requesters(
+    &self,
+    include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, RequesterRepositoryError>;
+  async fn find_requester(
+    &self,
+    requester_id: String,
+  ) -> Result<Option<RequesterView>, RequesterRepositoryError>;
+  /// Fails with `Conflict` when another requester already holds the same council registry.
+  async fn insert_requester(
+    &self,
+    requester: NewRequester,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError>;
+  async fn update_requester(
+    &self,
+    requester_id: String,
+    requester: NewRequester,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError>;
+  async fn set_requester_active(
+    &self,
+    requester_id: String,
+    is_active: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError>;
+}
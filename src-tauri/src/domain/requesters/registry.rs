@@ -0,0 +1,104 @@
+use super::errors::RequesterDomainError;
+
+/// Professional councils whose members may request lab exams.
+pub const COUNCIL_TYPES: [&str; 9] = [
+  "CRM", "CRO", "CRBM", "CRF", "COREN", "CRN", "CRMV", "CRP", "CREFITO",
+];
+
+/// Brazilian states (UF) that issue council registrations.
+pub const COUNCIL_STATES: [&str; 27] = [
+  "AC", "AL", "AM", "AP", "BA", "CE", "DF", "ES", "GO", "MA", "MG", "MS", "MT", "PA", "PB", "PE",
+  "PI", "PR", "RJ", "RN", "RO", "RR", "RS", "SC", "SE", "SP", "TO",
+];
+
+const MAX_NUMBER_DIGITS: usize = 10;
+
+/// Council registration of a requester (e.g. CRM 123456/SP) in canonical form: type and state
+/// uppercased, number as digits without leading zeros. Two requesters never share one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CouncilRegistry {
+  pub council_type: String,
+  pub number: String,
+  pub state: String,
+}
+
+impl CouncilRegistry {
+  /// `Ok(None)` when the three fields are blank; a partially filled registry is an error. The
+  /// number may carry `.`, `-` and spaces.
+  pub fn parse(
+    council_type: Option<&str>,
+    number: Option<&str>,
+    state: Option<&str>,
+  ) -> Result<Option<Self>, RequesterDomainError> {
+    let council_type = council_type.map(str::trim).unwrap_or_default();
+    let number = number.map(str::trim).unwrap_or_default();
+    let state = state.map(str::trim).unwrap_or_default();
+
+    if council_type.is_empty() && number.is_empty() && state.is_empty() {
+      return Ok(None);
+    }
+    let missing = [
+      ("council_type", council_type),
+      ("council_number", number),
+      ("council_state", state),
+    ]
+    .into_iter()
+    .find(|(_, value)| value.is_empty());
+    if let Some((field, _)) = missing {
+      return Err(invalid(
+        field,
+        "council_type, council_number and council_state must be filled together",
+      ));
+    }
+
+    let council_type = council_type.to_uppercase();
+    if !COUNCIL_TYPES.contains(&council_type.as_str()) {
+      return Err(invalid(
+        "council_type",
+        &format!("council_type must be one of {}", COUNCIL_TYPES.join(", ")),
+      ));
+    }
+
+    let state = state.to_uppercase();
+    if !COUNCIL_STATES.contains(&state.as_str()) {
+      return Err(invalid(
+        "council_state",
+        "council_state must be a Brazilian state code (UF)",
+      ));
+    }
+
+    if !number
+      .chars()
+      .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ' '))
+    {
+      return Err(invalid(
+        "council_number",
+        "council_number must contain only digits, '.' and '-'",
+      ));
+    }
+    let digits: String = number
+      .chars()
+      .filter(char::is_ascii_digit)
+      .skip_while(|c| *c == '0')
+      .collect();
+    if digits.is_empty() || digits.len() > MAX_NUMBER_DIGITS {
+      return Err(invalid(
+        "council_number",
+        &format!("council_number must have between 1 and {MAX_NUMBER_DIGITS} significant digits"),
+      ));
+    }
+
+    Ok(Some(Self {
+      council_type,
+      number: digits,
+      state,
+    }))
+  }
+}
+
+fn invalid(field: &'static str, message: &str) -> RequesterDomainError {
+  RequesterDomainError::InvalidRegistry {
+    field,
+    message: message.to_string(),
+  }
+}
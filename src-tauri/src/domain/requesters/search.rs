@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use super::dto::RequesterView;
+use crate::domain::patients::duplicates::normalize_name;
+
+pub const DEFAULT_SEARCH_LIMIT: i64 = 10;
+pub const MAX_SEARCH_LIMIT: i64 = 50;
+
+/// Autocomplete over `requesters`. Every query token must match the requester: a prefix of a name
+/// word (accents, case and honorifics such as `Dr.` ignored), a prefix of the council number, or
+/// the council type or state. Names starting with the first token come first, then alphabetical
+/// order.
+///
+/// Results are deduplicated for the picker: a requester without council data is hidden when one
+/// with the same normalized name has it, and among several without it only the oldest is kept.
+/// Requesters with a registry are never merged, since homonyms are different people.
+pub fn search_requesters(
+  requesters: &[RequesterView],
+  query: &str,
+  limit: usize,
+) -> Vec<RequesterView> {
+  let tokens = query_tokens(query);
+
+  let mut matches: Vec<(bool, String, &RequesterView)> = requesters
+    .iter()
+    .filter_map(|requester| {
+      let name = requester_name(&requester.name);
+      tokens
+        .iter()
+        .all(|token| token_matches(token, &name, requester))
+        .then(|| {
+          let starts_with_first = tokens.first().is_none_or(|first| name.starts_with(first));
+          (!starts_with_first, name, requester)
+        })
+    })
+    .collect();
+
+  matches.sort_by(|a, b| {
+    a.0
+      .cmp(&b.0)
+      .then_with(|| a.1.cmp(&b.1))
+      .then_with(|| a.2.created_at.cmp(&b.2.created_at))
+      .then_with(|| a.2.id.cmp(&b.2.id))
+  });
+
+  let registered_names: HashSet<String> = matches
+    .iter()
+    .filter(|(_, _, requester)| has_registry(requester))
+    .map(|(_, name, _)| name.clone())
+    .collect();
+  let mut seen_names = HashSet::new();
+
+  matches
+    .into_iter()
+    .filter(|(_, name, requester)| {
+      has_registry(requester)
+        || (!registered_names.contains(name) && seen_names.insert(name.clone()))
+    })
+    .take(limit)
+    .map(|(_, _, requester)| requester.clone())
+    .collect()
+}
+
+/// Normalized query words. Runs of digits split by `.`/`-` (as in `123.456`) are joined back and
+/// lose their leading zeros, like stored council numbers.
+fn query_tokens(query: &str) -> Vec<String> {
+  let mut tokens: Vec<String> = Vec::new();
+  for word in requester_name(query).split(' ').filter(|w| !w.is_empty()) {
+    let is_number = word.chars().all(|c| c.is_ascii_digit());
+    match tokens.last_mut() {
+      Some(last) if is_number && last.chars().all(|c| c.is_ascii_digit()) => last.push_str(word),
+      _ => tokens.push(word.to_string()),
+    }
+  }
+  for token in tokens.iter_mut() {
+    if token.chars().all(|c| c.is_ascii_digit()) {
+      let trimmed = token.trim_start_matches('0');
+      *token = if trimmed.is_empty() { "0" } else { trimmed }.to_string();
+    }
+  }
+  tokens
+}
+
+/// Normalized name without the honorifics prescriptions are usually signed with.
+fn requester_name(name: &str) -> String {
+  normalize_name(name)
+    .split(' ')
+    .filter(|word| !matches!(*word, "dr" | "dra" | "prof" | "profa"))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn token_matches(token: &str, name: &str, requester: &RequesterView) -> bool {
+  let lowered = |value: &Option<String>| value.as_deref().map(str::to_lowercase);
+
+  name.split(' ').any(|word| word.starts_with(token))
+    || requester
+      .council_number
+      .as_deref()
+      .is_some_and(|number| number.starts_with(token))
+    || lowered(&requester.council_type).as_deref() == Some(token)
+    || lowered(&requester.council_state).as_deref() == Some(token)
+}
+
+fn has_registry(requester: &RequesterView) -> bool {
+  requester.council_number.is_some()
+}
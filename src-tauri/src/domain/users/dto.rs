@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginInput {
+  pub username: String,
+  pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInitialAdminInput {
+  pub name: String,
+  pub cpf: String,
+  pub username: String,
+  pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserInput {
+  pub name: String,
+  pub cpf: String,
+  pub username: String,
+  pub password: String,
+  pub role: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetUserActiveInput {
+  pub user_id: String,
+  pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserView {
+  pub id: String,
+  pub name: String,
+  pub cpf: String,
+  pub username: String,
+  pub role: String,
+  pub is_active: bool,
+}
+
+/// A user row together with its stored password hash; never leaves the backend.
+#[derive(Debug, Clone)]
+pub struct UserCredentials {
+  pub user: UserView,
+  pub password_hash: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewUser {
+  pub name: String,
+  pub cpf: String,
+  pub username: String,
+  pub password_hash: String,
+  pub role: String,
+}
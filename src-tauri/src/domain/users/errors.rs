@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserDomainError {
+  UnknownRole(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserRepositoryError {
+  PersistenceError,
+
+  NotFound,
+
+  Conflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordHashError {
+  HashFailed(String),
+}
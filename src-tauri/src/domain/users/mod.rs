@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod errors;
+pub mod permission;
+pub mod ports;
+pub mod role;
@@ -0,0 +1,58 @@
+use super::role::Role;
+
+/// Actions guarded by role. Every use case asks for exactly one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+  /// Read patients, records, the attendance queue and status history.
+  ViewRecords,
+  RegisterPatients,
+  /// Create, collect, deliver and cancel attendances.
+  ManageAttendances,
+  EnterResults,
+  ReleaseResults,
+  GenerateReports,
+  ViewCatalog,
+  ManageCatalog,
+  ManageUsers,
+  /// Read `audit_log` ("who changed this result?").
+  ViewAuditLog,
+  /// Soft-delete and restore patients and attendances, and list what was deleted.
+  DeleteRecords,
+}
+
+impl Permission {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Permission::ViewRecords => "view_records",
+      Permission::RegisterPatients => "register_patients",
+      Permission::ManageAttendances => "manage_attendances",
+      Permission::EnterResults => "enter_results",
+      Permission::ReleaseResults => "release_results",
+      Permission::GenerateReports => "generate_reports",
+      Permission::ViewCatalog => "view_catalog",
+      Permission::ManageCatalog => "manage_catalog",
+      Permission::ManageUsers => "manage_users",
+      Permission::ViewAuditLog => "view_audit_log",
+      Permission::DeleteRecords => "delete_records",
+    }
+  }
+}
+
+impl Role {
+  pub fn allows(&self, permission: Permission) -> bool {
+    use Permission::*;
+
+    match self {
+      Role::Admin => true,
+      Role::Reception => matches!(
+        permission,
+        ViewRecords | RegisterPatients | ManageAttendances | GenerateReports | ViewCatalog
+      ),
+      Role::Bench => matches!(permission, ViewRecords | EnterResults | ViewCatalog),
+      Role::Biochemist => matches!(
+        permission,
+        ViewRecords | EnterResults | ReleaseResults | GenerateReports | ViewCatalog | ViewAuditLog
+      ),
+    }
+  }
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use super::{
+  dto::{NewUser, UserCredentials, UserView},
+  errors::{PasswordHashError, UserRepositoryError},
+};
+
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+  async fn find_credentials_by_username(
+    &self,
+    username: String,
+  ) -> Result<Option<UserCredentials>, UserRepositoryError>;
+  async fn count_users(&self) -> Result<i64, UserRepositoryError>;
+  /// Inserts `user` only while the table is still empty; fails with `Conflict` otherwise.
+  async fn insert_first_user(&self, user: NewUser) -> Result<UserView, UserRepositoryError>;
+  async fn list_users(&self) -> Result<Vec<UserView>, UserRepositoryError>;
+  async fn insert_user(
+    &self,
+    user: NewUser,
+    performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError>;
+  async fn set_user_active(
+    &self,
+    user_id: String,
+    is_active: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError>;
+}
+
+pub trait PasswordHasher: Send + Sync {
+  fn hash(&self, password: &str) -> Result<String, PasswordHashError>;
+  /// `false` for a wrong password and for a hash it cannot parse.
+  fn verify(&self, password: &str, password_hash: &str) -> bool;
+}
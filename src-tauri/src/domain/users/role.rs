@@ -0,0 +1,33 @@
+use super::errors::UserDomainError;
+
+/// Job role of a user, stored in `users.role`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+  Admin,
+  Reception,
+  Bench,
+  Biochemist,
+}
+
+impl Role {
+  pub const ALL: [Role; 4] = [Role::Admin, Role::Reception, Role::Bench, Role::Biochemist];
+
+  pub fn parse(value: &str) -> Result<Self, UserDomainError> {
+    match value.trim() {
+      "admin" => Ok(Role::Admin),
+      "reception" => Ok(Role::Reception),
+      "bench" => Ok(Role::Bench),
+      "biochemist" => Ok(Role::Biochemist),
+      other => Err(UserDomainError::UnknownRole(other.to_string())),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Role::Admin => "admin",
+      Role::Reception => "reception",
+      Role::Bench => "bench",
+      Role::Biochemist => "biochemist",
+    }
+  }
+}
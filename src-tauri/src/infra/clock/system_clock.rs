@@ -0,0 +1,26 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::domain::patients::{calendar::CalendarDate, ports::Clock};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Today's date from the system clock, in UTC. Near midnight it may already be tomorrow for a
+/// lab west of Greenwich, which only ever makes date rules a few hours more lenient.
+#[derive(Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Clock for SystemClock {
+  fn today(&self) -> CalendarDate {
+    let seconds = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|elapsed| elapsed.as_secs() as i64)
+      .unwrap_or_default();
+    CalendarDate::from_unix_days(seconds.div_euclid(SECONDS_PER_DAY))
+  }
+}
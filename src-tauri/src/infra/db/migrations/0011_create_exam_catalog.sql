@@ -0,0 +1,45 @@
+CREATE TABLE exam_categories (
+  id TEXT PRIMARY KEY NOT NULL,
+  title VARCHAR(100) NOT NULL UNIQUE,
+  created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
+  updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+);
+
+CREATE TABLE exam_catalog_items (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  category_id TEXT NOT NULL,
+  name VARCHAR(150) NOT NULL UNIQUE,
+  price_cents INTEGER NOT NULL CHECK(price_cents >= 0),
+  unit VARCHAR(20),
+  method VARCHAR(100),
+  reference_range TEXT,
+  is_active BOOLEAN NOT NULL DEFAULT TRUE,
+  created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
+  updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+  FOREIGN KEY (category_id) REFERENCES exam_categories(id)
+);
+
+CREATE INDEX idx_exam_catalog_items_category_id ON exam_catalog_items(category_id);
+
+ALTER TABLE exam_items ADD COLUMN catalog_item_id TEXT REFERENCES exam_catalog_items(id);
+
+CREATE INDEX idx_exam_items_catalog_item_id ON exam_items(catalog_item_id);
+
+INSERT INTO exam_categories (id, title, created_at, updated_at) VALUES
+  ('bioquimica', 'Bioquimica', datetime('now'), datetime('now')),
+  ('hematologia', 'Hematologia', datetime('now'), datetime('now')),
+  ('imunologia', 'Imunologia', datetime('now'), datetime('now'));
+
+INSERT INTO exam_catalog_items (id, category_id, name, price_cents, unit, created_at, updated_at) VALUES
+  ('glicose', 'bioquimica', 'Glicose', 1000, 'mg/dL', datetime('now'), datetime('now')),
+  ('colesterol-total', 'bioquimica', 'Colesterol Total', 1000, 'mg/dL', datetime('now'), datetime('now')),
+  ('triglicerideos', 'bioquimica', 'Triglicerideos', 1000, 'mg/dL', datetime('now'), datetime('now')),
+  ('ureia-creatinina', 'bioquimica', 'Bioquimica 2 (Ureia/Creatinina)', 2500, NULL, datetime('now'), datetime('now')),
+  ('hemograma-completo', 'hematologia', 'Hemograma Completo', 2000, NULL, datetime('now'), datetime('now')),
+  ('beta-hcg', 'imunologia', 'Beta HCG Qualitativo', 2000, NULL, datetime('now'), datetime('now'));
+
+UPDATE exam_items
+SET catalog_item_id = (
+  SELECT c.id FROM exam_catalog_items c WHERE c.name = exam_items.name
+)
+WHERE catalog_item_id IS NULL;
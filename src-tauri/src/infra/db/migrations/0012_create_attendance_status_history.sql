@@ -0,0 +1,20 @@
+UPDATE exams SET status = 'collected' WHERE status = 'completed';
+
+CREATE TABLE attendance_status_history (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  exam_id TEXT NOT NULL,
+  from_status VARCHAR(20),
+  to_status VARCHAR(20) NOT NULL,
+  changed_by_user_id TEXT,
+  changed_at DATETIME NOT NULL CHECK(typeof(changed_at) = 'text'),
+  note TEXT,
+  FOREIGN KEY (exam_id) REFERENCES exams(id),
+  FOREIGN KEY (changed_by_user_id) REFERENCES users(id)
+);
+
+CREATE INDEX idx_attendance_status_history_exam_id
+  ON attendance_status_history(exam_id, changed_at);
+
+INSERT INTO attendance_status_history (exam_id, from_status, to_status, changed_at, note)
+SELECT id, NULL, status, updated_at, 'status at migration time'
+FROM exams;
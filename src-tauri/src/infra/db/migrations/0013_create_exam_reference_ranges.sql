@@ -0,0 +1,30 @@
+CREATE TABLE exam_reference_ranges (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  catalog_item_id TEXT NOT NULL,
+  sex VARCHAR(1) CHECK(sex IS NULL OR sex IN ('F', 'M')),
+  age_min_years INTEGER,
+  age_max_years INTEGER,
+  low_value REAL,
+  high_value REAL,
+  critical_low REAL,
+  critical_high REAL,
+  unit VARCHAR(20),
+  expected_text TEXT,
+  created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
+  FOREIGN KEY (catalog_item_id) REFERENCES exam_catalog_items(id)
+);
+
+CREATE INDEX idx_exam_reference_ranges_catalog_item_id ON exam_reference_ranges(catalog_item_id);
+
+INSERT INTO exam_reference_ranges (catalog_item_id, low_value, high_value, critical_low, critical_high, unit, created_at) VALUES
+  ('glicose', 70, 99, 40, 400, 'mg/dL', datetime('now')),
+  ('colesterol-total', NULL, 190, NULL, NULL, 'mg/dL', datetime('now')),
+  ('triglicerideos', NULL, 150, NULL, NULL, 'mg/dL', datetime('now'));
+
+INSERT INTO exam_reference_ranges (catalog_item_id, expected_text, created_at) VALUES
+  ('beta-hcg', 'Negativo', datetime('now'));
+
+UPDATE exam_catalog_items SET reference_range = '70 - 99 mg/dL' WHERE id = 'glicose' AND reference_range IS NULL;
+UPDATE exam_catalog_items SET reference_range = '< 190 mg/dL' WHERE id = 'colesterol-total' AND reference_range IS NULL;
+UPDATE exam_catalog_items SET reference_range = '< 150 mg/dL' WHERE id = 'triglicerideos' AND reference_range IS NULL;
+UPDATE exam_catalog_items SET reference_range = 'Negativo' WHERE id = 'beta-hcg' AND reference_range IS NULL;
@@ -0,0 +1,8 @@
+-- Hash chain over audit_log: each entry stores its position, the previous entry's hash and
+-- the SHA-256 of its own contents. Rows written before this migration are sealed once by the
+-- application at startup (SQLite has no built-in SHA-256).
+ALTER TABLE audit_log ADD COLUMN sequence INTEGER;
+ALTER TABLE audit_log ADD COLUMN prev_hash TEXT;
+ALTER TABLE audit_log ADD COLUMN entry_hash TEXT;
+
+CREATE UNIQUE INDEX idx_audit_log_sequence ON audit_log(sequence);
@@ -0,0 +1,31 @@
+-- patients.cpf is now stored as digits only (the application validates and strips the mask).
+-- Rows whose digits-only CPF would collide with another patient are left untouched and listed
+-- in patient_cpf_collisions, to be resolved by hand (e.g. merging the duplicated patients).
+CREATE TABLE patient_cpf_collisions (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  patient_id TEXT NOT NULL REFERENCES patients(id),
+  original_cpf VARCHAR(14) NOT NULL,
+  normalized_cpf VARCHAR(11) NOT NULL,
+  detected_at DATETIME NOT NULL CHECK(typeof(detected_at) = 'text'),
+  resolved_at DATETIME CHECK(resolved_at IS NULL OR typeof(resolved_at) = 'text')
+);
+
+INSERT INTO patient_cpf_collisions (patient_id, original_cpf, normalized_cpf, detected_at)
+SELECT
+  p.id,
+  p.cpf,
+  replace(replace(replace(p.cpf, '.', ''), '-', ''), ' ', ''),
+  datetime('now')
+FROM patients p
+WHERE EXISTS (
+  SELECT 1
+  FROM patients other
+  WHERE other.id <> p.id
+    AND replace(replace(replace(other.cpf, '.', ''), '-', ''), ' ', '')
+      = replace(replace(replace(p.cpf, '.', ''), '-', ''), ' ', '')
+);
+
+UPDATE patients
+SET cpf = replace(replace(replace(cpf, '.', ''), '-', ''), ' ', '')
+WHERE cpf <> replace(replace(replace(cpf, '.', ''), '-', ''), ' ', '')
+  AND id NOT IN (SELECT patient_id FROM patient_cpf_collisions);
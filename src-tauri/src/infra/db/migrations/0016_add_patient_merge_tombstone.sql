@@ -0,0 +1,6 @@
+-- merge_patients keeps the losing record as a tombstone pointing at the surviving patient:
+-- its exams are moved, and it disappears from listings, search and new attendances.
+ALTER TABLE patients ADD COLUMN merged_into_patient_id TEXT REFERENCES patients(id);
+ALTER TABLE patients ADD COLUMN merged_at DATETIME;
+
+CREATE INDEX idx_patients_birth_date ON patients(birth_date);
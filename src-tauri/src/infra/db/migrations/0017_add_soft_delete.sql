@@ -0,0 +1,7 @@
+-- Soft delete for patients and attendances: rows are never removed (exams -> patients and
+-- exam_items -> exams must keep pointing at something), only hidden from the default queries.
+ALTER TABLE patients ADD COLUMN deleted_at DATETIME CHECK(deleted_at IS NULL OR typeof(deleted_at) = 'text');
+ALTER TABLE patients ADD COLUMN deleted_by TEXT REFERENCES users(id);
+
+ALTER TABLE exams ADD COLUMN deleted_at DATETIME CHECK(deleted_at IS NULL OR typeof(deleted_at) = 'text');
+ALTER TABLE exams ADD COLUMN deleted_by TEXT REFERENCES users(id);
@@ -0,0 +1,53 @@
+-- Full-text index behind list_patients search. unicode61 with remove_diacritics folds case and
+-- accents on both the indexed text and the query, so "jose" finds "José" and "joão" finds "JOÃO"
+-- (SQLite's lower() only folds ASCII). The prefix indexes keep "mar*"-style queries cheap.
+-- Phone is indexed as digits only, like cpf. Merged and deleted patients stay indexed; the listing
+-- query filters them out.
+CREATE VIRTUAL TABLE patients_fts USING fts5(
+  patient_id UNINDEXED,
+  full_name,
+  cpf,
+  phone,
+  legacy_code,
+  tokenize = 'unicode61 remove_diacritics 2',
+  prefix = '2 3'
+);
+
+INSERT INTO patients_fts (patient_id, full_name, cpf, phone, legacy_code)
+SELECT
+  id,
+  full_name,
+  cpf,
+  replace(replace(replace(replace(replace(replace(phone, ' ', ''), '(', ''), ')', ''), '-', ''), '.', ''), '+', ''),
+  CAST(legacy_code AS TEXT)
+FROM patients;
+
+CREATE TRIGGER patients_fts_after_insert AFTER INSERT ON patients
+BEGIN
+  INSERT INTO patients_fts (patient_id, full_name, cpf, phone, legacy_code)
+  VALUES (
+    NEW.id,
+    NEW.full_name,
+    NEW.cpf,
+    replace(replace(replace(replace(replace(replace(NEW.phone, ' ', ''), '(', ''), ')', ''), '-', ''), '.', ''), '+', ''),
+    CAST(NEW.legacy_code AS TEXT)
+  );
+END;
+
+CREATE TRIGGER patients_fts_after_update AFTER UPDATE OF id, full_name, cpf, phone, legacy_code ON patients
+BEGIN
+  DELETE FROM patients_fts WHERE patient_id = OLD.id;
+  INSERT INTO patients_fts (patient_id, full_name, cpf, phone, legacy_code)
+  VALUES (
+    NEW.id,
+    NEW.full_name,
+    NEW.cpf,
+    replace(replace(replace(replace(replace(replace(NEW.phone, ' ', ''), '(', ''), ')', ''), '-', ''), '.', ''), '+', ''),
+    CAST(NEW.legacy_code AS TEXT)
+  );
+END;
+
+CREATE TRIGGER patients_fts_after_delete AFTER DELETE ON patients
+BEGIN
+  DELETE FROM patients_fts WHERE patient_id = OLD.id;
+END;
@@ -0,0 +1,44 @@
+-- Requesters get their professional registration (council type, number, state) and become the
+-- duplicate key instead of the name: two physicians may share a name, never a registration.
+-- Legacy rows keep NULL registration data. SQLite cannot drop the UNIQUE(name) constraint in
+-- place, so the table is rebuilt; exams.requester_id is parked meanwhile because migrations run
+-- inside a transaction with foreign keys enforced.
+CREATE TABLE exam_requester_links AS
+SELECT id AS exam_id, requester_id
+FROM exams
+WHERE requester_id IS NOT NULL;
+
+UPDATE exams SET requester_id = NULL WHERE requester_id IS NOT NULL;
+
+CREATE TABLE requesters_new (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  name VARCHAR(150) NOT NULL,
+  council_type VARCHAR(10),
+  council_number VARCHAR(20),
+  council_state VARCHAR(2),
+  is_active BOOLEAN NOT NULL DEFAULT TRUE,
+  created_at DATETIME NOT NULL,
+  updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+  CHECK (
+    (council_type IS NULL AND council_number IS NULL AND council_state IS NULL)
+    OR (council_type IS NOT NULL AND council_number IS NOT NULL AND council_state IS NOT NULL)
+  )
+);
+
+INSERT INTO requesters_new (id, name, is_active, created_at, updated_at)
+SELECT id, name, TRUE, created_at, CAST(created_at AS TEXT)
+FROM requesters;
+
+DROP TABLE requesters;
+ALTER TABLE requesters_new RENAME TO requesters;
+
+UPDATE exams
+SET requester_id = (
+  SELECT l.requester_id FROM exam_requester_links l WHERE l.exam_id = exams.id
+)
+WHERE id IN (SELECT exam_id FROM exam_requester_links);
+
+DROP TABLE exam_requester_links;
+
+CREATE UNIQUE INDEX idx_requesters_council
+ON requesters(council_type, council_number, council_state);
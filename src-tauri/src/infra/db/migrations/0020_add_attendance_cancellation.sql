@@ -0,0 +1,28 @@
+-- Who cancelled an attendance, when and why. New cancellations always carry a reason; the ones
+-- made through `transition_attendance` before this migration take it from their status history
+-- note, which may be empty.
+ALTER TABLE exams ADD COLUMN cancelled_at DATETIME CHECK(cancelled_at IS NULL OR typeof(cancelled_at) = 'text');
+ALTER TABLE exams ADD COLUMN cancelled_by TEXT REFERENCES users(id);
+ALTER TABLE exams ADD COLUMN cancellation_reason TEXT;
+
+UPDATE exams
+SET
+  cancelled_at = COALESCE(
+    (
+      SELECT h.changed_at FROM attendance_status_history h
+      WHERE h.exam_id = exams.id AND h.to_status = 'cancelled'
+      ORDER BY h.changed_at DESC, h.rowid DESC LIMIT 1
+    ),
+    updated_at
+  ),
+  cancelled_by = (
+    SELECT h.changed_by_user_id FROM attendance_status_history h
+    WHERE h.exam_id = exams.id AND h.to_status = 'cancelled'
+    ORDER BY h.changed_at DESC, h.rowid DESC LIMIT 1
+  ),
+  cancellation_reason = (
+    SELECT h.note FROM attendance_status_history h
+    WHERE h.exam_id = exams.id AND h.to_status = 'cancelled'
+    ORDER BY h.changed_at DESC, h.rowid DESC LIMIT 1
+  )
+WHERE status = 'cancelled';
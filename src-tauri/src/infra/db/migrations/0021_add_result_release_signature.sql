@@ -0,0 +1,31 @@
+-- Technical validation: the biochemist who signed the release and when. Items carry the same
+-- signature and stop accepting result changes once it is set. Attendances released through
+-- `transition_attendance` before this migration take it from their status history.
+ALTER TABLE exams ADD COLUMN released_at DATETIME CHECK(released_at IS NULL OR typeof(released_at) = 'text');
+ALTER TABLE exams ADD COLUMN released_by TEXT REFERENCES users(id);
+
+ALTER TABLE exam_items ADD COLUMN released_at DATETIME CHECK(released_at IS NULL OR typeof(released_at) = 'text');
+ALTER TABLE exam_items ADD COLUMN released_by TEXT REFERENCES users(id);
+
+UPDATE exams
+SET
+  released_at = COALESCE(
+    (
+      SELECT h.changed_at FROM attendance_status_history h
+      WHERE h.exam_id = exams.id AND h.to_status = 'released'
+      ORDER BY h.changed_at DESC, h.rowid DESC LIMIT 1
+    ),
+    updated_at
+  ),
+  released_by = (
+    SELECT h.changed_by_user_id FROM attendance_status_history h
+    WHERE h.exam_id = exams.id AND h.to_status = 'released'
+    ORDER BY h.changed_at DESC, h.rowid DESC LIMIT 1
+  )
+WHERE status IN ('released', 'delivered');
+
+UPDATE exam_items
+SET
+  released_at = (SELECT e.released_at FROM exams e WHERE e.id = exam_items.exam_id),
+  released_by = (SELECT e.released_by FROM exams e WHERE e.id = exam_items.exam_id)
+WHERE exam_id IN (SELECT id FROM exams WHERE released_at IS NOT NULL);
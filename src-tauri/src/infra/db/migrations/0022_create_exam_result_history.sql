@@ -0,0 +1,20 @@
+-- Amendments of released results. Each row keeps a value that was replaced, with the version it
+-- had, why it was replaced and by whom; `exam_items` keeps the current value and its version.
+ALTER TABLE exam_items ADD COLUMN result_version INTEGER NOT NULL DEFAULT 1;
+
+CREATE TABLE exam_result_history (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  exam_item_id TEXT NOT NULL,
+  result_version INTEGER NOT NULL,
+  result_value TEXT,
+  result_flag VARCHAR(20),
+  reason TEXT NOT NULL,
+  amended_by TEXT,
+  amended_at DATETIME NOT NULL CHECK(typeof(amended_at) = 'text'),
+  FOREIGN KEY (exam_item_id) REFERENCES exam_items(id),
+  FOREIGN KEY (amended_by) REFERENCES users(id),
+  UNIQUE (exam_item_id, result_version)
+);
+
+-- A laudo reissued after an amendment is printed as "retificado".
+ALTER TABLE pdf_reports ADD COLUMN is_amended BOOLEAN NOT NULL DEFAULT FALSE;
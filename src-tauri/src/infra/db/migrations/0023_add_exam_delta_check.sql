@@ -0,0 +1,4 @@
+-- Delta check: how much a result may move from the patient's previous released value of the same
+-- catalog exam before result entry warns the bench. NULL disables that threshold.
+ALTER TABLE exam_catalog_items ADD COLUMN delta_check_absolute REAL CHECK(delta_check_absolute IS NULL OR delta_check_absolute > 0);
+ALTER TABLE exam_catalog_items ADD COLUMN delta_check_percent REAL CHECK(delta_check_percent IS NULL OR delta_check_percent > 0);
@@ -0,0 +1,32 @@
+-- Panic values: a result flagged LL/HH opens an alert that must be acknowledged, recording who
+-- was told, how and by whom, before the attendance can be released. Replacing the value drops the
+-- alert while it is still pending; acknowledged alerts are kept as the notification log.
+CREATE TABLE critical_alerts (
+  id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+  exam_id TEXT NOT NULL,
+  exam_item_id TEXT NOT NULL,
+  result_value TEXT NOT NULL,
+  result_flag VARCHAR(20) NOT NULL,
+  detected_at DATETIME NOT NULL CHECK(typeof(detected_at) = 'text'),
+  detected_by TEXT,
+  acknowledged_at DATETIME CHECK(acknowledged_at IS NULL OR typeof(acknowledged_at) = 'text'),
+  acknowledged_by TEXT,
+  contact_name TEXT,
+  contact_method VARCHAR(20) CHECK(contact_method IS NULL OR contact_method IN ('phone', 'in_person', 'email', 'message')),
+  note TEXT,
+  FOREIGN KEY (exam_id) REFERENCES exams(id),
+  FOREIGN KEY (exam_item_id) REFERENCES exam_items(id),
+  FOREIGN KEY (detected_by) REFERENCES users(id),
+  FOREIGN KEY (acknowledged_by) REFERENCES users(id)
+);
+
+CREATE INDEX idx_critical_alerts_pending
+  ON critical_alerts(acknowledged_at, detected_at);
+CREATE INDEX idx_critical_alerts_exam_id
+  ON critical_alerts(exam_id);
+
+-- Critical results typed before this migration and not yet released still need a notification.
+INSERT INTO critical_alerts (exam_id, exam_item_id, result_value, result_flag, detected_at)
+SELECT exam_id, id, result_value, result_flag, updated_at
+FROM exam_items
+WHERE result_flag IN ('LL', 'HH') AND result_value IS NOT NULL AND released_at IS NULL;
@@ -0,0 +1,17 @@
+-- Panels (perfis): a catalog exam made of other catalog exams, its analytes, each with its own
+-- unit, method and reference ranges. Ordering a panel creates one exam item per analyte, and the
+-- items keep the panel they came from so the record and the laudo can group them.
+CREATE TABLE exam_panel_analytes (
+  panel_id TEXT NOT NULL,
+  analyte_id TEXT NOT NULL,
+  position INTEGER NOT NULL CHECK(position >= 0),
+  PRIMARY KEY (panel_id, analyte_id),
+  FOREIGN KEY (panel_id) REFERENCES exam_catalog_items(id),
+  FOREIGN KEY (analyte_id) REFERENCES exam_catalog_items(id),
+  CHECK(panel_id <> analyte_id)
+);
+
+CREATE INDEX idx_exam_panel_analytes_analyte_id ON exam_panel_analytes(analyte_id);
+
+ALTER TABLE exam_items ADD COLUMN panel_id TEXT REFERENCES exam_catalog_items(id);
+ALTER TABLE exam_items ADD COLUMN panel_name VARCHAR(150);
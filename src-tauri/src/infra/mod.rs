@@ -1,2 +1,5 @@
+pub mod clock;
 pub mod db;
+pub mod reports;
 pub mod repositories;
+pub mod security;
@@ -0,0 +1,344 @@
+use crate::domain::{
+  patients::{dto::PatientRecordExamItemView, reference_range::age_in_years},
+  reports::{dto::LabReportSource, ports::LabReportRenderer},
+};
+
+use super::pdf_document::{PdfDocument, PdfFont, PdfPage, PAGE_HEIGHT, PAGE_WIDTH};
+
+const MARGIN: f32 = 50.0;
+const CONTENT_TOP: f32 = PAGE_HEIGHT - MARGIN;
+const CONTENT_BOTTOM: f32 = 80.0;
+const LINE_HEIGHT: f32 = 14.0;
+
+// Table columns: x position and how many characters fit before truncating.
+const COL_EXAM: (f32, usize) = (MARGIN, 34);
+const COL_RESULT: (f32, usize) = (235.0, 16);
+const COL_UNIT: (f32, usize) = (320.0, 10);
+const COL_REFERENCE: (f32, usize) = (380.0, 28);
+const COL_FLAG: (f32, usize) = (515.0, 12);
+
+/// Renders laudos as A4 PDFs: lab header, patient block, one table row per exam item (the
+/// analytes of a panel indented under its name) and a footer with report version and page
+/// numbers.
+pub struct PdfLabReportRenderer {
+  lab_name: String,
+}
+
+impl PdfLabReportRenderer {
+  pub fn new(lab_name: impl Into<String>) -> Self {
+    Self {
+      lab_name: lab_name.into(),
+    }
+  }
+}
+
+impl LabReportRenderer for PdfLabReportRenderer {
+  fn render(&self, source: &LabReportSource, report_version: i64) -> Vec<u8> {
+    let mut layout = Layout::new(&self.lab_name);
+
+    write_patient_block(&mut layout, source);
+    layout.y -= LINE_HEIGHT;
+    layout.start_table();
+    let mut current_panel = None;
+    for item in &source.entry.items {
+      if item.panel_id.is_some() && item.panel_id != current_panel {
+        layout.panel_row(item.panel_name.as_deref().unwrap_or("-"));
+      }
+      current_panel = item.panel_id.clone();
+      layout.item_row(item);
+    }
+    layout.legend();
+    if source.amended {
+      layout.amendment_notice();
+    }
+
+    layout.finish(report_version, source.amended)
+  }
+}
+
+fn write_patient_block(layout: &mut Layout, source: &LabReportSource) {
+  let patient = &source.patient;
+  let entry = &source.entry;
+  let birth_date = match age_in_years(&patient.birth_date, &entry.exam_date) {
+    Some(age) => format!("{} ({age} anos)", format_date(&patient.birth_date)),
+    None => format_date(&patient.birth_date),
+  };
+
+  let rows = [
+    ("Paciente", patient.full_name.clone()),
+    ("CPF", format_cpf(&patient.cpf)),
+    ("Nascimento", birth_date),
+    ("Sexo", format_sex(&patient.sex).to_string()),
+    (
+      "Solicitante",
+      entry
+        .requester_name
+        .clone()
+        .unwrap_or_else(|| "-".to_string()),
+    ),
+    ("Atendimento", entry.exam_id.clone()),
+    ("Data do exame", format_date(&entry.exam_date)),
+  ];
+  for (label, value) in rows {
+    layout.ensure_space(LINE_HEIGHT);
+    let y = layout.y;
+    let page = layout.page();
+    page.text(MARGIN, y, 10.0, PdfFont::Bold, &format!("{label}:"));
+    page.text(MARGIN + 90.0, y, 10.0, PdfFont::Regular, &fit(&value, 70));
+    layout.y -= LINE_HEIGHT;
+  }
+}
+
+struct Layout<'a> {
+  lab_name: &'a str,
+  pages: Vec<PdfPage>,
+  y: f32,
+  in_table: bool,
+}
+
+impl<'a> Layout<'a> {
+  fn new(lab_name: &'a str) -> Self {
+    let mut layout = Self {
+      lab_name,
+      pages: Vec::new(),
+      y: CONTENT_TOP,
+      in_table: false,
+    };
+    layout.new_page();
+    layout
+  }
+
+  fn page(&mut self) -> &mut PdfPage {
+    self.pages.last_mut().expect("layout always has a page")
+  }
+
+  fn new_page(&mut self) {
+    let mut page = PdfPage::new();
+    page.text(MARGIN, CONTENT_TOP, 16.0, PdfFont::Bold, self.lab_name);
+    page.text(
+      MARGIN,
+      CONTENT_TOP - 16.0,
+      10.0,
+      PdfFont::Regular,
+      "Laudo de exames laboratoriais",
+    );
+    page.line(
+      MARGIN,
+      CONTENT_TOP - 24.0,
+      PAGE_WIDTH - MARGIN,
+      CONTENT_TOP - 24.0,
+      1.0,
+    );
+    self.pages.push(page);
+    self.y = CONTENT_TOP - 44.0;
+
+    if self.in_table {
+      self.table_header();
+    }
+  }
+
+  fn ensure_space(&mut self, height: f32) {
+    if self.y - height < CONTENT_BOTTOM {
+      self.new_page();
+    }
+  }
+
+  fn start_table(&mut self) {
+    self.ensure_space(LINE_HEIGHT * 3.0);
+    self.in_table = true;
+    self.table_header();
+  }
+
+  fn table_header(&mut self) {
+    let y = self.y;
+    let page = self.page();
+    for (column, title) in [
+      (COL_EXAM, "Exame"),
+      (COL_RESULT, "Resultado"),
+      (COL_UNIT, "Unidade"),
+      (COL_REFERENCE, "Valores de referencia"),
+      (COL_FLAG, "Flag"),
+    ] {
+      page.text(column.0, y, 9.0, PdfFont::Bold, title);
+    }
+    page.line(MARGIN, y - 4.0, PAGE_WIDTH - MARGIN, y - 4.0, 0.5);
+    self.y -= LINE_HEIGHT + 4.0;
+  }
+
+  fn panel_row(&mut self, panel_name: &str) {
+    // Keeps the panel name on the same page as its first analyte.
+    self.ensure_space(LINE_HEIGHT * 2.0);
+    let y = self.y;
+    self
+      .page()
+      .text(COL_EXAM.0, y, 9.0, PdfFont::Bold, &fit(panel_name, 60));
+    self.y -= LINE_HEIGHT;
+  }
+
+  fn item_row(&mut self, item: &PatientRecordExamItemView) {
+    let height = if item.method.is_some() {
+      LINE_HEIGHT * 2.0
+    } else {
+      LINE_HEIGHT
+    };
+    self.ensure_space(height);
+
+    let abnormal = item
+      .result_flag
+      .as_deref()
+      .is_some_and(|flag| !flag.eq_ignore_ascii_case("normal"));
+    let result_font = if abnormal {
+      PdfFont::Bold
+    } else {
+      PdfFont::Regular
+    };
+    let y = self.y;
+    let page = self.page();
+    let (indent, width) = if item.panel_id.is_some() {
+      (8.0, COL_EXAM.1 - 2)
+    } else {
+      (0.0, COL_EXAM.1)
+    };
+    page.text(
+      COL_EXAM.0 + indent,
+      y,
+      9.0,
+      PdfFont::Regular,
+      &fit(&item.name, width),
+    );
+    page.text(
+      COL_RESULT.0,
+      y,
+      9.0,
+      result_font,
+      &fit(item.result_value.as_deref().unwrap_or("-"), COL_RESULT.1),
+    );
+    page.text(
+      COL_UNIT.0,
+      y,
+      9.0,
+      PdfFont::Regular,
+      &fit(item.unit.as_deref().unwrap_or(""), COL_UNIT.1),
+    );
+    page.text(
+      COL_REFERENCE.0,
+      y,
+      9.0,
+      PdfFont::Regular,
+      &fit(
+        item.reference_range.as_deref().unwrap_or(""),
+        COL_REFERENCE.1,
+      ),
+    );
+    if abnormal {
+      page.text(
+        COL_FLAG.0,
+        y,
+        9.0,
+        PdfFont::Bold,
+        &fit(item.result_flag.as_deref().unwrap_or(""), COL_FLAG.1),
+      );
+    }
+    if let Some(method) = &item.method {
+      page.text(
+        COL_EXAM.0 + indent + 8.0,
+        y - LINE_HEIGHT + 3.0,
+        7.5,
+        PdfFont::Regular,
+        &fit(&format!("Metodo: {method}"), 60),
+      );
+    }
+
+    self.y -= height;
+  }
+
+  fn legend(&mut self) {
+    self.in_table = false;
+    self.ensure_space(LINE_HEIGHT * 2.0);
+    self.y -= LINE_HEIGHT;
+    let y = self.y;
+    self.page().text(
+      MARGIN,
+      y,
+      8.0,
+      PdfFont::Regular,
+      "Flags: L abaixo, H acima, LL critico abaixo, HH critico acima da referencia.",
+    );
+  }
+
+  fn amendment_notice(&mut self) {
+    self.ensure_space(LINE_HEIGHT);
+    self.y -= LINE_HEIGHT;
+    let y = self.y;
+    self.page().text(
+      MARGIN,
+      y,
+      8.0,
+      PdfFont::Bold,
+      "Laudo retificado: resultados corrigidos apos a liberacao substituem as versoes anteriores.",
+    );
+  }
+
+  fn finish(mut self, report_version: i64, amended: bool) -> Vec<u8> {
+    let total = self.pages.len();
+    let version = if amended {
+      format!("{report_version} (retificado)")
+    } else {
+      report_version.to_string()
+    };
+    let mut document = PdfDocument::new();
+    for (index, mut page) in self.pages.drain(..).enumerate() {
+      page.line(MARGIN, 62.0, PAGE_WIDTH - MARGIN, 62.0, 0.5);
+      page.text(
+        MARGIN,
+        50.0,
+        8.0,
+        PdfFont::Regular,
+        &format!("Laudo versao {version} - Pagina {} de {total}", index + 1),
+      );
+      document.add_page(page);
+    }
+    document.to_bytes()
+  }
+}
+
+fn fit(text: &str, max_chars: usize) -> String {
+  if text.chars().count() <= max_chars {
+    return text.to_string();
+  }
+  let kept: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+  format!("{kept}...")
+}
+
+/// `YYYY-MM-DD[...]` -> `DD/MM/YYYY`; anything else is printed as is.
+fn format_date(value: &str) -> String {
+  let date = value.trim();
+  match (date.get(0..4), date.get(5..7), date.get(8..10)) {
+    (Some(year), Some(month), Some(day)) if date.as_bytes().get(4) == Some(&b'-') => {
+      format!("{day}/{month}/{year}")
+    }
+    _ => date.to_string(),
+  }
+}
+
+fn format_cpf(cpf: &str) -> String {
+  let digits: String = cpf.chars().filter(char::is_ascii_digit).collect();
+  if digits.len() != 11 {
+    return cpf.to_string();
+  }
+  format!(
+    "{}.{}.{}-{}",
+    &digits[0..3],
+    &digits[3..6],
+    &digits[6..9],
+    &digits[9..11]
+  )
+}
+
+fn format_sex(sex: &str) -> &str {
+  match sex.trim() {
+    "F" | "f" => "Feminino",
+    "M" | "m" => "Masculino",
+    other => other,
+  }
+}
@@ -0,0 +1,3 @@
+pub mod lab_report_pdf;
+pub mod pdf_document;
+pub mod report_files;
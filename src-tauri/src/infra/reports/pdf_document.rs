@@ -0,0 +1,151 @@
+//! Minimal PDF 1.4 writer: A4 pages with text in the standard Helvetica fonts and straight
+//! lines. Enough for laudos without pulling a PDF crate into the build.
+
+use std::fmt::Write as _;
+
+pub const PAGE_WIDTH: f32 = 595.0;
+pub const PAGE_HEIGHT: f32 = 842.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PdfFont {
+  Regular,
+  Bold,
+}
+
+impl PdfFont {
+  fn resource_name(&self) -> &'static str {
+    match self {
+      PdfFont::Regular => "F1",
+      PdfFont::Bold => "F2",
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct PdfPage {
+  content: Vec<u8>,
+}
+
+impl PdfPage {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Writes `text` with its baseline starting at (`x`, `y`), origin at the bottom-left corner.
+  pub fn text(&mut self, x: f32, y: f32, size: f32, font: PdfFont, text: &str) {
+    let mut op = String::new();
+    let _ = write!(
+      op,
+      "BT /{} {size:.1} Tf {x:.2} {y:.2} Td (",
+      font.resource_name()
+    );
+    self.content.extend_from_slice(op.as_bytes());
+    self.content.extend(encode_text(text));
+    self.content.extend_from_slice(b") Tj ET\n");
+  }
+
+  pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+    let mut op = String::new();
+    let _ = writeln!(op, "{width:.2} w {x1:.2} {y1:.2} m {x2:.2} {y2:.2} l S");
+    self.content.extend_from_slice(op.as_bytes());
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct PdfDocument {
+  pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn add_page(&mut self, page: PdfPage) {
+    self.pages.push(page);
+  }
+
+  pub fn to_bytes(&self) -> Vec<u8> {
+    // Objects 1-4 are fixed; each page then takes two: the page and its content stream.
+    let object_count = 4 + self.pages.len() * 2;
+    let page_ids: Vec<usize> = (0..self.pages.len()).map(|index| 5 + index * 2).collect();
+
+    let mut out: Vec<u8> = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = vec![0usize; object_count + 1];
+
+    let kids = page_ids
+      .iter()
+      .map(|id| format!("{id} 0 R"))
+      .collect::<Vec<_>>()
+      .join(" ");
+    let mut objects: Vec<(usize, Vec<u8>)> = vec![
+      (1, b"<< /Type /Catalog /Pages 2 0 R >>".to_vec()),
+      (
+        2,
+        format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", self.pages.len()).into_bytes(),
+      ),
+      (3, font_object("Helvetica")),
+      (4, font_object("Helvetica-Bold")),
+    ];
+    for (page, page_id) in self.pages.iter().zip(&page_ids) {
+      let content_id = page_id + 1;
+      objects.push((
+        *page_id,
+        format!(
+          "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH:.0} {PAGE_HEIGHT:.0}] \
+           /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {content_id} 0 R >>"
+        )
+        .into_bytes(),
+      ));
+      let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+      stream.extend_from_slice(&page.content);
+      stream.extend_from_slice(b"\nendstream");
+      objects.push((content_id, stream));
+    }
+
+    for (id, body) in objects {
+      offsets[id] = out.len();
+      out.extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+      out.extend_from_slice(&body);
+      out.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = out.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", object_count + 1);
+    for offset in &offsets[1..] {
+      let _ = writeln!(xref, "{offset:010} 00000 n ");
+    }
+    let _ = write!(
+      xref,
+      "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+      object_count + 1
+    );
+    out.extend_from_slice(xref.as_bytes());
+
+    out
+  }
+}
+
+fn font_object(base_font: &str) -> Vec<u8> {
+  format!("<< /Type /Font /Subtype /Type1 /BaseFont /{base_font} /Encoding /WinAnsiEncoding >>")
+    .into_bytes()
+}
+
+/// Encodes `text` as a WinAnsi string literal body. Latin-1 characters (accents, cedilla) map
+/// to themselves; anything the standard fonts cannot draw becomes `?`.
+fn encode_text(text: &str) -> Vec<u8> {
+  let mut bytes = Vec::with_capacity(text.len());
+  for ch in text.chars() {
+    match ch {
+      '(' | ')' | '\\' => {
+        bytes.push(b'\\');
+        bytes.push(ch as u8);
+      }
+      ' '..='~' => bytes.push(ch as u8),
+      '\u{a0}'..='\u{ff}' => bytes.push(ch as u32 as u8),
+      '\u{2013}' | '\u{2014}' => bytes.push(b'-'),
+      _ => bytes.push(b'?'),
+    }
+  }
+  bytes
+}
@@ -0,0 +1,70 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::domain::reports::{errors::ReportStorageError, ports::ReportFileStore};
+
+/// Keeps generated reports as plain files inside one directory (the `reports` folder of the
+/// app data dir in production).
+pub struct FsReportFileStore {
+  dir: PathBuf,
+}
+
+impl FsReportFileStore {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into() }
+  }
+}
+
+/// Makes staged names unique among the calls of this process.
+static STAGE_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+fn check_plain_name(file_name: &str) -> Result<(), ReportStorageError> {
+  let is_plain_name = Path::new(file_name)
+    .file_name()
+    .is_some_and(|name| name == file_name);
+  if !is_plain_name {
+    return Err(ReportStorageError::InvalidFileName(file_name.to_string()));
+  }
+  Ok(())
+}
+
+impl ReportFileStore for FsReportFileStore {
+  fn save(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError> {
+    check_plain_name(file_name)?;
+
+    std::fs::create_dir_all(&self.dir).map_err(|e| ReportStorageError::Io(e.to_string()))?;
+    let path = self.dir.join(file_name);
+    std::fs::write(&path, content).map_err(|e| ReportStorageError::Io(e.to_string()))?;
+
+    Ok(path.to_string_lossy().into_owned())
+  }
+  fn stage(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError> {
+    check_plain_name(file_name)?;
+    let staged_name = format!(
+      "{file_name}.{}-{}.tmp",
+      std::process::id(),
+      STAGE_SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    );
+    self.save(&staged_name, content)?;
+
+    Ok(staged_name)
+  }
+
+  fn promote(&self, staged_name: &str, file_name: &str) -> Result<String, ReportStorageError> {
+    check_plain_name(staged_name)?;
+    check_plain_name(file_name)?;
+    let path = self.dir.join(file_name);
+    std::fs::rename(self.dir.join(staged_name), &path)
+      .map_err(|e| ReportStorageError::Io(e.to_string()))?;
+
+    Ok(path.to_string_lossy().into_owned())
+  }
+
+  fn discard(&self, staged_name: &str) {
+    if check_plain_name(staged_name).is_ok() {
+      let _ = std::fs::remove_file(self.dir.join(staged_name));
+    }
+  }
+}
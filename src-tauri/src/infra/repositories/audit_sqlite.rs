@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use sqlx::{sqlite::SqliteRow, QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+
+use crate::domain::audit::{
+  dto::{AuditChainEntry, AuditEntryView, ListAuditLogInput},
+  errors::AuditRepositoryError,
+  hash_chain::compute_entry_hash,
+  ports::AuditRepository,
+};
+
+/// One `audit_log` row to be written by a repository inside its own write transaction, so the
+/// change and its audit entry commit (or roll back) together.
+pub(crate) struct AuditRecord<'a> {
+  /// Table name of the audited entity (`patients`, `exams`, `exam_items`, ...).
+  pub entity_name: &'a str,
+  pub entity_id: &'a str,
+  /// `create`, `update`, `delete` or `restore` (a soft-deleted record brought back).
+  pub action: &'a str,
+  pub performed_by_user_id: Option<&'a str>,
+  pub before_json: Option<String>,
+  pub after_json: Option<String>,
+}
+
+/// Appends the entry to the hash chain. The caller's transaction has already written the audited
+/// row, so it holds SQLite's write lock and no other entry can take the same `sequence`.
+pub(crate) async fn record_audit(
+  conn: &mut SqliteConnection,
+  record: AuditRecord<'_>,
+) -> Result<(), sqlx::Error> {
+  let head = sqlx::query(
+    r#"
+    SELECT lower(hex(randomblob(16))) AS id, datetime('now') AS performed_at
+    "#,
+  )
+  .fetch_one(&mut *conn)
+  .await?;
+  let (last_sequence, prev_hash) = fetch_chain_tail(&mut *conn).await?;
+
+  let mut entry = AuditChainEntry {
+    id: head.get::<String, _>("id"),
+    sequence: Some(last_sequence + 1),
+    entity_name: record.entity_name.to_string(),
+    entity_id: record.entity_id.to_string(),
+    action: record.action.to_string(),
+    performed_by_user_id: record.performed_by_user_id.map(str::to_string),
+    performed_at: head.get::<String, _>("performed_at"),
+    before_json: record.before_json,
+    after_json: record.after_json,
+    prev_hash,
+    entry_hash: None,
+  };
+  entry.entry_hash = Some(compute_entry_hash(&entry));
+
+  insert_chain_entry(conn, &entry).await
+}
+
+/// Seals rows written before the hash chain existed (migration 0014), oldest first. Runs only
+/// while the chain is still empty: an unsealed row after that is tampering, not legacy data.
+pub async fn seal_legacy_audit_entries(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+  let mut tx = pool.begin().await?;
+
+  let sealed = sqlx::query("SELECT COUNT(entry_hash) AS total FROM audit_log")
+    .fetch_one(&mut *tx)
+    .await?
+    .get::<i64, _>("total");
+  if sealed > 0 {
+    return Ok(0);
+  }
+
+  let rows = sqlx::query(
+    r#"
+    SELECT id, entity_name, entity_id, action, performed_by_user_id, performed_at, before_json, after_json
+    FROM audit_log
+    ORDER BY performed_at ASC, rowid ASC
+    "#,
+  )
+  .fetch_all(&mut *tx)
+  .await?;
+
+  let mut prev_hash: Option<String> = None;
+  for (index, row) in rows.iter().enumerate() {
+    let mut entry = map_chain_row(row);
+    entry.sequence = Some(index as i64 + 1);
+    entry.prev_hash = prev_hash.take();
+    let entry_hash = compute_entry_hash(&entry);
+
+    sqlx::query(
+      r#"
+      UPDATE audit_log
+      SET sequence = ?2, prev_hash = ?3, entry_hash = ?4
+      WHERE id = ?1
+      "#,
+    )
+    .bind(&entry.id)
+    .bind(entry.sequence)
+    .bind(&entry.prev_hash)
+    .bind(&entry_hash)
+    .execute(&mut *tx)
+    .await?;
+
+    prev_hash = Some(entry_hash);
+  }
+
+  tx.commit().await?;
+
+  Ok(rows.len() as u64)
+}
+
+async fn fetch_chain_tail(
+  conn: &mut SqliteConnection,
+) -> Result<(i64, Option<String>), sqlx::Error> {
+  let row = sqlx::query(
+    r#"
+    SELECT sequence, entry_hash
+    FROM audit_log
+    WHERE sequence IS NOT NULL
+    ORDER BY sequence DESC
+    LIMIT 1
+    "#,
+  )
+  .fetch_optional(conn)
+  .await?;
+
+  Ok(match row {
+    Some(row) => (
+      row.get::<i64, _>("sequence"),
+      row.get::<Option<String>, _>("entry_hash"),
+    ),
+    None => (0, None),
+  })
+}
+
+async fn insert_chain_entry(
+  conn: &mut SqliteConnection,
+  entry: &AuditChainEntry,
+) -> Result<(), sqlx::Error> {
+  sqlx::query(
+    r#"
+    INSERT INTO audit_log (
+      id, sequence, entity_name, entity_id, action, performed_by_user_id, performed_at,
+      before_json, after_json, prev_hash, entry_hash
+    )
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+    "#,
+  )
+  .bind(&entry.id)
+  .bind(entry.sequence)
+  .bind(&entry.entity_name)
+  .bind(&entry.entity_id)
+  .bind(&entry.action)
+  .bind(&entry.performed_by_user_id)
+  .bind(&entry.performed_at)
+  .bind(&entry.before_json)
+  .bind(&entry.after_json)
+  .bind(&entry.prev_hash)
+  .bind(&entry.entry_hash)
+  .execute(conn)
+  .await?;
+
+  Ok(())
+}
+
+fn map_chain_row(row: &SqliteRow) -> AuditChainEntry {
+  AuditChainEntry {
+    id: row.get::<String, _>("id"),
+    sequence: None,
+    entity_name: row.get::<String, _>("entity_name"),
+    entity_id: row.get::<String, _>("entity_id"),
+    action: row.get::<String, _>("action"),
+    performed_by_user_id: row.get::<Option<String>, _>("performed_by_user_id"),
+    performed_at: row.get::<String, _>("performed_at"),
+    before_json: row.get::<Option<String>, _>("before_json"),
+    after_json: row.get::<Option<String>, _>("after_json"),
+    prev_hash: None,
+    entry_hash: None,
+  }
+}
+
+/// JSON snapshot for `before_json`/`after_json`.
+pub(crate) fn snapshot<T: Serialize + ?Sized>(value: &T) -> Option<String> {
+  serde_json::to_string(value).ok()
+}
+
+pub struct AuditSqliteRepository {
+  pool: SqlitePool,
+}
+
+impl AuditSqliteRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl AuditRepository for AuditSqliteRepository {
+  async fn list_audit_log(
+    &self,
+    input: ListAuditLogInput,
+  ) -> Result<Vec<AuditEntryView>, AuditRepositoryError> {
+    let mut qb = QueryBuilder::<Sqlite>::new(
+      r#"
+      SELECT id, entity_name, entity_id, action, performed_by_user_id, performed_at, before_json, after_json
+      FROM audit_log
+      WHERE 1 = 1
+      "#,
+    );
+
+    if let Some(entity_name) = input.entity_name {
+      qb.push(" AND entity_name = ");
+      qb.push_bind(entity_name);
+    }
+    if let Some(entity_id) = input.entity_id {
+      qb.push(" AND entity_id = ");
+      qb.push_bind(entity_id);
+    }
+    if let Some(user_id) = input.performed_by_user_id {
+      qb.push(" AND performed_by_user_id = ");
+      qb.push_bind(user_id);
+    }
+    if let Some(from_date) = input.from_date {
+      qb.push(" AND date(performed_at) >= ");
+      qb.push_bind(from_date);
+    }
+    if let Some(to_date) = input.to_date {
+      qb.push(" AND date(performed_at) <= ");
+      qb.push_bind(to_date);
+    }
+
+    qb.push(" ORDER BY performed_at DESC, rowid DESC LIMIT ");
+    qb.push_bind(input.limit.unwrap_or(100));
+
+    let rows = qb
+      .build()
+      .fetch_all(&self.pool)
+      .await
+      .map_err(|_| AuditRepositoryError::PersistenceError)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| AuditEntryView {
+          id: row.get::<String, _>("id"),
+          entity_name: row.get::<String, _>("entity_name"),
+          entity_id: row.get::<String, _>("entity_id"),
+          action: row.get::<String, _>("action"),
+          performed_by_user_id: row.get::<Option<String>, _>("performed_by_user_id"),
+          performed_at: row.get::<String, _>("performed_at"),
+          before_json: row.get::<Option<String>, _>("before_json"),
+          after_json: row.get::<Option<String>, _>("after_json"),
+        })
+        .collect(),
+    )
+  }
+
+  async fn list_audit_chain(&self) -> Result<Vec<AuditChainEntry>, AuditRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT id, sequence, entity_name, entity_id, action, performed_by_user_id, performed_at,
+        before_json, after_json, prev_hash, entry_hash
+      FROM audit_log
+      ORDER BY sequence IS NOT NULL, sequence ASC, rowid ASC
+      "#,
+    )
+    .fetch_all(&self.pool)
+    .await
+    .map_err(|_| AuditRepositoryError::PersistenceError)?;
+
+    Ok(
+      rows
+        .iter()
+        .map(|row| AuditChainEntry {
+          sequence: row.get::<Option<i64>, _>("sequence"),
+          prev_hash: row.get::<Option<String>, _>("prev_hash"),
+          entry_hash: row.get::<Option<String>, _>("entry_hash"),
+          ..map_chain_row(row)
+        })
+        .collect(),
+    )
+  }
+}
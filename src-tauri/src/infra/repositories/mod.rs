@@ -1 +1,5 @@
+pub mod audit_sqlite;
 pub mod patients_sqlite;
+pub mod reports_sqlite;
+pub mod requesters_sqlite;
+pub mod users_sqlite;
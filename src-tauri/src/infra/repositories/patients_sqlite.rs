@@ -1,18 +1,51 @@
 use async_trait::async_trait;
 use std::collections::HashMap;
-use sqlx::{sqlite::SqliteRow, QueryBuilder, Row, Sqlite, SqlitePool};
+use serde_json::json;
+use sqlx::{sqlite::SqliteRow, QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
 
-use crate::domain::patients::{
-  dto::{
-    AttendanceQueueItemView, AttendanceQueueQueryInput, CompleteAttendanceInput, CreateAttendanceInput,
-    CreatePatientInput, ExamCatalogItemView, PatientRecordEntryView, PatientRecordExamItemView,
-    PatientRecordView, PatientView,
+use crate::{
+  domain::patients::{
+    delta_check::DeltaCheckRule,
+    dto::{
+      AcknowledgeCriticalAlertInput, AmendResultInput, AttendanceEditContext,
+      AttendanceEditItemContext, AttendanceQueueItemView,
+      AttendanceQueuePageQuery, AttendanceStatusChange, AttendanceStatusHistoryView,
+      AttendanceUpdate, CompleteAttendanceInput, CreateAttendanceInput, CreateAttendanceItemInput,
+      CreateExamCatalogItemInput, CreateExamCategoryInput, CreatePatientInput, CriticalAlertView,
+      DeactivateExamCatalogItemInput, EnterExamResultsInput, ExamCatalogItemView,
+      ExamCategoryView, MergePatientsInput, MergePatientsResultView, PatientPageQuery,
+      PatientRecordEntryView,
+      PatientRecordExamItemView, PatientRecordView, PatientView, PreviousResult,
+      ResultEntryContext, ResultEntryItemContext, ResultHistoryView, SetExamDeltaCheckInput,
+      SetExamPanelAnalytesInput, SetExamReferenceRangesInput,
+      UpdateExamCatalogItemInput,
+      UpdatePatientInput,
+    },
+    entity::Patient,
+    errors::PatientRepositoryError,
+    pagination::{AttendanceSort, PageCursor, PageView, PatientSort},
+    ports::{
+      AttendanceRepository, CriticalAlertRepository, ExamCatalogRepository, PatientMergeRepository,
+      PatientRepository, RecordDeletionRepository,
+    },
+    reference_range::{ReferenceRange, ResultFlag},
   },
-  entity::Patient,
-  errors::PatientRepositoryError,
-  ports::PatientRepository,
+  infra::repositories::audit_sqlite::{record_audit, snapshot, AuditRecord},
 };
 
+/// Reference ranges, delta-check rule and previous released value of one catalog exam.
+type CatalogResultContext = (Vec<ReferenceRange>, DeltaCheckRule, Option<PreviousResult>);
+
+/// What a status change records besides the status itself.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StatusChangeKind {
+  Transition,
+  /// Who cancelled and why (`change.note`).
+  Cancellation,
+  /// The signature of the release, on the attendance and on every item.
+  Release,
+}
+
 pub struct PatientsSqliteRepository {
   pool: SqlitePool,
 }
@@ -22,9 +55,37 @@ impl PatientsSqliteRepository {
     Self { pool }
   }
 
+  async fn get_critical_alert_by_id(
+    &self,
+    alert_id: &str,
+  ) -> Result<CriticalAlertView, PatientRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT
+        a.id, a.exam_id, a.exam_item_id, e.patient_id, p.full_name AS patient_name,
+        i.name AS exam_name, a.result_value, a.result_flag, i.unit, r.name AS requester_name,
+        a.detected_at, a.detected_by, a.acknowledged_at, a.acknowledged_by, a.contact_name,
+        a.contact_method, a.note
+      FROM critical_alerts a
+      JOIN exams e ON e.id = a.exam_id
+      JOIN patients p ON p.id = e.patient_id
+      JOIN exam_items i ON i.id = a.exam_item_id
+      LEFT JOIN requesters r ON r.id = e.requester_id
+      WHERE a.id = ?1
+      "#,
+    )
+    .bind(alert_id)
+    .fetch_one(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(map_critical_alert_row(&row))
+  }
+
   async fn get_attendance_by_id(
     &self,
     attendance_id: &str,
+    include_deleted: bool,
   ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
     let rows = sqlx::query(
       r#"
@@ -36,15 +97,17 @@ impl PatientsSqliteRepository {
         e.exam_date AS exam_date,
         e.status AS status,
         e.updated_at AS updated_at,
+        e.deleted_at AS deleted_at,
         ei.name AS exam_name
       FROM exams e
       JOIN patients p ON p.id = e.patient_id
       LEFT JOIN exam_items ei ON ei.exam_id = e.id
-      WHERE e.id = ?1
+      WHERE e.id = ?1 AND (?2 OR e.deleted_at IS NULL)
       ORDER BY ei.created_at ASC
       "#,
     )
     .bind(attendance_id)
+    .bind(include_deleted)
     .fetch_all(&self.pool)
     .await
     .map_err(map_sqlx_error)?;
@@ -61,6 +124,7 @@ impl PatientsSqliteRepository {
     let exam_date = first.get::<String, _>("exam_date");
     let status = first.get::<String, _>("status");
     let updated_at = first.get::<String, _>("updated_at");
+    let deleted_at = first.get::<Option<String>, _>("deleted_at");
     let mut exam_names: Vec<String> = Vec::new();
     for row in rows {
       if let Ok(exam_name) = row.try_get::<String, _>("exam_name") {
@@ -79,19 +143,184 @@ impl PatientsSqliteRepository {
       status,
       exam_names,
       updated_at,
+      deleted_at,
     })
   }
+
+  async fn get_record_entry_by_id(
+    &self,
+    exam_id: &str,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    fetch_record_entry(&self.pool, exam_id)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(PatientRepositoryError::NotFound)
+  }
+
+  async fn get_exam_catalog_item_by_id(
+    &self,
+    catalog_item_id: &str,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+    fetch_exam_catalog_item(&mut conn, catalog_item_id).await
+  }
+
+  async fn list_reference_ranges_by_catalog_item(
+    &self,
+    catalog_item_id: &str,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+    let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+    fetch_reference_ranges(&mut conn, catalog_item_id).await
+  }
+
+  /// Compare-and-set on `exams.status` plus history and audit, together with what `kind` records.
+  async fn apply_status_change(
+    &self,
+    change: AttendanceStatusChange,
+    kind: StatusChangeKind,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let updated = sqlx::query(
+      r#"
+      UPDATE exams
+      SET status = ?3, updated_at = datetime('now')
+      WHERE id = ?1 AND status = ?2 AND deleted_at IS NULL
+      "#,
+    )
+    .bind(&change.attendance_id)
+    .bind(&change.from_status)
+    .bind(&change.to_status)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    if updated.rows_affected() == 0 {
+      let exists = sqlx::query("SELECT id FROM exams WHERE id = ?1 AND deleted_at IS NULL")
+        .bind(&change.attendance_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+      return Err(match exists {
+        Some(_) => PatientRepositoryError::Conflict,
+        None => PatientRepositoryError::NotFound,
+      });
+    }
+
+    let note = normalize_text(change.note);
+    if kind == StatusChangeKind::Cancellation {
+      sqlx::query(
+        r#"
+        UPDATE exams
+        SET cancelled_at = datetime('now'), cancelled_by = ?2, cancellation_reason = ?3
+        WHERE id = ?1
+        "#,
+      )
+      .bind(&change.attendance_id)
+      .bind(change.changed_by_user_id.as_deref())
+      .bind(note.as_deref())
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+    if kind == StatusChangeKind::Release {
+      // A result may have been cleared or an item added since the use case checked.
+      let pending = sqlx::query(
+        "SELECT id FROM exam_items WHERE exam_id = ?1 AND result_value IS NULL LIMIT 1",
+      )
+      .bind(&change.attendance_id)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+      if pending.is_some() {
+        return Err(PatientRepositoryError::Conflict);
+      }
+      let unacknowledged = sqlx::query(
+        "SELECT id FROM critical_alerts WHERE exam_id = ?1 AND acknowledged_at IS NULL LIMIT 1",
+      )
+      .bind(&change.attendance_id)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+      if unacknowledged.is_some() {
+        return Err(PatientRepositoryError::Conflict);
+      }
+
+      sqlx::query("UPDATE exams SET released_at = datetime('now'), released_by = ?2 WHERE id = ?1")
+        .bind(&change.attendance_id)
+        .bind(change.changed_by_user_id.as_deref())
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+      sqlx::query(
+        r#"
+        UPDATE exam_items
+        SET released_at = datetime('now'), released_by = ?2, updated_at = datetime('now')
+        WHERE exam_id = ?1
+        "#,
+      )
+      .bind(&change.attendance_id)
+      .bind(change.changed_by_user_id.as_deref())
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+
+    insert_status_history(
+      &mut tx,
+      &change.attendance_id,
+      Some(&change.from_status),
+      &change.to_status,
+      change.changed_by_user_id.as_deref(),
+      note.as_deref(),
+    )
+    .await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exams",
+        entity_id: &change.attendance_id,
+        action: "update",
+        performed_by_user_id: change.changed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({ "status": change.from_status })),
+        after_json: snapshot(&json!({ "status": change.to_status, "note": note })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_attendance_by_id(&change.attendance_id, false).await
+  }
+
+  async fn ensure_exam_category_exists(&self, category_id: &str) -> Result<(), PatientRepositoryError> {
+    sqlx::query("SELECT id FROM exam_categories WHERE id = ?1")
+      .bind(category_id)
+      .fetch_one(&self.pool)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    Ok(())
+  }
 }
 
 #[async_trait]
 impl PatientRepository for PatientsSqliteRepository {
-  async fn insert(&self, input: CreatePatientInput) -> Result<Patient, PatientRepositoryError> {
+  async fn insert(
+    &self,
+    input: CreatePatientInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
 
     let result = sqlx::query(
       r#"
       INSERT INTO patients (full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
       VALUES (?1, ?2, ?3, ?4, ?5, ?6, datetime('now'), datetime('now'))
-      RETURNING id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at
+      RETURNING id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
       "#,
     )
     .bind(&input.full_name)
@@ -100,7 +329,7 @@ impl PatientRepository for PatientsSqliteRepository {
     .bind(&input.sex)
     .bind(&input.phone)
     .bind(&input.address)
-    .fetch_one(&self.pool)
+    .fetch_one(&mut *tx)
     .await;
 
     let row: SqliteRow = match result {
@@ -110,7 +339,7 @@ impl PatientRepository for PatientsSqliteRepository {
       }
     };
 
-    Ok(Patient {
+    let patient = Patient {
       id: row.get::<String, _>("id"),
       full_name: row.get::<String, _>("full_name"),
       cpf: row.get::<String, _>("cpf"),
@@ -120,68 +349,234 @@ impl PatientRepository for PatientsSqliteRepository {
       address: row.get::<String, _>("address"),
       created_at: row.get::<String, _>("created_at"),
       updated_at: row.get::<String, _>("updated_at"),
-    })
+      deleted_at: row.get::<Option<String>, _>("deleted_at"),
+    };
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "patients",
+        entity_id: &patient.id,
+        action: "create",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: None,
+        after_json: snapshot(&patient),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(patient)
   }
 
-  async fn list(&self, query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
-    let query = query.unwrap_or_default().trim().to_string();
-    let rows = if query.is_empty() {
-      sqlx::query(
-        r#"
-        SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at
-        FROM patients
-        ORDER BY created_at DESC
-        "#,
-      )
-      .fetch_all(&self.pool)
+  async fn update_patient(
+    &self,
+    input: UpdatePatientInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = sqlx::query(
+      r#"
+      SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      FROM patients
+      WHERE id = ?1 AND merged_into_patient_id IS NULL AND deleted_at IS NULL
+      "#,
+    )
+    .bind(&input.id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?
+    .map(|row| map_patient_row(&row))
+    .ok_or(PatientRepositoryError::NotFound)?;
+
+    if before.updated_at != input.expected_updated_at {
+      return Err(PatientRepositoryError::Conflict);
+    }
+
+    // Millisecond precision, so two edits within the same second still change `updated_at`.
+    let row = sqlx::query(
+      r#"
+      UPDATE patients
+      SET full_name = ?2, cpf = ?3, birth_date = ?4, sex = ?5, phone = ?6, address = ?7,
+        updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now')
+      WHERE id = ?1 AND updated_at = ?8
+      RETURNING id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      "#,
+    )
+    .bind(&input.id)
+    .bind(&input.full_name)
+    .bind(&input.cpf)
+    .bind(&input.birth_date)
+    .bind(&input.sex)
+    .bind(&input.phone)
+    .bind(&input.address)
+    .bind(&input.expected_updated_at)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?
+    .ok_or(PatientRepositoryError::Conflict)?;
+
+    let patient = map_patient_row(&row);
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "patients",
+        entity_id: &patient.id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&patient),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(patient)
+  }
+
+  async fn list(
+    &self,
+    query: PatientPageQuery,
+  ) -> Result<PageView<Patient>, PatientRepositoryError> {
+    let has_no_terms = query
+      .query
+      .as_deref()
+      .is_some_and(|text| fts_terms(text).is_empty());
+    if has_no_terms {
+      // Only punctuation: nothing can match, and an empty FTS5 query is a syntax error.
+      return Ok(PageView {
+        items: Vec::new(),
+        next_cursor: None,
+        total: 0,
+      });
+    }
+
+    let mut count_qb = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) AS total FROM patients");
+    push_patient_filters(&mut count_qb, &query);
+    let total = count_qb
+      .build()
+      .fetch_one(&self.pool)
       .await
-    } else {
-      let like = format!("%{}%", query);
-      sqlx::query(
-        r#"
-        SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at
-        FROM patients
-        WHERE lower(full_name) LIKE lower(?1) OR cpf LIKE ?1
-        ORDER BY created_at DESC
-        "#,
-      )
-      .bind(like)
+      .map_err(map_sqlx_error)?
+      .get::<i64, _>("total");
+
+    // Filters run in the inner query so the keyset below can use the computed relevance columns.
+    let mut qb = QueryBuilder::<Sqlite>::new(
+      r#"
+      SELECT * FROM (
+        SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      "#,
+    );
+    if let (PatientSort::Relevance, Some(text)) = (query.sort, &query.query) {
+      qb.push(", ");
+      push_search_tier(&mut qb, text);
+      qb.push(" AS search_tier, length(full_name) AS name_length");
+    }
+    qb.push(" FROM patients");
+    push_patient_filters(&mut qb, &query);
+    qb.push(") WHERE 1 = 1");
+    if let Some(after) = &query.after {
+      match query.sort {
+        PatientSort::CreatedAt => {
+          qb.push(" AND (created_at, id) < (");
+          qb.push_bind(after.keys[0].clone());
+        }
+        PatientSort::Name => {
+          qb.push(" AND (lower(full_name), id) > (lower(");
+          qb.push_bind(after.keys[0].clone());
+          qb.push(")");
+        }
+        PatientSort::Relevance => {
+          qb.push(" AND (search_tier, name_length, id) > (");
+          qb.push_bind(after.keys[0].parse::<i64>().unwrap_or_default());
+          qb.push(", ");
+          qb.push_bind(after.keys[1].parse::<i64>().unwrap_or_default());
+        }
+      }
+      qb.push(", ");
+      qb.push_bind(after.keys[after.keys.len() - 1].clone());
+      qb.push(")");
+    }
+    qb.push(match query.sort {
+      PatientSort::CreatedAt => " ORDER BY created_at DESC, id DESC",
+      PatientSort::Name => " ORDER BY lower(full_name) ASC, id ASC",
+      PatientSort::Relevance => " ORDER BY search_tier ASC, name_length ASC, id ASC",
+    });
+    // One extra row tells whether there is a next page.
+    qb.push(" LIMIT ");
+    qb.push_bind(query.limit + 1);
+
+    let mut rows = qb
+      .build()
       .fetch_all(&self.pool)
       .await
-    };
-
-    let rows = rows.map_err(map_sqlx_error)?;
+      .map_err(map_sqlx_error)?;
 
-    let mut patients = Vec::with_capacity(rows.len());
-    for row in rows {
-      patients.push(Patient {
-        id: row.get::<String, _>("id"),
-        full_name: row.get::<String, _>("full_name"),
-        cpf: row.get::<String, _>("cpf"),
-        birth_date: row.get::<String, _>("birth_date"),
-        sex: row.get::<String, _>("sex"),
-        phone: row.get::<String, _>("phone"),
-        address: row.get::<String, _>("address"),
-        created_at: row.get::<String, _>("created_at"),
-        updated_at: row.get::<String, _>("updated_at"),
-      });
+    let mut next_cursor = None;
+    if rows.len() as i64 > query.limit {
+      rows.truncate(query.limit as usize);
+      if let Some(last) = rows.last() {
+        let id = last.get::<String, _>("id");
+        let keys = match query.sort {
+          PatientSort::CreatedAt => vec![last.get::<String, _>("created_at"), id],
+          PatientSort::Name => vec![last.get::<String, _>("full_name"), id],
+          PatientSort::Relevance => vec![
+            last.get::<i64, _>("search_tier").to_string(),
+            last.get::<i64, _>("name_length").to_string(),
+            id,
+          ],
+        };
+        next_cursor = Some(PageCursor::new(query.sort.as_str(), keys).encode());
+      }
     }
+    let patients: Vec<Patient> = rows.iter().map(map_patient_row).collect();
+
+    Ok(PageView {
+      items: patients,
+      next_cursor,
+      total,
+    })
+  }
+
+  async fn find_patient(
+    &self,
+    patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      FROM patients
+      WHERE id = ?1 AND merged_into_patient_id IS NULL
+      "#,
+    )
+    .bind(&patient_id)
+    .fetch_optional(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
 
-    Ok(patients)
+    Ok(row.as_ref().map(map_patient_row))
   }
 
   async fn get_patient_record(
     &self,
     patient_id: String,
+    include_deleted: bool,
   ) -> Result<PatientRecordView, PatientRepositoryError> {
     let patient_row = sqlx::query(
       r#"
-      SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at
+      SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
       FROM patients
-      WHERE id = ?1
+      WHERE id = ?1 AND merged_into_patient_id IS NULL AND (?2 OR deleted_at IS NULL)
       "#,
     )
     .bind(&patient_id)
+    .bind(include_deleted)
     .fetch_one(&self.pool)
     .await
     .map_err(map_sqlx_error)?;
@@ -196,6 +591,7 @@ impl PatientRepository for PatientsSqliteRepository {
       address: patient_row.get::<String, _>("address"),
       created_at: patient_row.get::<String, _>("created_at"),
       updated_at: patient_row.get::<String, _>("updated_at"),
+      deleted_at: patient_row.get::<Option<String>, _>("deleted_at"),
     };
 
     let rows = sqlx::query(
@@ -204,6 +600,7 @@ impl PatientRepository for PatientsSqliteRepository {
         e.id AS exam_id,
         e.exam_date AS exam_date,
         e.status AS status,
+        e.deleted_at AS deleted_at,
         r.name AS requester_name,
         ei.id AS exam_item_id,
         ei.name AS item_name,
@@ -211,15 +608,20 @@ impl PatientRepository for PatientsSqliteRepository {
         ei.method AS method,
         ei.reference_range AS reference_range,
         ei.result_value AS result_value,
-        ei.result_flag AS result_flag
+        ei.result_flag AS result_flag,
+        ei.result_version AS result_version,
+        ei.released_at AS released_at,
+        ei.panel_id AS panel_id,
+        ei.panel_name AS panel_name
       FROM exams e
       LEFT JOIN requesters r ON r.id = e.requester_id
       LEFT JOIN exam_items ei ON ei.exam_id = e.id
-      WHERE e.patient_id = ?1
-      ORDER BY e.exam_date DESC, e.created_at DESC, ei.created_at ASC
+      WHERE e.patient_id = ?1 AND (?2 OR e.deleted_at IS NULL)
+      ORDER BY e.exam_date DESC, e.created_at DESC, ei.created_at ASC, ei.rowid ASC
       "#,
     )
     .bind(&patient_id)
+    .bind(include_deleted)
     .fetch_all(&self.pool)
     .await
     .map_err(map_sqlx_error)?;
@@ -240,22 +642,24 @@ impl PatientRepository for PatientsSqliteRepository {
           status: row.get::<String, _>("status"),
           requester_name: row.get::<Option<String>, _>("requester_name"),
           items: Vec::new(),
+          deleted_at: row.get::<Option<String>, _>("deleted_at"),
         });
         created_idx
       };
 
       if let Ok(exam_item_id) = row.try_get::<String, _>("exam_item_id") {
-        let result_value = row.get::<Option<String>, _>("result_value");
-        let result_flag = row.get::<Option<String>, _>("result_flag");
         entries[idx].items.push(PatientRecordExamItemView {
           exam_item_id,
           name: row.get::<String, _>("item_name"),
           unit: row.get::<Option<String>, _>("unit"),
           method: row.get::<Option<String>, _>("method"),
           reference_range: row.get::<Option<String>, _>("reference_range"),
-          result_value: result_value.clone(),
-          result_flag: result_flag.clone(),
-          report_available: result_value.is_some() || result_flag.is_some(),
+          result_value: row.get::<Option<String>, _>("result_value"),
+          result_flag: row.get::<Option<String>, _>("result_flag"),
+          report_available: row.get::<Option<String>, _>("released_at").is_some(),
+          result_version: row.get::<i64, _>("result_version"),
+          panel_id: row.get::<Option<String>, _>("panel_id"),
+          panel_name: row.get::<Option<String>, _>("panel_name"),
         });
       }
     }
@@ -263,56 +667,52 @@ impl PatientRepository for PatientsSqliteRepository {
     Ok(PatientRecordView { patient, entries })
   }
 
-  async fn list_exam_catalog(&self) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
-    Ok(vec![
-      ExamCatalogItemView {
-        id: "glicose".to_string(),
-        name: "Glicose".to_string(),
-        category_id: "bioquimica".to_string(),
-        category_title: "Bioquimica".to_string(),
-        price_cents: 1000,
-      },
-      ExamCatalogItemView {
-        id: "colesterol-total".to_string(),
-        name: "Colesterol Total".to_string(),
-        category_id: "bioquimica".to_string(),
-        category_title: "Bioquimica".to_string(),
-        price_cents: 1000,
-      },
-      ExamCatalogItemView {
-        id: "triglicerideos".to_string(),
-        name: "Triglicerideos".to_string(),
-        category_id: "bioquimica".to_string(),
-        category_title: "Bioquimica".to_string(),
-        price_cents: 1000,
-      },
-      ExamCatalogItemView {
-        id: "ureia-creatinina".to_string(),
-        name: "Bioquimica 2 (Ureia/Creatinina)".to_string(),
-        category_id: "bioquimica".to_string(),
-        category_title: "Bioquimica".to_string(),
-        price_cents: 2500,
-      },
-      ExamCatalogItemView {
-        id: "hemograma-completo".to_string(),
-        name: "Hemograma Completo".to_string(),
-        category_id: "hematologia".to_string(),
-        category_title: "Hematologia".to_string(),
-        price_cents: 2000,
-      },
-      ExamCatalogItemView {
-        id: "beta-hcg".to_string(),
-        name: "Beta HCG Qualitativo".to_string(),
-        category_id: "imunologia".to_string(),
-        category_title: "Imunologia".to_string(),
-        price_cents: 2000,
-      },
-    ])
+  async fn list_exam_catalog(
+    &self,
+    include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT
+        c.id AS id,
+        c.name AS name,
+        c.category_id AS category_id,
+        cat.title AS category_title,
+        c.price_cents AS price_cents,
+        c.unit AS unit,
+        c.method AS method,
+        c.reference_range AS reference_range,
+        c.is_active AS is_active
+      FROM exam_catalog_items c
+      JOIN exam_categories cat ON cat.id = c.category_id
+      WHERE ?1 OR c.is_active = 1
+      ORDER BY cat.title ASC, c.name ASC
+      "#,
+    )
+    .bind(include_inactive)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+    let mut analytes = fetch_panel_analyte_ids(&mut conn, None).await?;
+    Ok(
+      rows
+        .iter()
+        .map(|row| ExamCatalogItemView {
+          analyte_ids: analytes
+            .remove(&row.get::<String, _>("id"))
+            .unwrap_or_default(),
+          ..map_exam_catalog_row(row)
+        })
+        .collect(),
+    )
   }
 
   async fn create_attendance(
     &self,
     input: CreateAttendanceInput,
+    performed_by_user_id: Option<String>,
   ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
     let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
 
@@ -324,6 +724,9 @@ impl PatientRepository for PatientsSqliteRepository {
     let delivered_to = normalize_text(input.delivered_to);
     let notes = normalize_text(input.notes);
 
+    // A merged patient is only a tombstone; new attendances go to the surviving record.
+    fetch_active_patient(&mut tx, &patient_id).await?;
+
     let exam_row = sqlx::query(
       r#"
       INSERT INTO exams (patient_id, requester_id, exam_date, status, procedure_type, delivered_to, notes, created_at, updated_at)
@@ -346,6 +749,16 @@ impl PatientRepository for PatientsSqliteRepository {
     let created_exam_date = exam_row.get::<String, _>("exam_date");
     let created_status = exam_row.get::<String, _>("status");
 
+    insert_status_history(
+      &mut tx,
+      &exam_id,
+      None,
+      &created_status,
+      performed_by_user_id.as_deref(),
+      None,
+    )
+    .await?;
+
     let requester_name = if let Some(requester_id_value) = requester_id.as_deref() {
       let requester_row = sqlx::query(
         r#"
@@ -366,49 +779,53 @@ impl PatientRepository for PatientsSqliteRepository {
 
     let mut items = Vec::with_capacity(input.items.len());
     for item in input.items {
-      let item_row = sqlx::query(
-        r#"
-        INSERT INTO exam_items (exam_id, name, unit, method, reference_range, result_value, result_flag, created_at, updated_at)
-        VALUES (?1, ?2, ?3, ?4, ?5, NULL, NULL, datetime('now'), datetime('now'))
-        RETURNING id, name, unit, method, reference_range, result_value, result_flag
-        "#,
-      )
-      .bind(&exam_id)
-      .bind(item.name)
-      .bind(normalize_text(item.unit).as_deref())
-      .bind(normalize_text(item.method).as_deref())
-      .bind(normalize_text(item.reference_range).as_deref())
-      .fetch_one(&mut *tx)
-      .await
-      .map_err(map_sqlx_error)?;
-
-      items.push(PatientRecordExamItemView {
-        exam_item_id: item_row.get::<String, _>("id"),
-        name: item_row.get::<String, _>("name"),
-        unit: item_row.get::<Option<String>, _>("unit"),
-        method: item_row.get::<Option<String>, _>("method"),
-        reference_range: item_row.get::<Option<String>, _>("reference_range"),
-        result_value: item_row.get::<Option<String>, _>("result_value"),
-        result_flag: item_row.get::<Option<String>, _>("result_flag"),
-        report_available: false,
-      });
+      items.extend(insert_exam_items(&mut tx, &exam_id, item).await?);
     }
 
-    tx.commit().await.map_err(map_sqlx_error)?;
-
-    Ok(PatientRecordEntryView {
+    let entry = PatientRecordEntryView {
       exam_id,
       exam_date: created_exam_date,
       status: created_status,
       requester_name,
       items,
-    })
+      deleted_at: None,
+    };
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exams",
+        entity_id: &entry.exam_id,
+        action: "create",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: None,
+        after_json: snapshot(&json!({ "patient_id": patient_id, "attendance": entry })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(entry)
   }
 
   async fn list_attendance_queue(
     &self,
-    input: AttendanceQueueQueryInput,
-  ) -> Result<Vec<AttendanceQueueItemView>, PatientRepositoryError> {
+    query: AttendanceQueuePageQuery,
+  ) -> Result<PageView<AttendanceQueueItemView>, PatientRepositoryError> {
+    let mut count_qb = QueryBuilder::<Sqlite>::new(
+      "SELECT COUNT(*) AS total FROM exams e JOIN patients p ON p.id = e.patient_id",
+    );
+    push_queue_filters(&mut count_qb, &query);
+    let total = count_qb
+      .build()
+      .fetch_one(&self.pool)
+      .await
+      .map_err(map_sqlx_error)?
+      .get::<i64, _>("total");
+
+    // Paginate over exams alone; joining exam_items here would page over item rows.
     let mut qb = QueryBuilder::<Sqlite>::new(
       r#"
       SELECT
@@ -418,111 +835,1914 @@ impl PatientRepository for PatientsSqliteRepository {
         p.cpf AS patient_cpf,
         e.exam_date AS exam_date,
         e.status AS status,
+        e.created_at AS created_at,
         e.updated_at AS updated_at,
-        ei.name AS exam_name
+        e.deleted_at AS deleted_at
       FROM exams e
       JOIN patients p ON p.id = e.patient_id
-      LEFT JOIN exam_items ei ON ei.exam_id = e.id
-      WHERE 1 = 1
       "#,
     );
-
-    if let Some(date) = input.date {
-      let date = date.trim().to_string();
-      if !date.is_empty() {
-        qb.push(" AND e.exam_date = ");
-        qb.push_bind(date);
-      }
-    }
-
-    if let Some(status) = input.status {
-      let status = status.trim().to_string();
-      if !status.is_empty() {
-        qb.push(" AND e.status = ");
-        qb.push_bind(status);
-      }
-    }
-
-    if let Some(query) = input.query {
-      let query = query.trim().to_string();
-      if !query.is_empty() {
-        let like = format!("%{}%", query.to_lowercase());
-        qb.push(
-          " AND (lower(p.full_name) LIKE ",
-        );
-        qb.push_bind(like.clone());
-        qb.push(" OR p.cpf LIKE ");
-        qb.push_bind(like.clone());
-        qb.push(" OR lower(e.id) LIKE ");
-        qb.push_bind(like.clone());
-        qb.push(" OR lower(coalesce(ei.name, '')) LIKE ");
-        qb.push_bind(like);
-        qb.push(")");
+    push_queue_filters(&mut qb, &query);
+    if let Some(after) = &query.after {
+      match query.sort {
+        AttendanceSort::ExamDate => {
+          qb.push(" AND (e.exam_date, e.created_at, e.id) < (");
+          qb.push_bind(after.keys[0].clone());
+          qb.push(", ");
+          qb.push_bind(after.keys[1].clone());
+          qb.push(", ");
+          qb.push_bind(after.keys[2].clone());
+        }
+        AttendanceSort::CreatedAt => {
+          qb.push(" AND (e.created_at, e.id) < (");
+          qb.push_bind(after.keys[0].clone());
+          qb.push(", ");
+          qb.push_bind(after.keys[1].clone());
+        }
+        AttendanceSort::Name => {
+          qb.push(" AND (lower(p.full_name), e.id) > (lower(");
+          qb.push_bind(after.keys[0].clone());
+          qb.push("), ");
+          qb.push_bind(after.keys[1].clone());
+        }
       }
+      qb.push(")");
     }
+    qb.push(match query.sort {
+      AttendanceSort::ExamDate => " ORDER BY e.exam_date DESC, e.created_at DESC, e.id DESC",
+      AttendanceSort::CreatedAt => " ORDER BY e.created_at DESC, e.id DESC",
+      AttendanceSort::Name => " ORDER BY lower(p.full_name) ASC, e.id ASC",
+    });
+    qb.push(" LIMIT ");
+    qb.push_bind(query.limit + 1);
 
-    qb.push(" ORDER BY e.exam_date DESC, e.created_at DESC, ei.created_at ASC");
-
-    let rows = qb
+    let mut rows = qb
       .build()
       .fetch_all(&self.pool)
       .await
       .map_err(map_sqlx_error)?;
 
-    let mut entries: Vec<AttendanceQueueItemView> = Vec::new();
-    let mut index_by_attendance_id: HashMap<String, usize> = HashMap::new();
+    let mut next_cursor = None;
+    if rows.len() as i64 > query.limit {
+      rows.truncate(query.limit as usize);
+      if let Some(last) = rows.last() {
+        let id = last.get::<String, _>("attendance_id");
+        let keys = match query.sort {
+          AttendanceSort::ExamDate => vec![
+            last.get::<String, _>("exam_date"),
+            last.get::<String, _>("created_at"),
+            id,
+          ],
+          AttendanceSort::CreatedAt => vec![last.get::<String, _>("created_at"), id],
+          AttendanceSort::Name => vec![last.get::<String, _>("patient_name"), id],
+        };
+        next_cursor = Some(PageCursor::new(query.sort.as_str(), keys).encode());
+      }
+    }
 
-    for row in rows {
-      let attendance_id = row.get::<String, _>("attendance_id");
-      let idx = if let Some(existing_idx) = index_by_attendance_id.get(&attendance_id) {
-        *existing_idx
-      } else {
-        let created_idx = entries.len();
-        index_by_attendance_id.insert(attendance_id.clone(), created_idx);
-        entries.push(AttendanceQueueItemView {
-          attendance_id,
-          patient_id: row.get::<String, _>("patient_id"),
-          patient_name: row.get::<String, _>("patient_name"),
-          patient_cpf: row.get::<String, _>("patient_cpf"),
-          exam_date: row.get::<String, _>("exam_date"),
-          status: row.get::<String, _>("status"),
-          exam_names: Vec::new(),
-          updated_at: row.get::<String, _>("updated_at"),
-        });
-        created_idx
-      };
+    let mut entries: Vec<AttendanceQueueItemView> = rows
+      .iter()
+      .map(|row| AttendanceQueueItemView {
+        attendance_id: row.get::<String, _>("attendance_id"),
+        patient_id: row.get::<String, _>("patient_id"),
+        patient_name: row.get::<String, _>("patient_name"),
+        patient_cpf: row.get::<String, _>("patient_cpf"),
+        exam_date: row.get::<String, _>("exam_date"),
+        status: row.get::<String, _>("status"),
+        exam_names: Vec::new(),
+        updated_at: row.get::<String, _>("updated_at"),
+        deleted_at: row.get::<Option<String>, _>("deleted_at"),
+      })
+      .collect();
 
-      if let Ok(exam_name) = row.try_get::<String, _>("exam_name") {
-        if !entries[idx].exam_names.iter().any(|value| value == &exam_name) {
-          entries[idx].exam_names.push(exam_name);
+    if !entries.is_empty() {
+      let index_by_attendance_id: HashMap<String, usize> = entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| (entry.attendance_id.clone(), idx))
+        .collect();
+      let mut names_qb =
+        QueryBuilder::<Sqlite>::new("SELECT exam_id, name FROM exam_items WHERE exam_id IN (");
+      let mut ids = names_qb.separated(", ");
+      for entry in &entries {
+        ids.push_bind(entry.attendance_id.clone());
+      }
+      names_qb.push(") ORDER BY created_at ASC");
+      let name_rows = names_qb
+        .build()
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+      for row in name_rows {
+        let exam_name = row.get::<String, _>("name");
+        if let Some(idx) = index_by_attendance_id.get(&row.get::<String, _>("exam_id")) {
+          if !entries[*idx]
+            .exam_names
+            .iter()
+            .any(|value| value == &exam_name)
+          {
+            entries[*idx].exam_names.push(exam_name);
+          }
         }
       }
     }
 
-    Ok(entries)
+    Ok(PageView {
+      items: entries,
+      next_cursor,
+      total,
+    })
   }
 
   async fn complete_attendance(
     &self,
     input: CompleteAttendanceInput,
+    performed_by_user_id: Option<String>,
   ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self
+      .change_attendance_status(AttendanceStatusChange {
+        attendance_id: input.attendance_id,
+        from_status: "waiting".to_string(),
+        to_status: "collected".to_string(),
+        changed_by_user_id: performed_by_user_id,
+        note: None,
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl PatientMergeRepository for PatientsSqliteRepository {
+  async fn merge_patients(
+    &self,
+    input: MergePatientsInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<MergePatientsResultView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let survivor = fetch_active_patient(&mut tx, &input.surviving_patient_id).await?;
+    let merged = fetch_active_patient(&mut tx, &input.merged_patient_id).await?;
+
+    let moved = sqlx::query(
+      r#"
+      UPDATE exams
+      SET patient_id = ?2, updated_at = datetime('now')
+      WHERE patient_id = ?1
+      RETURNING id
+      "#,
+    )
+    .bind(&merged.id)
+    .bind(&survivor.id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    for row in &moved {
+      let exam_id = row.get::<String, _>("id");
+      record_audit(
+        &mut tx,
+        AuditRecord {
+          entity_name: "exams",
+          entity_id: &exam_id,
+          action: "update",
+          performed_by_user_id: performed_by_user_id.as_deref(),
+          before_json: snapshot(&json!({ "patient_id": merged.id })),
+          after_json: snapshot(&json!({ "patient_id": survivor.id })),
+        },
+      )
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+
+    sqlx::query(
+      r#"
+      UPDATE patients
+      SET merged_into_patient_id = ?2, merged_at = datetime('now'), updated_at = datetime('now')
+      WHERE id = ?1
+      "#,
+    )
+    .bind(&merged.id)
+    .bind(&survivor.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    // The CPF collision report (migration 0015) is settled once one side is merged away.
+    sqlx::query(
+      r#"
+      UPDATE patient_cpf_collisions
+      SET resolved_at = datetime('now')
+      WHERE patient_id IN (?1, ?2) AND resolved_at IS NULL
+      "#,
+    )
+    .bind(&merged.id)
+    .bind(&survivor.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    // The full merged record goes to the audit trail before it leaves every listing.
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "patients",
+        entity_id: &merged.id,
+        action: "delete",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&merged),
+        after_json: snapshot(&json!({ "merged_into_patient_id": survivor.id })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(MergePatientsResultView {
+      patient: PatientView {
+        id: survivor.id,
+        full_name: survivor.full_name,
+        cpf: survivor.cpf,
+        birth_date: survivor.birth_date,
+        sex: survivor.sex,
+        phone: survivor.phone,
+        address: survivor.address,
+        created_at: survivor.created_at,
+        updated_at: survivor.updated_at,
+        deleted_at: survivor.deleted_at,
+      },
+      merged_patient_id: merged.id,
+      moved_attendances: moved.len() as i64,
+    })
+  }
+}
+
+#[async_trait]
+impl RecordDeletionRepository for PatientsSqliteRepository {
+  async fn set_patient_deleted(
+    &self,
+    patient_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = sqlx::query(
+      r#"
+      SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      FROM patients
+      WHERE id = ?1 AND merged_into_patient_id IS NULL
+      "#,
+    )
+    .bind(&patient_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)
+    .map(|row| map_patient_row(&row))?;
+
+    if before.deleted_at.is_some() == deleted {
+      return Err(PatientRepositoryError::Conflict);
+    }
+
+    let row = sqlx::query(
+      r#"
+      UPDATE patients
+      SET deleted_at = CASE WHEN ?2 THEN datetime('now') END,
+        deleted_by = CASE WHEN ?2 THEN ?3 END,
+        updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now')
+      WHERE id = ?1
+      RETURNING id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+      "#,
+    )
+    .bind(&patient_id)
+    .bind(deleted)
+    .bind(performed_by_user_id.as_deref())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let patient = map_patient_row(&row);
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "patients",
+        entity_id: &patient.id,
+        action: if deleted { "delete" } else { "restore" },
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&patient),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(patient)
+  }
+
+  async fn set_attendance_deleted(
+    &self,
+    attendance_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = sqlx::query("SELECT deleted_at, deleted_by FROM exams WHERE id = ?1")
+      .bind(&attendance_id)
+      .fetch_one(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    let before_deleted_at = before.get::<Option<String>, _>("deleted_at");
+
+    if before_deleted_at.is_some() == deleted {
+      return Err(PatientRepositoryError::Conflict);
+    }
+
+    let after = sqlx::query(
+      r#"
+      UPDATE exams
+      SET deleted_at = CASE WHEN ?2 THEN datetime('now') END,
+        deleted_by = CASE WHEN ?2 THEN ?3 END,
+        updated_at = datetime('now')
+      WHERE id = ?1
+      RETURNING deleted_at, deleted_by
+      "#,
+    )
+    .bind(&attendance_id)
+    .bind(deleted)
+    .bind(performed_by_user_id.as_deref())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    // Items and results are untouched, so the deletion markers are the whole change.
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exams",
+        entity_id: &attendance_id,
+        action: if deleted { "delete" } else { "restore" },
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({
+          "deleted_at": before_deleted_at,
+          "deleted_by": before.get::<Option<String>, _>("deleted_by"),
+        })),
+        after_json: snapshot(&json!({
+          "deleted_at": after.get::<Option<String>, _>("deleted_at"),
+          "deleted_by": after.get::<Option<String>, _>("deleted_by"),
+        })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_attendance_by_id(&attendance_id, true).await
+  }
+}
+
+#[async_trait]
+impl AttendanceRepository for PatientsSqliteRepository {
+  async fn get_attendance(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self.get_attendance_by_id(&attendance_id, false).await
+  }
+
+  async fn change_attendance_status(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self
+      .apply_status_change(change, StatusChangeKind::Transition)
+      .await
+  }
+
+  async fn cancel_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self
+      .apply_status_change(change, StatusChangeKind::Cancellation)
+      .await
+  }
+
+  async fn release_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self
+      .apply_status_change(change, StatusChangeKind::Release)
+      .await
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    sqlx::query("SELECT id FROM exams WHERE id = ?1")
+      .bind(&attendance_id)
+      .fetch_one(&self.pool)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    let rows = sqlx::query(
+      r#"
+      SELECT id, exam_id, from_status, to_status, changed_by_user_id, changed_at, note
+      FROM attendance_status_history
+      WHERE exam_id = ?1
+      ORDER BY changed_at ASC, rowid ASC
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| AttendanceStatusHistoryView {
+          id: row.get::<String, _>("id"),
+          attendance_id: row.get::<String, _>("exam_id"),
+          from_status: row.get::<Option<String>, _>("from_status"),
+          to_status: row.get::<String, _>("to_status"),
+          changed_by_user_id: row.get::<Option<String>, _>("changed_by_user_id"),
+          changed_at: row.get::<String, _>("changed_at"),
+          note: row.get::<Option<String>, _>("note"),
+        })
+        .collect(),
+    )
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT e.id AS attendance_id, e.status AS status, e.exam_date AS exam_date, e.patient_id AS patient_id, p.sex AS sex, p.birth_date AS birth_date
+      FROM exams e
+      JOIN patients p ON p.id = e.patient_id
+      WHERE e.id = ?1 AND e.deleted_at IS NULL
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_one(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let item_rows = sqlx::query(
+      r#"
+      SELECT id, catalog_item_id
+      FROM exam_items
+      WHERE exam_id = ?1
+      ORDER BY created_at ASC
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let patient_id = row.get::<String, _>("patient_id");
+    let exam_date = row.get::<String, _>("exam_date");
+    let mut by_catalog_item: HashMap<String, CatalogResultContext> = HashMap::new();
+    let mut items = Vec::with_capacity(item_rows.len());
+    for item_row in item_rows {
+      let catalog_item_id = item_row.get::<Option<String>, _>("catalog_item_id");
+      let (reference_ranges, delta_check, previous_result) = match &catalog_item_id {
+        Some(id) => {
+          if !by_catalog_item.contains_key(id) {
+            let ranges = self.list_reference_ranges_by_catalog_item(id).await?;
+            let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+            let rule = fetch_delta_check_rule(&mut conn, id).await?;
+            let previous =
+              fetch_previous_result(&mut conn, &patient_id, id, &attendance_id, &exam_date).await?;
+            by_catalog_item.insert(id.clone(), (ranges, rule, previous));
+          }
+          by_catalog_item[id].clone()
+        }
+        None => (Vec::new(), DeltaCheckRule::default(), None),
+      };
+
+      items.push(ResultEntryItemContext {
+        exam_item_id: item_row.get::<String, _>("id"),
+        catalog_item_id,
+        reference_ranges,
+        delta_check,
+        previous_result,
+      });
+    }
+
+    Ok(ResultEntryContext {
+      attendance_id: row.get::<String, _>("attendance_id"),
+      status: row.get::<String, _>("status"),
+      exam_date,
+      patient_sex: row.get::<String, _>("sex"),
+      patient_birth_date: row.get::<String, _>("birth_date"),
+      items,
+    })
+  }
+
+  async fn enter_exam_results(
+    &self,
+    input: EnterExamResultsInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    for item in &input.items {
+      let exam_item_id = item.exam_item_id.trim();
+      let before = sqlx::query(
+        r#"
+        SELECT result_value, result_flag, released_at
+        FROM exam_items
+        WHERE id = ?1 AND exam_id = ?2
+        "#,
+      )
+      .bind(exam_item_id)
+      .bind(&input.attendance_id)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(PatientRepositoryError::NotFound)?;
+      // Signed results are immutable.
+      if before.get::<Option<String>, _>("released_at").is_some() {
+        return Err(PatientRepositoryError::Conflict);
+      }
+
+      let result_value = item.result_value.trim();
+      let result_flag = normalize_text(item.result_flag.clone());
+      let unchanged = before.get::<Option<String>, _>("result_value").as_deref()
+        == Some(result_value)
+        && before.get::<Option<String>, _>("result_flag") == result_flag;
+      sqlx::query(
+        r#"
+        UPDATE exam_items
+        SET result_value = ?3, result_flag = ?4, updated_at = datetime('now')
+        WHERE id = ?1 AND exam_id = ?2
+        "#,
+      )
+      .bind(exam_item_id)
+      .bind(&input.attendance_id)
+      .bind(result_value)
+      .bind(result_flag.as_deref())
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+      sync_critical_alert(
+        &mut tx,
+        &input.attendance_id,
+        exam_item_id,
+        result_value,
+        result_flag.as_deref(),
+        unchanged,
+        performed_by_user_id.as_deref(),
+      )
+      .await?;
+
+      record_audit(
+        &mut tx,
+        AuditRecord {
+          entity_name: "exam_items",
+          entity_id: exam_item_id,
+          action: "update",
+          performed_by_user_id: performed_by_user_id.as_deref(),
+          before_json: snapshot(&json!({
+            "result_value": before.get::<Option<String>, _>("result_value"),
+            "result_flag": before.get::<Option<String>, _>("result_flag"),
+          })),
+          after_json: snapshot(&json!({
+            "result_value": result_value,
+            "result_flag": result_flag,
+          })),
+        },
+      )
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+
+    sqlx::query("UPDATE exams SET updated_at = datetime('now') WHERE id = ?1")
+      .bind(&input.attendance_id)
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_record_entry_by_id(&input.attendance_id).await
+  }
+
+  async fn amend_result(
+    &self,
+    input: AmendResultInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let exam_item_id = input.exam_item_id.trim();
+    let before = sqlx::query(
+      r#"
+      SELECT result_value, result_flag, result_version, released_at
+      FROM exam_items
+      WHERE id = ?1 AND exam_id = ?2
+      "#,
+    )
+    .bind(exam_item_id)
+    .bind(&input.attendance_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?
+    .ok_or(PatientRepositoryError::NotFound)?;
+    // Results still being typed are changed through `enter_exam_results`.
+    if before.get::<Option<String>, _>("released_at").is_none() {
+      return Err(PatientRepositoryError::Conflict);
+    }
+
+    let previous_value = before.get::<Option<String>, _>("result_value");
+    let previous_flag = before.get::<Option<String>, _>("result_flag");
+    let previous_version = before.get::<i64, _>("result_version");
+    let reason = input.reason.trim();
+    sqlx::query(
+      r#"
+      INSERT INTO exam_result_history (exam_item_id, result_version, result_value, result_flag, reason, amended_by, amended_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, datetime('now'))
+      "#,
+    )
+    .bind(exam_item_id)
+    .bind(previous_version)
+    .bind(previous_value.as_deref())
+    .bind(previous_flag.as_deref())
+    .bind(reason)
+    .bind(performed_by_user_id.as_deref())
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let result_value = input.result_value.trim();
+    let result_flag = normalize_text(input.result_flag.clone());
+    // Compare-and-set on the version, so two amendments of the same value cannot both win.
+    let updated = sqlx::query(
+      r#"
+      UPDATE exam_items
+      SET result_value = ?3, result_flag = ?4, result_version = ?5 + 1, updated_at = datetime('now')
+      WHERE id = ?1 AND exam_id = ?2 AND result_version = ?5
+      "#,
+    )
+    .bind(exam_item_id)
+    .bind(&input.attendance_id)
+    .bind(result_value)
+    .bind(result_flag.as_deref())
+    .bind(previous_version)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+    if updated.rows_affected() == 0 {
+      return Err(PatientRepositoryError::Conflict);
+    }
+    sync_critical_alert(
+      &mut tx,
+      &input.attendance_id,
+      exam_item_id,
+      result_value,
+      result_flag.as_deref(),
+      previous_value.as_deref() == Some(result_value) && previous_flag == result_flag,
+      performed_by_user_id.as_deref(),
+    )
+    .await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_items",
+        entity_id: exam_item_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({
+          "result_value": previous_value,
+          "result_flag": previous_flag,
+          "result_version": previous_version,
+        })),
+        after_json: snapshot(&json!({
+          "result_value": result_value,
+          "result_flag": result_flag,
+          "result_version": previous_version + 1,
+          "reason": reason,
+        })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    sqlx::query("UPDATE exams SET updated_at = datetime('now') WHERE id = ?1")
+      .bind(&input.attendance_id)
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_record_entry_by_id(&input.attendance_id).await
+  }
+
+  async fn list_result_history(
+    &self,
+    exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    sqlx::query("SELECT id FROM exam_items WHERE id = ?1")
+      .bind(&exam_item_id)
+      .fetch_one(&self.pool)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    let rows = sqlx::query(
+      r#"
+      SELECT id, exam_item_id, result_version, result_value, result_flag, reason, amended_by, amended_at
+      FROM exam_result_history
+      WHERE exam_item_id = ?1
+      ORDER BY result_version ASC
+      "#,
+    )
+    .bind(&exam_item_id)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ResultHistoryView {
+          id: row.get::<String, _>("id"),
+          exam_item_id: row.get::<String, _>("exam_item_id"),
+          result_version: row.get::<i64, _>("result_version"),
+          result_value: row.get::<Option<String>, _>("result_value"),
+          result_flag: row.get::<Option<String>, _>("result_flag"),
+          reason: row.get::<String, _>("reason"),
+          amended_by_user_id: row.get::<Option<String>, _>("amended_by"),
+          amended_at: row.get::<String, _>("amended_at"),
+        })
+        .collect(),
+    )
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT e.id AS attendance_id, e.status AS status, e.requester_id AS requester_id
+      FROM exams e
+      JOIN patients p ON p.id = e.patient_id
+      WHERE e.id = ?1 AND e.deleted_at IS NULL AND p.deleted_at IS NULL
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_optional(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?
+    .ok_or(PatientRepositoryError::NotFound)?;
+
+    let item_rows = sqlx::query(
+      r#"
+      SELECT
+        i.id,
+        i.catalog_item_id,
+        i.panel_id,
+        (i.result_value IS NOT NULL OR i.result_flag IS NOT NULL) AS has_result,
+        EXISTS (
+          SELECT 1 FROM critical_alerts a
+          WHERE a.exam_item_id = i.id AND a.acknowledged_at IS NULL
+        ) AS has_pending_critical_alert
+      FROM exam_items i
+      WHERE i.exam_id = ?1
+      ORDER BY i.created_at ASC, i.rowid ASC
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(AttendanceEditContext {
+      attendance_id: row.get::<String, _>("attendance_id"),
+      status: row.get::<String, _>("status"),
+      requester_id: row.get::<Option<String>, _>("requester_id"),
+      items: item_rows
+        .iter()
+        .map(|item_row| AttendanceEditItemContext {
+          exam_item_id: item_row.get::<String, _>("id"),
+          catalog_item_id: item_row.get::<Option<String>, _>("catalog_item_id"),
+          panel_id: item_row.get::<Option<String>, _>("panel_id"),
+          has_result: item_row.get::<bool, _>("has_result"),
+          has_pending_critical_alert: item_row.get::<bool, _>("has_pending_critical_alert"),
+        })
+        .collect(),
+    })
+  }
+
+  async fn update_attendance(
+    &self,
+    update: AttendanceUpdate,
+    performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_attendance_snapshot(&mut tx, &update.attendance_id).await?;
+
     let updated = sqlx::query(
       r#"
-      UPDATE exams
-      SET status = 'completed', updated_at = datetime('now')
+      UPDATE exams
+      SET requester_id = ?3, procedure_type = ?4, delivered_to = ?5, notes = ?6, updated_at = datetime('now')
+      WHERE id = ?1 AND status = ?2 AND deleted_at IS NULL
+      "#,
+    )
+    .bind(&update.attendance_id)
+    .bind(&update.expected_status)
+    .bind(normalize_text(update.requester_id).as_deref())
+    .bind(normalize_text(update.procedure_type).as_deref())
+    .bind(normalize_text(update.delivered_to).as_deref())
+    .bind(normalize_text(update.notes).as_deref())
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    if updated.rows_affected() == 0 {
+      return Err(PatientRepositoryError::Conflict);
+    }
+
+    if !update.add_items.is_empty() || !update.remove_item_ids.is_empty() {
+      // Results may have been typed since the use case looked at the attendance.
+      let with_result = sqlx::query(
+        r#"
+        SELECT id
+        FROM exam_items
+        WHERE exam_id = ?1 AND (result_value IS NOT NULL OR result_flag IS NOT NULL)
+        LIMIT 1
+        "#,
+      )
+      .bind(&update.attendance_id)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+      if with_result.is_some() {
+        return Err(PatientRepositoryError::Conflict);
+      }
+
+      for exam_item_id in &update.remove_item_ids {
+        let removed = sqlx::query("DELETE FROM exam_items WHERE id = ?1 AND exam_id = ?2")
+          .bind(exam_item_id)
+          .bind(&update.attendance_id)
+          .execute(&mut *tx)
+          .await
+          .map_err(map_sqlx_error)?;
+        if removed.rows_affected() == 0 {
+          return Err(PatientRepositoryError::NotFound);
+        }
+      }
+      for item in update.add_items {
+        insert_exam_items(&mut tx, &update.attendance_id, item).await?;
+      }
+    }
+
+    let after = fetch_attendance_snapshot(&mut tx, &update.attendance_id).await?;
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exams",
+        entity_id: &update.attendance_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&after),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_record_entry_by_id(&update.attendance_id).await
+  }
+}
+
+#[async_trait]
+impl CriticalAlertRepository for PatientsSqliteRepository {
+  async fn list_pending_critical_alerts(
+    &self,
+  ) -> Result<Vec<CriticalAlertView>, PatientRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT
+        a.id, a.exam_id, a.exam_item_id, e.patient_id, p.full_name AS patient_name,
+        i.name AS exam_name, a.result_value, a.result_flag, i.unit, r.name AS requester_name,
+        a.detected_at, a.detected_by, a.acknowledged_at, a.acknowledged_by, a.contact_name,
+        a.contact_method, a.note
+      FROM critical_alerts a
+      JOIN exams e ON e.id = a.exam_id
+      JOIN patients p ON p.id = e.patient_id
+      JOIN exam_items i ON i.id = a.exam_item_id
+      LEFT JOIN requesters r ON r.id = e.requester_id
+      WHERE a.acknowledged_at IS NULL AND e.deleted_at IS NULL AND p.deleted_at IS NULL
+      ORDER BY a.detected_at ASC, a.rowid ASC
+      "#,
+    )
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(rows.iter().map(map_critical_alert_row).collect())
+  }
+
+  async fn acknowledge_critical_alert(
+    &self,
+    input: AcknowledgeCriticalAlertInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<CriticalAlertView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let alert_id = input.alert_id.trim();
+    let contact_name = input.contact_name.trim();
+    let note = normalize_text(input.note);
+    // Only the first acknowledgement counts; the log is never rewritten.
+    let updated = sqlx::query(
+      r#"
+      UPDATE critical_alerts
+      SET acknowledged_at = datetime('now'), acknowledged_by = ?2, contact_name = ?3,
+          contact_method = ?4, note = ?5
+      WHERE id = ?1 AND acknowledged_at IS NULL
+      "#,
+    )
+    .bind(alert_id)
+    .bind(performed_by_user_id.as_deref())
+    .bind(contact_name)
+    .bind(&input.contact_method)
+    .bind(note.as_deref())
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+    if updated.rows_affected() == 0 {
+      let exists = sqlx::query("SELECT id FROM critical_alerts WHERE id = ?1")
+        .bind(alert_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+      return Err(if exists.is_some() {
+        PatientRepositoryError::Conflict
+      } else {
+        PatientRepositoryError::NotFound
+      });
+    }
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "critical_alerts",
+        entity_id: alert_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({ "acknowledged_at": null })),
+        after_json: snapshot(&json!({
+          "contact_name": contact_name,
+          "contact_method": input.contact_method,
+          "note": note,
+        })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    self.get_critical_alert_by_id(alert_id).await
+  }
+}
+
+#[async_trait]
+impl ExamCatalogRepository for PatientsSqliteRepository {
+  async fn list_exam_categories(&self) -> Result<Vec<ExamCategoryView>, PatientRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT id, title
+      FROM exam_categories
+      ORDER BY title ASC
+      "#,
+    )
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ExamCategoryView {
+          id: row.get::<String, _>("id"),
+          title: row.get::<String, _>("title"),
+        })
+        .collect(),
+    )
+  }
+
+  async fn create_exam_category(
+    &self,
+    input: CreateExamCategoryInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCategoryView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let row = sqlx::query(
+      r#"
+      INSERT INTO exam_categories (id, title, created_at, updated_at)
+      VALUES (?1, ?2, datetime('now'), datetime('now'))
+      RETURNING id, title
+      "#,
+    )
+    .bind(input.id.trim())
+    .bind(input.title.trim())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let category = ExamCategoryView {
+      id: row.get::<String, _>("id"),
+      title: row.get::<String, _>("title"),
+    };
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_categories",
+        entity_id: &category.id,
+        action: "create",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: None,
+        after_json: snapshot(&category),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(category)
+  }
+
+  async fn create_exam_catalog_item(
+    &self,
+    input: CreateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.ensure_exam_category_exists(input.category_id.trim()).await?;
+
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let row = sqlx::query(
+      r#"
+      INSERT INTO exam_catalog_items (category_id, name, price_cents, unit, method, reference_range, is_active, created_at, updated_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, TRUE, datetime('now'), datetime('now'))
+      RETURNING id
+      "#,
+    )
+    .bind(input.category_id.trim())
+    .bind(input.name.trim())
+    .bind(input.price_cents)
+    .bind(normalize_text(input.unit).as_deref())
+    .bind(normalize_text(input.method).as_deref())
+    .bind(normalize_text(input.reference_range).as_deref())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let created = fetch_exam_catalog_item(&mut tx, &row.get::<String, _>("id")).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_catalog_items",
+        entity_id: &created.id,
+        action: "create",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: None,
+        after_json: snapshot(&created),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(created)
+  }
+
+  async fn update_exam_catalog_item(
+    &self,
+    input: UpdateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.ensure_exam_category_exists(input.category_id.trim()).await?;
+
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_exam_catalog_item(&mut tx, &input.id).await?;
+
+    sqlx::query(
+      r#"
+      UPDATE exam_catalog_items
+      SET category_id = ?2,
+          name = ?3,
+          price_cents = ?4,
+          unit = ?5,
+          method = ?6,
+          reference_range = ?7,
+          updated_at = datetime('now')
       WHERE id = ?1
       "#,
     )
-    .bind(&input.attendance_id)
-    .execute(&self.pool)
+    .bind(&input.id)
+    .bind(input.category_id.trim())
+    .bind(input.name.trim())
+    .bind(input.price_cents)
+    .bind(normalize_text(input.unit).as_deref())
+    .bind(normalize_text(input.method).as_deref())
+    .bind(normalize_text(input.reference_range).as_deref())
+    .execute(&mut *tx)
     .await
     .map_err(map_sqlx_error)?;
 
-    if updated.rows_affected() == 0 {
-      return Err(PatientRepositoryError::NotFound);
+    let after = fetch_exam_catalog_item(&mut tx, &input.id).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_catalog_items",
+        entity_id: &after.id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&after),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(after)
+  }
+
+  async fn deactivate_exam_catalog_item(
+    &self,
+    input: DeactivateExamCatalogItemInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_exam_catalog_item(&mut tx, &input.id).await?;
+
+    sqlx::query(
+      r#"
+      UPDATE exam_catalog_items
+      SET is_active = FALSE, updated_at = datetime('now')
+      WHERE id = ?1
+      "#,
+    )
+    .bind(&input.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let after = fetch_exam_catalog_item(&mut tx, &input.id).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_catalog_items",
+        entity_id: &after.id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&after),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(after)
+  }
+
+  async fn list_exam_reference_ranges(
+    &self,
+    catalog_item_id: String,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+    self.get_exam_catalog_item_by_id(&catalog_item_id).await?;
+    self.list_reference_ranges_by_catalog_item(&catalog_item_id).await
+  }
+
+  async fn set_exam_reference_ranges(
+    &self,
+    input: SetExamReferenceRangesInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    fetch_exam_catalog_item(&mut tx, &input.catalog_item_id).await?;
+    let before = fetch_reference_ranges(&mut tx, &input.catalog_item_id).await?;
+
+    sqlx::query("DELETE FROM exam_reference_ranges WHERE catalog_item_id = ?1")
+      .bind(&input.catalog_item_id)
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    for range in &input.ranges {
+      sqlx::query(
+        r#"
+        INSERT INTO exam_reference_ranges (
+          catalog_item_id, sex, age_min_years, age_max_years, low_value, high_value,
+          critical_low, critical_high, unit, expected_text, created_at
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, datetime('now'))
+        "#,
+      )
+      .bind(&input.catalog_item_id)
+      .bind(normalize_text(range.sex.clone()).as_deref())
+      .bind(range.age_min_years)
+      .bind(range.age_max_years)
+      .bind(range.low)
+      .bind(range.high)
+      .bind(range.critical_low)
+      .bind(range.critical_high)
+      .bind(normalize_text(range.unit.clone()).as_deref())
+      .bind(normalize_text(range.expected_text.clone()).as_deref())
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+
+    let after = fetch_reference_ranges(&mut tx, &input.catalog_item_id).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_reference_ranges",
+        entity_id: &input.catalog_item_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&before),
+        after_json: snapshot(&after),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(after)
+  }
+
+  async fn get_exam_delta_check(
+    &self,
+    catalog_item_id: String,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError> {
+    let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+    fetch_delta_check_rule(&mut conn, &catalog_item_id).await
+  }
+
+  async fn set_exam_delta_check(
+    &self,
+    input: SetExamDeltaCheckInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_delta_check_rule(&mut tx, &input.catalog_item_id).await?;
+
+    sqlx::query(
+      r#"
+      UPDATE exam_catalog_items
+      SET delta_check_absolute = ?2, delta_check_percent = ?3, updated_at = datetime('now')
+      WHERE id = ?1
+      "#,
+    )
+    .bind(&input.catalog_item_id)
+    .bind(input.rule.absolute_threshold)
+    .bind(input.rule.percent_threshold)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let after = fetch_delta_check_rule(&mut tx, &input.catalog_item_id).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_catalog_items",
+        entity_id: &input.catalog_item_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({ "delta_check": before })),
+        after_json: snapshot(&json!({ "delta_check": after })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(after)
+  }
+
+  async fn set_exam_panel_analytes(
+    &self,
+    input: SetExamPanelAnalytesInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_exam_catalog_item(&mut tx, &input.panel_id).await?;
+    if !input.analyte_ids.is_empty() {
+      let nested =
+        sqlx::query("SELECT panel_id FROM exam_panel_analytes WHERE analyte_id = ?1 LIMIT 1")
+          .bind(&input.panel_id)
+          .fetch_optional(&mut *tx)
+          .await
+          .map_err(map_sqlx_error)?;
+      if nested.is_some() {
+        return Err(PatientRepositoryError::Conflict);
+      }
+    }
+    for analyte_id in &input.analyte_ids {
+      let analyte = sqlx::query(
+        r#"
+        SELECT c.is_active, EXISTS (
+          SELECT 1 FROM exam_panel_analytes pa WHERE pa.panel_id = c.id
+        ) AS is_panel
+        FROM exam_catalog_items c
+        WHERE c.id = ?1
+        "#,
+      )
+      .bind(analyte_id)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(PatientRepositoryError::NotFound)?;
+      if !analyte.get::<bool, _>("is_active") {
+        return Err(PatientRepositoryError::NotFound);
+      }
+      if analyte.get::<bool, _>("is_panel") {
+        return Err(PatientRepositoryError::Conflict);
+      }
+    }
+
+    sqlx::query("DELETE FROM exam_panel_analytes WHERE panel_id = ?1")
+      .bind(&input.panel_id)
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    for (position, analyte_id) in input.analyte_ids.iter().enumerate() {
+      sqlx::query(
+        "INSERT INTO exam_panel_analytes (panel_id, analyte_id, position) VALUES (?1, ?2, ?3)",
+      )
+      .bind(&input.panel_id)
+      .bind(analyte_id)
+      .bind(position as i64)
+      .execute(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+    }
+
+    let after = fetch_exam_catalog_item(&mut tx, &input.panel_id).await?;
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "exam_catalog_items",
+        entity_id: &input.panel_id,
+        action: "update",
+        performed_by_user_id: performed_by_user_id.as_deref(),
+        before_json: snapshot(&json!({ "analyte_ids": before.analyte_ids })),
+        after_json: snapshot(&json!({ "analyte_ids": after.analyte_ids })),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(after)
+  }
+}
+
+/// Loads one attendance with its items as shown in the patient record.
+pub(crate) async fn fetch_record_entry(
+  pool: &SqlitePool,
+  exam_id: &str,
+) -> Result<Option<PatientRecordEntryView>, sqlx::Error> {
+  let rows = sqlx::query(
+    r#"
+    SELECT
+      e.id AS exam_id,
+      e.exam_date AS exam_date,
+      e.status AS status,
+      e.deleted_at AS deleted_at,
+      r.name AS requester_name,
+      ei.id AS exam_item_id,
+      ei.name AS item_name,
+      ei.unit AS unit,
+      ei.method AS method,
+      ei.reference_range AS reference_range,
+      ei.result_value AS result_value,
+      ei.result_flag AS result_flag,
+      ei.result_version AS result_version,
+      ei.released_at AS released_at,
+      ei.panel_id AS panel_id,
+      ei.panel_name AS panel_name
+    FROM exams e
+    LEFT JOIN requesters r ON r.id = e.requester_id
+    LEFT JOIN exam_items ei ON ei.exam_id = e.id
+    WHERE e.id = ?1
+    ORDER BY ei.created_at ASC, ei.rowid ASC
+    "#,
+  )
+  .bind(exam_id)
+  .fetch_all(pool)
+  .await?;
+
+  let Some(first) = rows.first() else {
+    return Ok(None);
+  };
+
+  let mut entry = PatientRecordEntryView {
+    exam_id: first.get::<String, _>("exam_id"),
+    exam_date: first.get::<String, _>("exam_date"),
+    status: first.get::<String, _>("status"),
+    requester_name: first.get::<Option<String>, _>("requester_name"),
+    items: Vec::new(),
+    deleted_at: first.get::<Option<String>, _>("deleted_at"),
+  };
+
+  for row in &rows {
+    if let Ok(exam_item_id) = row.try_get::<String, _>("exam_item_id") {
+      entry.items.push(PatientRecordExamItemView {
+        exam_item_id,
+        name: row.get::<String, _>("item_name"),
+        unit: row.get::<Option<String>, _>("unit"),
+        method: row.get::<Option<String>, _>("method"),
+        reference_range: row.get::<Option<String>, _>("reference_range"),
+        result_value: row.get::<Option<String>, _>("result_value"),
+        result_flag: row.get::<Option<String>, _>("result_flag"),
+        report_available: row.get::<Option<String>, _>("released_at").is_some(),
+        result_version: row.get::<i64, _>("result_version"),
+        panel_id: row.get::<Option<String>, _>("panel_id"),
+        panel_name: row.get::<Option<String>, _>("panel_name"),
+      });
     }
+  }
+
+  Ok(Some(entry))
+}
+
+async fn fetch_exam_catalog_item(
+  conn: &mut SqliteConnection,
+  catalog_item_id: &str,
+) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+  let row = sqlx::query(
+    r#"
+    SELECT
+      c.id AS id,
+      c.name AS name,
+      c.category_id AS category_id,
+      cat.title AS category_title,
+      c.price_cents AS price_cents,
+      c.unit AS unit,
+      c.method AS method,
+      c.reference_range AS reference_range,
+      c.is_active AS is_active
+    FROM exam_catalog_items c
+    JOIN exam_categories cat ON cat.id = c.category_id
+    WHERE c.id = ?1
+    "#,
+  )
+  .bind(catalog_item_id)
+  .fetch_one(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  let mut analytes = fetch_panel_analyte_ids(conn, Some(catalog_item_id)).await?;
+  Ok(ExamCatalogItemView {
+    analyte_ids: analytes.remove(catalog_item_id).unwrap_or_default(),
+    ..map_exam_catalog_row(&row)
+  })
+}
+
+async fn fetch_reference_ranges(
+  conn: &mut SqliteConnection,
+  catalog_item_id: &str,
+) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+  let rows = sqlx::query(
+    r#"
+    SELECT sex, age_min_years, age_max_years, low_value, high_value, critical_low, critical_high, unit, expected_text
+    FROM exam_reference_ranges
+    WHERE catalog_item_id = ?1
+    ORDER BY rowid ASC
+    "#,
+  )
+  .bind(catalog_item_id)
+  .fetch_all(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(rows.iter().map(map_reference_range_row).collect())
+}
+
+async fn fetch_delta_check_rule(
+  conn: &mut SqliteConnection,
+  catalog_item_id: &str,
+) -> Result<DeltaCheckRule, PatientRepositoryError> {
+  let row = sqlx::query(
+    "SELECT delta_check_absolute, delta_check_percent FROM exam_catalog_items WHERE id = ?1",
+  )
+  .bind(catalog_item_id)
+  .fetch_one(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(DeltaCheckRule {
+    absolute_threshold: row.get::<Option<f64>, _>("delta_check_absolute"),
+    percent_threshold: row.get::<Option<f64>, _>("delta_check_percent"),
+  })
+}
+
+/// Most recent released value of the catalog exam in another attendance of the patient dated
+/// up to `exam_date`.
+async fn fetch_previous_result(
+  conn: &mut SqliteConnection,
+  patient_id: &str,
+  catalog_item_id: &str,
+  attendance_id: &str,
+  exam_date: &str,
+) -> Result<Option<PreviousResult>, PatientRepositoryError> {
+  let row = sqlx::query(
+    r#"
+    SELECT e.id AS attendance_id, e.exam_date AS exam_date, ei.result_value AS result_value
+    FROM exam_items ei
+    JOIN exams e ON e.id = ei.exam_id
+    WHERE e.patient_id = ?1
+      AND ei.catalog_item_id = ?2
+      AND e.id <> ?3
+      AND e.exam_date <= ?4
+      AND e.deleted_at IS NULL
+      AND ei.released_at IS NOT NULL
+      AND ei.result_value IS NOT NULL
+    ORDER BY e.exam_date DESC, ei.released_at DESC
+    LIMIT 1
+    "#,
+  )
+  .bind(patient_id)
+  .bind(catalog_item_id)
+  .bind(attendance_id)
+  .bind(exam_date)
+  .fetch_optional(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(row.map(|row| PreviousResult {
+    attendance_id: row.get::<String, _>("attendance_id"),
+    exam_date: row.get::<String, _>("exam_date"),
+    result_value: row.get::<String, _>("result_value"),
+  }))
+}
+
+/// Inserts the exam items of one requested exam: a single item, or one per active analyte when
+/// the catalog entry is a panel, each carrying the panel for grouping.
+async fn insert_exam_items(
+  conn: &mut SqliteConnection,
+  exam_id: &str,
+  item: CreateAttendanceItemInput,
+) -> Result<Vec<PatientRecordExamItemView>, PatientRepositoryError> {
+  let Some(catalog_item_id) = normalize_text(item.catalog_item_id.clone()) else {
+    return Ok(vec![insert_exam_item(conn, exam_id, item, None).await?]);
+  };
+  let analyte_rows = sqlx::query(
+    r#"
+    SELECT pa.analyte_id, panel.name AS panel_name
+    FROM exam_panel_analytes pa
+    JOIN exam_catalog_items panel ON panel.id = pa.panel_id
+    JOIN exam_catalog_items a ON a.id = pa.analyte_id
+    WHERE pa.panel_id = ?1 AND panel.is_active = 1 AND a.is_active = 1
+    ORDER BY pa.position ASC
+    "#,
+  )
+  .bind(&catalog_item_id)
+  .fetch_all(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+  if analyte_rows.is_empty() {
+    return Ok(vec![insert_exam_item(conn, exam_id, item, None).await?]);
+  }
+
+  let mut items = Vec::with_capacity(analyte_rows.len());
+  for analyte_row in &analyte_rows {
+    let analyte = CreateAttendanceItemInput {
+      catalog_item_id: Some(analyte_row.get::<String, _>("analyte_id")),
+      name: String::new(),
+      unit: None,
+      method: None,
+      reference_range: None,
+    };
+    let panel_name = analyte_row.get::<String, _>("panel_name");
+    let panel = Some((catalog_item_id.as_str(), panel_name.as_str()));
+    items.push(insert_exam_item(conn, exam_id, analyte, panel).await?);
+  }
+  Ok(items)
+}
+
+/// Inserts one item of an attendance; a catalog item fills the name and any blank unit, method
+/// or reference range.
+async fn insert_exam_item(
+  conn: &mut SqliteConnection,
+  exam_id: &str,
+  item: CreateAttendanceItemInput,
+  panel: Option<(&str, &str)>,
+) -> Result<PatientRecordExamItemView, PatientRepositoryError> {
+  let catalog_item_id = normalize_text(item.catalog_item_id);
+  let mut name = item.name.trim().to_string();
+  let mut unit = normalize_text(item.unit);
+  let mut method = normalize_text(item.method);
+  let mut reference_range = normalize_text(item.reference_range);
+
+  if let Some(catalog_item_id_value) = catalog_item_id.as_deref() {
+    let catalog_row = sqlx::query(
+      r#"
+      SELECT name, unit, method, reference_range
+      FROM exam_catalog_items
+      WHERE id = ?1 AND is_active = 1
+      "#,
+    )
+    .bind(catalog_item_id_value)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    name = catalog_row.get::<String, _>("name");
+    unit = unit.or(catalog_row.get::<Option<String>, _>("unit"));
+    method = method.or(catalog_row.get::<Option<String>, _>("method"));
+    reference_range = reference_range.or(catalog_row.get::<Option<String>, _>("reference_range"));
+  }
+
+  let item_row = sqlx::query(
+    r#"
+    INSERT INTO exam_items (exam_id, catalog_item_id, name, unit, method, reference_range, result_value, result_flag, panel_id, panel_name, created_at, updated_at)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, NULL, ?7, ?8, datetime('now'), datetime('now'))
+    RETURNING id, name, unit, method, reference_range, result_value, result_flag, panel_id, panel_name
+    "#,
+  )
+  .bind(exam_id)
+  .bind(catalog_item_id.as_deref())
+  .bind(name)
+  .bind(unit.as_deref())
+  .bind(method.as_deref())
+  .bind(reference_range.as_deref())
+  .bind(panel.map(|(panel_id, _)| panel_id))
+  .bind(panel.map(|(_, panel_name)| panel_name))
+  .fetch_one(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(PatientRecordExamItemView {
+    exam_item_id: item_row.get::<String, _>("id"),
+    name: item_row.get::<String, _>("name"),
+    unit: item_row.get::<Option<String>, _>("unit"),
+    method: item_row.get::<Option<String>, _>("method"),
+    reference_range: item_row.get::<Option<String>, _>("reference_range"),
+    result_value: item_row.get::<Option<String>, _>("result_value"),
+    result_flag: item_row.get::<Option<String>, _>("result_flag"),
+    report_available: false,
+    result_version: 1,
+    panel_id: item_row.get::<Option<String>, _>("panel_id"),
+    panel_name: item_row.get::<Option<String>, _>("panel_name"),
+  })
+}
+
+/// Active analyte ids of every panel (or of `panel_id` only), keyed by panel, in report order.
+async fn fetch_panel_analyte_ids(
+  conn: &mut SqliteConnection,
+  panel_id: Option<&str>,
+) -> Result<HashMap<String, Vec<String>>, PatientRepositoryError> {
+  let rows = sqlx::query(
+    r#"
+    SELECT pa.panel_id, pa.analyte_id
+    FROM exam_panel_analytes pa
+    JOIN exam_catalog_items a ON a.id = pa.analyte_id
+    WHERE (?1 IS NULL OR pa.panel_id = ?1) AND a.is_active = 1
+    ORDER BY pa.panel_id ASC, pa.position ASC
+    "#,
+  )
+  .bind(panel_id)
+  .fetch_all(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  let mut analytes: HashMap<String, Vec<String>> = HashMap::new();
+  for row in &rows {
+    analytes
+      .entry(row.get::<String, _>("panel_id"))
+      .or_default()
+      .push(row.get::<String, _>("analyte_id"));
+  }
+  Ok(analytes)
+}
+
+/// Editable fields and items of an attendance, as recorded in the audit log by `update_attendance`.
+async fn fetch_attendance_snapshot(
+  conn: &mut SqliteConnection,
+  attendance_id: &str,
+) -> Result<serde_json::Value, PatientRepositoryError> {
+  let row = sqlx::query(
+    r#"
+    SELECT requester_id, procedure_type, delivered_to, notes
+    FROM exams
+    WHERE id = ?1 AND deleted_at IS NULL
+    "#,
+  )
+  .bind(attendance_id)
+  .fetch_optional(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?
+  .ok_or(PatientRepositoryError::NotFound)?;
+
+  let item_rows = sqlx::query(
+    r#"
+    SELECT id, name
+    FROM exam_items
+    WHERE exam_id = ?1
+    ORDER BY created_at ASC
+    "#,
+  )
+  .bind(attendance_id)
+  .fetch_all(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  let items: Vec<serde_json::Value> = item_rows
+    .iter()
+    .map(|item_row| {
+      json!({
+        "exam_item_id": item_row.get::<String, _>("id"),
+        "name": item_row.get::<String, _>("name"),
+      })
+    })
+    .collect();
+
+  Ok(json!({
+    "requester_id": row.get::<Option<String>, _>("requester_id"),
+    "procedure_type": row.get::<Option<String>, _>("procedure_type"),
+    "delivered_to": row.get::<Option<String>, _>("delivered_to"),
+    "notes": row.get::<Option<String>, _>("notes"),
+    "items": items,
+  }))
+}
+
+/// Keeps the item's pending critical alert in line with the value just stored: a pending alert of a
+/// replaced value is dropped, and a LL/HH flag opens one unless the value was saved again
+/// `unchanged` (already notified) or is still pending. A critical value that comes back after
+/// an acknowledged one was replaced is a new finding and alerts again.
+async fn sync_critical_alert(
+  conn: &mut SqliteConnection,
+  exam_id: &str,
+  exam_item_id: &str,
+  result_value: &str,
+  result_flag: Option<&str>,
+  unchanged: bool,
+  detected_by_user_id: Option<&str>,
+) -> Result<(), PatientRepositoryError> {
+  let critical = result_flag
+    .and_then(ResultFlag::parse)
+    .is_some_and(|flag| flag.is_critical());
+  sqlx::query(
+    r#"
+    DELETE FROM critical_alerts
+    WHERE exam_item_id = ?1 AND acknowledged_at IS NULL
+      AND NOT (?4 AND result_value = ?2 AND result_flag = ?3)
+    "#,
+  )
+  .bind(exam_item_id)
+  .bind(result_value)
+  .bind(result_flag)
+  .bind(critical)
+  .execute(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+  if !critical || unchanged {
+    return Ok(());
+  }
+
+  sqlx::query(
+    r#"
+    INSERT INTO critical_alerts (exam_id, exam_item_id, result_value, result_flag, detected_at, detected_by)
+    SELECT ?1, ?2, ?3, ?4, datetime('now'), ?5
+    WHERE NOT EXISTS (
+      SELECT 1 FROM critical_alerts
+      WHERE exam_item_id = ?2 AND result_value = ?3 AND result_flag = ?4
+        AND acknowledged_at IS NULL
+    )
+    "#,
+  )
+  .bind(exam_id)
+  .bind(exam_item_id)
+  .bind(result_value)
+  .bind(result_flag)
+  .bind(detected_by_user_id)
+  .execute(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(())
+}
+
+async fn insert_status_history(
+  conn: &mut SqliteConnection,
+  exam_id: &str,
+  from_status: Option<&str>,
+  to_status: &str,
+  changed_by_user_id: Option<&str>,
+  note: Option<&str>,
+) -> Result<(), PatientRepositoryError> {
+  sqlx::query(
+    r#"
+    INSERT INTO attendance_status_history (exam_id, from_status, to_status, changed_by_user_id, changed_at, note)
+    VALUES (?1, ?2, ?3, ?4, datetime('now'), ?5)
+    "#,
+  )
+  .bind(exam_id)
+  .bind(from_status)
+  .bind(to_status)
+  .bind(changed_by_user_id)
+  .bind(note)
+  .execute(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(())
+}
+
+fn map_critical_alert_row(row: &SqliteRow) -> CriticalAlertView {
+  CriticalAlertView {
+    id: row.get::<String, _>("id"),
+    attendance_id: row.get::<String, _>("exam_id"),
+    exam_item_id: row.get::<String, _>("exam_item_id"),
+    patient_id: row.get::<String, _>("patient_id"),
+    patient_name: row.get::<String, _>("patient_name"),
+    exam_name: row.get::<String, _>("exam_name"),
+    result_value: row.get::<String, _>("result_value"),
+    result_flag: row.get::<String, _>("result_flag"),
+    unit: row.get::<Option<String>, _>("unit"),
+    requester_name: row.get::<Option<String>, _>("requester_name"),
+    detected_at: row.get::<String, _>("detected_at"),
+    detected_by_user_id: row.get::<Option<String>, _>("detected_by"),
+    acknowledged_at: row.get::<Option<String>, _>("acknowledged_at"),
+    acknowledged_by_user_id: row.get::<Option<String>, _>("acknowledged_by"),
+    contact_name: row.get::<Option<String>, _>("contact_name"),
+    contact_method: row.get::<Option<String>, _>("contact_method"),
+    note: row.get::<Option<String>, _>("note"),
+  }
+}
 
-    self.get_attendance_by_id(&input.attendance_id).await
+fn map_exam_catalog_row(row: &SqliteRow) -> ExamCatalogItemView {
+  ExamCatalogItemView {
+    id: row.get::<String, _>("id"),
+    name: row.get::<String, _>("name"),
+    category_id: row.get::<String, _>("category_id"),
+    category_title: row.get::<String, _>("category_title"),
+    price_cents: row.get::<i64, _>("price_cents"),
+    unit: row.get::<Option<String>, _>("unit"),
+    method: row.get::<Option<String>, _>("method"),
+    reference_range: row.get::<Option<String>, _>("reference_range"),
+    is_active: row.get::<bool, _>("is_active"),
+    analyte_ids: Vec::new(),
+  }
+}
+
+fn map_reference_range_row(row: &SqliteRow) -> ReferenceRange {
+  ReferenceRange {
+    sex: row.get::<Option<String>, _>("sex"),
+    age_min_years: row.get::<Option<i64>, _>("age_min_years"),
+    age_max_years: row.get::<Option<i64>, _>("age_max_years"),
+    low: row.get::<Option<f64>, _>("low_value"),
+    high: row.get::<Option<f64>, _>("high_value"),
+    critical_low: row.get::<Option<f64>, _>("critical_low"),
+    critical_high: row.get::<Option<f64>, _>("critical_high"),
+    unit: row.get::<Option<String>, _>("unit"),
+    expected_text: row.get::<Option<String>, _>("expected_text"),
   }
 }
 
@@ -537,6 +2757,148 @@ fn normalize_text(value: Option<String>) -> Option<String> {
   })
 }
 
+async fn fetch_active_patient(
+  conn: &mut SqliteConnection,
+  patient_id: &str,
+) -> Result<Patient, PatientRepositoryError> {
+  let row = sqlx::query(
+    r#"
+    SELECT id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at, deleted_at
+    FROM patients
+    WHERE id = ?1 AND merged_into_patient_id IS NULL AND deleted_at IS NULL
+    "#,
+  )
+  .bind(patient_id)
+  .fetch_one(conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(map_patient_row(&row))
+}
+
+fn map_patient_row(row: &SqliteRow) -> Patient {
+  Patient {
+    id: row.get::<String, _>("id"),
+    full_name: row.get::<String, _>("full_name"),
+    cpf: row.get::<String, _>("cpf"),
+    birth_date: row.get::<String, _>("birth_date"),
+    sex: row.get::<String, _>("sex"),
+    phone: row.get::<String, _>("phone"),
+    address: row.get::<String, _>("address"),
+    created_at: row.get::<String, _>("created_at"),
+    updated_at: row.get::<String, _>("updated_at"),
+    deleted_at: row.get::<Option<String>, _>("deleted_at"),
+  }
+}
+
+/// `WHERE` clause shared by the count and the page query of `list`. Text search goes through
+/// `patients_fts`: every term must prefix-match a word of the name, cpf, phone or legacy code.
+/// A number also matches anywhere inside the (digits only) cpf and phone, so the last digits
+/// of either still find the patient.
+fn push_patient_filters(qb: &mut QueryBuilder<'_, Sqlite>, query: &PatientPageQuery) {
+  qb.push(" WHERE merged_into_patient_id IS NULL");
+  if !query.include_deleted {
+    qb.push(" AND deleted_at IS NULL");
+  }
+  if let Some(text) = &query.query {
+    qb.push(" AND id IN (SELECT patient_id FROM patients_fts WHERE patients_fts MATCH ");
+    qb.push_bind(fts_terms(text).join(" "));
+    if let Some(digits) = masked_number_digits(text) {
+      let like = format!("%{digits}%");
+      qb.push(" UNION SELECT patient_id FROM patients_fts WHERE cpf LIKE ");
+      qb.push_bind(like.clone());
+      qb.push(" OR phone LIKE ");
+      qb.push_bind(like);
+    }
+    qb.push(")");
+  }
+}
+
+/// Relevance tier of a search hit, best first: 0 when the name starts with the first term and
+/// holds every term, 1 when it only starts with the first term, 2 when it only holds every term,
+/// 3 when some term matched cpf, phone or legacy code instead.
+fn push_search_tier(qb: &mut QueryBuilder<'_, Sqlite>, text: &str) {
+  let terms = fts_terms(text);
+  let first = terms.first().cloned().unwrap_or_default();
+  qb.push("(CASE WHEN id IN (SELECT patient_id FROM patients_fts WHERE patients_fts MATCH ");
+  qb.push_bind(format!("full_name : ^ {first}"));
+  qb.push(") THEN 0 ELSE 2 END + CASE WHEN id IN (SELECT patient_id FROM patients_fts WHERE patients_fts MATCH ");
+  qb.push_bind(format!("full_name : ({})", terms.join(" ")));
+  qb.push(") THEN 0 ELSE 1 END)");
+}
+
+/// Search terms as FTS5 prefix queries (`"mar"*`). The tokenizer folds case and accents of the
+/// terms itself. A query made only of digits and mask punctuation (CPF, phone) is a single term,
+/// so `529.982` matches `52998224725`.
+fn fts_terms(text: &str) -> Vec<String> {
+  let terms: Vec<String> = match masked_number_digits(text) {
+    Some(digits) => vec![digits],
+    None => text
+      .split(|c: char| !c.is_alphanumeric())
+      .filter(|term| !term.is_empty())
+      .map(str::to_string)
+      .collect(),
+  };
+  terms.iter().map(|term| format!("\"{term}\"*")).collect()
+}
+
+/// Digits of a query made only of digits and mask punctuation (CPF, phone); `None` otherwise.
+fn masked_number_digits(text: &str) -> Option<String> {
+  let is_masked_number = text.chars().any(|c| c.is_ascii_digit())
+    && text
+      .chars()
+      .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ' ' | '(' | ')' | '+' | '/'));
+  is_masked_number.then(|| text.chars().filter(char::is_ascii_digit).collect())
+}
+
+/// `WHERE` clause shared by the count and the page query of `list_attendance_queue`. Item names
+/// are matched through `EXISTS` so an attendance counts once however many items match.
+fn push_queue_filters(qb: &mut QueryBuilder<'_, Sqlite>, query: &AttendanceQueuePageQuery) {
+  qb.push(" WHERE 1 = 1");
+  if !query.include_deleted {
+    qb.push(" AND e.deleted_at IS NULL AND p.deleted_at IS NULL");
+  }
+  if !query.include_cancelled {
+    qb.push(" AND e.status <> 'cancelled'");
+  }
+  if let Some(date) = &query.date {
+    qb.push(" AND e.exam_date = ");
+    qb.push_bind(date.clone());
+  }
+  if let Some(status) = &query.status {
+    qb.push(" AND e.status = ");
+    qb.push_bind(status.clone());
+  }
+  if let Some(text) = &query.query {
+    let like = format!("%{}%", text.to_lowercase());
+    qb.push(" AND (lower(p.full_name) LIKE ");
+    qb.push_bind(like.clone());
+    qb.push(" OR p.cpf LIKE ");
+    qb.push_bind(cpf_like(text, &like));
+    qb.push(" OR lower(e.id) LIKE ");
+    qb.push_bind(like.clone());
+    qb.push(
+      " OR EXISTS (SELECT 1 FROM exam_items ei WHERE ei.exam_id = e.id AND lower(ei.name) LIKE ",
+    );
+    qb.push_bind(like);
+    qb.push("))");
+  }
+}
+
+/// `patients.cpf` holds digits only, so a masked search (`123.456`) matches on its digits.
+fn cpf_like(query: &str, like: &str) -> String {
+  let is_masked_cpf = query.chars().any(|c| c.is_ascii_digit())
+    && query
+      .chars()
+      .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ' '));
+  if !is_masked_cpf {
+    return like.to_string();
+  }
+
+  let digits: String = query.chars().filter(char::is_ascii_digit).collect();
+  format!("%{digits}%")
+}
+
 fn map_sqlx_error(err: sqlx::Error) -> PatientRepositoryError {
   match err {
     sqlx::Error::RowNotFound => PatientRepositoryError::NotFound,
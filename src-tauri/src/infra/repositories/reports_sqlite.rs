@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+use crate::{
+  domain::{
+    patients::dto::PatientView,
+    reports::{
+      dto::{LabReportSource, PdfReportView, RecordPdfReportInput},
+      errors::ReportRepositoryError,
+      ports::ReportRepository,
+    },
+  },
+  infra::repositories::{
+    audit_sqlite::{record_audit, snapshot, AuditRecord},
+    patients_sqlite::fetch_record_entry,
+  },
+};
+
+pub struct ReportsSqliteRepository {
+  pool: SqlitePool,
+}
+
+impl ReportsSqliteRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl ReportRepository for ReportsSqliteRepository {
+  async fn get_lab_report_source(
+    &self,
+    attendance_id: String,
+  ) -> Result<LabReportSource, ReportRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT
+        p.id AS id,
+        p.full_name AS full_name,
+        p.cpf AS cpf,
+        p.birth_date AS birth_date,
+        p.sex AS sex,
+        p.phone AS phone,
+        p.address AS address,
+        p.created_at AS created_at,
+        p.updated_at AS updated_at,
+        p.deleted_at AS deleted_at,
+        r.report_version AS report_version,
+        EXISTS (
+          SELECT 1 FROM exam_items ei WHERE ei.exam_id = e.id AND ei.result_version > 1
+        ) AS amended
+      FROM exams e
+      JOIN patients p ON p.id = e.patient_id
+      LEFT JOIN pdf_reports r ON r.exam_id = e.id
+      WHERE e.id = ?1 AND e.deleted_at IS NULL
+      "#,
+    )
+    .bind(&attendance_id)
+    .fetch_one(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let entry = fetch_record_entry(&self.pool, &attendance_id)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(ReportRepositoryError::NotFound)?;
+
+    Ok(LabReportSource {
+      patient: PatientView {
+        id: row.get::<String, _>("id"),
+        full_name: row.get::<String, _>("full_name"),
+        cpf: row.get::<String, _>("cpf"),
+        birth_date: row.get::<String, _>("birth_date"),
+        sex: row.get::<String, _>("sex"),
+        phone: row.get::<String, _>("phone"),
+        address: row.get::<String, _>("address"),
+        created_at: row.get::<String, _>("created_at"),
+        updated_at: row.get::<String, _>("updated_at"),
+        deleted_at: row.get::<Option<String>, _>("deleted_at"),
+      },
+      entry,
+      current_report_version: row.get::<Option<i64>, _>("report_version"),
+      amended: row.get::<bool, _>("amended"),
+    })
+  }
+
+  async fn record_pdf_report(
+    &self,
+    input: RecordPdfReportInput,
+  ) -> Result<PdfReportView, ReportRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let (before, row) = if input.report_version == 1 {
+      let row = sqlx::query(
+        r#"
+        INSERT INTO pdf_reports (exam_id, generated_by_user_id, generated_at, report_version, note, is_amended)
+        VALUES (?1, ?2, datetime('now'), 1, ?3, ?4)
+        RETURNING id, exam_id, generated_by_user_id, generated_at, report_version, note, is_amended
+        "#,
+      )
+      .bind(&input.attendance_id)
+      .bind(input.generated_by_user_id.as_deref())
+      .bind(input.note.as_deref())
+      .bind(input.amended)
+      .fetch_one(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?;
+      (None, row)
+    } else {
+      let before = sqlx::query(
+        r#"
+        SELECT id, exam_id, generated_by_user_id, generated_at, report_version, note, is_amended
+        FROM pdf_reports
+        WHERE exam_id = ?1 AND report_version = ?2 - 1
+        "#,
+      )
+      .bind(&input.attendance_id)
+      .bind(input.report_version)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(ReportRepositoryError::Conflict)?;
+
+      let row = sqlx::query(
+        r#"
+        UPDATE pdf_reports
+        SET report_version = ?2, generated_by_user_id = ?3, generated_at = datetime('now'), note = ?4, is_amended = ?5
+        WHERE exam_id = ?1 AND report_version = ?2 - 1
+        RETURNING id, exam_id, generated_by_user_id, generated_at, report_version, note, is_amended
+        "#,
+      )
+      .bind(&input.attendance_id)
+      .bind(input.report_version)
+      .bind(input.generated_by_user_id.as_deref())
+      .bind(input.note.as_deref())
+      .bind(input.amended)
+      .fetch_optional(&mut *tx)
+      .await
+      .map_err(map_sqlx_error)?
+      .ok_or(ReportRepositoryError::Conflict)?;
+      (Some(map_pdf_report_row(&before)), row)
+    };
+
+    let report = map_pdf_report_row(&row);
+
+    record_audit(
+      &mut tx,
+      AuditRecord {
+        entity_name: "pdf_reports",
+        entity_id: &report.id,
+        action: if before.is_some() { "update" } else { "create" },
+        performed_by_user_id: input.generated_by_user_id.as_deref(),
+        before_json: before.as_ref().and_then(snapshot),
+        after_json: snapshot(&report),
+      },
+    )
+    .await
+    .map_err(map_sqlx_error)?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(report)
+  }
+}
+
+fn map_pdf_report_row(row: &SqliteRow) -> PdfReportView {
+  PdfReportView {
+    id: row.get::<String, _>("id"),
+    attendance_id: row.get::<String, _>("exam_id"),
+    report_version: row.get::<i64, _>("report_version"),
+    generated_by_user_id: row.get::<Option<String>, _>("generated_by_user_id"),
+    generated_at: row.get::<String, _>("generated_at"),
+    note: row.get::<Option<String>, _>("note"),
+    is_amended: row.get::<bool, _>("is_amended"),
+  }
+}
+
+fn map_sqlx_error(err: sqlx::Error) -> ReportRepositoryError {
+  match err {
+    sqlx::Error::RowNotFound => ReportRepositoryError::NotFound,
+    sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+      ReportRepositoryError::Conflict
+    }
+    _ => ReportRepositoryError::PersistenceError,
+  }
+}
@@ -0,0 +1,248 @@
+use async_trait::async_trait;
+use sqlx::{sqlite::SqliteRow, Row, SqliteConnection, SqlitePool};
+
+use crate::{
+  domain::requesters::{
+    dto::{NewRequester, RequesterView},
+    errors::RequesterRepositoryError,
+    ports::RequesterRepository,
+  },
+  infra::repositories::audit_sqlite::{record_audit, snapshot, AuditRecord},
+};
+
+pub struct RequestersSqliteRepository {
+  pool: SqlitePool,
+}
+
+impl RequestersSqliteRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl RequesterRepository for RequestersSqliteRepository {
+  async fn list_requesters(
+    &self,
+    include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, RequesterRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT id, name, council_type, council_number, council_state, is_active, created_at, updated_at
+      FROM requesters
+      WHERE ?1 OR is_active = 1
+      ORDER BY name ASC, id ASC
+      "#,
+    )
+    .bind(include_inactive)
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(rows.iter().map(map_requester_row).collect())
+  }
+
+  async fn find_requester(
+    &self,
+    requester_id: String,
+  ) -> Result<Option<RequesterView>, RequesterRepositoryError> {
+    let row = sqlx::query(
+      "SELECT id, name, council_type, council_number, council_state, is_active, created_at, updated_at FROM requesters WHERE id = ?1"
+    )
+    .bind(&requester_id)
+    .fetch_optional(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(row.as_ref().map(map_requester_row))
+  }
+
+  async fn insert_requester(
+    &self,
+    requester: NewRequester,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+    let registry = requester.registry.as_ref();
+
+    let row = sqlx::query(
+      r#"
+      INSERT INTO requesters (
+        name, council_type, council_number, council_state, is_active, created_at, updated_at
+      )
+      VALUES (?1, ?2, ?3, ?4, TRUE, datetime('now'), datetime('now'))
+      RETURNING id, name, council_type, council_number, council_state, is_active, created_at, updated_at
+      "#,
+    )
+    .bind(&requester.name)
+    .bind(registry.map(|r| r.council_type.as_str()))
+    .bind(registry.map(|r| r.number.as_str()))
+    .bind(registry.map(|r| r.state.as_str()))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let created = map_requester_row(&row);
+
+    record_requester_audit(
+      &mut tx,
+      "create",
+      performed_by_user_id.as_deref(),
+      None,
+      &created,
+    )
+    .await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(created)
+  }
+
+  async fn update_requester(
+    &self,
+    requester_id: String,
+    requester: NewRequester,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+    let registry = requester.registry.as_ref();
+
+    let before = fetch_requester(&mut tx, &requester_id).await?;
+
+    let row = sqlx::query(
+      r#"
+      UPDATE requesters
+      SET name = ?2,
+        council_type = ?3,
+        council_number = ?4,
+        council_state = ?5,
+        updated_at = datetime('now')
+      WHERE id = ?1
+      RETURNING id, name, council_type, council_number, council_state, is_active, created_at, updated_at
+      "#,
+    )
+    .bind(&requester_id)
+    .bind(&requester.name)
+    .bind(registry.map(|r| r.council_type.as_str()))
+    .bind(registry.map(|r| r.number.as_str()))
+    .bind(registry.map(|r| r.state.as_str()))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let updated = map_requester_row(&row);
+
+    record_requester_audit(
+      &mut tx,
+      "update",
+      performed_by_user_id.as_deref(),
+      Some(&before),
+      &updated,
+    )
+    .await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(updated)
+  }
+
+  async fn set_requester_active(
+    &self,
+    requester_id: String,
+    is_active: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = fetch_requester(&mut tx, &requester_id).await?;
+
+    let row = sqlx::query(
+      r#"
+      UPDATE requesters
+      SET is_active = ?2, updated_at = datetime('now')
+      WHERE id = ?1
+      RETURNING id, name, council_type, council_number, council_state, is_active, created_at, updated_at
+      "#,
+    )
+    .bind(&requester_id)
+    .bind(is_active)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let updated = map_requester_row(&row);
+
+    record_requester_audit(
+      &mut tx,
+      "update",
+      performed_by_user_id.as_deref(),
+      Some(&before),
+      &updated,
+    )
+    .await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(updated)
+  }
+}
+
+async fn fetch_requester(
+  conn: &mut SqliteConnection,
+  requester_id: &str,
+) -> Result<RequesterView, RequesterRepositoryError> {
+  let row = sqlx::query(
+    "SELECT id, name, council_type, council_number, council_state, is_active, created_at, updated_at FROM requesters WHERE id = ?1"
+  )
+  .bind(requester_id)
+  .fetch_one(&mut *conn)
+  .await
+  .map_err(map_sqlx_error)?;
+
+  Ok(map_requester_row(&row))
+}
+
+async fn record_requester_audit(
+  conn: &mut SqliteConnection,
+  action: &str,
+  performed_by_user_id: Option<&str>,
+  before: Option<&RequesterView>,
+  after: &RequesterView,
+) -> Result<(), RequesterRepositoryError> {
+  record_audit(
+    conn,
+    AuditRecord {
+      entity_name: "requesters",
+      entity_id: &after.id,
+      action,
+      performed_by_user_id,
+      before_json: before.and_then(snapshot),
+      after_json: snapshot(after),
+    },
+  )
+  .await
+  .map_err(map_sqlx_error)
+}
+
+fn map_requester_row(row: &SqliteRow) -> RequesterView {
+  RequesterView {
+    id: row.get::<String, _>("id"),
+    name: row.get::<String, _>("name"),
+    council_type: row.get::<Option<String>, _>("council_type"),
+    council_number: row.get::<Option<String>, _>("council_number"),
+    council_state: row.get::<Option<String>, _>("council_state"),
+    is_active: row.get::<bool, _>("is_active"),
+    created_at: row.get::<String, _>("created_at"),
+    updated_at: row.get::<String, _>("updated_at"),
+  }
+}
+
+fn map_sqlx_error(err: sqlx::Error) -> RequesterRepositoryError {
+  match err {
+    sqlx::Error::RowNotFound => RequesterRepositoryError::NotFound,
+    sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+      RequesterRepositoryError::Conflict
+    }
+    _ => RequesterRepositoryError::PersistenceError,
+  }
+}
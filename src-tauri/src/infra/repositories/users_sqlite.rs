@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use sqlx::{sqlite::SqliteRow, Row, SqliteConnection, SqlitePool};
+
+use crate::{
+  domain::users::{
+    dto::{NewUser, UserCredentials, UserView},
+    errors::UserRepositoryError,
+    ports::UserRepository,
+  },
+  infra::repositories::audit_sqlite::{record_audit, snapshot, AuditRecord},
+};
+
+pub struct UsersSqliteRepository {
+  pool: SqlitePool,
+}
+
+impl UsersSqliteRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl UserRepository for UsersSqliteRepository {
+  async fn find_credentials_by_username(
+    &self,
+    username: String,
+  ) -> Result<Option<UserCredentials>, UserRepositoryError> {
+    let row = sqlx::query(
+      r#"
+      SELECT id, name, cpf, username, role, is_active, password_hash
+      FROM users
+      WHERE username = ?1
+      "#,
+    )
+    .bind(&username)
+    .fetch_optional(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(row.map(|row| UserCredentials {
+      user: map_user_row(&row),
+      password_hash: row.get::<String, _>("password_hash"),
+    }))
+  }
+
+  async fn count_users(&self) -> Result<i64, UserRepositoryError> {
+    let row = sqlx::query("SELECT COUNT(*) AS total FROM users")
+      .fetch_one(&self.pool)
+      .await
+      .map_err(map_sqlx_error)?;
+
+    Ok(row.get::<i64, _>("total"))
+  }
+
+  async fn insert_first_user(&self, user: NewUser) -> Result<UserView, UserRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let row = sqlx::query(
+      r#"
+      INSERT INTO users (name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      SELECT ?1, ?2, ?3, ?4, ?5, TRUE, datetime('now'), datetime('now')
+      WHERE NOT EXISTS (SELECT 1 FROM users)
+      RETURNING id, name, cpf, username, role, is_active
+      "#,
+    )
+    .bind(&user.name)
+    .bind(&user.cpf)
+    .bind(&user.username)
+    .bind(&user.password_hash)
+    .bind(&user.role)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let created = row
+      .map(|row| map_user_row(&row))
+      .ok_or(UserRepositoryError::Conflict)?;
+
+    // The first admin creates itself: nobody else is logged in during setup.
+    record_user_audit(&mut tx, "create", Some(&created.id), None, &created).await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(created)
+  }
+
+  async fn list_users(&self) -> Result<Vec<UserView>, UserRepositoryError> {
+    let rows = sqlx::query(
+      r#"
+      SELECT id, name, cpf, username, role, is_active
+      FROM users
+      ORDER BY name ASC
+      "#,
+    )
+    .fetch_all(&self.pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(rows.iter().map(map_user_row).collect())
+  }
+
+  async fn insert_user(
+    &self,
+    user: NewUser,
+    performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let row = sqlx::query(
+      r#"
+      INSERT INTO users (name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, TRUE, datetime('now'), datetime('now'))
+      RETURNING id, name, cpf, username, role, is_active
+      "#,
+    )
+    .bind(&user.name)
+    .bind(&user.cpf)
+    .bind(&user.username)
+    .bind(&user.password_hash)
+    .bind(&user.role)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let created = map_user_row(&row);
+
+    record_user_audit(
+      &mut tx,
+      "create",
+      performed_by_user_id.as_deref(),
+      None,
+      &created,
+    )
+    .await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(created)
+  }
+
+  async fn set_user_active(
+    &self,
+    user_id: String,
+    is_active: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+    let before = sqlx::query(
+      r#"
+      SELECT id, name, cpf, username, role, is_active
+      FROM users
+      WHERE id = ?1
+      "#,
+    )
+    .bind(&user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let row = sqlx::query(
+      r#"
+      UPDATE users
+      SET is_active = ?2, updated_at = datetime('now')
+      WHERE id = ?1
+      RETURNING id, name, cpf, username, role, is_active
+      "#,
+    )
+    .bind(&user_id)
+    .bind(is_active)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    let updated = map_user_row(&row);
+
+    record_user_audit(
+      &mut tx,
+      "update",
+      performed_by_user_id.as_deref(),
+      Some(&map_user_row(&before)),
+      &updated,
+    )
+    .await?;
+
+    tx.commit().await.map_err(map_sqlx_error)?;
+
+    Ok(updated)
+  }
+}
+
+/// Snapshots use `UserView`, so password hashes never reach `audit_log`.
+async fn record_user_audit(
+  conn: &mut SqliteConnection,
+  action: &str,
+  performed_by_user_id: Option<&str>,
+  before: Option<&UserView>,
+  after: &UserView,
+) -> Result<(), UserRepositoryError> {
+  record_audit(
+    conn,
+    AuditRecord {
+      entity_name: "users",
+      entity_id: &after.id,
+      action,
+      performed_by_user_id,
+      before_json: before.and_then(snapshot),
+      after_json: snapshot(after),
+    },
+  )
+  .await
+  .map_err(map_sqlx_error)
+}
+
+fn map_user_row(row: &SqliteRow) -> UserView {
+  UserView {
+    id: row.get::<String, _>("id"),
+    name: row.get::<String, _>("name"),
+    cpf: row.get::<String, _>("cpf"),
+    username: row.get::<String, _>("username"),
+    role: row.get::<String, _>("role"),
+    is_active: row.get::<bool, _>("is_active"),
+  }
+}
+
+fn map_sqlx_error(err: sqlx::Error) -> UserRepositoryError {
+  match err {
+    sqlx::Error::RowNotFound => UserRepositoryError::NotFound,
+    sqlx::Error::Database(db_err) if db_err.is_unique_violation() => UserRepositoryError::Conflict,
+    _ => UserRepositoryError::PersistenceError,
+  }
+}
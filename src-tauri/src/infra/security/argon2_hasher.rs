@@ -0,0 +1,41 @@
+use argon2::Argon2;
+use password_hash::{
+  rand_core::OsRng, PasswordHash, PasswordHasher as _, PasswordVerifier as _, SaltString,
+};
+
+use crate::domain::users::{errors::PasswordHashError, ports::PasswordHasher};
+
+/// Argon2id with the crate defaults; hashes are stored in PHC string format, so the
+/// parameters travel with each hash and can be raised later without breaking old ones.
+#[derive(Default)]
+pub struct Argon2PasswordHasher {
+  argon2: Argon2<'static>,
+}
+
+impl Argon2PasswordHasher {
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+  fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+    let salt = SaltString::generate(&mut OsRng);
+    self
+      .argon2
+      .hash_password(password.as_bytes(), &salt)
+      .map(|hash| hash.to_string())
+      .map_err(|e| PasswordHashError::HashFailed(e.to_string()))
+  }
+
+  fn verify(&self, password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+      .map(|parsed| {
+        self
+          .argon2
+          .verify_password(password.as_bytes(), &parsed)
+          .is_ok()
+      })
+      .unwrap_or(false)
+  }
+}
@@ -0,0 +1,32 @@
+use tauri::State;
+
+use crate::{
+  app::state::AppState,
+  domain::audit::dto::{AuditChainVerificationView, AuditEntryView, ListAuditLogInput},
+  interface::ipc::error::IpcError,
+};
+
+#[tauri::command]
+pub async fn list_audit_log(
+  state: State<'_, AppState>,
+  input: ListAuditLogInput,
+) -> Result<Vec<AuditEntryView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_audit_log_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn verify_audit_chain(
+  state: State<'_, AppState>,
+) -> Result<AuditChainVerificationView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .verify_audit_chain_use_case
+    .execute(&actor)
+    .await
+    .map_err(IpcError::from)
+}
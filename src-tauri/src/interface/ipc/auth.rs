@@ -0,0 +1,52 @@
+use tauri::State;
+
+use crate::{
+  app::state::AppState,
+  domain::users::dto::{CreateInitialAdminInput, LoginInput, UserView},
+  interface::ipc::error::IpcError,
+};
+
+#[tauri::command]
+pub async fn login(state: State<'_, AppState>, input: LoginInput) -> Result<UserView, IpcError> {
+  let user = state
+    .login_use_case
+    .execute(input)
+    .await
+    .map_err(IpcError::from)?;
+  state.session.start(user.clone());
+  Ok(user)
+}
+
+#[tauri::command]
+pub async fn logout(state: State<'_, AppState>) -> Result<(), IpcError> {
+  state.session.end();
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn current_user(state: State<'_, AppState>) -> Result<Option<UserView>, IpcError> {
+  Ok(state.session.current_user())
+}
+
+#[tauri::command]
+pub async fn needs_initial_setup(state: State<'_, AppState>) -> Result<bool, IpcError> {
+  state
+    .needs_initial_setup_use_case
+    .execute()
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn create_initial_admin(
+  state: State<'_, AppState>,
+  input: CreateInitialAdminInput,
+) -> Result<UserView, IpcError> {
+  let user = state
+    .create_initial_admin_use_case
+    .execute(input)
+    .await
+    .map_err(IpcError::from)?;
+  state.session.start(user.clone());
+  Ok(user)
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::{app::error::AppError, domain::patients::errors::PatientRepositoryError};
+
+/// Stable error category the frontend can switch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpcErrorCode {
+  Validation,
+  NotFound,
+  Conflict,
+  Forbidden,
+  Database,
+  Unexpected,
+}
+
+/// Error returned by every IPC command, serialized as `{ code, message, field }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IpcError {
+  pub code: IpcErrorCode,
+  pub message: String,
+  /// Input field the error refers to, so forms can highlight it.
+  pub field: Option<String>,
+}
+
+impl IpcError {
+  fn new(code: IpcErrorCode, message: String) -> Self {
+    Self {
+      code,
+      message,
+      field: None,
+    }
+  }
+}
+
+impl From<AppError> for IpcError {
+  fn from(err: AppError) -> Self {
+    match err {
+      AppError::Validation { field, message } => Self {
+        field,
+        ..Self::new(IpcErrorCode::Validation, message)
+      },
+      AppError::NotFound(message) => Self::new(IpcErrorCode::NotFound, message),
+      AppError::Conflict(message) => Self::new(IpcErrorCode::Conflict, message),
+      AppError::Forbidden(message) => Self::new(IpcErrorCode::Forbidden, message),
+      AppError::Database(message) => Self::new(IpcErrorCode::Database, message),
+      AppError::Unexpected(message) => Self::new(IpcErrorCode::Unexpected, message),
+    }
+  }
+}
+
+impl From<PatientRepositoryError> for IpcError {
+  fn from(err: PatientRepositoryError) -> Self {
+    match err {
+      PatientRepositoryError::NotFound => {
+        Self::new(IpcErrorCode::NotFound, "record not found".into())
+      }
+      PatientRepositoryError::Conflict => Self::new(
+        IpcErrorCode::Conflict,
+        "record conflicts with existing data".into(),
+      ),
+      PatientRepositoryError::PersistenceError => {
+        Self::new(IpcErrorCode::Database, "failed to access database".into())
+      }
+    }
+  }
+}
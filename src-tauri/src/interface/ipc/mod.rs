@@ -1,2 +1,8 @@
+pub mod audit;
+pub mod auth;
+pub mod error;
 pub mod patient_records;
 pub mod patients;
+pub mod reports;
+pub mod requesters;
+pub mod users;
@@ -2,73 +2,371 @@ use tauri::State;
 
 use crate::{
   app::state::AppState,
-  domain::patients::dto::{
-    AttendanceQueueItemView, AttendanceQueueQueryInput, CompleteAttendanceInput,
-    CreateAttendanceInput, ExamCatalogItemView, PatientRecordEntryView, PatientRecordView,
+  domain::patients::{
+    delta_check::DeltaCheckRule,
+    dto::{
+      AcknowledgeCriticalAlertInput, AmendResultInput, AmendResultView, AttendanceDeletionInput,
+      AttendanceQueueItemView, AttendanceQueueQueryInput, AttendanceStatusHistoryView,
+      CancelAttendanceInput, CompleteAttendanceInput, CreateAttendanceInput,
+      CreateExamCatalogItemInput, CreateExamCategoryInput, CriticalAlertView,
+      DeactivateExamCatalogItemInput, EnterExamResultsInput, EnterExamResultsView,
+      ExamCatalogItemView, ExamCategoryView, PatientRecordEntryView, PatientRecordView,
+      ReleaseAttendanceInput, ResultHistoryView, SetExamDeltaCheckInput, SetExamPanelAnalytesInput,
+      SetExamReferenceRangesInput, TransitionAttendanceInput, UpdateAttendanceInput,
+      UpdateExamCatalogItemInput,
+    },
+    pagination::PageView,
+    reference_range::ReferenceRange,
   },
+  interface::ipc::error::IpcError,
 };
 
 #[tauri::command]
 pub async fn get_patient_record(
   state: State<'_, AppState>,
   patient_id: String,
-) -> Result<PatientRecordView, String> {
+  include_deleted: Option<bool>,
+) -> Result<PatientRecordView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .get_patient_record_use_case
-    .execute(patient_id)
+    .execute(&actor, patient_id, include_deleted.unwrap_or(false))
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
 }
 
 #[tauri::command]
 pub async fn list_exam_catalog(
   state: State<'_, AppState>,
-) -> Result<Vec<ExamCatalogItemView>, String> {
+  include_inactive: Option<bool>,
+) -> Result<Vec<ExamCatalogItemView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .list_exam_catalog_use_case
-    .execute()
+    .execute(&actor, include_inactive.unwrap_or(false))
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn list_exam_categories(
+  state: State<'_, AppState>,
+) -> Result<Vec<ExamCategoryView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_exam_categories_use_case
+    .execute(&actor)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn create_exam_category(
+  state: State<'_, AppState>,
+  input: CreateExamCategoryInput,
+) -> Result<ExamCategoryView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .create_exam_category_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn create_exam_catalog_item(
+  state: State<'_, AppState>,
+  input: CreateExamCatalogItemInput,
+) -> Result<ExamCatalogItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .create_exam_catalog_item_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn update_exam_catalog_item(
+  state: State<'_, AppState>,
+  input: UpdateExamCatalogItemInput,
+) -> Result<ExamCatalogItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .update_exam_catalog_item_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn deactivate_exam_catalog_item(
+  state: State<'_, AppState>,
+  input: DeactivateExamCatalogItemInput,
+) -> Result<ExamCatalogItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .deactivate_exam_catalog_item_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn list_exam_reference_ranges(
+  state: State<'_, AppState>,
+  catalog_item_id: String,
+) -> Result<Vec<ReferenceRange>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_exam_reference_ranges_use_case
+    .execute(&actor, catalog_item_id)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn set_exam_reference_ranges(
+  state: State<'_, AppState>,
+  input: SetExamReferenceRangesInput,
+) -> Result<Vec<ReferenceRange>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .set_exam_reference_ranges_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn get_exam_delta_check(
+  state: State<'_, AppState>,
+  catalog_item_id: String,
+) -> Result<DeltaCheckRule, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .get_exam_delta_check_use_case
+    .execute(&actor, catalog_item_id)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn set_exam_delta_check(
+  state: State<'_, AppState>,
+  input: SetExamDeltaCheckInput,
+) -> Result<DeltaCheckRule, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .set_exam_delta_check_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn set_exam_panel_analytes(
+  state: State<'_, AppState>,
+  input: SetExamPanelAnalytesInput,
+) -> Result<ExamCatalogItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .set_exam_panel_analytes_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
 }
 
 #[tauri::command]
 pub async fn create_attendance(
   state: State<'_, AppState>,
   input: CreateAttendanceInput,
-) -> Result<PatientRecordEntryView, String> {
+) -> Result<PatientRecordEntryView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .create_attendance_use_case
-    .execute(input)
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn update_attendance(
+  state: State<'_, AppState>,
+  input: UpdateAttendanceInput,
+) -> Result<PatientRecordEntryView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .update_attendance_use_case
+    .execute(&actor, input)
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
 }
 
 #[tauri::command]
 pub async fn list_attendance_queue(
   state: State<'_, AppState>,
-  date: Option<String>,
-  status: Option<String>,
-  query: Option<String>,
-) -> Result<Vec<AttendanceQueueItemView>, String> {
+  input: AttendanceQueueQueryInput,
+) -> Result<PageView<AttendanceQueueItemView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .list_attendance_queue_use_case
-    .execute(AttendanceQueueQueryInput {
-      date,
-      status,
-      query,
-    })
+    .execute(&actor, input)
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
 }
 
 #[tauri::command]
 pub async fn complete_attendance(
   state: State<'_, AppState>,
   input: CompleteAttendanceInput,
-) -> Result<AttendanceQueueItemView, String> {
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .complete_attendance_use_case
-    .execute(input)
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn cancel_attendance(
+  state: State<'_, AppState>,
+  input: CancelAttendanceInput,
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .cancel_attendance_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn transition_attendance(
+  state: State<'_, AppState>,
+  input: TransitionAttendanceInput,
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .transition_attendance_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn list_attendance_status_history(
+  state: State<'_, AppState>,
+  attendance_id: String,
+) -> Result<Vec<AttendanceStatusHistoryView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_attendance_status_history_use_case
+    .execute(&actor, attendance_id)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn enter_exam_results(
+  state: State<'_, AppState>,
+  input: EnterExamResultsInput,
+) -> Result<EnterExamResultsView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .enter_exam_results_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn release_attendance(
+  state: State<'_, AppState>,
+  input: ReleaseAttendanceInput,
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .release_attendance_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn amend_result(
+  state: State<'_, AppState>,
+  input: AmendResultInput,
+) -> Result<AmendResultView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .amend_result_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn list_result_history(
+  state: State<'_, AppState>,
+  exam_item_id: String,
+) -> Result<Vec<ResultHistoryView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_result_history_use_case
+    .execute(&actor, exam_item_id)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn list_pending_critical_alerts(
+  state: State<'_, AppState>,
+) -> Result<Vec<CriticalAlertView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_pending_critical_alerts_use_case
+    .execute(&actor)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn acknowledge_critical_alert(
+  state: State<'_, AppState>,
+  input: AcknowledgeCriticalAlertInput,
+) -> Result<CriticalAlertView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .acknowledge_critical_alert_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn delete_attendance(
+  state: State<'_, AppState>,
+  input: AttendanceDeletionInput,
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .delete_attendance_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn restore_attendance(
+  state: State<'_, AppState>,
+  input: AttendanceDeletionInput,
+) -> Result<AttendanceQueueItemView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .restore_attendance_use_case
+    .execute(&actor, input)
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
 }
@@ -2,29 +2,104 @@ use tauri::State;
 
 use crate::{
   app::state::AppState,
-  domain::patients::dto::{CreatePatientInput, PatientView},
+  domain::patients::{
+    dto::{
+      CreatePatientInput, DuplicatePatientsView, FindDuplicatePatientsInput, ListPatientsInput,
+      MergePatientsInput, MergePatientsResultView, PatientDeletionInput, PatientView,
+      UpdatePatientInput,
+    },
+    pagination::PageView,
+  },
+  interface::ipc::error::IpcError,
 };
 
 #[tauri::command]
 pub async fn create_patient(
   state: State<'_, AppState>,
   input: CreatePatientInput,
-) -> Result<PatientView, String> {
+) -> Result<PatientView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .create_patient_use_case
-    .execute(input)
+    .execute(&actor, input)
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn update_patient(
+  state: State<'_, AppState>,
+  input: UpdatePatientInput,
+) -> Result<PatientView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .update_patient_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
 }
 
 #[tauri::command]
 pub async fn list_patients(
   state: State<'_, AppState>,
-  query: Option<String>,
-) -> Result<Vec<PatientView>, String> {
+  input: ListPatientsInput,
+) -> Result<PageView<PatientView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
   state
     .list_patients_use_case
-    .execute(query)
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn find_duplicate_patients(
+  state: State<'_, AppState>,
+  input: FindDuplicatePatientsInput,
+) -> Result<Vec<DuplicatePatientsView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .find_duplicate_patients_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn merge_patients(
+  state: State<'_, AppState>,
+  input: MergePatientsInput,
+) -> Result<MergePatientsResultView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .merge_patients_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn delete_patient(
+  state: State<'_, AppState>,
+  input: PatientDeletionInput,
+) -> Result<PatientView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .delete_patient_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn restore_patient(
+  state: State<'_, AppState>,
+  input: PatientDeletionInput,
+) -> Result<PatientView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .restore_patient_use_case
+    .execute(&actor, input)
     .await
-    .map_err(|e| format!("{e:?}"))
+    .map_err(IpcError::from)
 }
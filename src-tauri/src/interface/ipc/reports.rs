@@ -0,0 +1,20 @@
+use tauri::State;
+
+use crate::{
+  app::state::AppState,
+  domain::reports::dto::{GenerateLabReportInput, GeneratedLabReportView},
+  interface::ipc::error::IpcError,
+};
+
+#[tauri::command]
+pub async fn generate_lab_report(
+  state: State<'_, AppState>,
+  input: GenerateLabReportInput,
+) -> Result<GeneratedLabReportView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .generate_lab_report_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
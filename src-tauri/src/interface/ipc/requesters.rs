@@ -0,0 +1,75 @@
+use tauri::State;
+
+use crate::{
+  app::state::AppState,
+  domain::requesters::dto::{
+    CreateRequesterInput, RequesterView, SearchRequestersInput, SetRequesterActiveInput,
+    UpdateRequesterInput,
+  },
+  interface::ipc::error::IpcError,
+};
+
+#[tauri::command]
+pub async fn list_requesters(
+  state: State<'_, AppState>,
+  include_inactive: Option<bool>,
+) -> Result<Vec<RequesterView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_requesters_use_case
+    .execute(&actor, include_inactive.unwrap_or(false))
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn search_requesters(
+  state: State<'_, AppState>,
+  input: SearchRequestersInput,
+) -> Result<Vec<RequesterView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .search_requesters_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn create_requester(
+  state: State<'_, AppState>,
+  input: CreateRequesterInput,
+) -> Result<RequesterView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .create_requester_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn update_requester(
+  state: State<'_, AppState>,
+  input: UpdateRequesterInput,
+) -> Result<RequesterView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .update_requester_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn set_requester_active(
+  state: State<'_, AppState>,
+  input: SetRequesterActiveInput,
+) -> Result<RequesterView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .set_requester_active_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
@@ -0,0 +1,45 @@
+use tauri::State;
+
+use crate::{
+  app::state::AppState,
+  domain::users::dto::{CreateUserInput, SetUserActiveInput, UserView},
+  interface::ipc::error::IpcError,
+};
+
+#[tauri::command]
+pub async fn list_users(state: State<'_, AppState>) -> Result<Vec<UserView>, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .list_users_use_case
+    .execute(&actor)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn create_user(
+  state: State<'_, AppState>,
+  input: CreateUserInput,
+) -> Result<UserView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  state
+    .create_user_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)
+}
+
+#[tauri::command]
+pub async fn set_user_active(
+  state: State<'_, AppState>,
+  input: SetUserActiveInput,
+) -> Result<UserView, IpcError> {
+  let actor = state.session.require_user().map_err(IpcError::from)?;
+  let user = state
+    .set_user_active_use_case
+    .execute(&actor, input)
+    .await
+    .map_err(IpcError::from)?;
+  state.session.refresh(&user);
+  Ok(user)
+}
@@ -17,10 +17,11 @@ pub fn run() {
       std::fs::create_dir_all(&data_dir)?;
 
       let db_path = data_dir.join("laboratory.sqlite");
+      let reports_dir = data_dir.join("reports");
 
  
       let state = tauri::async_runtime::block_on(async {
-        compose(db_path.to_string_lossy().as_ref()).await
+        compose(db_path.to_string_lossy().as_ref(), &reports_dir).await
       })
       .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, format!("{e:?}")))?;
 
@@ -29,13 +30,56 @@ pub fn run() {
       Ok(())
     })
     .invoke_handler(tauri::generate_handler![
+      interface::ipc::auth::login,
+      interface::ipc::auth::logout,
+      interface::ipc::auth::current_user,
+      interface::ipc::auth::needs_initial_setup,
+      interface::ipc::auth::create_initial_admin,
+      interface::ipc::users::list_users,
+      interface::ipc::users::create_user,
+      interface::ipc::users::set_user_active,
+      interface::ipc::audit::list_audit_log,
+      interface::ipc::audit::verify_audit_chain,
       interface::ipc::patients::create_patient,
+      interface::ipc::patients::update_patient,
       interface::ipc::patients::list_patients,
+      interface::ipc::patients::find_duplicate_patients,
+      interface::ipc::patients::merge_patients,
+      interface::ipc::patients::delete_patient,
+      interface::ipc::patients::restore_patient,
       interface::ipc::patient_records::get_patient_record,
       interface::ipc::patient_records::list_exam_catalog,
       interface::ipc::patient_records::create_attendance,
+      interface::ipc::patient_records::update_attendance,
       interface::ipc::patient_records::list_attendance_queue,
-      interface::ipc::patient_records::complete_attendance
+      interface::ipc::patient_records::complete_attendance,
+      interface::ipc::patient_records::cancel_attendance,
+      interface::ipc::patient_records::delete_attendance,
+      interface::ipc::patient_records::restore_attendance,
+      interface::ipc::patient_records::list_exam_categories,
+      interface::ipc::patient_records::create_exam_category,
+      interface::ipc::patient_records::create_exam_catalog_item,
+      interface::ipc::patient_records::update_exam_catalog_item,
+      interface::ipc::patient_records::deactivate_exam_catalog_item,
+      interface::ipc::patient_records::list_exam_reference_ranges,
+      interface::ipc::patient_records::set_exam_reference_ranges,
+      interface::ipc::patient_records::get_exam_delta_check,
+      interface::ipc::patient_records::set_exam_delta_check,
+      interface::ipc::patient_records::set_exam_panel_analytes,
+      interface::ipc::patient_records::transition_attendance,
+      interface::ipc::patient_records::list_attendance_status_history,
+      interface::ipc::patient_records::enter_exam_results,
+      interface::ipc::patient_records::release_attendance,
+      interface::ipc::patient_records::amend_result,
+      interface::ipc::patient_records::list_result_history,
+      interface::ipc::patient_records::list_pending_critical_alerts,
+      interface::ipc::patient_records::acknowledge_critical_alert,
+      interface::ipc::reports::generate_lab_report,
+      interface::ipc::requesters::list_requesters,
+      interface::ipc::requesters::search_requesters,
+      interface::ipc::requesters::create_requester,
+      interface::ipc::requesters::update_requester,
+      interface::ipc::requesters::set_requester_active
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
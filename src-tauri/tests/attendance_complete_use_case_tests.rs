@@ -4,13 +4,25 @@ use laboratory_app_lib::{
   app::error::AppError,
   application::patients::complete_attendance::CompleteAttendanceUseCase,
   domain::patients::{
-    dto::{AttendanceQueueItemView, AttendanceQueueQueryInput, CompleteAttendanceInput},
+    dto::{AttendanceQueueItemView, AttendanceQueuePageQuery, CompleteAttendanceInput},
     entity::Patient,
     errors::PatientRepositoryError,
     ports::PatientRepository,
   },
+  domain::users::dto::UserView,
 };
 
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
 struct StubCompleteAttendanceRepository {
   result: Result<AttendanceQueueItemView, PatientRepositoryError>,
 }
@@ -20,17 +32,40 @@ impl PatientRepository for StubCompleteAttendanceRepository {
   async fn insert(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreatePatientInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<Patient, PatientRepositoryError> {
     unimplemented!()
   }
 
-  async fn list(&self, _query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
+  async fn list(
+    &self,
+    _query: laboratory_app_lib::domain::patients::dto::PatientPageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<Patient>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: laboratory_app_lib::domain::patients::dto::UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
     unimplemented!()
   }
 
   async fn get_patient_record(
     &self,
     _patient_id: String,
+    _include_deleted: bool,
   ) -> Result<laboratory_app_lib::domain::patients::dto::PatientRecordView, PatientRepositoryError>
   {
     unimplemented!()
@@ -38,6 +73,7 @@ impl PatientRepository for StubCompleteAttendanceRepository {
 
   async fn list_exam_catalog(
     &self,
+    _include_inactive: bool,
   ) -> Result<
     Vec<laboratory_app_lib::domain::patients::dto::ExamCatalogItemView>,
     PatientRepositoryError,
@@ -48,6 +84,7 @@ impl PatientRepository for StubCompleteAttendanceRepository {
   async fn create_attendance(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<
     laboratory_app_lib::domain::patients::dto::PatientRecordEntryView,
     PatientRepositoryError,
@@ -57,14 +94,18 @@ impl PatientRepository for StubCompleteAttendanceRepository {
 
   async fn list_attendance_queue(
     &self,
-    _input: AttendanceQueueQueryInput,
-  ) -> Result<Vec<AttendanceQueueItemView>, PatientRepositoryError> {
+    _input: AttendanceQueuePageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<AttendanceQueueItemView>,
+    PatientRepositoryError,
+  > {
     unimplemented!()
   }
 
   async fn complete_attendance(
     &self,
     _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
     self.result.clone()
   }
@@ -78,12 +119,17 @@ async fn complete_attendance_requires_attendance_id() {
   let use_case = CompleteAttendanceUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(CompleteAttendanceInput {
-      attendance_id: "  ".to_string(),
-    })
+    .execute(
+      &admin(),
+      CompleteAttendanceInput {
+        attendance_id: "  ".to_string(),
+      },
+    )
     .await;
 
-  assert!(matches!(result, Err(AppError::Validation(msg)) if msg == "attendance_id is required"));
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "attendance_id is required")
+  );
 }
 
 #[tokio::test]
@@ -95,21 +141,25 @@ async fn complete_attendance_returns_updated_item() {
       patient_name: "Maria".to_string(),
       patient_cpf: "12345678900".to_string(),
       exam_date: "2026-02-14".to_string(),
-      status: "completed".to_string(),
+      status: "collected".to_string(),
       exam_names: vec!["Glicose".to_string()],
       updated_at: "2026-02-14T10:30:00".to_string(),
+      deleted_at: None,
     }),
   };
   let use_case = CompleteAttendanceUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(CompleteAttendanceInput {
-      attendance_id: "att-1".to_string(),
-    })
+    .execute(
+      &admin(),
+      CompleteAttendanceInput {
+        attendance_id: "att-1".to_string(),
+      },
+    )
     .await;
 
   match result {
-    Ok(item) => assert_eq!(item.status, "completed"),
+    Ok(item) => assert_eq!(item.status, "collected"),
     Err(_) => panic!("expected success"),
   }
 }
@@ -122,10 +172,13 @@ async fn complete_attendance_maps_repository_error() {
   let use_case = CompleteAttendanceUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(CompleteAttendanceInput {
-      attendance_id: "missing".to_string(),
-    })
+    .execute(
+      &admin(),
+      CompleteAttendanceInput {
+        attendance_id: "missing".to_string(),
+      },
+    )
     .await;
 
-  assert!(matches!(result, Err(AppError::Database(msg)) if msg == "attendance not found"));
+  assert!(matches!(result, Err(AppError::NotFound(msg)) if msg == "attendance not found"));
 }
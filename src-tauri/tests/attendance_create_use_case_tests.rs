@@ -0,0 +1,477 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::create_attendance::CreateAttendanceUseCase,
+  domain::{
+    patients::{
+      calendar::CalendarDate,
+      dto::{
+        AttendanceQueueItemView, AttendanceQueuePageQuery, CompleteAttendanceInput,
+        CreateAttendanceInput, CreateAttendanceItemInput, CreatePatientInput, ExamCatalogItemView,
+        PatientPageQuery, PatientRecordEntryView, PatientRecordView, UpdatePatientInput,
+      },
+      entity::Patient,
+      errors::PatientRepositoryError,
+      pagination::PageView,
+      ports::{Clock, PatientRepository},
+    },
+    requesters::{
+      dto::{NewRequester, RequesterView},
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+struct StubAttendanceRepository {
+  patients: Vec<Patient>,
+  catalog: Vec<ExamCatalogItemView>,
+  created: Mutex<Vec<CreateAttendanceInput>>,
+}
+
+#[async_trait::async_trait]
+impl PatientRepository for StubAttendanceRepository {
+  async fn insert(
+    &self,
+    _input: CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list(
+    &self,
+    _query: PatientPageQuery,
+  ) -> Result<PageView<Patient>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_patient(
+    &self,
+    patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    Ok(self.patients.iter().find(|p| p.id == patient_id).cloned())
+  }
+
+  async fn get_patient_record(
+    &self,
+    _patient_id: String,
+    _include_deleted: bool,
+  ) -> Result<PatientRecordView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_exam_catalog(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+    Ok(self.catalog.clone())
+  }
+
+  async fn create_attendance(
+    &self,
+    input: CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    self.created.lock().unwrap().push(input.clone());
+    Ok(PatientRecordEntryView {
+      exam_id: "att-1".to_string(),
+      exam_date: input.exam_date,
+      status: "waiting".to_string(),
+      requester_name: None,
+      items: Vec::new(),
+      deleted_at: None,
+    })
+  }
+
+  async fn list_attendance_queue(
+    &self,
+    _input: AttendanceQueuePageQuery,
+  ) -> Result<PageView<AttendanceQueueItemView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn complete_attendance(
+    &self,
+    _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct StubRequesterRepository {
+  requesters: Vec<RequesterView>,
+}
+
+#[async_trait::async_trait]
+impl RequesterRepository for StubRequesterRepository {
+  async fn list_requesters(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_requester(
+    &self,
+    requester_id: String,
+  ) -> Result<Option<RequesterView>, RequesterRepositoryError> {
+    Ok(
+      self
+        .requesters
+        .iter()
+        .find(|r| r.id == requester_id)
+        .cloned(),
+    )
+  }
+
+  async fn insert_requester(
+    &self,
+    _requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_requester(
+    &self,
+    _requester_id: String,
+    _requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn set_requester_active(
+    &self,
+    _requester_id: String,
+    _is_active: bool,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct FixedClock(CalendarDate);
+
+impl Clock for FixedClock {
+  fn today(&self) -> CalendarDate {
+    self.0
+  }
+}
+
+fn reception() -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Rita Recepcao".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "rita".to_string(),
+    role: "reception".to_string(),
+    is_active: true,
+  }
+}
+
+fn patient(id: &str, deleted_at: Option<&str>) -> Patient {
+  Patient {
+    id: id.to_string(),
+    full_name: "Maria Souza".to_string(),
+    cpf: "52998224725".to_string(),
+    birth_date: "1990-01-01".to_string(),
+    sex: "F".to_string(),
+    phone: "11999999999".to_string(),
+    address: "Rua A".to_string(),
+    created_at: "2026-01-01 00:00:00".to_string(),
+    updated_at: "2026-01-01 00:00:00".to_string(),
+    deleted_at: deleted_at.map(str::to_string),
+  }
+}
+
+fn catalog_item(id: &str, name: &str) -> ExamCatalogItemView {
+  ExamCatalogItemView {
+    id: id.to_string(),
+    name: name.to_string(),
+    category_id: "bioquimica".to_string(),
+    category_title: "Bioquimica".to_string(),
+    price_cents: 1500,
+    unit: None,
+    method: None,
+    reference_range: None,
+    is_active: true,
+    analyte_ids: vec![],
+  }
+}
+
+fn requester(id: &str, is_active: bool) -> RequesterView {
+  RequesterView {
+    id: id.to_string(),
+    name: "Dra. Ana Lima".to_string(),
+    council_type: Some("CRM".to_string()),
+    council_number: Some("12345".to_string()),
+    council_state: Some("SP".to_string()),
+    is_active,
+    created_at: "2026-01-01 00:00:00".to_string(),
+    updated_at: "2026-01-01 00:00:00".to_string(),
+  }
+}
+
+/// Today is 2026-03-10 and attendances may be scheduled up to a week ahead.
+fn setup() -> (Arc<StubAttendanceRepository>, CreateAttendanceUseCase) {
+  let repo = Arc::new(StubAttendanceRepository {
+    patients: vec![
+      patient("pt-1", None),
+      patient("pt-2", Some("2026-02-01 10:00:00")),
+    ],
+    catalog: vec![
+      catalog_item("cat-glicose", "Glicose"),
+      catalog_item("cat-urico", "Ácido Úrico"),
+      catalog_item("cat-tsh-1", "TSH"),
+      catalog_item("cat-tsh-2", "tsh"),
+      catalog_item("cat-ureia", "Ureia"),
+      catalog_item("cat-creatinina", "Creatinina"),
+      ExamCatalogItemView {
+        analyte_ids: vec!["cat-ureia".to_string(), "cat-creatinina".to_string()],
+        ..catalog_item("cat-bioq-2", "Bioquimica 2 (Ureia/Creatinina)")
+      },
+    ],
+    created: Mutex::new(Vec::new()),
+  });
+  let requesters = Arc::new(StubRequesterRepository {
+    requesters: vec![requester("rq-1", true), requester("rq-2", false)],
+  });
+  let clock = Arc::new(FixedClock(CalendarDate::parse("2026-03-10").unwrap()));
+  (
+    repo.clone(),
+    CreateAttendanceUseCase::new(repo, requesters, clock, 7),
+  )
+}
+
+fn item(catalog_item_id: Option<&str>, name: &str) -> CreateAttendanceItemInput {
+  CreateAttendanceItemInput {
+    catalog_item_id: catalog_item_id.map(str::to_string),
+    name: name.to_string(),
+    unit: None,
+    method: None,
+    reference_range: None,
+  }
+}
+
+fn input(requester_id: Option<&str>) -> CreateAttendanceInput {
+  CreateAttendanceInput {
+    patient_id: "pt-1".to_string(),
+    exam_date: "2026-03-10".to_string(),
+    requester_id: requester_id.map(str::to_string),
+    status: None,
+    procedure_type: None,
+    delivered_to: None,
+    notes: None,
+    items: vec![item(None, "Glicose")],
+  }
+}
+
+async fn expect_validation(
+  use_case: &CreateAttendanceUseCase,
+  input: CreateAttendanceInput,
+  expected: &str,
+) {
+  let result = use_case.execute(&reception(), input).await;
+  assert!(
+    matches!(&result, Err(AppError::Validation { message: msg, .. }) if msg == expected),
+    "expected {expected:?}, got {result:?}"
+  );
+}
+
+#[tokio::test]
+async fn create_attendance_accepts_active_or_missing_requester() {
+  let (repo, use_case) = setup();
+
+  for requester_id in [Some("rq-1"), None, Some("  ")] {
+    use_case
+      .execute(&reception(), input(requester_id))
+      .await
+      .expect("expected success");
+  }
+
+  assert_eq!(repo.created.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn create_attendance_rejects_unknown_or_inactive_requester() {
+  let (repo, use_case) = setup();
+
+  expect_validation(
+    &use_case,
+    input(Some("rq-404")),
+    "requester_id does not match any requester",
+  )
+  .await;
+  expect_validation(
+    &use_case,
+    input(Some("rq-2")),
+    "requester_id points at an inactive requester",
+  )
+  .await;
+  assert!(repo.created.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn create_attendance_rejects_missing_or_deleted_patient() {
+  let (_, use_case) = setup();
+
+  let missing = use_case
+    .execute(
+      &reception(),
+      CreateAttendanceInput {
+        patient_id: "pt-404".to_string(),
+        ..input(None)
+      },
+    )
+    .await;
+  assert!(matches!(missing, Err(AppError::NotFound(msg)) if msg == "patient not found"));
+
+  expect_validation(
+    &use_case,
+    CreateAttendanceInput {
+      patient_id: "pt-2".to_string(),
+      ..input(None)
+    },
+    "patient_id points at a deleted patient",
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn create_attendance_checks_exam_date_against_window() {
+  let (repo, use_case) = setup();
+  let with_date = |exam_date: &str| CreateAttendanceInput {
+    exam_date: exam_date.to_string(),
+    ..input(None)
+  };
+
+  for (exam_date, expected) in [
+    (" ", "exam_date is required"),
+    ("10/03/2026", "exam_date must be a valid YYYY-MM-DD date"),
+    ("2026-02-30", "exam_date must be a valid YYYY-MM-DD date"),
+    ("2026-03-18", "exam_date must not be later than 2026-03-17"),
+  ] {
+    expect_validation(&use_case, with_date(exam_date), expected).await;
+  }
+
+  for exam_date in ["2026-03-17", "2019-07-01", " 2026-03-10 "] {
+    use_case
+      .execute(&reception(), with_date(exam_date))
+      .await
+      .expect("date inside the window should be accepted");
+  }
+  assert_eq!(repo.created.lock().unwrap()[2].exam_date, "2026-03-10");
+}
+
+#[tokio::test]
+async fn create_attendance_resolves_items_against_catalog() {
+  let (repo, use_case) = setup();
+
+  use_case
+    .execute(
+      &reception(),
+      CreateAttendanceInput {
+        items: vec![
+          item(None, "  acido   URICO "),
+          item(Some("cat-glicose"), ""),
+        ],
+        ..input(None)
+      },
+    )
+    .await
+    .expect("expected success");
+
+  let created = repo.created.lock().unwrap();
+  let ids: Vec<_> = created[0]
+    .items
+    .iter()
+    .map(|i| i.catalog_item_id.as_deref())
+    .collect();
+  assert_eq!(ids, vec![Some("cat-urico"), Some("cat-glicose")]);
+}
+
+#[tokio::test]
+async fn create_attendance_rejects_invalid_items() {
+  let (repo, use_case) = setup();
+  let with_items = |items: Vec<CreateAttendanceItemInput>| CreateAttendanceInput {
+    items,
+    ..input(None)
+  };
+
+  let cases = [
+    (vec![], "items is required"),
+    (
+      vec![item(None, "Glicose"), item(None, " ")],
+      "items[1]: name or catalog_item_id is required",
+    ),
+    (
+      vec![item(None, "Colesterol")],
+      "items[0]: Colesterol does not match any active catalog entry",
+    ),
+    (
+      vec![item(Some("cat-retired"), "Glicose")],
+      "items[0]: catalog_item_id does not match an active catalog entry",
+    ),
+    (
+      vec![item(None, "TSH")],
+      "items[0]: TSH matches more than one catalog entry; send catalog_item_id",
+    ),
+    (
+      vec![
+        item(None, "glicose"),
+        item(None, "Ácido Úrico"),
+        item(Some("cat-glicose"), ""),
+      ],
+      "items[2]: Glicose is already requested in items[0]",
+    ),
+  ];
+
+  for (items, expected) in cases {
+    expect_validation(&use_case, with_items(items), expected).await;
+  }
+  assert!(repo.created.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn create_attendance_rejects_a_panel_together_with_its_analytes() {
+  let (repo, use_case) = setup();
+  let with_items = |items: Vec<CreateAttendanceItemInput>| CreateAttendanceInput {
+    items,
+    ..input(None)
+  };
+
+  use_case
+    .execute(
+      &reception(),
+      with_items(vec![item(Some("cat-bioq-2"), ""), item(None, "Glicose")]),
+    )
+    .await
+    .expect("expected success");
+  expect_validation(
+    &use_case,
+    with_items(vec![item(None, "ureia"), item(Some("cat-bioq-2"), "")]),
+    "items[1]: Bioquimica 2 (Ureia/Creatinina) is already requested in items[0]",
+  )
+  .await;
+  expect_validation(
+    &use_case,
+    with_items(vec![item(Some("cat-bioq-2"), ""), item(None, "Creatinina")]),
+    "items[1]: Creatinina is already requested in items[0]",
+  )
+  .await;
+
+  assert_eq!(repo.created.lock().unwrap().len(), 1);
+}
@@ -1,18 +1,44 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use laboratory_app_lib::{
   app::error::AppError,
   application::patients::list_attendance_queue::ListAttendanceQueueUseCase,
   domain::patients::{
-    dto::{AttendanceQueueItemView, AttendanceQueueQueryInput, CompleteAttendanceInput},
+    dto::{
+      AttendanceQueueItemView, AttendanceQueuePageQuery, AttendanceQueueQueryInput,
+      CompleteAttendanceInput,
+    },
     entity::Patient,
     errors::PatientRepositoryError,
+    pagination::{AttendanceSort, PageCursor, PageView},
     ports::PatientRepository,
   },
+  domain::users::dto::UserView,
 };
 
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
 struct StubAttendanceQueueRepository {
   result: Result<Vec<AttendanceQueueItemView>, PatientRepositoryError>,
+  queries: Mutex<Vec<AttendanceQueuePageQuery>>,
+}
+
+impl StubAttendanceQueueRepository {
+  fn with(result: Result<Vec<AttendanceQueueItemView>, PatientRepositoryError>) -> Self {
+    Self {
+      result,
+      queries: Mutex::new(Vec::new()),
+    }
+  }
 }
 
 #[async_trait::async_trait]
@@ -20,17 +46,40 @@ impl PatientRepository for StubAttendanceQueueRepository {
   async fn insert(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list(
+    &self,
+    _query: laboratory_app_lib::domain::patients::dto::PatientPageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<Patient>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: laboratory_app_lib::domain::patients::dto::UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<Patient, PatientRepositoryError> {
     unimplemented!()
   }
 
-  async fn list(&self, _query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
     unimplemented!()
   }
 
   async fn get_patient_record(
     &self,
     _patient_id: String,
+    _include_deleted: bool,
   ) -> Result<laboratory_app_lib::domain::patients::dto::PatientRecordView, PatientRepositoryError>
   {
     unimplemented!()
@@ -38,6 +87,7 @@ impl PatientRepository for StubAttendanceQueueRepository {
 
   async fn list_exam_catalog(
     &self,
+    _include_inactive: bool,
   ) -> Result<
     Vec<laboratory_app_lib::domain::patients::dto::ExamCatalogItemView>,
     PatientRepositoryError,
@@ -48,6 +98,7 @@ impl PatientRepository for StubAttendanceQueueRepository {
   async fn create_attendance(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<
     laboratory_app_lib::domain::patients::dto::PatientRecordEntryView,
     PatientRepositoryError,
@@ -57,14 +108,20 @@ impl PatientRepository for StubAttendanceQueueRepository {
 
   async fn list_attendance_queue(
     &self,
-    _input: AttendanceQueueQueryInput,
-  ) -> Result<Vec<AttendanceQueueItemView>, PatientRepositoryError> {
-    self.result.clone()
+    query: AttendanceQueuePageQuery,
+  ) -> Result<PageView<AttendanceQueueItemView>, PatientRepositoryError> {
+    self.queries.lock().unwrap().push(query);
+    self.result.clone().map(|items| PageView {
+      total: items.len() as i64,
+      items,
+      next_cursor: None,
+    })
   }
 
   async fn complete_attendance(
     &self,
     _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
     unimplemented!()
   }
@@ -72,32 +129,36 @@ impl PatientRepository for StubAttendanceQueueRepository {
 
 #[tokio::test]
 async fn list_attendance_queue_returns_items() {
-  let repo = StubAttendanceQueueRepository {
-    result: Ok(vec![AttendanceQueueItemView {
-      attendance_id: "att-1".to_string(),
-      patient_id: "pt-1".to_string(),
-      patient_name: "Maria".to_string(),
-      patient_cpf: "12345678900".to_string(),
-      exam_date: "2026-02-14".to_string(),
-      status: "waiting".to_string(),
-      exam_names: vec!["Glicose".to_string()],
-      updated_at: "2026-02-14T09:00:00".to_string(),
-    }]),
-  };
+  let repo = StubAttendanceQueueRepository::with(Ok(vec![AttendanceQueueItemView {
+    attendance_id: "att-1".to_string(),
+    patient_id: "pt-1".to_string(),
+    patient_name: "Maria".to_string(),
+    patient_cpf: "12345678900".to_string(),
+    exam_date: "2026-02-14".to_string(),
+    status: "waiting".to_string(),
+    exam_names: vec!["Glicose".to_string()],
+    updated_at: "2026-02-14T09:00:00".to_string(),
+    deleted_at: None,
+  }]));
   let use_case = ListAttendanceQueueUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(AttendanceQueueQueryInput {
-      date: Some("2026-02-14".to_string()),
-      status: Some("waiting".to_string()),
-      query: None,
-    })
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        date: Some("2026-02-14".to_string()),
+        status: Some("waiting".to_string()),
+        query: None,
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
     .await;
 
   match result {
-    Ok(items) => {
-      assert_eq!(items.len(), 1);
-      assert_eq!(items[0].attendance_id, "att-1");
+    Ok(page) => {
+      assert_eq!(page.items.len(), 1);
+      assert_eq!(page.total, 1);
+      assert_eq!(page.items[0].attendance_id, "att-1");
     }
     Err(_) => panic!("expected success"),
   }
@@ -105,34 +166,134 @@ async fn list_attendance_queue_returns_items() {
 
 #[tokio::test]
 async fn list_attendance_queue_rejects_invalid_status() {
-  let repo = StubAttendanceQueueRepository { result: Ok(vec![]) };
+  let repo = StubAttendanceQueueRepository::with(Ok(vec![]));
   let use_case = ListAttendanceQueueUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(AttendanceQueueQueryInput {
-      date: None,
-      status: Some("done".to_string()),
-      query: None,
-    })
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        date: None,
+        status: Some("done".to_string()),
+        query: None,
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
     .await;
 
-  assert!(matches!(result, Err(AppError::Validation(msg)) if msg == "status must be waiting or completed"));
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "status must be one of waiting, collected, in_analysis, awaiting_validation, released, delivered, cancelled"
+  ));
 }
 
 #[tokio::test]
 async fn list_attendance_queue_maps_repository_error() {
-  let repo = StubAttendanceQueueRepository {
-    result: Err(PatientRepositoryError::PersistenceError),
-  };
+  let repo = StubAttendanceQueueRepository::with(Err(PatientRepositoryError::PersistenceError));
   let use_case = ListAttendanceQueueUseCase::new(Arc::new(repo));
 
   let result = use_case
-    .execute(AttendanceQueueQueryInput {
-      date: None,
-      status: None,
-      query: None,
-    })
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        date: None,
+        status: None,
+        query: None,
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Database(msg)) if msg == "failed to fetch attendance queue")
+  );
+}
+
+#[tokio::test]
+async fn list_attendance_queue_normalizes_filters_and_defaults_sort() {
+  let repo = Arc::new(StubAttendanceQueueRepository::with(Ok(vec![])));
+  let use_case = ListAttendanceQueueUseCase::new(repo.clone());
+  let cursor = PageCursor::new(
+    "exam_date",
+    vec![
+      "2026-02-14".to_string(),
+      "2026-02-14 09:00:00".to_string(),
+      "att-1".to_string(),
+    ],
+  );
+
+  use_case
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        date: Some(" ".to_string()),
+        status: Some(" waiting ".to_string()),
+        cursor: Some(cursor.encode()),
+        limit: Some(200),
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
+    .await
+    .expect("expected success");
+
+  let queries = repo.queries.lock().unwrap();
+  assert!(queries[0].date.is_none());
+  assert_eq!(queries[0].status.as_deref(), Some("waiting"));
+  assert_eq!(queries[0].sort, AttendanceSort::ExamDate);
+  assert_eq!(queries[0].limit, 200);
+  assert_eq!(queries[0].after.as_ref(), Some(&cursor));
+}
+
+#[tokio::test]
+async fn list_attendance_queue_validates_sort_limit_and_cursor() {
+  let repo = Arc::new(StubAttendanceQueueRepository::with(Ok(vec![])));
+  let use_case = ListAttendanceQueueUseCase::new(repo.clone());
+  // A two-key cursor cannot continue an exam_date listing, which needs three keys.
+  let short_cursor = PageCursor::new(
+    "exam_date",
+    vec!["2026-02-14".to_string(), "att-1".to_string()],
+  );
+
+  let bad_sort = use_case
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        sort: Some("status".to_string()),
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
+    .await;
+  let bad_limit = use_case
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        limit: Some(201),
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
+    .await;
+  let bad_cursor = use_case
+    .execute(
+      &admin(),
+      AttendanceQueueQueryInput {
+        cursor: Some(short_cursor.encode()),
+        ..AttendanceQueueQueryInput::default()
+      },
+    )
     .await;
 
-  assert!(matches!(result, Err(AppError::Database(msg)) if msg == "failed to fetch attendance queue"));
+  assert!(matches!(
+    bad_sort,
+    Err(AppError::Validation { message: msg, .. }) if msg == "sort must be one of exam_date, created_at, name"
+  ));
+  assert!(matches!(
+    bad_limit,
+    Err(AppError::Validation { message: msg, .. }) if msg == "limit must be between 1 and 200"
+  ));
+  assert!(matches!(
+    bad_cursor,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cursor is invalid for this sort"
+  ));
+  assert!(repo.queries.lock().unwrap().is_empty());
 }
@@ -0,0 +1,366 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::release_attendance::ReleaseAttendanceUseCase,
+  domain::{
+    patients::{
+      dto::{
+        AmendResultInput, AttendanceEditContext, AttendanceEditItemContext,
+        AttendanceQueueItemView, AttendanceStatusChange, AttendanceStatusHistoryView,
+        AttendanceUpdate, EnterExamResultsInput, PatientRecordEntryView, ReleaseAttendanceInput,
+        ResultEntryContext, ResultHistoryView,
+      },
+      errors::PatientRepositoryError,
+      ports::AttendanceRepository,
+    },
+    users::{
+      dto::{NewUser, UserCredentials, UserView},
+      errors::{PasswordHashError, UserRepositoryError},
+      ports::{PasswordHasher, UserRepository},
+    },
+  },
+};
+
+struct StubAttendanceRepository {
+  context: AttendanceEditContext,
+  releases: Mutex<Vec<AttendanceStatusChange>>,
+}
+
+impl StubAttendanceRepository {
+  fn new(status: &str, results: &[bool]) -> Self {
+    Self {
+      context: AttendanceEditContext {
+        attendance_id: "att-1".to_string(),
+        status: status.to_string(),
+        requester_id: None,
+        items: results
+          .iter()
+          .enumerate()
+          .map(|(index, has_result)| AttendanceEditItemContext {
+            exam_item_id: format!("it-{}", index + 1),
+            catalog_item_id: None,
+            panel_id: None,
+            has_result: *has_result,
+            has_pending_critical_alert: false,
+          })
+          .collect(),
+      },
+      releases: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl AttendanceRepository for StubAttendanceRepository {
+  async fn get_attendance(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn change_attendance_status(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn cancel_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn release_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    let view = AttendanceQueueItemView {
+      attendance_id: change.attendance_id.clone(),
+      patient_id: "pt-1".to_string(),
+      patient_name: "Maria Souza".to_string(),
+      patient_cpf: "52998224725".to_string(),
+      exam_date: "2026-03-10".to_string(),
+      status: change.to_status.clone(),
+      exam_names: vec!["Glicose".to_string()],
+      updated_at: "2026-03-10 10:00:00".to_string(),
+      deleted_at: None,
+    };
+    self.releases.lock().unwrap().push(change);
+    Ok(view)
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    _attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn enter_exam_results(
+    &self,
+    _input: EnterExamResultsInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn amend_result(
+    &self,
+    _input: AmendResultInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_result_history(
+    &self,
+    _exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    if attendance_id == self.context.attendance_id {
+      Ok(self.context.clone())
+    } else {
+      Err(PatientRepositoryError::NotFound)
+    }
+  }
+
+  async fn update_attendance(
+    &self,
+    _update: AttendanceUpdate,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct StubUserRepository {
+  users: Vec<UserCredentials>,
+}
+
+#[async_trait::async_trait]
+impl UserRepository for StubUserRepository {
+  async fn find_credentials_by_username(
+    &self,
+    username: String,
+  ) -> Result<Option<UserCredentials>, UserRepositoryError> {
+    Ok(
+      self
+        .users
+        .iter()
+        .find(|c| c.user.username == username)
+        .cloned(),
+    )
+  }
+
+  async fn count_users(&self) -> Result<i64, UserRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn insert_first_user(&self, _user: NewUser) -> Result<UserView, UserRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_users(&self) -> Result<Vec<UserView>, UserRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn insert_user(
+    &self,
+    _user: NewUser,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn set_user_active(
+    &self,
+    _user_id: String,
+    _is_active: bool,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct StubHasher;
+
+impl PasswordHasher for StubHasher {
+  fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+    Ok(format!("hashed:{password}"))
+  }
+
+  fn verify(&self, password: &str, password_hash: &str) -> bool {
+    password_hash == format!("hashed:{password}")
+  }
+}
+
+fn biochemist() -> UserView {
+  UserView {
+    id: "usr-bio".to_string(),
+    name: "Beatriz Bioquimica".to_string(),
+    cpf: "52998224725".to_string(),
+    username: "beatriz".to_string(),
+    role: "biochemist".to_string(),
+    is_active: true,
+  }
+}
+
+fn use_case(repo: Arc<StubAttendanceRepository>, signer: UserView) -> ReleaseAttendanceUseCase {
+  let users = StubUserRepository {
+    users: vec![UserCredentials {
+      user: signer,
+      password_hash: "hashed:segredo123".to_string(),
+    }],
+  };
+  ReleaseAttendanceUseCase::new(repo, Arc::new(users), Arc::new(StubHasher))
+}
+
+fn input(password: &str) -> ReleaseAttendanceInput {
+  ReleaseAttendanceInput {
+    attendance_id: "att-1".to_string(),
+    password: password.to_string(),
+    note: Some("Resultados conferidos".to_string()),
+  }
+}
+
+#[tokio::test]
+async fn release_attendance_signs_with_the_biochemist() {
+  let repo = Arc::new(StubAttendanceRepository::new(
+    "awaiting_validation",
+    &[true, true],
+  ));
+  let use_case = use_case(repo.clone(), biochemist());
+
+  let released = use_case
+    .execute(&biochemist(), input("segredo123"))
+    .await
+    .expect("expected success");
+
+  assert_eq!(released.status, "released");
+  let releases = repo.releases.lock().unwrap();
+  assert_eq!(releases.len(), 1);
+  assert_eq!(releases[0].from_status, "awaiting_validation");
+  assert_eq!(releases[0].changed_by_user_id.as_deref(), Some("usr-bio"));
+  assert_eq!(releases[0].note.as_deref(), Some("Resultados conferidos"));
+}
+
+#[tokio::test]
+async fn release_attendance_rejects_a_wrong_or_foreign_password() {
+  let repo = Arc::new(StubAttendanceRepository::new(
+    "awaiting_validation",
+    &[true],
+  ));
+  let inactive = UserView {
+    is_active: false,
+    ..biochemist()
+  };
+  let renamed = UserView {
+    id: "usr-other".to_string(),
+    ..biochemist()
+  };
+
+  let wrong = use_case(repo.clone(), biochemist())
+    .execute(&biochemist(), input("errada"))
+    .await;
+  let deactivated = use_case(repo.clone(), inactive)
+    .execute(&biochemist(), input("segredo123"))
+    .await;
+  let other_user = use_case(repo.clone(), renamed)
+    .execute(&biochemist(), input("segredo123"))
+    .await;
+  let blank = use_case(repo.clone(), biochemist())
+    .execute(&biochemist(), input(""))
+    .await;
+
+  for result in [wrong, deactivated, other_user] {
+    assert!(matches!(
+      result,
+      Err(AppError::Validation { message: msg, .. }) if msg == "password: does not match the signed-in user"
+    ));
+  }
+  assert!(
+    matches!(blank, Err(AppError::Validation { message: msg, .. }) if msg == "password is required")
+  );
+  assert!(repo.releases.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn release_attendance_requires_every_result_and_awaiting_validation() {
+  let pending = Arc::new(StubAttendanceRepository::new(
+    "awaiting_validation",
+    &[true, false],
+  ));
+  let in_analysis = Arc::new(StubAttendanceRepository::new("in_analysis", &[true]));
+
+  let missing_result = use_case(pending.clone(), biochemist())
+    .execute(&biochemist(), input("segredo123"))
+    .await;
+  let too_early = use_case(in_analysis, biochemist())
+    .execute(&biochemist(), input("segredo123"))
+    .await;
+
+  assert!(matches!(
+    missing_result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "items: it-2 has no result yet"
+  ));
+  assert!(matches!(
+    too_early,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cannot move attendance from in_analysis to released"
+  ));
+  assert!(pending.releases.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn release_attendance_requires_critical_values_to_be_acknowledged() {
+  let mut stub = StubAttendanceRepository::new("awaiting_validation", &[true, true]);
+  stub.context.items[1].has_pending_critical_alert = true;
+  let repo = Arc::new(stub);
+
+  let result = use_case(repo.clone(), biochemist())
+    .execute(&biochemist(), input("segredo123"))
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "items: it-2 has an unacknowledged critical value"
+  ));
+  assert!(repo.releases.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn release_attendance_requires_release_permission() {
+  let repo = Arc::new(StubAttendanceRepository::new(
+    "awaiting_validation",
+    &[true],
+  ));
+  let bench = UserView {
+    role: "bench".to_string(),
+    ..biochemist()
+  };
+
+  let result = use_case(repo, bench.clone())
+    .execute(&bench, input("segredo123"))
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Forbidden(msg)) if msg == "role bench is not allowed to release_results"
+  ));
+}
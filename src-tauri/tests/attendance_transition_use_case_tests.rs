@@ -0,0 +1,328 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::{
+    cancel_attendance::CancelAttendanceUseCase, transition_attendance::TransitionAttendanceUseCase,
+  },
+  domain::patients::{
+    dto::{
+      AmendResultInput, AttendanceEditContext, AttendanceQueueItemView, AttendanceStatusChange,
+      AttendanceStatusHistoryView, AttendanceUpdate, CancelAttendanceInput, EnterExamResultsInput,
+      PatientRecordEntryView, ResultEntryContext, ResultHistoryView, TransitionAttendanceInput,
+    },
+    errors::PatientRepositoryError,
+    ports::AttendanceRepository,
+  },
+  domain::users::dto::UserView,
+};
+
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
+struct StubAttendanceRepository {
+  current_status: String,
+  changes: Mutex<Vec<AttendanceStatusChange>>,
+}
+
+impl StubAttendanceRepository {
+  fn with_status(status: &str) -> Self {
+    Self {
+      current_status: status.to_string(),
+      changes: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+fn attendance(status: &str) -> AttendanceQueueItemView {
+  AttendanceQueueItemView {
+    attendance_id: "att-1".to_string(),
+    patient_id: "pt-1".to_string(),
+    patient_name: "Maria".to_string(),
+    patient_cpf: "12345678900".to_string(),
+    exam_date: "2026-02-14".to_string(),
+    status: status.to_string(),
+    exam_names: vec!["Glicose".to_string()],
+    updated_at: "2026-02-14T10:30:00".to_string(),
+    deleted_at: None,
+  }
+}
+
+#[async_trait::async_trait]
+impl AttendanceRepository for StubAttendanceRepository {
+  async fn get_attendance(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    Ok(attendance(&self.current_status))
+  }
+
+  async fn change_attendance_status(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    let status = change.to_status.clone();
+    self.changes.lock().unwrap().push(change);
+    Ok(attendance(&status))
+  }
+
+  async fn cancel_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self.change_attendance_status(change).await
+  }
+
+  async fn release_attendance(
+    &self,
+    change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    self.change_attendance_status(change).await
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    _attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn enter_exam_results(
+    &self,
+    _input: EnterExamResultsInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn amend_result(
+    &self,
+    _input: AmendResultInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_result_history(
+    &self,
+    _exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_attendance(
+    &self,
+    _update: AttendanceUpdate,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn input(to_status: &str) -> TransitionAttendanceInput {
+  TransitionAttendanceInput {
+    attendance_id: "att-1".to_string(),
+    to_status: to_status.to_string(),
+    note: None,
+  }
+}
+
+#[tokio::test]
+async fn transition_attendance_moves_to_next_status() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("collected"));
+  let use_case = TransitionAttendanceUseCase::new(repo.clone());
+
+  let result = use_case.execute(&admin(), input("in_analysis")).await;
+
+  match result {
+    Ok(item) => assert_eq!(item.status, "in_analysis"),
+    Err(_) => panic!("expected success"),
+  }
+  let changes = repo.changes.lock().unwrap();
+  assert_eq!(changes.len(), 1);
+  assert_eq!(changes[0].from_status, "collected");
+}
+
+#[tokio::test]
+async fn transition_attendance_rejects_delivering_unreleased_attendance() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("in_analysis"));
+  let use_case = TransitionAttendanceUseCase::new(repo.clone());
+
+  let result = use_case.execute(&admin(), input("delivered")).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cannot move attendance from in_analysis to delivered"
+  ));
+  assert!(repo.changes.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn transition_attendance_rejects_leaving_a_final_status() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("cancelled"));
+  let use_case = TransitionAttendanceUseCase::new(repo);
+
+  let result = use_case.execute(&admin(), input("waiting")).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cannot move attendance from cancelled to waiting"
+  ));
+}
+
+#[tokio::test]
+async fn transition_attendance_rejects_unknown_status() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("waiting"));
+  let use_case = TransitionAttendanceUseCase::new(repo);
+
+  let result = use_case.execute(&admin(), input("done")).await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "unknown attendance status: done")
+  );
+}
+
+#[tokio::test]
+async fn transition_attendance_leaves_release_to_release_attendance() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("awaiting_validation"));
+  let use_case = TransitionAttendanceUseCase::new(repo.clone());
+
+  let result = use_case.execute(&admin(), input("released")).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "to_status must not be released; use release_attendance, which signs the results"
+  ));
+  assert!(repo.changes.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn transition_attendance_leaves_cancellation_to_cancel_attendance() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("waiting"));
+  let use_case = TransitionAttendanceUseCase::new(repo.clone());
+
+  let result = use_case.execute(&admin(), input("cancelled")).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "to_status must not be cancelled; use cancel_attendance, which records the reason"
+  ));
+  assert!(repo.changes.lock().unwrap().is_empty());
+}
+
+fn cancel_input(reason: &str) -> CancelAttendanceInput {
+  CancelAttendanceInput {
+    attendance_id: "att-1".to_string(),
+    reason: reason.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn cancel_attendance_records_reason_and_user() {
+  let repo = Arc::new(StubAttendanceRepository::with_status("awaiting_validation"));
+  let use_case = CancelAttendanceUseCase::new(repo.clone());
+
+  let cancelled = use_case
+    .execute(&admin(), cancel_input("  Paciente desistiu da coleta "))
+    .await
+    .expect("expected success");
+
+  assert_eq!(cancelled.status, "cancelled");
+  let changes = repo.changes.lock().unwrap();
+  assert_eq!(changes[0].from_status, "awaiting_validation");
+  assert_eq!(changes[0].to_status, "cancelled");
+  assert_eq!(changes[0].changed_by_user_id.as_deref(), Some("usr-admin"));
+  assert_eq!(
+    changes[0].note.as_deref(),
+    Some("Paciente desistiu da coleta")
+  );
+}
+
+#[tokio::test]
+async fn cancel_attendance_requires_a_reason() {
+  let use_case =
+    CancelAttendanceUseCase::new(Arc::new(StubAttendanceRepository::with_status("waiting")));
+
+  let blank = use_case.execute(&admin(), cancel_input("   ")).await;
+  let too_long = use_case
+    .execute(&admin(), cancel_input(&"x".repeat(501)))
+    .await;
+
+  assert!(
+    matches!(blank, Err(AppError::Validation { message: msg, .. }) if msg == "reason is required")
+  );
+  assert!(matches!(
+    too_long,
+    Err(AppError::Validation { message: msg, .. }) if msg == "reason must have at most 500 characters"
+  ));
+}
+
+#[tokio::test]
+async fn cancel_attendance_refuses_released_or_cancelled_attendances() {
+  let cases = [
+    (
+      "released",
+      "attendance cannot be cancelled after its results were released",
+    ),
+    (
+      "delivered",
+      "attendance cannot be cancelled after its results were released",
+    ),
+    ("cancelled", "attendance is already cancelled"),
+  ];
+
+  for (status, expected) in cases {
+    let repo = Arc::new(StubAttendanceRepository::with_status(status));
+    let use_case = CancelAttendanceUseCase::new(repo.clone());
+
+    let result = use_case
+      .execute(&admin(), cancel_input("Pedido duplicado"))
+      .await;
+
+    assert!(
+      matches!(&result, Err(AppError::Validation { message: msg, .. }) if msg == expected),
+      "{status}: got {result:?}"
+    );
+    assert!(repo.changes.lock().unwrap().is_empty());
+  }
+}
+
+#[tokio::test]
+async fn cancel_attendance_requires_manage_permission() {
+  let use_case =
+    CancelAttendanceUseCase::new(Arc::new(StubAttendanceRepository::with_status("waiting")));
+  let bench = UserView {
+    role: "bench".to_string(),
+    ..admin()
+  };
+
+  let result = use_case
+    .execute(&bench, cancel_input("Pedido duplicado"))
+    .await;
+
+  assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
@@ -0,0 +1,616 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::update_attendance::UpdateAttendanceUseCase,
+  domain::{
+    patients::{
+      dto::{
+        AmendResultInput, AttendanceEditContext, AttendanceEditItemContext,
+        AttendanceQueueItemView, AttendanceQueuePageQuery, AttendanceStatusChange,
+        AttendanceStatusHistoryView, AttendanceUpdate, CompleteAttendanceInput,
+        CreateAttendanceInput, CreateAttendanceItemInput, CreatePatientInput,
+        EnterExamResultsInput, ExamCatalogItemView, PatientPageQuery, PatientRecordEntryView,
+        PatientRecordView, ResultEntryContext, ResultHistoryView, UpdateAttendanceInput,
+        UpdatePatientInput,
+      },
+      entity::Patient,
+      errors::PatientRepositoryError,
+      pagination::PageView,
+      ports::{AttendanceRepository, PatientRepository},
+    },
+    requesters::{
+      dto::{NewRequester, RequesterView},
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+struct StubAttendanceRepository {
+  context: AttendanceEditContext,
+  catalog: Vec<ExamCatalogItemView>,
+  updates: Mutex<Vec<AttendanceUpdate>>,
+}
+
+#[async_trait::async_trait]
+impl AttendanceRepository for StubAttendanceRepository {
+  async fn get_attendance(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn change_attendance_status(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn cancel_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn release_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    _attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn enter_exam_results(
+    &self,
+    _input: EnterExamResultsInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn amend_result(
+    &self,
+    _input: AmendResultInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_result_history(
+    &self,
+    _exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    if attendance_id == self.context.attendance_id {
+      Ok(self.context.clone())
+    } else {
+      Err(PatientRepositoryError::NotFound)
+    }
+  }
+
+  async fn update_attendance(
+    &self,
+    update: AttendanceUpdate,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    self.updates.lock().unwrap().push(update.clone());
+    Ok(PatientRecordEntryView {
+      exam_id: update.attendance_id,
+      exam_date: "2026-03-10".to_string(),
+      status: update.expected_status,
+      requester_name: None,
+      items: Vec::new(),
+      deleted_at: None,
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl PatientRepository for StubAttendanceRepository {
+  async fn insert(
+    &self,
+    _input: CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list(
+    &self,
+    _query: PatientPageQuery,
+  ) -> Result<PageView<Patient>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_patient_record(
+    &self,
+    _patient_id: String,
+    _include_deleted: bool,
+  ) -> Result<PatientRecordView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_exam_catalog(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+    Ok(self.catalog.clone())
+  }
+
+  async fn create_attendance(
+    &self,
+    _input: CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_queue(
+    &self,
+    _input: AttendanceQueuePageQuery,
+  ) -> Result<PageView<AttendanceQueueItemView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn complete_attendance(
+    &self,
+    _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct StubRequesterRepository {
+  requesters: Vec<RequesterView>,
+}
+
+#[async_trait::async_trait]
+impl RequesterRepository for StubRequesterRepository {
+  async fn list_requesters(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_requester(
+    &self,
+    requester_id: String,
+  ) -> Result<Option<RequesterView>, RequesterRepositoryError> {
+    Ok(
+      self
+        .requesters
+        .iter()
+        .find(|r| r.id == requester_id)
+        .cloned(),
+    )
+  }
+
+  async fn insert_requester(
+    &self,
+    _requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_requester(
+    &self,
+    _requester_id: String,
+    _requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn set_requester_active(
+    &self,
+    _requester_id: String,
+    _is_active: bool,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn actor(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Rita Recepcao".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "rita".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn catalog_item(id: &str, name: &str) -> ExamCatalogItemView {
+  ExamCatalogItemView {
+    id: id.to_string(),
+    name: name.to_string(),
+    category_id: "bioquimica".to_string(),
+    category_title: "Bioquimica".to_string(),
+    price_cents: 1500,
+    unit: None,
+    method: None,
+    reference_range: None,
+    is_active: true,
+    analyte_ids: vec![],
+  }
+}
+
+fn requester(id: &str, is_active: bool) -> RequesterView {
+  RequesterView {
+    id: id.to_string(),
+    name: "Dra. Ana Lima".to_string(),
+    council_type: None,
+    council_number: None,
+    council_state: None,
+    is_active,
+    created_at: "2026-01-01 00:00:00".to_string(),
+    updated_at: "2026-01-01 00:00:00".to_string(),
+  }
+}
+
+fn exam_item(
+  exam_item_id: &str,
+  catalog_item_id: &str,
+  has_result: bool,
+) -> AttendanceEditItemContext {
+  AttendanceEditItemContext {
+    exam_item_id: exam_item_id.to_string(),
+    catalog_item_id: Some(catalog_item_id.to_string()),
+    panel_id: None,
+    has_result,
+    has_pending_critical_alert: false,
+  }
+}
+
+/// `att-1` was requested by `rq-2`, deactivated since.
+fn setup(
+  status: &str,
+  items: Vec<AttendanceEditItemContext>,
+) -> (Arc<StubAttendanceRepository>, UpdateAttendanceUseCase) {
+  let repo = Arc::new(StubAttendanceRepository {
+    context: AttendanceEditContext {
+      attendance_id: "att-1".to_string(),
+      status: status.to_string(),
+      requester_id: Some("rq-2".to_string()),
+      items,
+    },
+    catalog: vec![
+      catalog_item("cat-glicose", "Glicose"),
+      catalog_item("cat-urico", "Ácido Úrico"),
+      catalog_item("cat-hemograma", "Hemograma Completo"),
+      ExamCatalogItemView {
+        analyte_ids: vec!["cat-ureia".to_string(), "cat-creatinina".to_string()],
+        ..catalog_item("cat-bioq-2", "Bioquimica 2 (Ureia/Creatinina)")
+      },
+    ],
+    updates: Mutex::new(Vec::new()),
+  });
+  let requesters = Arc::new(StubRequesterRepository {
+    requesters: vec![requester("rq-1", true), requester("rq-2", false)],
+  });
+  (
+    repo.clone(),
+    UpdateAttendanceUseCase::new(repo.clone(), repo, requesters),
+  )
+}
+
+fn open_attendance() -> (Arc<StubAttendanceRepository>, UpdateAttendanceUseCase) {
+  setup(
+    "collected",
+    vec![
+      exam_item("it-1", "cat-glicose", false),
+      exam_item("it-2", "cat-urico", false),
+    ],
+  )
+}
+
+fn input() -> UpdateAttendanceInput {
+  UpdateAttendanceInput {
+    attendance_id: "att-1".to_string(),
+    requester_id: Some("rq-2".to_string()),
+    procedure_type: None,
+    delivered_to: None,
+    notes: None,
+    add_items: Vec::new(),
+    remove_item_ids: Vec::new(),
+  }
+}
+
+fn item_named(name: &str) -> CreateAttendanceItemInput {
+  CreateAttendanceItemInput {
+    catalog_item_id: None,
+    name: name.to_string(),
+    unit: None,
+    method: None,
+    reference_range: None,
+  }
+}
+
+async fn expect_validation(
+  use_case: &UpdateAttendanceUseCase,
+  input: UpdateAttendanceInput,
+  expected: &str,
+) {
+  let result = use_case.execute(&actor("reception"), input).await;
+  assert!(
+    matches!(&result, Err(AppError::Validation { message: msg, .. }) if msg == expected),
+    "expected {expected:?}, got {result:?}"
+  );
+}
+
+#[tokio::test]
+async fn update_attendance_adds_and_removes_items_before_results() {
+  let (repo, use_case) = open_attendance();
+
+  use_case
+    .execute(
+      &actor("reception"),
+      UpdateAttendanceInput {
+        attendance_id: " att-1 ".to_string(),
+        notes: Some("Paciente em jejum".to_string()),
+        add_items: vec![item_named("hemograma completo"), item_named("acido urico")],
+        remove_item_ids: vec!["it-2".to_string()],
+        ..input()
+      },
+    )
+    .await
+    .expect("expected success");
+
+  let updates = repo.updates.lock().unwrap();
+  let update = &updates[0];
+  assert_eq!(update.attendance_id, "att-1");
+  assert_eq!(update.expected_status, "collected");
+  assert_eq!(update.notes.as_deref(), Some("Paciente em jejum"));
+  assert_eq!(update.remove_item_ids, vec!["it-2"]);
+  let added: Vec<Option<&str>> = update
+    .add_items
+    .iter()
+    .map(|item| item.catalog_item_id.as_deref())
+    .collect();
+  assert_eq!(added, vec![Some("cat-hemograma"), Some("cat-urico")]);
+}
+
+#[tokio::test]
+async fn update_attendance_rejects_exam_already_in_attendance() {
+  let (_, use_case) = open_attendance();
+
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      add_items: vec![item_named("GLICOSE")],
+      ..input()
+    },
+    "add_items[0]: Glicose is already requested in this attendance",
+  )
+  .await;
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      add_items: vec![
+        item_named("Hemograma Completo"),
+        item_named("hemograma completo"),
+      ],
+      ..input()
+    },
+    "add_items[1]: Hemograma Completo is already requested in add_items[0]",
+  )
+  .await;
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      add_items: vec![item_named("Sorologia")],
+      ..input()
+    },
+    "add_items[0]: Sorologia does not match any active catalog entry",
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn update_attendance_validates_removals() {
+  let (_, use_case) = open_attendance();
+
+  let cases = [
+    (
+      vec!["it-9"],
+      "remove_item_ids[0]: it-9 does not belong to attendance",
+    ),
+    (vec!["it-1", "it-1"], "remove_item_ids[1]: it-1 is repeated"),
+    (vec!["it-1", "it-2"], "items must keep at least one exam"),
+  ];
+  for (remove_item_ids, expected) in cases {
+    expect_validation(
+      &use_case,
+      UpdateAttendanceInput {
+        remove_item_ids: remove_item_ids.into_iter().map(str::to_string).collect(),
+        ..input()
+      },
+      expected,
+    )
+    .await;
+  }
+}
+
+#[tokio::test]
+async fn update_attendance_keeps_panel_analytes_together() {
+  let analyte = |exam_item_id: &str, catalog_item_id: &str| AttendanceEditItemContext {
+    panel_id: Some("cat-bioq-2".to_string()),
+    ..exam_item(exam_item_id, catalog_item_id, false)
+  };
+  let (repo, use_case) = setup(
+    "collected",
+    vec![
+      exam_item("it-1", "cat-glicose", false),
+      analyte("it-2", "cat-ureia"),
+      analyte("it-3", "cat-creatinina"),
+    ],
+  );
+  let removing = |ids: &[&str]| UpdateAttendanceInput {
+    remove_item_ids: ids.iter().map(|id| id.to_string()).collect(),
+    ..input()
+  };
+
+  expect_validation(
+    &use_case,
+    removing(&["it-1", "it-3"]),
+    "remove_item_ids[1]: it-3 is part of a panel; remove all of its analytes",
+  )
+  .await;
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      add_items: vec![item_named("bioquimica 2 (ureia/creatinina)")],
+      ..input()
+    },
+    "add_items[0]: Bioquimica 2 (Ureia/Creatinina) is already requested in this attendance",
+  )
+  .await;
+
+  use_case
+    .execute(&actor("reception"), removing(&["it-3", "it-2"]))
+    .await
+    .expect("the whole panel can be removed");
+  assert_eq!(
+    repo.updates.lock().unwrap()[0].remove_item_ids,
+    vec!["it-3", "it-2"]
+  );
+}
+
+#[tokio::test]
+async fn update_attendance_freezes_items_once_a_result_was_entered() {
+  let (repo, use_case) = setup(
+    "in_analysis",
+    vec![
+      exam_item("it-1", "cat-glicose", true),
+      exam_item("it-2", "cat-urico", false),
+    ],
+  );
+
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      remove_item_ids: vec!["it-2".to_string()],
+      ..input()
+    },
+    "items cannot change once a result has been entered",
+  )
+  .await;
+
+  use_case
+    .execute(
+      &actor("reception"),
+      UpdateAttendanceInput {
+        delivered_to: Some("Joao (filho)".to_string()),
+        ..input()
+      },
+    )
+    .await
+    .expect("fields other than items stay editable");
+  assert_eq!(repo.updates.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn update_attendance_rejects_closed_attendance() {
+  let (_, use_case) = setup("released", vec![exam_item("it-1", "cat-glicose", true)]);
+
+  expect_validation(
+    &use_case,
+    input(),
+    "attendance cannot be edited while released",
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn update_attendance_checks_only_a_new_requester() {
+  let (repo, use_case) = open_attendance();
+
+  use_case
+    .execute(&actor("reception"), input())
+    .await
+    .expect("keeping the inactive requester is allowed");
+  use_case
+    .execute(
+      &actor("reception"),
+      UpdateAttendanceInput {
+        requester_id: Some("  ".to_string()),
+        ..input()
+      },
+    )
+    .await
+    .expect("blank requester clears it");
+  assert_eq!(repo.updates.lock().unwrap()[1].requester_id, None);
+
+  expect_validation(
+    &use_case,
+    UpdateAttendanceInput {
+      requester_id: Some("rq-404".to_string()),
+      ..input()
+    },
+    "requester_id does not match any requester",
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn update_attendance_reports_unknown_attendance_and_permission() {
+  let (_, use_case) = open_attendance();
+
+  let missing = use_case
+    .execute(
+      &actor("reception"),
+      UpdateAttendanceInput {
+        attendance_id: "att-404".to_string(),
+        ..input()
+      },
+    )
+    .await;
+  assert!(matches!(missing, Err(AppError::NotFound(msg)) if msg == "attendance not found"));
+
+  let forbidden = use_case.execute(&actor("bench"), input()).await;
+  assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+}
@@ -0,0 +1,330 @@
+use laboratory_app_lib::{
+  domain::{
+    audit::{dto::ListAuditLogInput, hash_chain::verify_chain, ports::AuditRepository},
+    patients::{
+      dto::{
+        CreateAttendanceInput, CreateAttendanceItemInput, CreatePatientInput,
+        EnterExamResultsInput, ExamItemResultInput,
+      },
+      errors::PatientRepositoryError,
+      ports::{AttendanceRepository, PatientRepository},
+    },
+    users::{dto::NewUser, ports::UserRepository},
+  },
+  infra::{
+    db::sqlite::run_migrations,
+    repositories::{
+      audit_sqlite::{seal_legacy_audit_entries, AuditSqliteRepository},
+      patients_sqlite::PatientsSqliteRepository,
+      users_sqlite::UsersSqliteRepository,
+    },
+  },
+};
+use serde_json::Value;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+}
+
+async fn seed_user(pool: &SqlitePool) -> String {
+  UsersSqliteRepository::new(pool.clone())
+    .insert_first_user(NewUser {
+      name: "Ana Admin".to_string(),
+      cpf: "00000000000".to_string(),
+      username: "ana".to_string(),
+      password_hash: "$argon2id$stub".to_string(),
+      role: "admin".to_string(),
+    })
+    .await
+    .expect("first user should be created")
+    .id
+}
+
+fn patient_input(cpf: &str) -> CreatePatientInput {
+  CreatePatientInput {
+    full_name: "Maria Silva".to_string(),
+    cpf: cpf.to_string(),
+    birth_date: "1990-01-01".to_string(),
+    sex: "F".to_string(),
+    phone: "11999999999".to_string(),
+    address: "Rua A".to_string(),
+  }
+}
+
+fn filter() -> ListAuditLogInput {
+  ListAuditLogInput {
+    limit: Some(100),
+    ..ListAuditLogInput::default()
+  }
+}
+
+fn json(value: &Option<String>) -> Value {
+  serde_json::from_str(value.as_deref().expect("snapshot")).expect("valid json")
+}
+
+#[tokio::test]
+async fn writes_are_audited_with_user_and_snapshots() {
+  let pool = setup_pool().await;
+  let user_id = seed_user(&pool).await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let audit = AuditSqliteRepository::new(pool);
+
+  let patient = repo
+    .insert(patient_input("12345678900"), Some(user_id.clone()))
+    .await
+    .expect("patient should be created");
+  let entry = repo
+    .create_attendance(
+      CreateAttendanceInput {
+        patient_id: patient.id.clone(),
+        exam_date: "2026-02-14".to_string(),
+        requester_id: None,
+        status: None,
+        procedure_type: None,
+        delivered_to: None,
+        notes: None,
+        items: vec![CreateAttendanceItemInput {
+          catalog_item_id: None,
+          name: "Glicose".to_string(),
+          unit: Some("mg/dL".to_string()),
+          method: None,
+          reference_range: None,
+        }],
+      },
+      Some(user_id.clone()),
+    )
+    .await
+    .expect("attendance should be created");
+  let item_id = entry.items[0].exam_item_id.clone();
+  for value in ["92", "95"] {
+    repo
+      .enter_exam_results(
+        EnterExamResultsInput {
+          attendance_id: entry.exam_id.clone(),
+          items: vec![ExamItemResultInput {
+            exam_item_id: item_id.clone(),
+            result_value: value.to_string(),
+            result_flag: None,
+          }],
+        },
+        Some(user_id.clone()),
+      )
+      .await
+      .expect("results should be saved");
+  }
+
+  let patient_rows = audit
+    .list_audit_log(ListAuditLogInput {
+      entity_name: Some("patients".to_string()),
+      entity_id: Some(patient.id.clone()),
+      ..filter()
+    })
+    .await
+    .expect("audit should load");
+  assert_eq!(patient_rows.len(), 1);
+  assert_eq!(patient_rows[0].action, "create");
+  assert_eq!(
+    patient_rows[0].performed_by_user_id.as_deref(),
+    Some(user_id.as_str())
+  );
+  assert!(patient_rows[0].before_json.is_none());
+  assert_eq!(json(&patient_rows[0].after_json)["cpf"], "12345678900");
+
+  let result_rows = audit
+    .list_audit_log(ListAuditLogInput {
+      entity_name: Some("exam_items".to_string()),
+      entity_id: Some(item_id),
+      ..filter()
+    })
+    .await
+    .expect("audit should load");
+  assert_eq!(result_rows.len(), 2);
+  // Newest first: the correction from 92 to 95.
+  assert_eq!(json(&result_rows[0].before_json)["result_value"], "92");
+  assert_eq!(json(&result_rows[0].after_json)["result_value"], "95");
+  assert_eq!(
+    json(&result_rows[1].before_json)["result_value"],
+    Value::Null
+  );
+
+  let by_user = audit
+    .list_audit_log(ListAuditLogInput {
+      performed_by_user_id: Some(user_id),
+      ..filter()
+    })
+    .await
+    .expect("audit should load");
+  // first user, patient, attendance and two result entries
+  assert_eq!(by_user.len(), 5);
+}
+
+#[tokio::test]
+async fn failed_write_leaves_no_audit_row() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let audit = AuditSqliteRepository::new(pool);
+
+  repo
+    .insert(patient_input("12345678900"), None)
+    .await
+    .expect("patient should be created");
+  let duplicate = repo.insert(patient_input("12345678900"), None).await;
+  let missing_item = repo
+    .enter_exam_results(
+      EnterExamResultsInput {
+        attendance_id: "att-x".to_string(),
+        items: vec![ExamItemResultInput {
+          exam_item_id: "it-x".to_string(),
+          result_value: "1".to_string(),
+          result_flag: None,
+        }],
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(duplicate, Err(PatientRepositoryError::Conflict)));
+  assert!(matches!(
+    missing_item,
+    Err(PatientRepositoryError::NotFound)
+  ));
+  let rows = audit
+    .list_audit_log(filter())
+    .await
+    .expect("audit should load");
+  assert_eq!(rows.len(), 1);
+}
+
+#[tokio::test]
+async fn list_audit_log_filters_by_date_range() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let audit = AuditSqliteRepository::new(pool.clone());
+  repo
+    .insert(patient_input("12345678900"), None)
+    .await
+    .expect("patient should be created");
+  sqlx::query("UPDATE audit_log SET performed_at = '2026-01-10 08:00:00'")
+    .execute(&pool)
+    .await
+    .expect("backdate audit row");
+
+  let inside = audit
+    .list_audit_log(ListAuditLogInput {
+      from_date: Some("2026-01-10".to_string()),
+      to_date: Some("2026-01-10".to_string()),
+      ..filter()
+    })
+    .await
+    .expect("audit should load");
+  let after = audit
+    .list_audit_log(ListAuditLogInput {
+      from_date: Some("2026-01-11".to_string()),
+      ..filter()
+    })
+    .await
+    .expect("audit should load");
+
+  assert_eq!(inside.len(), 1);
+  assert!(after.is_empty());
+}
+
+#[tokio::test]
+async fn audit_entries_form_a_hash_chain() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let audit = AuditSqliteRepository::new(pool);
+  for cpf in ["12345678900", "98765432100", "11122233344"] {
+    repo
+      .insert(patient_input(cpf), None)
+      .await
+      .expect("patient should be created");
+  }
+
+  let chain = audit.list_audit_chain().await.expect("chain should load");
+  let report = verify_chain(&chain);
+
+  assert_eq!(
+    chain.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+    vec![Some(1), Some(2), Some(3)]
+  );
+  assert!(chain[0].prev_hash.is_none());
+  assert_eq!(chain[1].prev_hash, chain[0].entry_hash);
+  assert!(report.is_valid);
+  assert_eq!(report.last_entry_hash, chain[2].entry_hash);
+}
+
+#[tokio::test]
+async fn tampering_with_audit_log_breaks_the_chain() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let audit = AuditSqliteRepository::new(pool.clone());
+  for cpf in ["12345678900", "98765432100", "11122233344"] {
+    repo
+      .insert(patient_input(cpf), None)
+      .await
+      .expect("patient should be created");
+  }
+
+  sqlx::query("UPDATE audit_log SET after_json = '{}' WHERE sequence = 2")
+    .execute(&pool)
+    .await
+    .expect("tamper audit row");
+  let edited = verify_chain(&audit.list_audit_chain().await.expect("chain should load"));
+
+  sqlx::query("DELETE FROM audit_log WHERE sequence = 2")
+    .execute(&pool)
+    .await
+    .expect("delete audit row");
+  let deleted = verify_chain(&audit.list_audit_chain().await.expect("chain should load"));
+
+  assert!(!edited.is_valid);
+  assert_eq!(edited.first_broken_sequence, Some(2));
+  assert!(!deleted.is_valid);
+  assert_eq!(deleted.first_broken_sequence, Some(3));
+}
+
+#[tokio::test]
+async fn legacy_audit_rows_are_sealed_once() {
+  let pool = setup_pool().await;
+  let audit = AuditSqliteRepository::new(pool.clone());
+  for (entity_id, performed_at) in [
+    ("pt-2", "2026-01-11 08:00:00"),
+    ("pt-1", "2026-01-10 08:00:00"),
+  ] {
+    sqlx::query(
+      "INSERT INTO audit_log (entity_name, entity_id, action, performed_at) VALUES ('patients', ?1, 'create', ?2)",
+    )
+    .bind(entity_id)
+    .bind(performed_at)
+    .execute(&pool)
+    .await
+    .expect("insert legacy row");
+  }
+
+  let unsealed = verify_chain(&audit.list_audit_chain().await.expect("chain should load"));
+  let sealed = seal_legacy_audit_entries(&pool)
+    .await
+    .expect("seal should run");
+  let resealed = seal_legacy_audit_entries(&pool)
+    .await
+    .expect("seal should run");
+  let chain = audit.list_audit_chain().await.expect("chain should load");
+
+  assert_eq!(unsealed.reason.as_deref(), Some("entry is not sealed"));
+  assert_eq!(sealed, 2);
+  assert_eq!(resealed, 0);
+  assert_eq!(chain[0].entity_id, "pt-1");
+  assert!(verify_chain(&chain).is_valid);
+}
@@ -0,0 +1,221 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::audit::{
+    list_audit_log::ListAuditLogUseCase, verify_audit_chain::VerifyAuditChainUseCase,
+  },
+  domain::{
+    audit::{
+      dto::{AuditChainEntry, AuditEntryView, ListAuditLogInput},
+      errors::AuditRepositoryError,
+      hash_chain::compute_entry_hash,
+      ports::AuditRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+#[derive(Default)]
+struct StubAuditRepository {
+  queries: Mutex<Vec<ListAuditLogInput>>,
+  chain: Vec<AuditChainEntry>,
+}
+
+#[async_trait::async_trait]
+impl AuditRepository for StubAuditRepository {
+  async fn list_audit_log(
+    &self,
+    input: ListAuditLogInput,
+  ) -> Result<Vec<AuditEntryView>, AuditRepositoryError> {
+    self.queries.lock().unwrap().push(input);
+    Ok(Vec::new())
+  }
+
+  async fn list_audit_chain(&self) -> Result<Vec<AuditChainEntry>, AuditRepositoryError> {
+    Ok(self.chain.clone())
+  }
+}
+
+fn user(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+#[tokio::test]
+async fn list_audit_log_normalizes_filters_and_defaults_limit() {
+  let repo = Arc::new(StubAuditRepository::default());
+  let use_case = ListAuditLogUseCase::new(repo.clone());
+
+  use_case
+    .execute(
+      &user("biochemist"),
+      ListAuditLogInput {
+        entity_name: Some(" exam_items ".to_string()),
+        entity_id: Some("  ".to_string()),
+        from_date: Some("2026-02-01".to_string()),
+        to_date: Some("2026-02-28".to_string()),
+        ..ListAuditLogInput::default()
+      },
+    )
+    .await
+    .expect("expected success");
+
+  let queries = repo.queries.lock().unwrap();
+  assert_eq!(queries[0].entity_name.as_deref(), Some("exam_items"));
+  assert!(queries[0].entity_id.is_none());
+  assert_eq!(queries[0].limit, Some(100));
+}
+
+#[tokio::test]
+async fn list_audit_log_validates_dates_and_limit() {
+  let use_case = ListAuditLogUseCase::new(Arc::new(StubAuditRepository::default()));
+
+  let bad_date = use_case
+    .execute(
+      &user("admin"),
+      ListAuditLogInput {
+        from_date: Some("01/02/2026".to_string()),
+        ..ListAuditLogInput::default()
+      },
+    )
+    .await;
+  let inverted = use_case
+    .execute(
+      &user("admin"),
+      ListAuditLogInput {
+        from_date: Some("2026-03-01".to_string()),
+        to_date: Some("2026-02-01".to_string()),
+        ..ListAuditLogInput::default()
+      },
+    )
+    .await;
+  let huge_limit = use_case
+    .execute(
+      &user("admin"),
+      ListAuditLogInput {
+        limit: Some(10_000),
+        ..ListAuditLogInput::default()
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(bad_date, Err(AppError::Validation { message: msg, .. }) if msg == "from_date must be YYYY-MM-DD")
+  );
+  assert!(matches!(
+    inverted,
+    Err(AppError::Validation { message: msg, .. }) if msg == "from_date must not be after to_date"
+  ));
+  assert!(matches!(
+    huge_limit,
+    Err(AppError::Validation { message: msg, .. }) if msg == "limit must be between 1 and 500"
+  ));
+}
+
+#[tokio::test]
+async fn list_audit_log_is_not_open_to_bench() {
+  let repo = Arc::new(StubAuditRepository::default());
+  let use_case = ListAuditLogUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(&user("bench"), ListAuditLogInput::default())
+    .await;
+
+  assert!(matches!(result, Err(AppError::Forbidden(_))));
+  assert!(repo.queries.lock().unwrap().is_empty());
+}
+
+fn sealed_chain(len: i64) -> Vec<AuditChainEntry> {
+  let mut chain: Vec<AuditChainEntry> = Vec::new();
+  for sequence in 1..=len {
+    let mut entry = AuditChainEntry {
+      id: format!("aud-{sequence}"),
+      sequence: Some(sequence),
+      entity_name: "patients".to_string(),
+      entity_id: format!("pt-{sequence}"),
+      action: "create".to_string(),
+      performed_by_user_id: Some("usr-1".to_string()),
+      performed_at: "2026-02-14 10:00:00".to_string(),
+      before_json: None,
+      after_json: Some(format!(r#"{{"id":"pt-{sequence}"}}"#)),
+      prev_hash: chain.last().and_then(|prev| prev.entry_hash.clone()),
+      entry_hash: None,
+    };
+    entry.entry_hash = Some(compute_entry_hash(&entry));
+    chain.push(entry);
+  }
+  chain
+}
+
+#[tokio::test]
+async fn verify_audit_chain_accepts_an_intact_chain() {
+  let chain = sealed_chain(3);
+  let use_case = VerifyAuditChainUseCase::new(Arc::new(StubAuditRepository {
+    chain: chain.clone(),
+    ..StubAuditRepository::default()
+  }));
+
+  let report = use_case
+    .execute(&user("biochemist"))
+    .await
+    .expect("expected success");
+
+  assert!(report.is_valid);
+  assert_eq!(report.checked_entries, 3);
+  assert_eq!(report.last_entry_hash, chain[2].entry_hash);
+  assert!(report.reason.is_none());
+}
+
+#[tokio::test]
+async fn verify_audit_chain_reports_first_broken_link() {
+  let mut edited = sealed_chain(3);
+  edited[1].after_json = Some(r#"{"id":"pt-x"}"#.to_string());
+  let mut gap = sealed_chain(3);
+  gap.remove(1);
+
+  let edited_report = VerifyAuditChainUseCase::new(Arc::new(StubAuditRepository {
+    chain: edited,
+    ..StubAuditRepository::default()
+  }))
+  .execute(&user("admin"))
+  .await
+  .expect("expected success");
+  let gap_report = VerifyAuditChainUseCase::new(Arc::new(StubAuditRepository {
+    chain: gap,
+    ..StubAuditRepository::default()
+  }))
+  .execute(&user("admin"))
+  .await
+  .expect("expected success");
+
+  assert!(!edited_report.is_valid);
+  assert_eq!(edited_report.checked_entries, 1);
+  assert_eq!(edited_report.first_broken_sequence, Some(2));
+  assert_eq!(
+    edited_report.reason.as_deref(),
+    Some("entry_hash does not match the entry contents")
+  );
+  assert!(!gap_report.is_valid);
+  assert_eq!(gap_report.checked_entries, 1);
+  assert_eq!(gap_report.first_broken_entry_id.as_deref(), Some("aud-3"));
+  assert_eq!(
+    gap_report.reason.as_deref(),
+    Some("expected sequence 2; an entry is missing or out of order")
+  );
+}
+
+#[tokio::test]
+async fn verify_audit_chain_is_not_open_to_reception() {
+  let use_case = VerifyAuditChainUseCase::new(Arc::new(StubAuditRepository::default()));
+
+  let result = use_case.execute(&user("reception")).await;
+
+  assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
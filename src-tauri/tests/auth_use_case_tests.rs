@@ -0,0 +1,425 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::{error::AppError, session::Session},
+  application::users::{
+    create_initial_admin::CreateInitialAdminUseCase, create_user::CreateUserUseCase,
+    list_users::ListUsersUseCase, login::LoginUseCase,
+    needs_initial_setup::NeedsInitialSetupUseCase, set_user_active::SetUserActiveUseCase,
+  },
+  domain::users::{
+    dto::{
+      CreateInitialAdminInput, CreateUserInput, LoginInput, NewUser, SetUserActiveInput,
+      UserCredentials, UserView,
+    },
+    errors::{PasswordHashError, UserRepositoryError},
+    ports::{PasswordHasher, UserRepository},
+  },
+};
+
+#[derive(Default)]
+struct StubUserRepository {
+  users: Mutex<Vec<UserCredentials>>,
+}
+
+impl StubUserRepository {
+  fn with_user(username: &str, password: &str, is_active: bool) -> Self {
+    let repo = Self::default();
+    repo.users.lock().unwrap().push(UserCredentials {
+      user: UserView {
+        id: "usr-1".to_string(),
+        name: "Ana Admin".to_string(),
+        cpf: "12345678900".to_string(),
+        username: username.to_string(),
+        role: "admin".to_string(),
+        is_active,
+      },
+      password_hash: format!("hashed:{password}"),
+    });
+    repo
+  }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for StubUserRepository {
+  async fn find_credentials_by_username(
+    &self,
+    username: String,
+  ) -> Result<Option<UserCredentials>, UserRepositoryError> {
+    Ok(
+      self
+        .users
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|c| c.user.username == username)
+        .cloned(),
+    )
+  }
+
+  async fn count_users(&self) -> Result<i64, UserRepositoryError> {
+    Ok(self.users.lock().unwrap().len() as i64)
+  }
+
+  async fn insert_first_user(&self, user: NewUser) -> Result<UserView, UserRepositoryError> {
+    let mut users = self.users.lock().unwrap();
+    if !users.is_empty() {
+      return Err(UserRepositoryError::Conflict);
+    }
+    let view = UserView {
+      id: "usr-1".to_string(),
+      name: user.name,
+      cpf: user.cpf,
+      username: user.username,
+      role: user.role,
+      is_active: true,
+    };
+    users.push(UserCredentials {
+      user: view.clone(),
+      password_hash: user.password_hash,
+    });
+    Ok(view)
+  }
+
+  async fn list_users(&self) -> Result<Vec<UserView>, UserRepositoryError> {
+    Ok(
+      self
+        .users
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|c| c.user.clone())
+        .collect(),
+    )
+  }
+
+  async fn insert_user(
+    &self,
+    user: NewUser,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    let mut users = self.users.lock().unwrap();
+    if users.iter().any(|c| c.user.username == user.username) {
+      return Err(UserRepositoryError::Conflict);
+    }
+    let view = UserView {
+      id: format!("usr-{}", users.len() + 1),
+      name: user.name,
+      cpf: user.cpf,
+      username: user.username,
+      role: user.role,
+      is_active: true,
+    };
+    users.push(UserCredentials {
+      user: view.clone(),
+      password_hash: user.password_hash,
+    });
+    Ok(view)
+  }
+
+  async fn set_user_active(
+    &self,
+    user_id: String,
+    is_active: bool,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<UserView, UserRepositoryError> {
+    let mut users = self.users.lock().unwrap();
+    let credentials = users
+      .iter_mut()
+      .find(|c| c.user.id == user_id)
+      .ok_or(UserRepositoryError::NotFound)?;
+    credentials.user.is_active = is_active;
+    Ok(credentials.user.clone())
+  }
+}
+
+struct StubHasher;
+
+impl PasswordHasher for StubHasher {
+  fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+    Ok(format!("hashed:{password}"))
+  }
+
+  fn verify(&self, password: &str, password_hash: &str) -> bool {
+    password_hash == format!("hashed:{password}")
+  }
+}
+
+fn actor(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana Admin".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn new_user_input(username: &str, role: &str) -> CreateUserInput {
+  CreateUserInput {
+    name: "Bia Bancada".to_string(),
+    cpf: "98765432100".to_string(),
+    username: username.to_string(),
+    password: "bancada-123".to_string(),
+    role: role.to_string(),
+  }
+}
+
+fn login_input(username: &str, password: &str) -> LoginInput {
+  LoginInput {
+    username: username.to_string(),
+    password: password.to_string(),
+  }
+}
+
+fn admin_input(password: &str) -> CreateInitialAdminInput {
+  CreateInitialAdminInput {
+    name: " Ana Admin ".to_string(),
+    cpf: "12345678900".to_string(),
+    username: " Ana.Admin ".to_string(),
+    password: password.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn login_accepts_valid_credentials_case_insensitively() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  let use_case = LoginUseCase::new(repo, Arc::new(StubHasher));
+
+  let user = use_case
+    .execute(login_input(" ANA ", "s3cret-pass"))
+    .await
+    .expect("expected success");
+
+  assert_eq!(user.username, "ana");
+}
+
+#[tokio::test]
+async fn login_answers_unknown_user_and_wrong_password_alike() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  let use_case = LoginUseCase::new(repo, Arc::new(StubHasher));
+
+  let wrong_password = use_case.execute(login_input("ana", "nope")).await;
+  let unknown_user = use_case.execute(login_input("bia", "s3cret-pass")).await;
+
+  for result in [wrong_password, unknown_user] {
+    assert!(
+      matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "invalid username or password")
+    );
+  }
+}
+
+#[tokio::test]
+async fn login_refuses_inactive_user() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", false));
+  let use_case = LoginUseCase::new(repo, Arc::new(StubHasher));
+
+  let result = use_case.execute(login_input("ana", "s3cret-pass")).await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "user is inactive")
+  );
+}
+
+#[tokio::test]
+async fn initial_setup_creates_admin_once() {
+  let repo = Arc::new(StubUserRepository::default());
+  let needs_setup = NeedsInitialSetupUseCase::new(repo.clone());
+  let create_admin = CreateInitialAdminUseCase::new(repo.clone(), Arc::new(StubHasher));
+
+  assert!(needs_setup.execute().await.expect("count users"));
+
+  let admin = create_admin
+    .execute(admin_input("s3cret-pass"))
+    .await
+    .expect("expected success");
+
+  assert_eq!(admin.username, "ana.admin");
+  assert_eq!(admin.name, "Ana Admin");
+  assert_eq!(admin.role, "admin");
+  assert_eq!(
+    repo.users.lock().unwrap()[0].password_hash,
+    "hashed:s3cret-pass"
+  );
+  assert!(!needs_setup.execute().await.expect("count users"));
+
+  let again = create_admin.execute(admin_input("s3cret-pass")).await;
+  assert!(
+    matches!(again, Err(AppError::Conflict(msg)) if msg == "initial setup was already completed")
+  );
+}
+
+#[tokio::test]
+async fn initial_admin_requires_strong_enough_password_and_clean_username() {
+  let use_case = CreateInitialAdminUseCase::new(
+    Arc::new(StubUserRepository::default()),
+    Arc::new(StubHasher),
+  );
+
+  let short = use_case.execute(admin_input("1234567")).await;
+  let bad_username = use_case
+    .execute(CreateInitialAdminInput {
+      username: "ana admin".to_string(),
+      ..admin_input("s3cret-pass")
+    })
+    .await;
+
+  assert!(matches!(
+    short,
+    Err(AppError::Validation { message: msg, .. }) if msg == "password must have at least 8 characters"
+  ));
+  assert!(matches!(
+    bad_username,
+    Err(AppError::Validation { message: msg, .. }) if msg == "username may only contain letters, digits, '.', '_' and '-'"
+  ));
+}
+
+#[test]
+fn session_tracks_current_user() {
+  let session = Session::new();
+  assert!(session.current_user().is_none());
+
+  let user = StubUserRepository::with_user("ana", "x", true)
+    .users
+    .lock()
+    .unwrap()[0]
+    .user
+    .clone();
+  session.start(user.clone());
+  assert_eq!(session.current_user(), Some(user));
+
+  session.end();
+  assert!(session.current_user().is_none());
+  assert!(matches!(
+    session.require_user(),
+    Err(AppError::Forbidden(msg)) if msg == "login required"
+  ));
+}
+
+#[test]
+fn session_refresh_applies_changes_to_the_logged_in_user_only() {
+  let session = Session::new();
+  let user = StubUserRepository::with_user("ana", "x", true)
+    .users
+    .lock()
+    .unwrap()[0]
+    .user
+    .clone();
+  session.start(user.clone());
+
+  session.refresh(&UserView {
+    id: "usr-other".to_string(),
+    role: "admin".to_string(),
+    ..user.clone()
+  });
+  assert_eq!(session.current_user(), Some(user.clone()));
+
+  let deactivated = UserView {
+    is_active: false,
+    ..user.clone()
+  };
+  session.refresh(&deactivated);
+  assert_eq!(session.require_user().ok(), Some(deactivated));
+
+  session.end();
+  session.refresh(&user);
+  assert!(session.current_user().is_none());
+}
+
+#[tokio::test]
+async fn admin_creates_and_lists_users() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  let create_user = CreateUserUseCase::new(repo.clone(), Arc::new(StubHasher));
+  let list_users = ListUsersUseCase::new(repo.clone());
+
+  let created = create_user
+    .execute(&actor("admin"), new_user_input(" Bia ", "Bench"))
+    .await
+    .expect("expected success");
+
+  assert_eq!(created.username, "bia");
+  assert_eq!(created.role, "bench");
+  assert_eq!(
+    repo.users.lock().unwrap()[1].password_hash,
+    "hashed:bancada-123"
+  );
+  let users = list_users
+    .execute(&actor("admin"))
+    .await
+    .expect("list users");
+  assert_eq!(users.len(), 2);
+}
+
+#[tokio::test]
+async fn create_user_rejects_unknown_role_and_duplicate_username() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  let use_case = CreateUserUseCase::new(repo, Arc::new(StubHasher));
+
+  let unknown_role = use_case
+    .execute(&actor("admin"), new_user_input("bia", "intern"))
+    .await;
+  let duplicate = use_case
+    .execute(&actor("admin"), new_user_input("ana", "bench"))
+    .await;
+
+  assert!(matches!(
+    unknown_role,
+    Err(AppError::Validation { message: msg, .. }) if msg == "role must be one of admin, reception, bench, biochemist"
+  ));
+  assert!(
+    matches!(duplicate, Err(AppError::Conflict(msg)) if msg == "username or cpf already registered")
+  );
+}
+
+#[tokio::test]
+async fn only_admin_manages_users() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  let list_users = ListUsersUseCase::new(repo.clone());
+  let create_user = CreateUserUseCase::new(repo.clone(), Arc::new(StubHasher));
+
+  let listed = list_users.execute(&actor("reception")).await;
+  let created = create_user
+    .execute(&actor("biochemist"), new_user_input("bia", "bench"))
+    .await;
+
+  assert!(matches!(listed, Err(AppError::Forbidden(_))));
+  assert!(matches!(created, Err(AppError::Forbidden(_))));
+  assert_eq!(repo.users.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn set_user_active_toggles_user_but_not_the_actor() {
+  let repo = Arc::new(StubUserRepository::with_user("ana", "s3cret-pass", true));
+  CreateUserUseCase::new(repo.clone(), Arc::new(StubHasher))
+    .execute(&actor("admin"), new_user_input("bia", "bench"))
+    .await
+    .expect("create user");
+  let use_case = SetUserActiveUseCase::new(repo);
+
+  let deactivated = use_case
+    .execute(
+      &actor("admin"),
+      SetUserActiveInput {
+        user_id: "usr-2".to_string(),
+        is_active: false,
+      },
+    )
+    .await
+    .expect("expected success");
+  let self_deactivation = use_case
+    .execute(
+      &actor("admin"),
+      SetUserActiveInput {
+        user_id: "usr-1".to_string(),
+        is_active: false,
+      },
+    )
+    .await;
+
+  assert!(!deactivated.is_active);
+  assert!(matches!(
+    self_deactivation,
+    Err(AppError::Validation { message: msg, .. }) if msg == "you cannot deactivate your own user"
+  ));
+}
@@ -0,0 +1,78 @@
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::authorization::authorize,
+  domain::users::{dto::UserView, permission::Permission, role::Role},
+};
+
+fn user(role: &str, is_active: bool) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active,
+  }
+}
+
+#[test]
+fn admin_is_allowed_everything() {
+  for permission in [
+    Permission::ViewRecords,
+    Permission::RegisterPatients,
+    Permission::ManageAttendances,
+    Permission::EnterResults,
+    Permission::ReleaseResults,
+    Permission::GenerateReports,
+    Permission::ViewCatalog,
+    Permission::ManageCatalog,
+    Permission::ManageUsers,
+    Permission::ViewAuditLog,
+    Permission::DeleteRecords,
+  ] {
+    assert!(Role::Admin.allows(permission), "{}", permission.as_str());
+  }
+}
+
+#[test]
+fn roles_only_get_their_own_tasks() {
+  assert!(Role::Reception.allows(Permission::RegisterPatients));
+  assert!(Role::Reception.allows(Permission::GenerateReports));
+  assert!(!Role::Reception.allows(Permission::EnterResults));
+  assert!(!Role::Reception.allows(Permission::ManageCatalog));
+
+  assert!(Role::Bench.allows(Permission::EnterResults));
+  assert!(!Role::Bench.allows(Permission::ReleaseResults));
+  assert!(!Role::Bench.allows(Permission::RegisterPatients));
+
+  assert!(Role::Biochemist.allows(Permission::ReleaseResults));
+  assert!(!Role::Biochemist.allows(Permission::ManageUsers));
+
+  for role in [Role::Reception, Role::Bench, Role::Biochemist] {
+    assert!(!role.allows(Permission::DeleteRecords), "{}", role.as_str());
+  }
+
+  for role in Role::ALL {
+    assert!(role.allows(Permission::ViewRecords), "{}", role.as_str());
+  }
+}
+
+#[test]
+fn authorize_explains_denied_permission() {
+  let result = authorize(&user("reception", true), Permission::EnterResults);
+
+  assert!(matches!(
+    result,
+    Err(AppError::Forbidden(msg)) if msg == "role reception is not allowed to enter_results"
+  ));
+  assert!(authorize(&user("bench", true), Permission::EnterResults).is_ok());
+}
+
+#[test]
+fn authorize_refuses_inactive_users_and_unknown_roles() {
+  let inactive = authorize(&user("admin", false), Permission::ViewRecords);
+  let unknown = authorize(&user("intern", true), Permission::ViewRecords);
+
+  assert!(matches!(inactive, Err(AppError::Forbidden(msg)) if msg == "user is inactive"));
+  assert!(matches!(unknown, Err(AppError::Forbidden(msg)) if msg == "unknown role: intern"));
+}
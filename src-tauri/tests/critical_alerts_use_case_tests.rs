@@ -0,0 +1,166 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::{
+    acknowledge_critical_alert::AcknowledgeCriticalAlertUseCase,
+    list_pending_critical_alerts::ListPendingCriticalAlertsUseCase,
+  },
+  domain::{
+    patients::{
+      dto::{AcknowledgeCriticalAlertInput, CriticalAlertView},
+      errors::PatientRepositoryError,
+      ports::CriticalAlertRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+fn actor(role: &str) -> UserView {
+  UserView {
+    id: "usr-bench".to_string(),
+    name: "Bruno Bancada".to_string(),
+    cpf: "11144477735".to_string(),
+    username: "bruno".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn alert() -> CriticalAlertView {
+  CriticalAlertView {
+    id: "alert-1".to_string(),
+    attendance_id: "att-1".to_string(),
+    exam_item_id: "it-1".to_string(),
+    patient_id: "pt-1".to_string(),
+    patient_name: "Maria Souza".to_string(),
+    exam_name: "Glicose".to_string(),
+    result_value: "35".to_string(),
+    result_flag: "LL".to_string(),
+    unit: Some("mg/dL".to_string()),
+    requester_name: Some("Dra. Ana".to_string()),
+    detected_at: "2026-02-17 09:00:00".to_string(),
+    detected_by_user_id: Some("usr-bench".to_string()),
+    acknowledged_at: None,
+    acknowledged_by_user_id: None,
+    contact_name: None,
+    contact_method: None,
+    note: None,
+  }
+}
+
+#[derive(Default)]
+struct StubCriticalAlertRepository {
+  acknowledged: Mutex<Vec<(AcknowledgeCriticalAlertInput, Option<String>)>>,
+}
+
+#[async_trait::async_trait]
+impl CriticalAlertRepository for StubCriticalAlertRepository {
+  async fn list_pending_critical_alerts(
+    &self,
+  ) -> Result<Vec<CriticalAlertView>, PatientRepositoryError> {
+    Ok(vec![alert()])
+  }
+
+  async fn acknowledge_critical_alert(
+    &self,
+    input: AcknowledgeCriticalAlertInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<CriticalAlertView, PatientRepositoryError> {
+    if input.alert_id != "alert-1" {
+      return Err(PatientRepositoryError::NotFound);
+    }
+    let view = CriticalAlertView {
+      acknowledged_at: Some("2026-02-17 09:10:00".to_string()),
+      acknowledged_by_user_id: performed_by_user_id.clone(),
+      contact_name: Some(input.contact_name.clone()),
+      contact_method: Some(input.contact_method.clone()),
+      note: input.note.clone(),
+      ..alert()
+    };
+    self
+      .acknowledged
+      .lock()
+      .unwrap()
+      .push((input, performed_by_user_id));
+    Ok(view)
+  }
+}
+
+fn input(contact_name: &str, contact_method: &str) -> AcknowledgeCriticalAlertInput {
+  AcknowledgeCriticalAlertInput {
+    alert_id: " alert-1 ".to_string(),
+    contact_name: contact_name.to_string(),
+    contact_method: contact_method.to_string(),
+    note: None,
+  }
+}
+
+#[tokio::test]
+async fn acknowledge_critical_alert_records_contact_and_actor() {
+  let repo = Arc::new(StubCriticalAlertRepository::default());
+  let use_case = AcknowledgeCriticalAlertUseCase::new(repo.clone());
+
+  let acknowledged = use_case
+    .execute(&actor("bench"), input("  Dra. Ana  ", " Phone "))
+    .await
+    .expect("expected success");
+
+  assert_eq!(acknowledged.contact_name.as_deref(), Some("Dra. Ana"));
+  let saved = repo.acknowledged.lock().unwrap();
+  assert_eq!(saved[0].0.alert_id, "alert-1");
+  assert_eq!(saved[0].0.contact_method, "phone");
+  assert_eq!(saved[0].1.as_deref(), Some("usr-bench"));
+}
+
+#[tokio::test]
+async fn acknowledge_critical_alert_requires_contact_name_and_known_method() {
+  let repo = Arc::new(StubCriticalAlertRepository::default());
+  let use_case = AcknowledgeCriticalAlertUseCase::new(repo.clone());
+
+  let no_contact = use_case
+    .execute(&actor("bench"), input("   ", "phone"))
+    .await;
+  let unknown_method = use_case
+    .execute(&actor("bench"), input("Dra. Ana", "fax"))
+    .await;
+
+  assert!(matches!(
+    no_contact,
+    Err(AppError::Validation { message: msg, .. }) if msg == "contact_name is required"
+  ));
+  assert!(matches!(
+    unknown_method,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "contact_method must be one of phone, in_person, email, message"
+  ));
+  assert!(repo.acknowledged.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn critical_alerts_require_result_entry_permission() {
+  let repo = Arc::new(StubCriticalAlertRepository::default());
+  let list = ListPendingCriticalAlertsUseCase::new(repo.clone());
+  let acknowledge = AcknowledgeCriticalAlertUseCase::new(repo.clone());
+
+  let pending = list
+    .execute(&actor("biochemist"))
+    .await
+    .expect("expected success");
+  let listed_by_reception = list.execute(&actor("reception")).await;
+  let acknowledged_by_reception = acknowledge
+    .execute(&actor("reception"), input("Dra. Ana", "phone"))
+    .await;
+
+  assert_eq!(pending.len(), 1);
+  for result in [
+    listed_by_reception.map(|_| ()),
+    acknowledged_by_reception.map(|_| ()),
+  ] {
+    assert!(matches!(
+      result,
+      Err(AppError::Forbidden(msg)) if msg == "role reception is not allowed to enter_results"
+    ));
+  }
+  assert!(repo.acknowledged.lock().unwrap().is_empty());
+}
@@ -0,0 +1,384 @@
+use std::sync::Arc;
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::{
+    create_exam_catalog_item::CreateExamCatalogItemUseCase,
+    deactivate_exam_catalog_item::DeactivateExamCatalogItemUseCase,
+    set_exam_delta_check::SetExamDeltaCheckUseCase,
+    set_exam_panel_analytes::SetExamPanelAnalytesUseCase,
+    set_exam_reference_ranges::SetExamReferenceRangesUseCase,
+  },
+  domain::patients::{
+    delta_check::DeltaCheckRule,
+    dto::{
+      CreateExamCatalogItemInput, CreateExamCategoryInput, DeactivateExamCatalogItemInput,
+      ExamCatalogItemView, ExamCategoryView, SetExamDeltaCheckInput, SetExamPanelAnalytesInput,
+      SetExamReferenceRangesInput, UpdateExamCatalogItemInput,
+    },
+    errors::PatientRepositoryError,
+    ports::ExamCatalogRepository,
+    reference_range::ReferenceRange,
+  },
+  domain::users::dto::UserView,
+};
+
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
+struct StubExamCatalogRepository {
+  result: Result<ExamCatalogItemView, PatientRepositoryError>,
+}
+
+#[async_trait::async_trait]
+impl ExamCatalogRepository for StubExamCatalogRepository {
+  async fn list_exam_categories(&self) -> Result<Vec<ExamCategoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn create_exam_category(
+    &self,
+    _input: CreateExamCategoryInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<ExamCategoryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn create_exam_catalog_item(
+    &self,
+    _input: CreateExamCatalogItemInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.result.clone()
+  }
+
+  async fn update_exam_catalog_item(
+    &self,
+    _input: UpdateExamCatalogItemInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.result.clone()
+  }
+
+  async fn deactivate_exam_catalog_item(
+    &self,
+    _input: DeactivateExamCatalogItemInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.result.clone()
+  }
+
+  async fn list_exam_reference_ranges(
+    &self,
+    _catalog_item_id: String,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn set_exam_reference_ranges(
+    &self,
+    input: SetExamReferenceRangesInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Vec<ReferenceRange>, PatientRepositoryError> {
+    Ok(input.ranges)
+  }
+
+  async fn get_exam_delta_check(
+    &self,
+    _catalog_item_id: String,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn set_exam_delta_check(
+    &self,
+    input: SetExamDeltaCheckInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<DeltaCheckRule, PatientRepositoryError> {
+    Ok(input.rule)
+  }
+
+  async fn set_exam_panel_analytes(
+    &self,
+    input: SetExamPanelAnalytesInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<ExamCatalogItemView, PatientRepositoryError> {
+    self.result.clone().map(|item| ExamCatalogItemView {
+      id: input.panel_id,
+      analyte_ids: input.analyte_ids,
+      ..item
+    })
+  }
+}
+
+fn sample_item(is_active: bool) -> ExamCatalogItemView {
+  ExamCatalogItemView {
+    id: "glicose".to_string(),
+    name: "Glicose".to_string(),
+    category_id: "bioquimica".to_string(),
+    category_title: "Bioquimica".to_string(),
+    price_cents: 1000,
+    unit: Some("mg/dL".to_string()),
+    method: None,
+    reference_range: None,
+    is_active,
+    analyte_ids: vec![],
+  }
+}
+
+fn create_input(name: &str, price_cents: i64) -> CreateExamCatalogItemInput {
+  CreateExamCatalogItemInput {
+    name: name.to_string(),
+    category_id: "bioquimica".to_string(),
+    price_cents,
+    unit: None,
+    method: None,
+    reference_range: None,
+  }
+}
+
+#[tokio::test]
+async fn create_exam_catalog_item_requires_name() {
+  let repo = StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  };
+  let use_case = CreateExamCatalogItemUseCase::new(Arc::new(repo));
+
+  let result = use_case.execute(&admin(), create_input("  ", 1000)).await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "name is required")
+  );
+}
+
+#[tokio::test]
+async fn create_exam_catalog_item_rejects_negative_price() {
+  let repo = StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  };
+  let use_case = CreateExamCatalogItemUseCase::new(Arc::new(repo));
+
+  let result = use_case
+    .execute(&admin(), create_input("Glicose", -1))
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "price_cents must not be negative")
+  );
+}
+
+#[tokio::test]
+async fn create_exam_catalog_item_maps_conflict() {
+  let repo = StubExamCatalogRepository {
+    result: Err(PatientRepositoryError::Conflict),
+  };
+  let use_case = CreateExamCatalogItemUseCase::new(Arc::new(repo));
+
+  let result = use_case
+    .execute(&admin(), create_input("Glicose", 1000))
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Conflict(msg)) if msg == "conflict while saving exam catalog item")
+  );
+}
+
+#[tokio::test]
+async fn deactivate_exam_catalog_item_returns_inactive_item() {
+  let repo = StubExamCatalogRepository {
+    result: Ok(sample_item(false)),
+  };
+  let use_case = DeactivateExamCatalogItemUseCase::new(Arc::new(repo));
+
+  let result = use_case
+    .execute(
+      &admin(),
+      DeactivateExamCatalogItemInput {
+        id: "glicose".to_string(),
+      },
+    )
+    .await;
+
+  match result {
+    Ok(item) => assert!(!item.is_active),
+    Err(_) => panic!("expected success"),
+  }
+}
+
+fn sample_range() -> ReferenceRange {
+  ReferenceRange {
+    sex: Some(" m ".to_string()),
+    age_min_years: Some(18),
+    age_max_years: None,
+    low: Some(70.0),
+    high: Some(99.0),
+    critical_low: Some(40.0),
+    critical_high: Some(400.0),
+    unit: Some("mg/dL".to_string()),
+    expected_text: None,
+  }
+}
+
+#[tokio::test]
+async fn set_exam_reference_ranges_normalizes_sex() {
+  let repo = StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  };
+  let use_case = SetExamReferenceRangesUseCase::new(Arc::new(repo));
+
+  let ranges = use_case
+    .execute(
+      &admin(),
+      SetExamReferenceRangesInput {
+        catalog_item_id: "glicose".to_string(),
+        ranges: vec![sample_range()],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(ranges[0].sex.as_deref(), Some("M"));
+}
+
+#[tokio::test]
+async fn set_exam_reference_ranges_rejects_inverted_limits() {
+  let repo = StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  };
+  let use_case = SetExamReferenceRangesUseCase::new(Arc::new(repo));
+
+  let result = use_case
+    .execute(
+      &admin(),
+      SetExamReferenceRangesInput {
+        catalog_item_id: "glicose".to_string(),
+        ranges: vec![
+          sample_range(),
+          ReferenceRange {
+            critical_low: Some(80.0),
+            ..sample_range()
+          },
+        ],
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "ranges[1]: critical_low must not exceed low"
+  ));
+}
+
+#[tokio::test]
+async fn set_exam_delta_check_requires_positive_thresholds() {
+  let use_case = SetExamDeltaCheckUseCase::new(Arc::new(StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  }));
+  let input = |absolute_threshold, percent_threshold| SetExamDeltaCheckInput {
+    catalog_item_id: " hemoglobina ".to_string(),
+    rule: DeltaCheckRule {
+      absolute_threshold,
+      percent_threshold,
+    },
+  };
+
+  let saved = use_case
+    .execute(&admin(), input(Some(2.0), Some(50.0)))
+    .await
+    .expect("expected success");
+  let disabled = use_case
+    .execute(&admin(), input(None, None))
+    .await
+    .expect("clearing both thresholds is allowed");
+  let zero = use_case.execute(&admin(), input(Some(0.0), None)).await;
+  let negative = use_case.execute(&admin(), input(None, Some(-10.0))).await;
+
+  assert_eq!(saved.percent_threshold, Some(50.0));
+  assert_eq!(disabled, DeltaCheckRule::default());
+  assert!(matches!(
+    zero,
+    Err(AppError::Validation { message: msg, .. }) if msg == "absolute_threshold must be greater than zero"
+  ));
+  assert!(matches!(
+    negative,
+    Err(AppError::Validation { message: msg, .. }) if msg == "percent_threshold must be greater than zero"
+  ));
+}
+
+#[tokio::test]
+async fn set_exam_panel_analytes_trims_ids_and_rejects_repeats() {
+  let use_case = SetExamPanelAnalytesUseCase::new(Arc::new(StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  }));
+  let input = |analyte_ids: &[&str]| SetExamPanelAnalytesInput {
+    panel_id: " bioquimica-2 ".to_string(),
+    analyte_ids: analyte_ids.iter().map(|id| id.to_string()).collect(),
+  };
+
+  let saved = use_case
+    .execute(&admin(), input(&[" ureia ", "creatinina"]))
+    .await
+    .expect("expected success");
+  let blank = use_case.execute(&admin(), input(&["ureia", "  "])).await;
+  let repeated = use_case
+    .execute(&admin(), input(&["ureia", "creatinina", " ureia"]))
+    .await;
+  let itself = use_case.execute(&admin(), input(&["bioquimica-2"])).await;
+
+  assert_eq!(saved.id, "bioquimica-2");
+  assert_eq!(saved.analyte_ids, ["ureia", "creatinina"]);
+  assert!(matches!(
+    blank,
+    Err(AppError::Validation { message: msg, .. }) if msg == "analyte_ids[1] is required"
+  ));
+  assert!(matches!(
+    repeated,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "analyte_ids[2]: ureia is already listed in analyte_ids[0]"
+  ));
+  assert!(matches!(
+    itself,
+    Err(AppError::Validation { message: msg, .. }) if msg == "analyte_ids[0]: a panel cannot contain itself"
+  ));
+}
+
+#[tokio::test]
+async fn set_exam_panel_analytes_maps_nesting_conflict_and_requires_manage_catalog() {
+  let input = SetExamPanelAnalytesInput {
+    panel_id: "hemograma".to_string(),
+    analyte_ids: vec!["bioquimica-2".to_string()],
+  };
+  let nested = SetExamPanelAnalytesUseCase::new(Arc::new(StubExamCatalogRepository {
+    result: Err(PatientRepositoryError::Conflict),
+  }))
+  .execute(&admin(), input.clone())
+  .await;
+  let by_reception = SetExamPanelAnalytesUseCase::new(Arc::new(StubExamCatalogRepository {
+    result: Ok(sample_item(true)),
+  }))
+  .execute(
+    &UserView {
+      role: "reception".to_string(),
+      ..admin()
+    },
+    input,
+  )
+  .await;
+
+  assert!(matches!(
+    nested,
+    Err(AppError::Conflict(msg)) if msg == "panels cannot contain other panels"
+  ));
+  assert!(matches!(
+    by_reception,
+    Err(AppError::Forbidden(msg)) if msg == "role reception is not allowed to manage_catalog"
+  ));
+}
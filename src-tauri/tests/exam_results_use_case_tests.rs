@@ -0,0 +1,534 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::enter_exam_results::EnterExamResultsUseCase,
+  domain::patients::{
+    delta_check::DeltaCheckRule,
+    dto::{
+      AmendResultInput, AttendanceEditContext, AttendanceQueueItemView, AttendanceStatusChange,
+      AttendanceStatusHistoryView, AttendanceUpdate, EnterExamResultsInput, ExamItemResultInput,
+      PatientRecordEntryView, PatientRecordExamItemView, PreviousResult, ResultEntryContext,
+      ResultEntryItemContext, ResultHistoryView,
+    },
+    errors::PatientRepositoryError,
+    ports::AttendanceRepository,
+    reference_range::ReferenceRange,
+  },
+  domain::users::dto::UserView,
+};
+
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
+struct StubResultsRepository {
+  status: String,
+  patient_sex: String,
+  patient_birth_date: String,
+  ranges: Vec<ReferenceRange>,
+  delta_check: DeltaCheckRule,
+  previous_result: Option<PreviousResult>,
+  saved: Mutex<Vec<EnterExamResultsInput>>,
+}
+
+impl StubResultsRepository {
+  fn with_status(status: &str) -> Self {
+    Self {
+      status: status.to_string(),
+      patient_sex: "F".to_string(),
+      patient_birth_date: "1991-10-01".to_string(),
+      ranges: Vec::new(),
+      delta_check: DeltaCheckRule::default(),
+      previous_result: None,
+      saved: Mutex::new(Vec::new()),
+    }
+  }
+
+  fn with_previous(previous_value: &str, delta_check: DeltaCheckRule) -> Self {
+    Self {
+      delta_check,
+      previous_result: Some(PreviousResult {
+        attendance_id: "att-0".to_string(),
+        exam_date: "2026-01-10".to_string(),
+        result_value: previous_value.to_string(),
+      }),
+      ..Self::with_status("in_analysis")
+    }
+  }
+
+  fn with_ranges(sex: &str, birth_date: &str, ranges: Vec<ReferenceRange>) -> Self {
+    Self {
+      patient_sex: sex.to_string(),
+      patient_birth_date: birth_date.to_string(),
+      ranges,
+      ..Self::with_status("in_analysis")
+    }
+  }
+
+  fn saved_flags(&self) -> Vec<Option<String>> {
+    self.saved.lock().unwrap()[0]
+      .items
+      .iter()
+      .map(|item| item.result_flag.clone())
+      .collect()
+  }
+}
+
+#[async_trait::async_trait]
+impl AttendanceRepository for StubResultsRepository {
+  async fn get_attendance(
+    &self,
+    attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    Ok(AttendanceQueueItemView {
+      attendance_id,
+      patient_id: "pt-1".to_string(),
+      patient_name: "Maria".to_string(),
+      patient_cpf: "12345678900".to_string(),
+      exam_date: "2026-02-14".to_string(),
+      status: self.status.clone(),
+      exam_names: vec!["Glicose".to_string()],
+      updated_at: "2026-02-14T10:30:00".to_string(),
+      deleted_at: None,
+    })
+  }
+
+  async fn change_attendance_status(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn cancel_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn release_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    _attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    Ok(ResultEntryContext {
+      attendance_id,
+      status: self.status.clone(),
+      exam_date: "2026-02-14".to_string(),
+      patient_sex: self.patient_sex.clone(),
+      patient_birth_date: self.patient_birth_date.clone(),
+      items: ["it-1", "it-2"]
+        .iter()
+        .map(|id| ResultEntryItemContext {
+          exam_item_id: id.to_string(),
+          catalog_item_id: Some("glicose".to_string()),
+          reference_ranges: self.ranges.clone(),
+          delta_check: self.delta_check.clone(),
+          previous_result: self.previous_result.clone(),
+        })
+        .collect(),
+    })
+  }
+
+  async fn enter_exam_results(
+    &self,
+    input: EnterExamResultsInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    let entry = PatientRecordEntryView {
+      exam_id: input.attendance_id.clone(),
+      exam_date: "2026-02-14".to_string(),
+      status: self.status.clone(),
+      requester_name: None,
+      items: input
+        .items
+        .iter()
+        .map(|item| PatientRecordExamItemView {
+          exam_item_id: item.exam_item_id.clone(),
+          name: "Glicose".to_string(),
+          unit: Some("mg/dL".to_string()),
+          method: None,
+          reference_range: None,
+          result_value: Some(item.result_value.clone()),
+          result_flag: item.result_flag.clone(),
+          report_available: true,
+          result_version: 1,
+          panel_id: None,
+          panel_name: None,
+        })
+        .collect(),
+      deleted_at: None,
+    };
+    self.saved.lock().unwrap().push(input);
+    Ok(entry)
+  }
+
+  async fn amend_result(
+    &self,
+    _input: AmendResultInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_result_history(
+    &self,
+    _exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_attendance(
+    &self,
+    _update: AttendanceUpdate,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn range(low: Option<f64>, high: Option<f64>) -> ReferenceRange {
+  ReferenceRange {
+    sex: None,
+    age_min_years: None,
+    age_max_years: None,
+    low,
+    high,
+    critical_low: None,
+    critical_high: None,
+    unit: Some("mg/dL".to_string()),
+    expected_text: None,
+  }
+}
+
+fn result(exam_item_id: &str, value: &str) -> ExamItemResultInput {
+  ExamItemResultInput {
+    exam_item_id: exam_item_id.to_string(),
+    result_value: value.to_string(),
+    result_flag: None,
+  }
+}
+
+#[tokio::test]
+async fn enter_exam_results_saves_batch() {
+  let repo = Arc::new(StubResultsRepository::with_status("in_analysis"));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  let entry = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "92"), result("it-2", "180")],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(entry.entry.items.len(), 2);
+  assert_eq!(entry.entry.items[0].result_value.as_deref(), Some("92"));
+  assert!(entry.delta_checks.is_empty());
+  assert_eq!(repo.saved.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn enter_exam_results_rejects_repeated_items() {
+  let repo = Arc::new(StubResultsRepository::with_status("in_analysis"));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "92"), result("it-1", "93")],
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "exam_item_id it-1 is repeated")
+  );
+  assert!(repo.saved.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn enter_exam_results_requires_value() {
+  let repo = Arc::new(StubResultsRepository::with_status("in_analysis"));
+  let use_case = EnterExamResultsUseCase::new(repo);
+
+  let result = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "  ")],
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "result_value is required")
+  );
+}
+
+#[tokio::test]
+async fn enter_exam_results_rejects_attendance_not_on_bench() {
+  let repo = Arc::new(StubResultsRepository::with_status("waiting"));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "92")],
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "results cannot be entered while attendance is waiting"
+  ));
+  assert!(repo.saved.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn enter_exam_results_rejects_item_outside_attendance() {
+  let repo = Arc::new(StubResultsRepository::with_status("in_analysis"));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-9", "92")],
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "exam_item_id it-9 does not belong to attendance"
+  ));
+  assert!(repo.saved.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn enter_exam_results_flags_against_critical_and_normal_limits() {
+  let glicose = ReferenceRange {
+    critical_low: Some(40.0),
+    critical_high: Some(400.0),
+    ..range(Some(70.0), Some(99.0))
+  };
+  let repo = Arc::new(StubResultsRepository::with_ranges(
+    "F",
+    "1991-10-01",
+    vec![glicose],
+  ));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "35"), result("it-2", "120,5")],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(
+    repo.saved_flags(),
+    vec![Some("LL".to_string()), Some("H".to_string())]
+  );
+}
+
+#[tokio::test]
+async fn enter_exam_results_picks_range_by_sex_and_age() {
+  let ranges = vec![
+    range(Some(10.0), Some(20.0)),
+    ReferenceRange {
+      sex: Some("M".to_string()),
+      ..range(Some(30.0), Some(40.0))
+    },
+    ReferenceRange {
+      age_max_years: Some(12),
+      ..range(Some(1.0), Some(5.0))
+    },
+  ];
+  let adult_man = Arc::new(StubResultsRepository::with_ranges(
+    "M",
+    "1980-01-01",
+    ranges.clone(),
+  ));
+  let child = Arc::new(StubResultsRepository::with_ranges(
+    "F",
+    "2020-06-01",
+    ranges,
+  ));
+
+  for repo in [&adult_man, &child] {
+    EnterExamResultsUseCase::new(repo.clone())
+      .execute(
+        &admin(),
+        EnterExamResultsInput {
+          attendance_id: "att-1".to_string(),
+          items: vec![result("it-1", "15")],
+        },
+      )
+      .await
+      .expect("expected success");
+  }
+
+  assert_eq!(adult_man.saved_flags(), vec![Some("L".to_string())]);
+  assert_eq!(child.saved_flags(), vec![Some("H".to_string())]);
+}
+
+#[tokio::test]
+async fn enter_exam_results_keeps_manual_flag_without_applicable_range() {
+  let qualitative = ReferenceRange {
+    expected_text: Some("Negativo".to_string()),
+    ..range(None, None)
+  };
+  let male_only = ReferenceRange {
+    sex: Some("M".to_string()),
+    ..qualitative.clone()
+  };
+  let repo = Arc::new(StubResultsRepository::with_ranges(
+    "F",
+    "1991-10-01",
+    vec![male_only],
+  ));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![ExamItemResultInput {
+          result_flag: Some("manual".to_string()),
+          ..result("it-1", "Positivo")
+        }],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(repo.saved_flags(), vec![Some("manual".to_string())]);
+
+  let repo = Arc::new(StubResultsRepository::with_ranges(
+    "F",
+    "1991-10-01",
+    vec![qualitative],
+  ));
+  EnterExamResultsUseCase::new(repo.clone())
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "negativo"), result("it-2", "Positivo")],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(
+    repo.saved_flags(),
+    vec![Some("normal".to_string()), Some("out_of_range".to_string())]
+  );
+}
+
+#[tokio::test]
+async fn enter_exam_results_warns_when_change_reaches_delta_threshold() {
+  let repo = Arc::new(StubResultsRepository::with_previous(
+    "14,0",
+    DeltaCheckRule {
+      absolute_threshold: None,
+      percent_threshold: Some(50.0),
+    },
+  ));
+  let use_case = EnterExamResultsUseCase::new(repo.clone());
+
+  let saved = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "6.5"), result("it-2", "13.1")],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(saved.delta_checks.len(), 2);
+  let drop = &saved.delta_checks[0];
+  assert_eq!(drop.exam_item_id, "it-1");
+  assert_eq!(drop.previous_attendance_id, "att-0");
+  assert_eq!(drop.previous_value, "14,0");
+  assert_eq!(drop.current_value, "6.5");
+  assert_eq!(drop.absolute_change, Some(-7.5));
+  assert!(drop
+    .percent_change
+    .is_some_and(|percent| (percent + 53.57).abs() < 0.01));
+  assert!(drop.exceeds_threshold);
+  assert!(!saved.delta_checks[1].exceeds_threshold);
+  assert_eq!(repo.saved.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn enter_exam_results_shows_previous_value_without_threshold() {
+  let repo = Arc::new(StubResultsRepository::with_previous(
+    "Negativo",
+    DeltaCheckRule::default(),
+  ));
+  let use_case = EnterExamResultsUseCase::new(repo);
+
+  let saved = use_case
+    .execute(
+      &admin(),
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "Positivo")],
+      },
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(saved.delta_checks[0].previous_value, "Negativo");
+  assert_eq!(saved.delta_checks[0].absolute_change, None);
+  assert!(!saved.delta_checks[0].exceeds_threshold);
+}
@@ -0,0 +1,90 @@
+use laboratory_app_lib::{
+  app::error::AppError,
+  domain::patients::errors::PatientRepositoryError,
+  interface::ipc::error::{IpcError, IpcErrorCode},
+};
+use serde_json::json;
+
+#[test]
+fn ipc_error_serializes_code_message_and_field() {
+  let err = IpcError::from(AppError::invalid("cpf", "cpf is required".into()));
+
+  assert_eq!(
+    serde_json::to_value(&err).expect("serializable"),
+    json!({ "code": "validation", "message": "cpf is required", "field": "cpf" })
+  );
+}
+
+#[test]
+fn ipc_error_keeps_the_field_given_by_the_use_case() {
+  let check_digits = IpcError::from(AppError::invalid(
+    "cpf",
+    "cpf check digits do not match".into(),
+  ));
+  let range = IpcError::from(AppError::invalid(
+    "ranges[1]",
+    "ranges[1]: min must not be greater than max".into(),
+  ));
+  let no_field = IpcError::from(AppError::validation(
+    "cannot move attendance from cancelled to waiting".into(),
+  ));
+
+  assert_eq!(check_digits.code, IpcErrorCode::Validation);
+  assert_eq!(check_digits.field.as_deref(), Some("cpf"));
+  assert_eq!(check_digits.message, "cpf check digits do not match");
+  assert_eq!(range.field.as_deref(), Some("ranges[1]"));
+  assert_eq!(no_field.code, IpcErrorCode::Validation);
+  assert!(no_field.field.is_none());
+}
+
+#[test]
+fn ipc_error_maps_app_error_codes() {
+  let cases = [
+    (
+      AppError::NotFound("patient not found".into()),
+      IpcErrorCode::NotFound,
+    ),
+    (
+      AppError::Conflict("username or cpf already registered".into()),
+      IpcErrorCode::Conflict,
+    ),
+    (
+      AppError::Forbidden("login required".into()),
+      IpcErrorCode::Forbidden,
+    ),
+    (
+      AppError::Database("failed to save patient".into()),
+      IpcErrorCode::Database,
+    ),
+    (
+      AppError::Unexpected("boom".into()),
+      IpcErrorCode::Unexpected,
+    ),
+  ];
+
+  for (app_error, code) in cases {
+    let err = IpcError::from(app_error);
+    assert_eq!(err.code, code);
+    assert!(err.field.is_none());
+  }
+  assert_eq!(
+    IpcError::from(AppError::Forbidden("login required".into())).message,
+    "login required"
+  );
+}
+
+#[test]
+fn ipc_error_maps_repository_errors() {
+  assert_eq!(
+    IpcError::from(PatientRepositoryError::NotFound).code,
+    IpcErrorCode::NotFound
+  );
+  assert_eq!(
+    IpcError::from(PatientRepositoryError::Conflict).code,
+    IpcErrorCode::Conflict
+  );
+  assert_eq!(
+    IpcError::from(PatientRepositoryError::PersistenceError).code,
+    IpcErrorCode::Database
+  );
+}
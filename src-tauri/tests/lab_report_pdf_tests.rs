@@ -0,0 +1,164 @@
+use laboratory_app_lib::{
+  domain::{
+    patients::dto::{PatientRecordEntryView, PatientRecordExamItemView, PatientView},
+    reports::{dto::LabReportSource, ports::LabReportRenderer},
+  },
+  infra::reports::lab_report_pdf::PdfLabReportRenderer,
+};
+
+fn item(name: &str, value: &str, flag: Option<&str>) -> PatientRecordExamItemView {
+  PatientRecordExamItemView {
+    exam_item_id: format!("it-{name}"),
+    name: name.to_string(),
+    unit: Some("mg/dL".to_string()),
+    method: Some("Enzimatico".to_string()),
+    reference_range: Some("70 - 99 mg/dL".to_string()),
+    result_value: Some(value.to_string()),
+    result_flag: flag.map(str::to_string),
+    report_available: true,
+    result_version: 1,
+    panel_id: None,
+    panel_name: None,
+  }
+}
+
+fn source(items: Vec<PatientRecordExamItemView>) -> LabReportSource {
+  LabReportSource {
+    patient: PatientView {
+      id: "pt-1".to_string(),
+      full_name: "Jo\u{e3}o (Lima)".to_string(),
+      cpf: "12345678900".to_string(),
+      birth_date: "1991-10-01".to_string(),
+      sex: "M".to_string(),
+      phone: "11999999999".to_string(),
+      address: "Rua A".to_string(),
+      created_at: "2026-01-01T00:00:00".to_string(),
+      updated_at: "2026-01-01T00:00:00".to_string(),
+      deleted_at: None,
+    },
+    entry: PatientRecordEntryView {
+      exam_id: "att-1".to_string(),
+      exam_date: "2026-02-14".to_string(),
+      status: "released".to_string(),
+      requester_name: Some("Dra. Ana".to_string()),
+      items,
+      deleted_at: None,
+    },
+    current_report_version: None,
+    amended: false,
+  }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+  haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+fn page_count(pdf: &[u8]) -> usize {
+  pdf.windows(b"/Type /Page ".len()).filter(|w| w == b"/Type /Page ").count()
+}
+
+#[test]
+fn render_builds_a_well_formed_pdf() {
+  let renderer = PdfLabReportRenderer::new("Laboratorio Teste");
+
+  let pdf = renderer.render(&source(vec![item("Glicose", "120", Some("H"))]), 1);
+
+  assert!(pdf.starts_with(b"%PDF-1.4"));
+  assert!(pdf.ends_with(b"%%EOF\n"));
+
+  let text = String::from_utf8_lossy(&pdf);
+  let startxref: usize = text
+    .rsplit("startxref\n")
+    .next()
+    .and_then(|tail| tail.lines().next())
+    .and_then(|offset| offset.parse().ok())
+    .expect("startxref offset");
+  assert!(pdf[startxref..].starts_with(b"xref"));
+
+  let xref = &text[startxref..];
+  for (id, line) in xref.lines().skip(3).take_while(|l| l.ends_with(" n ")).enumerate() {
+    let offset: usize = line[..10].parse().expect("xref offset");
+    let header = format!("{} 0 obj", id + 1);
+    assert!(pdf[offset..].starts_with(header.as_bytes()), "bad offset for object {}", id + 1);
+  }
+}
+
+#[test]
+fn render_prints_patient_items_and_flags() {
+  let renderer = PdfLabReportRenderer::new("Laboratorio Teste");
+
+  let pdf = renderer.render(&source(vec![item("Glicose", "120", Some("H"))]), 2);
+
+  assert!(contains(&pdf, b"(Laboratorio Teste)"));
+  assert!(contains(&pdf, b"(Jo\xe3o \\(Lima\\))"));
+  assert!(contains(&pdf, b"(123.456.789-00)"));
+  assert!(contains(&pdf, b"(01/10/1991 \\(34 anos\\))"));
+  assert!(contains(&pdf, b"(Dra. Ana)"));
+  assert!(contains(&pdf, b"/F2 9.0 Tf 235.00"));
+  assert!(contains(&pdf, b"(Metodo: Enzimatico)"));
+  assert!(contains(&pdf, b"(Laudo versao 2 - Pagina 1 de 1)"));
+}
+
+#[test]
+fn render_breaks_long_reports_into_pages() {
+  let renderer = PdfLabReportRenderer::new("Laboratorio Teste");
+  let items = (0..60).map(|n| item(&format!("Exame {n}"), "90", None)).collect();
+
+  let pdf = renderer.render(&source(items), 1);
+
+  assert_eq!(page_count(&pdf), 3);
+  assert!(contains(&pdf, b"(Laudo versao 1 - Pagina 3 de 3)"));
+  assert!(contains(&pdf, b"(Exame 59)"));
+}
+
+#[test]
+fn render_marks_amended_reports_as_retificado() {
+  let renderer = PdfLabReportRenderer::new("Laboratorio Teste");
+  let amended = LabReportSource {
+    amended: true,
+    ..source(vec![item("Glicose", "95", None)])
+  };
+
+  let pdf = renderer.render(&amended, 3);
+
+  assert!(contains(&pdf, b"(Laudo retificado: "));
+  assert!(contains(&pdf, b"(Laudo versao 3 \\(retificado\\) - Pagina 1 de 1)"));
+  assert!(!contains(
+    &renderer.render(&source(vec![item("Glicose", "95", None)]), 3),
+    b"retificado"
+  ));
+}
+
+#[test]
+fn render_groups_panel_analytes_under_the_panel_name() {
+  let renderer = PdfLabReportRenderer::new("Laboratorio Teste");
+  let analyte = |name: &str| PatientRecordExamItemView {
+    method: None,
+    panel_id: Some("bioquimica-2".to_string()),
+    panel_name: Some("Bioquimica 2".to_string()),
+    ..item(name, "30", None)
+  };
+
+  let pdf = renderer.render(
+    &source(vec![
+      analyte("Ureia"),
+      analyte("Creatinina"),
+      item("Glicose", "90", None),
+    ]),
+    1,
+  );
+  let text = String::from_utf8_lossy(&pdf);
+  let operator = |label: &str| {
+    text
+      .lines()
+      .find(|line| line.contains(&format!("({label}) Tj")))
+      .unwrap_or_else(|| panic!("{label} should be printed"))
+      .to_string()
+  };
+
+  assert_eq!(text.matches("(Bioquimica 2) Tj").count(), 1);
+  assert!(operator("Bioquimica 2").starts_with("BT /F2 9.0 Tf 50.00 "));
+  assert!(operator("Ureia").starts_with("BT /F1 9.0 Tf 58.00 "));
+  assert!(operator("Creatinina").starts_with("BT /F1 9.0 Tf 58.00 "));
+  assert!(operator("Glicose").starts_with("BT /F1 9.0 Tf 50.00 "));
+}
@@ -0,0 +1,240 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::reports::generate_lab_report::GenerateLabReportUseCase,
+  domain::users::dto::UserView,
+  domain::{
+    patients::dto::{PatientRecordEntryView, PatientView},
+    reports::{
+      dto::{GenerateLabReportInput, LabReportSource, PdfReportView, RecordPdfReportInput},
+      errors::{ReportRepositoryError, ReportStorageError},
+      ports::{LabReportRenderer, ReportFileStore, ReportRepository},
+    },
+  },
+};
+
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
+struct StubReportRepository {
+  status: String,
+  current_report_version: Option<i64>,
+  record_result: Option<ReportRepositoryError>,
+  recorded: Mutex<Vec<RecordPdfReportInput>>,
+}
+
+impl StubReportRepository {
+  fn new(status: &str, current_report_version: Option<i64>) -> Self {
+    Self {
+      status: status.to_string(),
+      current_report_version,
+      record_result: None,
+      recorded: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl ReportRepository for StubReportRepository {
+  async fn get_lab_report_source(
+    &self,
+    attendance_id: String,
+  ) -> Result<LabReportSource, ReportRepositoryError> {
+    Ok(LabReportSource {
+      patient: PatientView {
+        id: "pt-1".to_string(),
+        full_name: "Maria Souza".to_string(),
+        cpf: "12345678900".to_string(),
+        birth_date: "1991-10-01".to_string(),
+        sex: "F".to_string(),
+        phone: "11999999999".to_string(),
+        address: "Rua A".to_string(),
+        created_at: "2026-01-01T00:00:00".to_string(),
+        updated_at: "2026-01-01T00:00:00".to_string(),
+        deleted_at: None,
+      },
+      entry: PatientRecordEntryView {
+        exam_id: attendance_id,
+        exam_date: "2026-02-14".to_string(),
+        status: self.status.clone(),
+        requester_name: None,
+        items: vec![],
+        deleted_at: None,
+      },
+      current_report_version: self.current_report_version,
+      amended: false,
+    })
+  }
+
+  async fn record_pdf_report(
+    &self,
+    input: RecordPdfReportInput,
+  ) -> Result<PdfReportView, ReportRepositoryError> {
+    if let Some(err) = &self.record_result {
+      return Err(err.clone());
+    }
+    let view = PdfReportView {
+      id: "rep-1".to_string(),
+      attendance_id: input.attendance_id.clone(),
+      report_version: input.report_version,
+      generated_by_user_id: input.generated_by_user_id.clone(),
+      generated_at: "2026-02-14 12:00:00".to_string(),
+      note: input.note.clone(),
+      is_amended: input.amended,
+    };
+    self.recorded.lock().unwrap().push(input);
+    Ok(view)
+  }
+}
+
+struct StubRenderer;
+
+impl LabReportRenderer for StubRenderer {
+  fn render(&self, source: &LabReportSource, report_version: i64) -> Vec<u8> {
+    format!("{} v{report_version}", source.patient.full_name).into_bytes()
+  }
+}
+
+#[derive(Default)]
+struct StubFileStore {
+  staged: Mutex<Vec<(String, Vec<u8>)>>,
+  saved: Mutex<Vec<(String, Vec<u8>)>>,
+  discarded: Mutex<Vec<String>>,
+}
+
+impl ReportFileStore for StubFileStore {
+  fn save(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError> {
+    self
+      .saved
+      .lock()
+      .unwrap()
+      .push((file_name.to_string(), content.to_vec()));
+    Ok(format!("/reports/{file_name}"))
+  }
+
+  fn stage(&self, file_name: &str, content: &[u8]) -> Result<String, ReportStorageError> {
+    let staged_name = format!("{file_name}.tmp");
+    self
+      .staged
+      .lock()
+      .unwrap()
+      .push((staged_name.clone(), content.to_vec()));
+    Ok(staged_name)
+  }
+
+  fn promote(&self, staged_name: &str, file_name: &str) -> Result<String, ReportStorageError> {
+    let mut staged = self.staged.lock().unwrap();
+    let index = staged
+      .iter()
+      .position(|(name, _)| name == staged_name)
+      .ok_or_else(|| ReportStorageError::Io(format!("{staged_name} was not staged")))?;
+    let (_, content) = staged.remove(index);
+    self.save(file_name, &content)
+  }
+
+  fn discard(&self, staged_name: &str) {
+    self
+      .staged
+      .lock()
+      .unwrap()
+      .retain(|(name, _)| name != staged_name);
+    self.discarded.lock().unwrap().push(staged_name.to_string());
+  }
+}
+
+fn input(note: Option<&str>) -> GenerateLabReportInput {
+  GenerateLabReportInput {
+    attendance_id: "att-1".to_string(),
+    note: note.map(str::to_string),
+  }
+}
+
+#[tokio::test]
+async fn generate_lab_report_writes_next_version() {
+  let repo = Arc::new(StubReportRepository::new("released", Some(2)));
+  let files = Arc::new(StubFileStore::default());
+  let use_case = GenerateLabReportUseCase::new(repo.clone(), Arc::new(StubRenderer), files.clone());
+
+  let generated = use_case
+    .execute(&admin(), input(Some("  segunda via  ")))
+    .await
+    .expect("expected success");
+
+  assert_eq!(generated.file_path, "/reports/laudo-att-1-v3.pdf");
+  assert_eq!(generated.report.report_version, 3);
+  assert_eq!(generated.report.note.as_deref(), Some("segunda via"));
+  assert_eq!(files.saved.lock().unwrap()[0].1, b"Maria Souza v3".to_vec());
+  assert_eq!(repo.recorded.lock().unwrap()[0].report_version, 3);
+}
+
+#[tokio::test]
+async fn generate_lab_report_rejects_unreleased_attendance() {
+  let repo = Arc::new(StubReportRepository::new("awaiting_validation", None));
+  let files = Arc::new(StubFileStore::default());
+  let use_case = GenerateLabReportUseCase::new(repo.clone(), Arc::new(StubRenderer), files.clone());
+
+  let result = use_case.execute(&admin(), input(None)).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "report cannot be generated while attendance is awaiting_validation"
+  ));
+  assert!(files.saved.lock().unwrap().is_empty());
+  assert!(repo.recorded.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn generate_lab_report_requires_attendance_id() {
+  let use_case = GenerateLabReportUseCase::new(
+    Arc::new(StubReportRepository::new("released", None)),
+    Arc::new(StubRenderer),
+    Arc::new(StubFileStore::default()),
+  );
+
+  let result = use_case
+    .execute(
+      &admin(),
+      GenerateLabReportInput {
+        attendance_id: " ".to_string(),
+        note: None,
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "attendance_id is required")
+  );
+}
+
+#[tokio::test]
+async fn generate_lab_report_maps_version_conflict() {
+  let repo = StubReportRepository {
+    record_result: Some(ReportRepositoryError::Conflict),
+    ..StubReportRepository::new("delivered", Some(1))
+  };
+  let files = Arc::new(StubFileStore::default());
+  let use_case =
+    GenerateLabReportUseCase::new(Arc::new(repo), Arc::new(StubRenderer), files.clone());
+
+  let result = use_case.execute(&admin(), input(None)).await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Conflict(msg)) if msg == "report was generated by someone else; try again"
+  ));
+  assert!(files.saved.lock().unwrap().is_empty());
+  assert!(files.staged.lock().unwrap().is_empty());
+  assert_eq!(
+    files.discarded.lock().unwrap().as_slice(),
+    ["laudo-att-1-v2.pdf.tmp".to_string()]
+  );
+}
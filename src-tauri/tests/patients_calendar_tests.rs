@@ -0,0 +1,34 @@
+use laboratory_app_lib::domain::patients::calendar::CalendarDate;
+
+fn date(value: &str) -> CalendarDate {
+  CalendarDate::parse(value).expect("valid date")
+}
+
+#[test]
+fn calendar_date_parses_only_real_days() {
+  assert_eq!(date("2024-02-29").to_string(), "2024-02-29");
+  for invalid in [
+    "2023-02-29",
+    "1900-02-29",
+    "2026-04-31",
+    "2026-13-01",
+    "2026-00-10",
+    "2026-3-10",
+    "2026/03/10",
+    "2026-03-10 10:00",
+    "+202-03-10",
+  ] {
+    assert_eq!(CalendarDate::parse(invalid), None, "{invalid}");
+  }
+}
+
+#[test]
+fn calendar_date_counts_days_across_months_and_years() {
+  assert_eq!(CalendarDate::from_unix_days(0), date("1970-01-01"));
+  assert_eq!(date("2000-03-01").unix_days(), 11_017);
+  assert_eq!(date("2024-02-28").add_days(1), date("2024-02-29"));
+  assert_eq!(date("2024-02-29").add_days(1), date("2024-03-01"));
+  assert_eq!(date("2026-12-25").add_days(7), date("2027-01-01"));
+  assert_eq!(date("1969-12-31").add_days(-364), date("1969-01-01"));
+  assert!(date("2026-03-09") < date("2026-03-10"));
+}
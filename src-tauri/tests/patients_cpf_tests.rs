@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use laboratory_app_lib::{
+  domain::patients::{cpf::Cpf, errors::PatientDomainError},
+  infra::db::sqlite::run_migrations,
+};
+use sqlx::{migrate::Migrator, sqlite::SqlitePoolOptions, Row};
+
+#[test]
+fn cpf_accepts_masked_and_plain_forms() {
+  let masked = Cpf::parse("529.982.247-25").expect("valid cpf");
+  let plain = Cpf::parse("52998224725").expect("valid cpf");
+
+  assert_eq!(masked, plain);
+  assert_eq!(masked.as_str(), "52998224725");
+  assert_eq!(masked.to_string(), "529.982.247-25");
+  assert_eq!(
+    Cpf::parse("123.456.789-09").expect("valid cpf").as_str(),
+    "12345678909"
+  );
+}
+
+#[test]
+fn cpf_rejects_invalid_numbers() {
+  let cases = [
+    ("", PatientDomainError::CpfRequired),
+    (
+      "123",
+      PatientDomainError::InvalidCpf("cpf must have 11 digits".into()),
+    ),
+    (
+      "111.111.111-11",
+      PatientDomainError::InvalidCpf("cpf must not repeat a single digit".into()),
+    ),
+    (
+      "529.982.247-52",
+      PatientDomainError::InvalidCpf("cpf check digits do not match".into()),
+    ),
+    (
+      "529/982/247-25",
+      PatientDomainError::InvalidCpf("cpf must contain only digits, '.' and '-'".into()),
+    ),
+  ];
+
+  for (value, expected) in cases {
+    assert_eq!(Cpf::parse(value), Err(expected), "{value}");
+  }
+}
+
+#[tokio::test]
+async fn migration_normalizes_cpfs_and_reports_collisions() {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+  let mut before_normalization = Migrator::new(Path::new("src/infra/db/migrations"))
+    .await
+    .expect("migrations should load");
+  before_normalization.migrations = before_normalization
+    .migrations
+    .iter()
+    .filter(|m| m.version < 15)
+    .cloned()
+    .collect::<Vec<_>>()
+    .into();
+  before_normalization
+    .run(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  for (name, cpf) in [
+    ("Maria", "529.982.247-25"),
+    ("Joao", "123.456.789-09"),
+    ("Joao Duplicado", "12345678909"),
+  ] {
+    sqlx::query(
+      r#"
+      INSERT INTO patients (full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES (?1, ?2, '1990-01-01', 'F', '', '', datetime('now'), datetime('now'))
+      "#,
+    )
+    .bind(name)
+    .bind(cpf)
+    .execute(&pool)
+    .await
+    .expect("insert legacy patient");
+  }
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  let patients = sqlx::query("SELECT full_name, cpf FROM patients ORDER BY full_name")
+    .fetch_all(&pool)
+    .await
+    .expect("patients should load");
+  let collisions = sqlx::query(
+    r#"
+    SELECT p.full_name, c.original_cpf, c.normalized_cpf
+    FROM patient_cpf_collisions c
+    JOIN patients p ON p.id = c.patient_id
+    ORDER BY p.full_name
+    "#,
+  )
+  .fetch_all(&pool)
+  .await
+  .expect("collisions should load");
+
+  let cpfs: Vec<(String, String)> = patients
+    .iter()
+    .map(|row| (row.get("full_name"), row.get("cpf")))
+    .collect();
+  assert_eq!(
+    cpfs,
+    vec![
+      ("Joao".to_string(), "123.456.789-09".to_string()),
+      ("Joao Duplicado".to_string(), "12345678909".to_string()),
+      ("Maria".to_string(), "52998224725".to_string()),
+    ]
+  );
+  assert_eq!(collisions.len(), 2);
+  assert_eq!(
+    collisions[0].get::<String, _>("original_cpf"),
+    "123.456.789-09"
+  );
+  assert_eq!(
+    collisions[1].get::<String, _>("normalized_cpf"),
+    "12345678909"
+  );
+}
@@ -0,0 +1,170 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::create_patient::CreatePatientUseCase,
+  domain::patients::{
+    dto::{
+      AttendanceQueueItemView, AttendanceQueuePageQuery, CompleteAttendanceInput,
+      CreateAttendanceInput, CreatePatientInput, ExamCatalogItemView, PatientRecordEntryView,
+      PatientRecordView, UpdatePatientInput,
+    },
+    entity::Patient,
+    errors::PatientRepositoryError,
+    ports::PatientRepository,
+  },
+  domain::users::dto::UserView,
+};
+
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
+#[derive(Default)]
+struct StubCreateRepository {
+  inserted: Mutex<Vec<CreatePatientInput>>,
+}
+
+#[async_trait::async_trait]
+impl PatientRepository for StubCreateRepository {
+  async fn insert(
+    &self,
+    input: CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    self.inserted.lock().unwrap().push(input.clone());
+    Ok(Patient {
+      id: "pt-1".to_string(),
+      full_name: input.full_name,
+      cpf: input.cpf,
+      birth_date: input.birth_date,
+      sex: input.sex,
+      phone: input.phone,
+      address: input.address,
+      created_at: "2026-01-01T00:00:00".to_string(),
+      updated_at: "2026-01-01T00:00:00".to_string(),
+      deleted_at: None,
+    })
+  }
+
+  async fn list(
+    &self,
+    _query: laboratory_app_lib::domain::patients::dto::PatientPageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<Patient>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_patient_record(
+    &self,
+    _patient_id: String,
+    _include_deleted: bool,
+  ) -> Result<PatientRecordView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_exam_catalog(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn create_attendance(
+    &self,
+    _input: CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_queue(
+    &self,
+    _input: AttendanceQueuePageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<AttendanceQueueItemView>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn complete_attendance(
+    &self,
+    _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn input(cpf: &str) -> CreatePatientInput {
+  CreatePatientInput {
+    full_name: "Maria Silva".to_string(),
+    cpf: cpf.to_string(),
+    birth_date: "1990-01-01".to_string(),
+    sex: "F".to_string(),
+    phone: "11999999999".to_string(),
+    address: "Rua A".to_string(),
+  }
+}
+
+#[tokio::test]
+async fn create_patient_stores_canonical_cpf() {
+  let repo = Arc::new(StubCreateRepository::default());
+  let use_case = CreatePatientUseCase::new(repo.clone());
+
+  let created = use_case
+    .execute(&admin(), input(" 529.982.247-25 "))
+    .await
+    .expect("expected success");
+
+  assert_eq!(created.cpf, "52998224725");
+  assert_eq!(repo.inserted.lock().unwrap()[0].cpf, "52998224725");
+}
+
+#[tokio::test]
+async fn create_patient_rejects_invalid_cpf() {
+  let repo = Arc::new(StubCreateRepository::default());
+  let use_case = CreatePatientUseCase::new(repo.clone());
+
+  let short = use_case.execute(&admin(), input("123")).await;
+  let wrong_digits = use_case.execute(&admin(), input("529.982.247-26")).await;
+  let empty = use_case.execute(&admin(), input("  ")).await;
+
+  assert!(
+    matches!(short, Err(AppError::Validation { message: msg, .. }) if msg == "cpf must have 11 digits")
+  );
+  assert!(matches!(
+    wrong_digits,
+    Err(AppError::Validation { field: Some(field), message: msg })
+      if field == "cpf" && msg == "cpf check digits do not match"
+  ));
+  assert!(
+    matches!(empty, Err(AppError::Validation { message: msg, .. }) if msg == "cpf is required")
+  );
+  assert!(repo.inserted.lock().unwrap().is_empty());
+}
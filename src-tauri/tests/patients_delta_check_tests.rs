@@ -0,0 +1,39 @@
+use laboratory_app_lib::domain::patients::delta_check::DeltaCheckRule;
+
+#[test]
+fn delta_check_flags_absolute_or_percent_changes() {
+  let rule = DeltaCheckRule {
+    absolute_threshold: Some(3.0),
+    percent_threshold: Some(50.0),
+  };
+
+  let halved = rule.compare("14,0", "7").expect("numeric values");
+  let small = rule.compare("14", "13").expect("numeric values");
+  let absolute = rule.compare("40", "44").expect("numeric values");
+
+  assert_eq!(halved.absolute_change, -7.0);
+  assert_eq!(halved.percent_change, Some(-50.0));
+  assert!(halved.exceeds_threshold);
+  assert!(!small.exceeds_threshold);
+  assert!(absolute.exceeds_threshold);
+}
+
+#[test]
+fn delta_check_skips_text_and_zero_baselines() {
+  let rule = DeltaCheckRule {
+    absolute_threshold: None,
+    percent_threshold: Some(10.0),
+  };
+
+  let from_zero = rule.compare("0", "5").expect("numeric values");
+
+  assert_eq!(rule.compare("Negativo", "Positivo"), None);
+  assert_eq!(from_zero.percent_change, None);
+  assert!(!from_zero.exceeds_threshold);
+  assert!(
+    !DeltaCheckRule::default()
+      .compare("14", "2")
+      .expect("numeric values")
+      .exceeds_threshold
+  );
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::find_duplicate_patients::FindDuplicatePatientsUseCase,
+  domain::{
+    patients::{
+      dto::FindDuplicatePatientsInput,
+      duplicates::{
+        find_duplicates, name_similarity, score_duplicate, REASON_SAME_BIRTH_DATE,
+        REASON_SAME_PHONE, REASON_SIMILAR_NAME,
+      },
+      entity::Patient,
+    },
+    users::dto::UserView,
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor};
+
+fn patient(id: &str, full_name: &str, birth_date: &str, phone: &str) -> Patient {
+  Patient {
+    id: id.to_string(),
+    full_name: full_name.to_string(),
+    cpf: format!("cpf-{id}"),
+    birth_date: birth_date.to_string(),
+    sex: "F".to_string(),
+    phone: phone.to_string(),
+    address: String::new(),
+    created_at: "2026-02-14 10:00:00".to_string(),
+    updated_at: "2026-02-14 10:00:00".to_string(),
+    deleted_at: None,
+  }
+}
+
+fn user(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+#[test]
+fn name_similarity_ignores_case_accents_and_connectives() {
+  assert_eq!(
+    name_similarity("Maria da Conceição", "MARIA CONCEICAO"),
+    1.0
+  );
+  assert!(name_similarity("Maria Souza", "Maria Sousa") > 0.7);
+  assert!(name_similarity("Maria Souza", "Pedro Lima") < 0.3);
+  assert_eq!(name_similarity("", ""), 0.0);
+}
+
+#[test]
+fn score_duplicate_adds_weights_and_reasons() {
+  let maria = patient("pt-1", "Maria Souza", "1991-10-01", "(11) 99999-1234");
+  let typo = patient("pt-2", "Maria Sousa", "1991-10-01", "11 99999 1234");
+  let namesake = patient("pt-3", "Maria Souza", "1980-05-05", "");
+
+  let strong = score_duplicate(&maria, &typo);
+  let weak = score_duplicate(&maria, &namesake);
+
+  assert!(strong.score >= 90, "{}", strong.score);
+  assert_eq!(
+    strong.reasons,
+    vec![
+      REASON_SIMILAR_NAME,
+      REASON_SAME_BIRTH_DATE,
+      REASON_SAME_PHONE
+    ]
+  );
+  assert_eq!(weak.score, 50);
+  assert_eq!(weak.reasons, vec![REASON_SIMILAR_NAME]);
+}
+
+#[test]
+fn find_duplicates_filters_by_score_and_orients_on_target() {
+  let patients = vec![
+    patient("pt-1", "Maria Souza", "1991-10-01", "11999991234"),
+    patient("pt-2", "Maria Sousa", "1991-10-01", ""),
+    patient("pt-3", "Pedro Lima", "1991-10-01", ""),
+    patient("pt-4", "Maria Souza", "1980-05-05", ""),
+  ];
+
+  let all = find_duplicates(&patients, None, 60);
+  let targeted = find_duplicates(&patients, Some("pt-2"), 60);
+
+  assert_eq!(all.len(), 1);
+  assert_eq!(all[0].patient.id, "pt-1");
+  assert_eq!(all[0].duplicate.id, "pt-2");
+  assert_eq!(targeted.len(), 1);
+  assert_eq!(targeted[0].patient.id, "pt-2");
+  assert_eq!(targeted[0].duplicate.id, "pt-1");
+}
+
+async fn sqlite_use_case() -> FindDuplicatePatientsUseCase {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES
+        ('pt-1', 'Maria Souza', '52998224725', '1991-10-01', 'F', '11999991234', '', '2026-02-03 08:00:00', '2026-02-03 08:00:00'),
+        ('pt-2', 'Maria Sousa', '12345678909', '1991-10-01', 'F', '', '', '2026-02-02 08:00:00', '2026-02-02 08:00:00'),
+        ('pt-3', 'Maria Souza Lima', '11144477735', '1991-10-01', 'F', '', '', '2026-02-01 08:00:00', '2026-02-01 08:00:00');
+
+      UPDATE patients SET merged_into_patient_id = 'pt-1', merged_at = datetime('now') WHERE id = 'pt-3';
+      "#,
+    )
+    .await
+    .expect("failed to seed patients");
+
+  FindDuplicatePatientsUseCase::new(Arc::new(PatientsSqliteRepository::new(pool)))
+}
+
+#[tokio::test]
+async fn find_duplicate_patients_skips_merged_records() {
+  let use_case = sqlite_use_case().await;
+
+  let found = use_case
+    .execute(&user("reception"), FindDuplicatePatientsInput::default())
+    .await
+    .expect("expected success");
+
+  assert_eq!(found.len(), 1);
+  assert_eq!(found[0].patient.id, "pt-1");
+  assert_eq!(found[0].duplicate.id, "pt-2");
+  assert!(found[0]
+    .reasons
+    .contains(&REASON_SAME_BIRTH_DATE.to_string()));
+}
+
+#[tokio::test]
+async fn find_duplicate_patients_validates_input_and_role() {
+  let use_case = sqlite_use_case().await;
+
+  let bad_score = use_case
+    .execute(
+      &user("admin"),
+      FindDuplicatePatientsInput {
+        min_score: Some(101),
+        ..FindDuplicatePatientsInput::default()
+      },
+    )
+    .await;
+  let merged_target = use_case
+    .execute(
+      &user("admin"),
+      FindDuplicatePatientsInput {
+        patient_id: Some(" pt-3 ".to_string()),
+        ..FindDuplicatePatientsInput::default()
+      },
+    )
+    .await;
+  let bench = use_case
+    .execute(&user("bench"), FindDuplicatePatientsInput::default())
+    .await;
+
+  assert!(matches!(
+    bad_score,
+    Err(AppError::Validation { message: msg, .. }) if msg == "min_score must be between 0 and 100"
+  ));
+  assert!(matches!(merged_target, Err(AppError::NotFound(_))));
+  assert!(matches!(bench, Err(AppError::Forbidden(_))));
+}
@@ -4,13 +4,28 @@ use laboratory_app_lib::{
   app::error::AppError,
   application::patients::get_patient_record::GetPatientRecordUseCase,
   domain::patients::{
-    dto::{CreateAttendanceInput, ExamCatalogItemView, PatientRecordEntryView, PatientRecordView, PatientView},
+    dto::{
+      CreateAttendanceInput, ExamCatalogItemView, PatientRecordEntryView, PatientRecordView,
+      PatientView,
+    },
     entity::Patient,
     errors::PatientRepositoryError,
     ports::PatientRepository,
   },
+  domain::users::dto::UserView,
 };
 
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
 struct StubGetRecordRepository {
   result: Result<PatientRecordView, PatientRepositoryError>,
 }
@@ -20,37 +35,66 @@ impl PatientRepository for StubGetRecordRepository {
   async fn insert(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list(
+    &self,
+    _query: laboratory_app_lib::domain::patients::dto::PatientPageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<Patient>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    _input: laboratory_app_lib::domain::patients::dto::UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<Patient, PatientRepositoryError> {
     unimplemented!()
   }
 
-  async fn list(&self, _query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
     unimplemented!()
   }
 
   async fn get_patient_record(
     &self,
     _patient_id: String,
+    _include_deleted: bool,
   ) -> Result<PatientRecordView, PatientRepositoryError> {
     self.result.clone()
   }
 
-  async fn list_exam_catalog(&self) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+  async fn list_exam_catalog(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
     unimplemented!()
   }
 
   async fn create_attendance(
     &self,
     _input: CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
     unimplemented!()
   }
 
   async fn list_attendance_queue(
     &self,
-    _input: laboratory_app_lib::domain::patients::dto::AttendanceQueueQueryInput,
+    _input: laboratory_app_lib::domain::patients::dto::AttendanceQueuePageQuery,
   ) -> Result<
-    Vec<laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView>,
+    laboratory_app_lib::domain::patients::pagination::PageView<
+      laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
+    >,
     PatientRepositoryError,
   > {
     unimplemented!()
@@ -59,7 +103,11 @@ impl PatientRepository for StubGetRecordRepository {
   async fn complete_attendance(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CompleteAttendanceInput,
-  ) -> Result<laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView, PatientRepositoryError> {
+    _performed_by_user_id: Option<String>,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
+    PatientRepositoryError,
+  > {
     unimplemented!()
   }
 }
@@ -71,9 +119,11 @@ async fn get_record_requires_patient_id() {
   };
   let use_case = GetPatientRecordUseCase::new(Arc::new(repo));
 
-  let result = use_case.execute("   ".to_string()).await;
+  let result = use_case.execute(&admin(), "   ".to_string(), false).await;
 
-  assert!(matches!(result, Err(AppError::Validation(msg)) if msg == "patient_id is required"));
+  assert!(
+    matches!(result, Err(AppError::Validation { message: msg, .. }) if msg == "patient_id is required")
+  );
 }
 
 #[tokio::test]
@@ -83,7 +133,7 @@ async fn get_record_returns_record_on_success() {
   };
   let use_case = GetPatientRecordUseCase::new(Arc::new(repo));
 
-  let result = use_case.execute("pt-1".to_string()).await;
+  let result = use_case.execute(&admin(), "pt-1".to_string(), false).await;
 
   match result {
     Ok(record) => {
@@ -101,9 +151,9 @@ async fn get_record_maps_repository_not_found_error() {
   };
   let use_case = GetPatientRecordUseCase::new(Arc::new(repo));
 
-  let result = use_case.execute("pt-1".to_string()).await;
+  let result = use_case.execute(&admin(), "pt-1".to_string(), false).await;
 
-  assert!(matches!(result, Err(AppError::Database(msg)) if msg == "patient not found"));
+  assert!(matches!(result, Err(AppError::NotFound(msg)) if msg == "patient not found"));
 }
 
 fn sample_record() -> PatientRecordView {
@@ -118,6 +168,7 @@ fn sample_record() -> PatientRecordView {
       address: "Rua A".to_string(),
       created_at: "2026-01-01T00:00:00".to_string(),
       updated_at: "2026-01-01T00:00:00".to_string(),
+      deleted_at: None,
     },
     entries: vec![PatientRecordEntryView {
       exam_id: "ex-1".to_string(),
@@ -125,6 +176,7 @@ fn sample_record() -> PatientRecordView {
       status: "waiting".to_string(),
       requester_name: None,
       items: vec![],
+      deleted_at: None,
     }],
   }
 }
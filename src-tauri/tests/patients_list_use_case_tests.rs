@@ -1,13 +1,41 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use laboratory_app_lib::{
   app::error::AppError,
   application::patients::list_patients::ListPatientsUseCase,
-  domain::patients::{entity::Patient, errors::PatientRepositoryError, ports::PatientRepository},
+  domain::patients::{
+    dto::{ListPatientsInput, PatientPageQuery},
+    entity::Patient,
+    errors::PatientRepositoryError,
+    pagination::{PageCursor, PageView, PatientSort},
+    ports::PatientRepository,
+  },
+  domain::users::dto::UserView,
 };
 
+fn admin() -> UserView {
+  UserView {
+    id: "usr-admin".to_string(),
+    name: "Administrador".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "admin".to_string(),
+    role: "admin".to_string(),
+    is_active: true,
+  }
+}
+
 struct StubListRepository {
   data: Vec<Patient>,
+  queries: Mutex<Vec<PatientPageQuery>>,
+}
+
+impl StubListRepository {
+  fn with(data: Vec<Patient>) -> Self {
+    Self {
+      data,
+      queries: Mutex::new(Vec::new()),
+    }
+  }
 }
 
 #[async_trait::async_trait]
@@ -15,27 +43,50 @@ impl PatientRepository for StubListRepository {
   async fn insert(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreatePatientInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<Patient, PatientRepositoryError> {
     Err(PatientRepositoryError::PersistenceError)
   }
 
-  async fn list(&self, query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
-    let q = query.unwrap_or_default().to_lowercase();
-    if q.trim().is_empty() {
-      return Ok(self.data.clone());
-    }
+  async fn list(
+    &self,
+    query: PatientPageQuery,
+  ) -> Result<PageView<Patient>, PatientRepositoryError> {
+    let q = query.query.clone().unwrap_or_default().to_lowercase();
+    self.queries.lock().unwrap().push(query);
 
-    Ok(self
+    let items: Vec<Patient> = self
       .data
       .iter()
       .filter(|p| p.full_name.to_lowercase().contains(&q) || p.cpf.contains(&q))
       .cloned()
-      .collect())
+      .collect();
+    Ok(PageView {
+      total: items.len() as i64,
+      items,
+      next_cursor: None,
+    })
+  }
+
+  async fn update_patient(
+    &self,
+    _input: laboratory_app_lib::domain::patients::dto::UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    unimplemented!()
   }
 
   async fn get_patient_record(
     &self,
     _patient_id: String,
+    _include_deleted: bool,
   ) -> Result<laboratory_app_lib::domain::patients::dto::PatientRecordView, PatientRepositoryError>
   {
     unimplemented!()
@@ -43,6 +94,7 @@ impl PatientRepository for StubListRepository {
 
   async fn list_exam_catalog(
     &self,
+    _include_inactive: bool,
   ) -> Result<
     Vec<laboratory_app_lib::domain::patients::dto::ExamCatalogItemView>,
     PatientRepositoryError,
@@ -53,6 +105,7 @@ impl PatientRepository for StubListRepository {
   async fn create_attendance(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<
     laboratory_app_lib::domain::patients::dto::PatientRecordEntryView,
     PatientRepositoryError,
@@ -62,9 +115,11 @@ impl PatientRepository for StubListRepository {
 
   async fn list_attendance_queue(
     &self,
-    _input: laboratory_app_lib::domain::patients::dto::AttendanceQueueQueryInput,
+    _input: laboratory_app_lib::domain::patients::dto::AttendanceQueuePageQuery,
   ) -> Result<
-    Vec<laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView>,
+    laboratory_app_lib::domain::patients::pagination::PageView<
+      laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
+    >,
     PatientRepositoryError,
   > {
     unimplemented!()
@@ -73,6 +128,7 @@ impl PatientRepository for StubListRepository {
   async fn complete_attendance(
     &self,
     _input: laboratory_app_lib::domain::patients::dto::CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
   ) -> Result<
     laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
     PatientRepositoryError,
@@ -92,54 +148,199 @@ fn mk_patient(id: &str, full_name: &str, cpf: &str) -> Patient {
     address: "Rua A, 1".to_string(),
     created_at: "2026-01-01T00:00:00".to_string(),
     updated_at: "2026-01-01T00:00:00".to_string(),
+    deleted_at: None,
   }
 }
 
 #[tokio::test]
 async fn list_patients_returns_all_when_query_is_none() {
-  let repo = StubListRepository {
-    data: vec![
-      mk_patient("1", "Maria Silva", "11111111111"),
-      mk_patient("2", "Joao Souza", "22222222222"),
-    ],
-  };
+  let repo = StubListRepository::with(vec![
+    mk_patient("1", "Maria Silva", "11111111111"),
+    mk_patient("2", "Joao Souza", "22222222222"),
+  ]);
   let use_case = ListPatientsUseCase::new(Arc::new(repo));
 
-  let result = use_case.execute(None).await;
+  let result = use_case
+    .execute(&admin(), ListPatientsInput::default())
+    .await;
 
   match result {
-    Ok(items) => {
-      assert_eq!(items.len(), 2);
-      assert_eq!(items[0].cpf, "11111111111");
-      assert_eq!(items[1].cpf, "22222222222");
+    Ok(page) => {
+      assert_eq!(page.items.len(), 2);
+      assert_eq!(page.total, 2);
+      assert_eq!(page.items[0].cpf, "11111111111");
+      assert_eq!(page.items[1].cpf, "22222222222");
     }
     Err(_) => panic!("expected success"),
   }
 }
 
 #[tokio::test]
-async fn list_patients_filters_by_name_or_cpf() {
-  let repo = StubListRepository {
-    data: vec![
-      mk_patient("1", "Maria Silva", "11111111111"),
-      mk_patient("2", "Joao Souza", "22222222222"),
-    ],
+async fn list_patients_only_shows_deleted_to_admin() {
+  let use_case = ListPatientsUseCase::new(Arc::new(StubListRepository::with(vec![mk_patient(
+    "1",
+    "Maria Silva",
+    "11111111111",
+  )])));
+  let with_deleted = || ListPatientsInput {
+    include_deleted: Some(true),
+    ..ListPatientsInput::default()
+  };
+  let reception = UserView {
+    role: "reception".to_string(),
+    ..admin()
   };
+
+  let denied = use_case.execute(&reception, with_deleted()).await;
+  let allowed = use_case.execute(&admin(), with_deleted()).await;
+  let default_view = use_case
+    .execute(&reception, ListPatientsInput::default())
+    .await;
+
+  assert!(matches!(
+    denied,
+    Err(AppError::Forbidden(msg)) if msg == "role reception is not allowed to delete_records"
+  ));
+  assert!(allowed.is_ok());
+  assert!(default_view.is_ok());
+}
+
+#[tokio::test]
+async fn list_patients_filters_by_name_or_cpf() {
+  let repo = StubListRepository::with(vec![
+    mk_patient("1", "Maria Silva", "11111111111"),
+    mk_patient("2", "Joao Souza", "22222222222"),
+  ]);
   let use_case = ListPatientsUseCase::new(Arc::new(repo));
 
-  let by_name = use_case.execute(Some("maria".to_string())).await;
-  let by_cpf = use_case.execute(Some("2222".to_string())).await;
+  let by_name = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        query: Some("maria".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+  let by_cpf = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        query: Some("2222".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
 
   match by_name {
-    Ok(items) => assert_eq!(items.len(), 1),
+    Ok(page) => assert_eq!(page.items.len(), 1),
     Err(_) => panic!("expected success"),
   }
   match by_cpf {
-    Ok(items) => assert_eq!(items.len(), 1),
+    Ok(page) => assert_eq!(page.items.len(), 1),
     Err(_) => panic!("expected success"),
   }
 }
 
+#[tokio::test]
+async fn list_patients_defaults_sort_and_limit_and_decodes_cursor() {
+  let repo = Arc::new(StubListRepository::with(Vec::new()));
+  let use_case = ListPatientsUseCase::new(repo.clone());
+  let cursor = PageCursor::new("name", vec!["Maria".to_string(), "pt-1".to_string()]);
+
+  use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        query: Some("   ".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await
+    .expect("expected success");
+  use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        sort: Some("name".to_string()),
+        cursor: Some(cursor.encode()),
+        limit: Some(10),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await
+    .expect("expected success");
+
+  let queries = repo.queries.lock().unwrap();
+  assert!(queries[0].query.is_none());
+  assert_eq!(queries[0].sort, PatientSort::CreatedAt);
+  assert_eq!(queries[0].limit, 50);
+  assert!(queries[0].after.is_none());
+  assert_eq!(queries[1].sort, PatientSort::Name);
+  assert_eq!(queries[1].limit, 10);
+  assert_eq!(queries[1].after.as_ref(), Some(&cursor));
+}
+
+#[tokio::test]
+async fn list_patients_validates_sort_limit_and_cursor() {
+  let repo = Arc::new(StubListRepository::with(Vec::new()));
+  let use_case = ListPatientsUseCase::new(repo.clone());
+  let name_cursor = PageCursor::new("name", vec!["Maria".to_string(), "pt-1".to_string()]);
+
+  let bad_sort = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        sort: Some("cpf".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+  let bad_limit = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        limit: Some(0),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+  let garbage_cursor = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        cursor: Some("not-a-cursor".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+  let other_sort_cursor = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        cursor: Some(name_cursor.encode()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    bad_sort,
+    Err(AppError::Validation { message: msg, .. }) if msg == "sort must be one of created_at, name, relevance"
+  ));
+  assert!(matches!(
+    bad_limit,
+    Err(AppError::Validation { message: msg, .. }) if msg == "limit must be between 1 and 200"
+  ));
+  for result in [garbage_cursor, other_sort_cursor] {
+    assert!(matches!(
+      result,
+      Err(AppError::Validation { message: msg, .. }) if msg == "cursor is invalid for this sort"
+    ));
+  }
+  assert!(repo.queries.lock().unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn list_patients_maps_repository_error() {
   struct ErrRepo;
@@ -149,26 +350,45 @@ async fn list_patients_maps_repository_error() {
     async fn insert(
       &self,
       _input: laboratory_app_lib::domain::patients::dto::CreatePatientInput,
+      _performed_by_user_id: Option<String>,
     ) -> Result<Patient, PatientRepositoryError> {
       Err(PatientRepositoryError::PersistenceError)
     }
 
-    async fn list(&self, _query: Option<String>) -> Result<Vec<Patient>, PatientRepositoryError> {
+    async fn list(
+      &self,
+      _query: PatientPageQuery,
+    ) -> Result<PageView<Patient>, PatientRepositoryError> {
       Err(PatientRepositoryError::PersistenceError)
     }
 
+    async fn update_patient(
+      &self,
+      _input: laboratory_app_lib::domain::patients::dto::UpdatePatientInput,
+      _performed_by_user_id: Option<String>,
+    ) -> Result<Patient, PatientRepositoryError> {
+      unimplemented!()
+    }
+
+    async fn find_patient(
+      &self,
+      _patient_id: String,
+    ) -> Result<Option<Patient>, PatientRepositoryError> {
+      unimplemented!()
+    }
+
     async fn get_patient_record(
       &self,
       _patient_id: String,
-    ) -> Result<
-      laboratory_app_lib::domain::patients::dto::PatientRecordView,
-      PatientRepositoryError,
-    > {
+      _include_deleted: bool,
+    ) -> Result<laboratory_app_lib::domain::patients::dto::PatientRecordView, PatientRepositoryError>
+    {
       unimplemented!()
     }
 
     async fn list_exam_catalog(
       &self,
+      _include_inactive: bool,
     ) -> Result<
       Vec<laboratory_app_lib::domain::patients::dto::ExamCatalogItemView>,
       PatientRepositoryError,
@@ -179,6 +399,7 @@ async fn list_patients_maps_repository_error() {
     async fn create_attendance(
       &self,
       _input: laboratory_app_lib::domain::patients::dto::CreateAttendanceInput,
+      _performed_by_user_id: Option<String>,
     ) -> Result<
       laboratory_app_lib::domain::patients::dto::PatientRecordEntryView,
       PatientRepositoryError,
@@ -188,9 +409,11 @@ async fn list_patients_maps_repository_error() {
 
     async fn list_attendance_queue(
       &self,
-      _input: laboratory_app_lib::domain::patients::dto::AttendanceQueueQueryInput,
+      _input: laboratory_app_lib::domain::patients::dto::AttendanceQueuePageQuery,
     ) -> Result<
-      Vec<laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView>,
+      laboratory_app_lib::domain::patients::pagination::PageView<
+        laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
+      >,
       PatientRepositoryError,
     > {
       unimplemented!()
@@ -199,6 +422,7 @@ async fn list_patients_maps_repository_error() {
     async fn complete_attendance(
       &self,
       _input: laboratory_app_lib::domain::patients::dto::CompleteAttendanceInput,
+      _performed_by_user_id: Option<String>,
     ) -> Result<
       laboratory_app_lib::domain::patients::dto::AttendanceQueueItemView,
       PatientRepositoryError,
@@ -208,7 +432,61 @@ async fn list_patients_maps_repository_error() {
   }
 
   let use_case = ListPatientsUseCase::new(Arc::new(ErrRepo));
-  let result = use_case.execute(None).await;
+  let result = use_case
+    .execute(&admin(), ListPatientsInput::default())
+    .await;
 
   assert!(matches!(result, Err(AppError::Database(msg)) if msg == "failed to fetch patients"));
 }
+
+#[tokio::test]
+async fn list_patients_sorts_searches_by_relevance() {
+  let repo = Arc::new(StubListRepository::with(Vec::new()));
+  let use_case = ListPatientsUseCase::new(repo.clone());
+
+  use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        query: Some("mar sil".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await
+    .expect("expected success");
+  let without_query = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        sort: Some("relevance".to_string()),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+  let non_numeric_cursor = use_case
+    .execute(
+      &admin(),
+      ListPatientsInput {
+        query: Some("mar".to_string()),
+        cursor: Some(
+          PageCursor::new(
+            "relevance",
+            vec!["x".to_string(), "1".to_string(), "pt-1".to_string()],
+          )
+          .encode(),
+        ),
+        ..ListPatientsInput::default()
+      },
+    )
+    .await;
+
+  assert_eq!(repo.queries.lock().unwrap()[0].sort, PatientSort::Relevance);
+  assert!(matches!(
+    without_query,
+    Err(AppError::Validation { message: msg, .. }) if msg == "sort relevance requires a query"
+  ));
+  assert!(matches!(
+    non_numeric_cursor,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cursor is invalid for this sort"
+  ));
+}
@@ -0,0 +1,133 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::merge_patients::MergePatientsUseCase,
+  domain::{
+    patients::{
+      dto::{MergePatientsInput, MergePatientsResultView, PatientView},
+      errors::PatientRepositoryError,
+      ports::PatientMergeRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+#[derive(Default)]
+struct StubMergeRepository {
+  calls: Mutex<Vec<(MergePatientsInput, Option<String>)>>,
+  fail_with: Option<PatientRepositoryError>,
+}
+
+#[async_trait::async_trait]
+impl PatientMergeRepository for StubMergeRepository {
+  async fn merge_patients(
+    &self,
+    input: MergePatientsInput,
+    performed_by_user_id: Option<String>,
+  ) -> Result<MergePatientsResultView, PatientRepositoryError> {
+    if let Some(err) = &self.fail_with {
+      return Err(err.clone());
+    }
+    let result = MergePatientsResultView {
+      patient: PatientView {
+        id: input.surviving_patient_id.clone(),
+        full_name: "Maria Souza".to_string(),
+        cpf: "52998224725".to_string(),
+        birth_date: "1991-10-01".to_string(),
+        sex: "F".to_string(),
+        phone: String::new(),
+        address: String::new(),
+        created_at: "2026-02-14 10:00:00".to_string(),
+        updated_at: "2026-02-14 10:00:00".to_string(),
+        deleted_at: None,
+      },
+      merged_patient_id: input.merged_patient_id.clone(),
+      moved_attendances: 2,
+    };
+    self
+      .calls
+      .lock()
+      .unwrap()
+      .push((input, performed_by_user_id));
+    Ok(result)
+  }
+}
+
+fn user(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn input(surviving: &str, merged: &str) -> MergePatientsInput {
+  MergePatientsInput {
+    surviving_patient_id: surviving.to_string(),
+    merged_patient_id: merged.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn merge_patients_trims_ids_and_records_actor() {
+  let repo = Arc::new(StubMergeRepository::default());
+  let use_case = MergePatientsUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(&user("reception"), input(" pt-1 ", "pt-2 "))
+    .await
+    .expect("expected success");
+
+  assert_eq!(result.patient.id, "pt-1");
+  assert_eq!(result.merged_patient_id, "pt-2");
+  let calls = repo.calls.lock().unwrap();
+  assert_eq!(calls[0].0.surviving_patient_id, "pt-1");
+  assert_eq!(calls[0].0.merged_patient_id, "pt-2");
+  assert_eq!(calls[0].1.as_deref(), Some("usr-1"));
+}
+
+#[tokio::test]
+async fn merge_patients_validates_ids() {
+  let repo = Arc::new(StubMergeRepository::default());
+  let use_case = MergePatientsUseCase::new(repo.clone());
+
+  let missing = use_case.execute(&user("admin"), input("", "pt-2")).await;
+  let same = use_case
+    .execute(&user("admin"), input("pt-1", " pt-1"))
+    .await;
+
+  assert!(matches!(
+    missing,
+    Err(AppError::Validation { message: msg, .. }) if msg == "surviving_patient_id is required"
+  ));
+  assert!(matches!(
+    same,
+    Err(AppError::Validation { message: msg, .. }) if msg == "merged_patient_id must differ from surviving_patient_id"
+  ));
+  assert!(repo.calls.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn merge_patients_maps_missing_patient_and_role() {
+  let use_case = MergePatientsUseCase::new(Arc::new(StubMergeRepository {
+    fail_with: Some(PatientRepositoryError::NotFound),
+    ..StubMergeRepository::default()
+  }));
+
+  let missing = use_case
+    .execute(&user("admin"), input("pt-1", "pt-2"))
+    .await;
+  let bench = use_case
+    .execute(&user("bench"), input("pt-1", "pt-2"))
+    .await;
+
+  assert!(matches!(
+    missing,
+    Err(AppError::NotFound(msg)) if msg == "patient not found or already merged"
+  ));
+  assert!(matches!(bench, Err(AppError::Forbidden(_))));
+}
@@ -0,0 +1,199 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::{
+    delete_attendance::DeleteAttendanceUseCase, delete_patient::DeletePatientUseCase,
+    restore_attendance::RestoreAttendanceUseCase, restore_patient::RestorePatientUseCase,
+  },
+  domain::{
+    patients::{
+      dto::{AttendanceDeletionInput, AttendanceQueueItemView, PatientDeletionInput},
+      entity::Patient,
+      errors::PatientRepositoryError,
+      ports::RecordDeletionRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+#[derive(Default)]
+struct StubDeletionRepository {
+  calls: Mutex<Vec<(String, bool, Option<String>)>>,
+  fail_with: Option<PatientRepositoryError>,
+}
+
+#[async_trait::async_trait]
+impl RecordDeletionRepository for StubDeletionRepository {
+  async fn set_patient_deleted(
+    &self,
+    patient_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    if let Some(err) = &self.fail_with {
+      return Err(err.clone());
+    }
+    self
+      .calls
+      .lock()
+      .unwrap()
+      .push((patient_id.clone(), deleted, performed_by_user_id));
+    Ok(Patient {
+      id: patient_id,
+      full_name: "Maria Souza".to_string(),
+      cpf: "52998224725".to_string(),
+      birth_date: "1991-10-01".to_string(),
+      sex: "F".to_string(),
+      phone: String::new(),
+      address: String::new(),
+      created_at: "2026-02-14 10:00:00".to_string(),
+      updated_at: "2026-02-14 11:00:00".to_string(),
+      deleted_at: deleted.then(|| "2026-02-14 11:00:00".to_string()),
+    })
+  }
+
+  async fn set_attendance_deleted(
+    &self,
+    attendance_id: String,
+    deleted: bool,
+    performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    if let Some(err) = &self.fail_with {
+      return Err(err.clone());
+    }
+    self
+      .calls
+      .lock()
+      .unwrap()
+      .push((attendance_id.clone(), deleted, performed_by_user_id));
+    Ok(AttendanceQueueItemView {
+      attendance_id,
+      patient_id: "pt-1".to_string(),
+      patient_name: "Maria Souza".to_string(),
+      patient_cpf: "52998224725".to_string(),
+      exam_date: "2026-02-14".to_string(),
+      status: "waiting".to_string(),
+      exam_names: vec!["Glicose".to_string()],
+      updated_at: "2026-02-14 11:00:00".to_string(),
+      deleted_at: deleted.then(|| "2026-02-14 11:00:00".to_string()),
+    })
+  }
+}
+
+fn user(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn patient_input(patient_id: &str) -> PatientDeletionInput {
+  PatientDeletionInput {
+    patient_id: patient_id.to_string(),
+  }
+}
+
+fn attendance_input(attendance_id: &str) -> AttendanceDeletionInput {
+  AttendanceDeletionInput {
+    attendance_id: attendance_id.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn delete_and_restore_patient_pass_the_target_state_and_actor() {
+  let repo = Arc::new(StubDeletionRepository::default());
+
+  let deleted = DeletePatientUseCase::new(repo.clone())
+    .execute(&user("admin"), patient_input(" pt-1 "))
+    .await
+    .expect("expected success");
+  let restored = RestorePatientUseCase::new(repo.clone())
+    .execute(&user("admin"), patient_input("pt-1"))
+    .await
+    .expect("expected success");
+
+  assert!(deleted.deleted_at.is_some());
+  assert!(restored.deleted_at.is_none());
+  let calls = repo.calls.lock().unwrap();
+  assert_eq!(
+    calls[0],
+    ("pt-1".to_string(), true, Some("usr-1".to_string()))
+  );
+  assert_eq!(
+    calls[1],
+    ("pt-1".to_string(), false, Some("usr-1".to_string()))
+  );
+}
+
+#[tokio::test]
+async fn delete_and_restore_attendance_pass_the_target_state() {
+  let repo = Arc::new(StubDeletionRepository::default());
+
+  DeleteAttendanceUseCase::new(repo.clone())
+    .execute(&user("admin"), attendance_input("att-1"))
+    .await
+    .expect("expected success");
+  RestoreAttendanceUseCase::new(repo.clone())
+    .execute(&user("admin"), attendance_input("att-1"))
+    .await
+    .expect("expected success");
+
+  let calls = repo.calls.lock().unwrap();
+  assert!(calls[0].1);
+  assert!(!calls[1].1);
+}
+
+#[tokio::test]
+async fn soft_delete_is_admin_only_and_validates_ids() {
+  let repo = Arc::new(StubDeletionRepository::default());
+
+  let reception = DeletePatientUseCase::new(repo.clone())
+    .execute(&user("reception"), patient_input("pt-1"))
+    .await;
+  let biochemist = DeleteAttendanceUseCase::new(repo.clone())
+    .execute(&user("biochemist"), attendance_input("att-1"))
+    .await;
+  let blank = RestoreAttendanceUseCase::new(repo.clone())
+    .execute(&user("admin"), attendance_input("  "))
+    .await;
+
+  assert!(matches!(
+    reception,
+    Err(AppError::Forbidden(msg)) if msg == "role reception is not allowed to delete_records"
+  ));
+  assert!(matches!(biochemist, Err(AppError::Forbidden(_))));
+  assert!(matches!(
+    blank,
+    Err(AppError::Validation { message: msg, .. }) if msg == "attendance_id is required"
+  ));
+  assert!(repo.calls.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn soft_delete_explains_state_conflicts() {
+  let repo = Arc::new(StubDeletionRepository {
+    fail_with: Some(PatientRepositoryError::Conflict),
+    ..StubDeletionRepository::default()
+  });
+
+  let delete_twice = DeletePatientUseCase::new(repo.clone())
+    .execute(&user("admin"), patient_input("pt-1"))
+    .await;
+  let restore_active = RestoreAttendanceUseCase::new(repo)
+    .execute(&user("admin"), attendance_input("att-1"))
+    .await;
+
+  assert!(matches!(
+    delete_twice,
+    Err(AppError::Conflict(msg)) if msg == "patient is already deleted"
+  ));
+  assert!(matches!(
+    restore_active,
+    Err(AppError::Conflict(msg)) if msg == "attendance is not deleted"
+  ));
+}
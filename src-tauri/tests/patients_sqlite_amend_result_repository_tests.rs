@@ -0,0 +1,188 @@
+use laboratory_app_lib::{
+  domain::{
+    patients::{
+      dto::AmendResultInput, errors::PatientRepositoryError, ports::AttendanceRepository,
+    },
+    reports::{dto::RecordPdfReportInput, ports::ReportRepository},
+  },
+  infra::{
+    db::sqlite::run_migrations,
+    repositories::{
+      patients_sqlite::PatientsSqliteRepository, reports_sqlite::ReportsSqliteRepository,
+    },
+  },
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-bio', 'Beatriz Bioquimica', '52998224725', 'beatriz', 'hash', 'biochemist', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, released_at, released_by, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'released', '2026-02-17 12:00:00', 'usr-bio', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-2', 'pt-1', '2026-02-18', 'in_analysis', NULL, NULL, '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+
+      INSERT INTO exam_items (id, exam_id, name, unit, result_value, result_flag, released_at, released_by, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'Glicose', 'mg/dL', '92', NULL, '2026-02-17 12:00:00', 'usr-bio', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-2', 'Glicose', 'mg/dL', '88', NULL, NULL, NULL, '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn amendment(attendance_id: &str, exam_item_id: &str, value: &str) -> AmendResultInput {
+  AmendResultInput {
+    attendance_id: attendance_id.to_string(),
+    exam_item_id: exam_item_id.to_string(),
+    result_value: value.to_string(),
+    result_flag: Some("H".to_string()),
+    reason: "erro de digitacao".to_string(),
+  }
+}
+
+#[tokio::test]
+async fn amend_result_keeps_previous_values_in_history() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let entry = repo
+    .amend_result(
+      amendment("att-1", "it-1", "120"),
+      Some("usr-bio".to_string()),
+    )
+    .await
+    .expect("amendment should succeed");
+  assert_eq!(entry.items[0].result_value.as_deref(), Some("120"));
+  assert_eq!(entry.items[0].result_version, 2);
+  assert!(entry.items[0].report_available);
+
+  repo
+    .amend_result(
+      AmendResultInput {
+        result_flag: None,
+        reason: "amostra reprocessada".to_string(),
+        ..amendment("att-1", "it-1", "95")
+      },
+      Some("usr-bio".to_string()),
+    )
+    .await
+    .expect("second amendment should succeed");
+
+  let history = repo
+    .list_result_history("it-1".to_string())
+    .await
+    .expect("history should load");
+  assert_eq!(history.len(), 2);
+  assert_eq!(history[0].result_version, 1);
+  assert_eq!(history[0].result_value.as_deref(), Some("92"));
+  assert_eq!(history[0].result_flag, None);
+  assert_eq!(history[0].reason, "erro de digitacao");
+  assert_eq!(history[0].amended_by_user_id.as_deref(), Some("usr-bio"));
+  assert_eq!(history[1].result_version, 2);
+  assert_eq!(history[1].result_value.as_deref(), Some("120"));
+  assert_eq!(history[1].result_flag.as_deref(), Some("H"));
+
+  let item = sqlx::query("SELECT result_value, result_version FROM exam_items WHERE id = 'it-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("item should exist");
+  assert_eq!(
+    item.get::<Option<String>, _>("result_value").as_deref(),
+    Some("95")
+  );
+  assert_eq!(item.get::<i64, _>("result_version"), 3);
+
+  let audits = sqlx::query(
+    "SELECT COUNT(*) AS total FROM audit_log WHERE entity_name = 'exam_items' AND entity_id = 'it-1' AND action = 'update'",
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("audit should load");
+  assert_eq!(audits.get::<i64, _>("total"), 2);
+}
+
+#[tokio::test]
+async fn amend_result_refuses_unreleased_or_unknown_items() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let unreleased = repo
+    .amend_result(amendment("att-2", "it-2", "120"), None)
+    .await;
+  let other_attendance = repo
+    .amend_result(amendment("att-2", "it-1", "120"), None)
+    .await;
+  let unknown_history = repo.list_result_history("it-404".to_string()).await;
+
+  assert!(matches!(unreleased, Err(PatientRepositoryError::Conflict)));
+  assert!(matches!(
+    other_attendance,
+    Err(PatientRepositoryError::NotFound)
+  ));
+  assert!(matches!(
+    unknown_history,
+    Err(PatientRepositoryError::NotFound)
+  ));
+  let history = sqlx::query("SELECT COUNT(*) AS total FROM exam_result_history")
+    .fetch_one(&pool)
+    .await
+    .expect("history should load");
+  assert_eq!(history.get::<i64, _>("total"), 0);
+}
+
+#[tokio::test]
+async fn amended_results_mark_the_report_as_amended() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let reports = ReportsSqliteRepository::new(pool);
+
+  let source = reports
+    .get_lab_report_source("att-1".to_string())
+    .await
+    .expect("source should load");
+  assert!(!source.amended);
+
+  repo
+    .amend_result(amendment("att-1", "it-1", "120"), None)
+    .await
+    .expect("amendment should succeed");
+  let source = reports
+    .get_lab_report_source("att-1".to_string())
+    .await
+    .expect("source should load");
+  assert!(source.amended);
+  assert_eq!(source.entry.items[0].result_version, 2);
+
+  let report = reports
+    .record_pdf_report(RecordPdfReportInput {
+      attendance_id: "att-1".to_string(),
+      report_version: 1,
+      generated_by_user_id: None,
+      note: Some("Retificado: erro de digitacao".to_string()),
+      amended: source.amended,
+    })
+    .await
+    .expect("report should be recorded");
+  assert!(report.is_amended);
+}
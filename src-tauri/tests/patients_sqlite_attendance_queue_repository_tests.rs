@@ -1,6 +1,7 @@
 use laboratory_app_lib::{
   domain::patients::{
-    dto::AttendanceQueueQueryInput,
+    dto::AttendanceQueuePageQuery,
+    pagination::{AttendanceSort, PageCursor},
     ports::PatientRepository,
   },
   infra::repositories::patients_sqlite::PatientsSqliteRepository,
@@ -27,7 +28,11 @@ async fn setup_pool() -> SqlitePool {
         address TEXT NOT NULL,
         cpf VARCHAR(14) NOT NULL UNIQUE,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        merged_into_patient_id TEXT,
+        merged_at DATETIME,
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -47,7 +52,9 @@ async fn setup_pool() -> SqlitePool {
         delivered_to TEXT,
         notes TEXT,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -77,6 +84,19 @@ async fn setup_pool() -> SqlitePool {
   pool
 }
 
+fn queue_query() -> AttendanceQueuePageQuery {
+  AttendanceQueuePageQuery {
+    date: None,
+    status: None,
+    query: None,
+    include_deleted: false,
+    include_cancelled: false,
+    sort: AttendanceSort::ExamDate,
+    after: None,
+    limit: 50,
+  }
+}
+
 async fn seed_data(pool: &SqlitePool) {
   pool
     .execute(
@@ -96,7 +116,7 @@ async fn seed_data(pool: &SqlitePool) {
       INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
       VALUES
         ('att-1', 'pt-1', '2026-02-17', 'waiting', datetime('now'), datetime('now')),
-        ('att-2', 'pt-2', '2026-02-17', 'completed', datetime('now'), datetime('now')),
+        ('att-2', 'pt-2', '2026-02-17', 'collected', datetime('now'), datetime('now')),
         ('att-3', 'pt-1', '2026-02-18', 'waiting', datetime('now'), datetime('now'));
       "#,
     )
@@ -125,17 +145,19 @@ async fn list_attendance_queue_filters_by_date_status_and_query() {
   let repo = PatientsSqliteRepository::new(pool);
 
   let listed = repo
-    .list_attendance_queue(AttendanceQueueQueryInput {
+    .list_attendance_queue(AttendanceQueuePageQuery {
       date: Some("2026-02-17".to_string()),
       status: Some("waiting".to_string()),
       query: Some("maria".to_string()),
+      ..queue_query()
     })
     .await
     .expect("list should succeed");
 
-  assert_eq!(listed.len(), 1);
-  assert_eq!(listed[0].attendance_id, "att-1");
-  assert_eq!(listed[0].exam_names.len(), 2);
+  assert_eq!(listed.items.len(), 1);
+  assert_eq!(listed.total, 1);
+  assert_eq!(listed.items[0].attendance_id, "att-1");
+  assert_eq!(listed.items[0].exam_names.len(), 2);
 }
 
 #[tokio::test]
@@ -145,13 +167,143 @@ async fn list_attendance_queue_returns_empty_when_no_match() {
   let repo = PatientsSqliteRepository::new(pool);
 
   let listed = repo
-    .list_attendance_queue(AttendanceQueueQueryInput {
+    .list_attendance_queue(AttendanceQueuePageQuery {
       date: Some("2026-02-19".to_string()),
       status: Some("waiting".to_string()),
-      query: None,
+      ..queue_query()
     })
     .await
     .expect("list should succeed");
 
-  assert!(listed.is_empty());
+  assert!(listed.items.is_empty());
+  assert_eq!(listed.total, 0);
+  assert!(listed.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn list_attendance_queue_pages_over_attendances_not_items() {
+  let pool = setup_pool().await;
+  seed_data(&pool).await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let first = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      limit: 2,
+      ..queue_query()
+    })
+    .await
+    .expect("first page should succeed");
+  let cursor = first.next_cursor.clone().expect("expected a next page");
+  let second = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      limit: 2,
+      after: PageCursor::decode(&cursor, "exam_date", 3),
+      ..queue_query()
+    })
+    .await
+    .expect("second page should succeed");
+
+  let first_ids: Vec<&str> = first
+    .items
+    .iter()
+    .map(|i| i.attendance_id.as_str())
+    .collect();
+  assert_eq!(first_ids, vec!["att-3", "att-2"]);
+  assert_eq!(first.total, 3);
+  assert_eq!(second.items.len(), 1);
+  assert_eq!(second.items[0].attendance_id, "att-1");
+  assert_eq!(
+    second.items[0].exam_names,
+    vec!["Glicose".to_string(), "Colesterol Total".to_string()]
+  );
+  assert!(second.next_cursor.is_none());
+  assert_eq!(second.total, 3);
+}
+
+#[tokio::test]
+async fn list_attendance_queue_sorts_by_patient_name_and_counts_item_matches_once() {
+  let pool = setup_pool().await;
+  seed_data(&pool).await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let by_name = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      sort: AttendanceSort::Name,
+      limit: 1,
+      ..queue_query()
+    })
+    .await
+    .expect("list should succeed");
+  let rest = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      sort: AttendanceSort::Name,
+      after: by_name
+        .next_cursor
+        .as_deref()
+        .and_then(|cursor| PageCursor::decode(cursor, "name", 2)),
+      ..queue_query()
+    })
+    .await
+    .expect("list should succeed");
+  let by_item = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      query: Some("o".to_string()),
+      date: Some("2026-02-17".to_string()),
+      ..queue_query()
+    })
+    .await
+    .expect("list should succeed");
+
+  assert_eq!(by_name.items[0].patient_name, "Joao Silva");
+  let rest_ids: Vec<&str> = rest
+    .items
+    .iter()
+    .map(|i| i.attendance_id.as_str())
+    .collect();
+  assert_eq!(rest_ids, vec!["att-1", "att-3"]);
+  assert_eq!(by_item.total, 2);
+  assert_eq!(by_item.items.len(), 2);
+}
+
+#[tokio::test]
+async fn list_attendance_queue_hides_cancelled_unless_requested() {
+  let pool = setup_pool().await;
+  seed_data(&pool).await;
+  pool
+    .execute("UPDATE exams SET status = 'cancelled' WHERE id = 'att-2'")
+    .await
+    .expect("failed to cancel attendance");
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let default = repo
+    .list_attendance_queue(queue_query())
+    .await
+    .expect("list should succeed");
+  let ids: Vec<&str> = default
+    .items
+    .iter()
+    .map(|item| item.attendance_id.as_str())
+    .collect();
+  assert!(!ids.contains(&"att-2"));
+  assert_eq!(default.total, 2);
+
+  let included = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      include_cancelled: true,
+      ..queue_query()
+    })
+    .await
+    .expect("list should succeed");
+  assert_eq!(included.total, 3);
+
+  let only_cancelled = repo
+    .list_attendance_queue(AttendanceQueuePageQuery {
+      status: Some("cancelled".to_string()),
+      include_cancelled: true,
+      ..queue_query()
+    })
+    .await
+    .expect("list should succeed");
+  assert_eq!(only_cancelled.items.len(), 1);
+  assert_eq!(only_cancelled.items[0].attendance_id, "att-2");
 }
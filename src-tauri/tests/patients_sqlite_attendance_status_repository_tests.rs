@@ -0,0 +1,142 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::AttendanceStatusChange, errors::PatientRepositoryError, ports::AttendanceRepository,
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool).await.expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES ('att-1', 'pt-1', '2026-02-17', 'collected', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn change(from: &str, to: &str) -> AttendanceStatusChange {
+  AttendanceStatusChange {
+    attendance_id: "att-1".to_string(),
+    from_status: from.to_string(),
+    to_status: to.to_string(),
+    changed_by_user_id: None,
+    note: Some("amostra recebida na bancada".to_string()),
+  }
+}
+
+#[tokio::test]
+async fn change_attendance_status_updates_exam_and_records_history() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let updated = repo
+    .change_attendance_status(change("collected", "in_analysis"))
+    .await
+    .expect("change should succeed");
+  assert_eq!(updated.status, "in_analysis");
+
+  let history = repo
+    .list_attendance_status_history("att-1".to_string())
+    .await
+    .expect("history should load");
+  assert_eq!(history.len(), 1);
+  assert_eq!(history[0].from_status.as_deref(), Some("collected"));
+  assert_eq!(history[0].to_status, "in_analysis");
+  assert_eq!(history[0].note.as_deref(), Some("amostra recebida na bancada"));
+}
+
+#[tokio::test]
+async fn change_attendance_status_returns_conflict_on_stale_status() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let result = repo
+    .change_attendance_status(change("waiting", "collected"))
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::Conflict)));
+}
+
+#[tokio::test]
+async fn cancel_attendance_stores_reason_and_user_alongside_history() {
+  let pool = setup_pool().await;
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-1', 'Rita Recepcao', '52998224725', 'rita', 'hash', 'reception', TRUE, datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed user");
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let cancelled = repo
+    .cancel_attendance(AttendanceStatusChange {
+      changed_by_user_id: Some("usr-1".to_string()),
+      note: Some("  paciente desistiu ".to_string()),
+      ..change("collected", "cancelled")
+    })
+    .await
+    .expect("cancel should succeed");
+  assert_eq!(cancelled.status, "cancelled");
+
+  let exam = sqlx::query(
+    "SELECT cancelled_at, cancelled_by, cancellation_reason FROM exams WHERE id = 'att-1'",
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("exam should exist");
+  assert!(exam.get::<Option<String>, _>("cancelled_at").is_some());
+  assert_eq!(
+    exam.get::<Option<String>, _>("cancelled_by").as_deref(),
+    Some("usr-1")
+  );
+  assert_eq!(
+    exam
+      .get::<Option<String>, _>("cancellation_reason")
+      .as_deref(),
+    Some("paciente desistiu")
+  );
+
+  let history = repo
+    .list_attendance_status_history("att-1".to_string())
+    .await
+    .expect("history should load");
+  assert_eq!(history[0].to_status, "cancelled");
+  assert_eq!(history[0].note.as_deref(), Some("paciente desistiu"));
+
+  let again = repo
+    .cancel_attendance(change("collected", "cancelled"))
+    .await;
+  assert!(matches!(again, Err(PatientRepositoryError::Conflict)));
+}
+
+#[tokio::test]
+async fn list_attendance_status_history_returns_not_found_for_missing_attendance() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let result = repo
+    .list_attendance_status_history("missing".to_string())
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::NotFound)));
+}
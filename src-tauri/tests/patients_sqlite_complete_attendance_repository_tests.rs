@@ -1,7 +1,8 @@
 use laboratory_app_lib::{
   domain::patients::{
-    dto::{AttendanceQueueQueryInput, CompleteAttendanceInput},
+    dto::{AttendanceQueuePageQuery, CompleteAttendanceInput},
     errors::PatientRepositoryError,
+    pagination::AttendanceSort,
     ports::PatientRepository,
   },
   infra::repositories::patients_sqlite::PatientsSqliteRepository,
@@ -28,7 +29,11 @@ async fn setup_pool() -> SqlitePool {
         address TEXT NOT NULL,
         cpf VARCHAR(14) NOT NULL UNIQUE,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        merged_into_patient_id TEXT,
+        merged_at DATETIME,
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -48,7 +53,9 @@ async fn setup_pool() -> SqlitePool {
         delivered_to TEXT,
         notes TEXT,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -75,6 +82,43 @@ async fn setup_pool() -> SqlitePool {
     .await
     .expect("failed to create exam_items table");
 
+  pool
+    .execute(
+      r#"
+      CREATE TABLE attendance_status_history (
+        id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+        exam_id TEXT NOT NULL,
+        from_status VARCHAR(20),
+        to_status VARCHAR(20) NOT NULL,
+        changed_by_user_id TEXT,
+        changed_at DATETIME NOT NULL CHECK(typeof(changed_at) = 'text'),
+        note TEXT
+      );
+      "#,
+    )
+    .await
+    .expect("failed to create attendance_status_history table");
+
+  pool
+    .execute(
+      r#"
+      CREATE TABLE audit_log (
+        id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+        entity_name VARCHAR(50) NOT NULL,
+        entity_id TEXT NOT NULL,
+        action VARCHAR(10) NOT NULL,
+        performed_by_user_id TEXT,
+        performed_at DATETIME NOT NULL,
+        before_json TEXT,
+        after_json TEXT,
+        sequence INTEGER UNIQUE,
+        prev_hash TEXT,
+        entry_hash TEXT
+      );
+      "#,
+    )
+    .await
+    .expect("failed to create audit_log table");
   pool
 }
 
@@ -117,25 +161,33 @@ async fn complete_attendance_updates_status_and_returns_updated_item() {
   let repo = PatientsSqliteRepository::new(pool);
 
   let completed = repo
-    .complete_attendance(CompleteAttendanceInput {
-      attendance_id: "att-1".to_string(),
-    })
+    .complete_attendance(
+      CompleteAttendanceInput {
+        attendance_id: "att-1".to_string(),
+      },
+      None,
+    )
     .await
     .expect("complete should succeed");
 
-  assert_eq!(completed.status, "completed");
+  assert_eq!(completed.status, "collected");
 
   let listed = repo
-    .list_attendance_queue(AttendanceQueueQueryInput {
+    .list_attendance_queue(AttendanceQueuePageQuery {
       date: Some("2026-02-17".to_string()),
-      status: Some("completed".to_string()),
+      status: Some("collected".to_string()),
       query: None,
+      include_deleted: false,
+      include_cancelled: false,
+      sort: AttendanceSort::ExamDate,
+      after: None,
+      limit: 50,
     })
     .await
     .expect("list should succeed");
 
-  assert_eq!(listed.len(), 1);
-  assert_eq!(listed[0].attendance_id, "att-1");
+  assert_eq!(listed.items.len(), 1);
+  assert_eq!(listed.items[0].attendance_id, "att-1");
 }
 
 #[tokio::test]
@@ -145,10 +197,41 @@ async fn complete_attendance_returns_not_found_for_missing_id() {
   let repo = PatientsSqliteRepository::new(pool);
 
   let result = repo
-    .complete_attendance(CompleteAttendanceInput {
-      attendance_id: "missing".to_string(),
-    })
+    .complete_attendance(
+      CompleteAttendanceInput {
+        attendance_id: "missing".to_string(),
+      },
+      None,
+    )
     .await;
 
   assert!(matches!(result, Err(PatientRepositoryError::NotFound)));
 }
+
+#[tokio::test]
+async fn complete_attendance_returns_conflict_when_not_waiting() {
+  let pool = setup_pool().await;
+  seed_data(&pool).await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  repo
+    .complete_attendance(
+      CompleteAttendanceInput {
+        attendance_id: "att-1".to_string(),
+      },
+      None,
+    )
+    .await
+    .expect("first complete should succeed");
+
+  let result = repo
+    .complete_attendance(
+      CompleteAttendanceInput {
+        attendance_id: "att-1".to_string(),
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::Conflict)));
+}
@@ -0,0 +1,288 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{
+      AcknowledgeCriticalAlertInput, AttendanceStatusChange, EnterExamResultsInput,
+      ExamItemResultInput,
+    },
+    errors::PatientRepositoryError,
+    ports::{AttendanceRepository, CriticalAlertRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES
+        ('usr-bench', 'Bruno Bancada', '11144477735', 'bruno', 'hash', 'bench', TRUE, datetime('now'), datetime('now')),
+        ('usr-bio', 'Beatriz Bioquimica', '52998224725', 'beatriz', 'hash', 'biochemist', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO requesters (id, name, created_at, updated_at) VALUES ('rq-1', 'Dra. Ana', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, requester_id, exam_date, status, deleted_at, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', 'rq-1', '2026-02-17', 'in_analysis', NULL, '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-deleted', 'pt-1', NULL, '2026-02-18', 'in_analysis', '2026-02-18 10:00:00', '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+
+      INSERT INTO exam_items (id, exam_id, name, unit, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'Glicose', 'mg/dL', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-1', 'Potassio', 'mEq/L', '2026-02-17 08:00:01', '2026-02-17 08:00:01'),
+        ('it-3', 'att-deleted', 'Glicose', 'mg/dL', '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn results(attendance_id: &str, items: &[(&str, &str, Option<&str>)]) -> EnterExamResultsInput {
+  EnterExamResultsInput {
+    attendance_id: attendance_id.to_string(),
+    items: items
+      .iter()
+      .map(|(exam_item_id, value, flag)| ExamItemResultInput {
+        exam_item_id: exam_item_id.to_string(),
+        result_value: value.to_string(),
+        result_flag: flag.map(str::to_string),
+      })
+      .collect(),
+  }
+}
+
+fn acknowledgement(alert_id: &str) -> AcknowledgeCriticalAlertInput {
+  AcknowledgeCriticalAlertInput {
+    alert_id: alert_id.to_string(),
+    contact_name: "Dra. Ana".to_string(),
+    contact_method: "phone".to_string(),
+    note: Some("Paciente orientada a procurar o pronto-socorro".to_string()),
+  }
+}
+
+#[tokio::test]
+async fn enter_exam_results_opens_alerts_for_critical_flags_only() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  repo
+    .enter_exam_results(
+      results(
+        "att-1",
+        &[("it-1", "35", Some("LL")), ("it-2", "4,5", Some("normal"))],
+      ),
+      Some("usr-bench".to_string()),
+    )
+    .await
+    .expect("results should be saved");
+  repo
+    .enter_exam_results(results("att-deleted", &[("it-3", "600", Some("HH"))]), None)
+    .await
+    .expect("results should be saved");
+
+  let pending = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load");
+  assert_eq!(pending.len(), 1);
+  assert_eq!(pending[0].attendance_id, "att-1");
+  assert_eq!(pending[0].exam_item_id, "it-1");
+  assert_eq!(pending[0].patient_name, "Maria Souza");
+  assert_eq!(pending[0].exam_name, "Glicose");
+  assert_eq!(pending[0].result_value, "35");
+  assert_eq!(pending[0].result_flag, "LL");
+  assert_eq!(pending[0].unit.as_deref(), Some("mg/dL"));
+  assert_eq!(pending[0].requester_name.as_deref(), Some("Dra. Ana"));
+  assert_eq!(pending[0].detected_by_user_id.as_deref(), Some("usr-bench"));
+  assert!(pending[0].acknowledged_at.is_none());
+
+  let context = repo
+    .get_attendance_edit_context("att-1".to_string())
+    .await
+    .expect("context should load");
+  assert!(context.items[0].has_pending_critical_alert);
+  assert!(!context.items[1].has_pending_critical_alert);
+}
+
+#[tokio::test]
+async fn correcting_a_pending_critical_value_replaces_its_alert() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  for input in [
+    results("att-1", &[("it-1", "35", Some("LL"))]),
+    results("att-1", &[("it-1", "35", Some("LL"))]),
+  ] {
+    repo
+      .enter_exam_results(input, None)
+      .await
+      .expect("results should be saved");
+  }
+  let first = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load");
+  assert_eq!(first.len(), 1);
+
+  repo
+    .enter_exam_results(results("att-1", &[("it-1", "32", Some("LL"))]), None)
+    .await
+    .expect("results should be saved");
+  let replaced = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load");
+  assert_eq!(replaced.len(), 1);
+  assert_ne!(replaced[0].id, first[0].id);
+  assert_eq!(replaced[0].result_value, "32");
+
+  repo
+    .enter_exam_results(results("att-1", &[("it-1", "85", None)]), None)
+    .await
+    .expect("results should be saved");
+  assert!(repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load")
+    .is_empty());
+}
+
+#[tokio::test]
+async fn acknowledge_critical_alert_logs_the_contact_once_and_unblocks_release() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  repo
+    .enter_exam_results(
+      results(
+        "att-1",
+        &[("it-1", "35", Some("LL")), ("it-2", "4,5", None)],
+      ),
+      None,
+    )
+    .await
+    .expect("results should be saved");
+  sqlx::query("UPDATE exams SET status = 'awaiting_validation' WHERE id = 'att-1'")
+    .execute(&pool)
+    .await
+    .expect("status should change");
+  let release = AttendanceStatusChange {
+    attendance_id: "att-1".to_string(),
+    from_status: "awaiting_validation".to_string(),
+    to_status: "released".to_string(),
+    changed_by_user_id: Some("usr-bio".to_string()),
+    note: None,
+  };
+
+  let blocked = repo.release_attendance(release.clone()).await;
+  assert!(matches!(blocked, Err(PatientRepositoryError::Conflict)));
+
+  let alert_id = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load")[0]
+    .id
+    .clone();
+  let acknowledged = repo
+    .acknowledge_critical_alert(acknowledgement(&alert_id), Some("usr-bench".to_string()))
+    .await
+    .expect("acknowledgement should succeed");
+  assert!(acknowledged.acknowledged_at.is_some());
+  assert_eq!(
+    acknowledged.acknowledged_by_user_id.as_deref(),
+    Some("usr-bench")
+  );
+  assert_eq!(acknowledged.contact_name.as_deref(), Some("Dra. Ana"));
+  assert_eq!(acknowledged.contact_method.as_deref(), Some("phone"));
+
+  let again = repo
+    .acknowledge_critical_alert(acknowledgement(&alert_id), None)
+    .await;
+  let missing = repo
+    .acknowledge_critical_alert(acknowledgement("alert-404"), None)
+    .await;
+  assert!(matches!(again, Err(PatientRepositoryError::Conflict)));
+  assert!(matches!(missing, Err(PatientRepositoryError::NotFound)));
+
+  let audit = sqlx::query(
+    "SELECT performed_by_user_id FROM audit_log WHERE entity_name = 'critical_alerts' AND entity_id = ?1",
+  )
+  .bind(&alert_id)
+  .fetch_one(&pool)
+  .await
+  .expect("audit should be recorded");
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-bench")
+  );
+
+  // The same value entered again was already notified.
+  repo
+    .enter_exam_results(results("att-1", &[("it-1", "35", Some("LL"))]), None)
+    .await
+    .expect("results should be saved");
+  assert!(repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load")
+    .is_empty());
+
+  let released = repo
+    .release_attendance(release)
+    .await
+    .expect("release should succeed");
+  assert_eq!(released.status, "released");
+}
+
+#[tokio::test]
+async fn critical_value_returning_after_an_acknowledged_one_alerts_again() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+  repo
+    .enter_exam_results(results("att-1", &[("it-1", "35", Some("LL"))]), None)
+    .await
+    .expect("results should be saved");
+  let first = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load")[0]
+    .id
+    .clone();
+  repo
+    .acknowledge_critical_alert(acknowledgement(&first), None)
+    .await
+    .expect("acknowledgement should succeed");
+
+  for input in [
+    results("att-1", &[("it-1", "85", None)]),
+    results("att-1", &[("it-1", "35", Some("LL"))]),
+  ] {
+    repo
+      .enter_exam_results(input, None)
+      .await
+      .expect("results should be saved");
+  }
+
+  let pending = repo
+    .list_pending_critical_alerts()
+    .await
+    .expect("alerts should load");
+  assert_eq!(pending.len(), 1);
+  assert_ne!(pending[0].id, first);
+  assert_eq!(pending[0].result_value, "35");
+  assert_eq!(pending[0].result_flag, "LL");
+}
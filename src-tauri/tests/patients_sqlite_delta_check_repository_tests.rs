@@ -0,0 +1,157 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    delta_check::DeltaCheckRule,
+    dto::SetExamDeltaCheckInput,
+    errors::PatientRepositoryError,
+    ports::{AttendanceRepository, ExamCatalogRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-admin', 'Administrador', '52998224725', 'admin', 'hash', 'admin', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES
+        ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now')),
+        ('pt-2', 'Joao Lima', '98765432100', '1980-05-20', 'M', '11988888888', 'Rua B', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, deleted_at, created_at, updated_at)
+      VALUES
+        ('att-jan', 'pt-1', '2026-01-10', 'delivered', NULL, datetime('now'), datetime('now')),
+        ('att-feb', 'pt-1', '2026-02-05', 'released', NULL, datetime('now'), datetime('now')),
+        ('att-deleted', 'pt-1', '2026-02-20', 'released', '2026-02-21 10:00:00', datetime('now'), datetime('now')),
+        ('att-pending', 'pt-1', '2026-02-25', 'awaiting_validation', NULL, datetime('now'), datetime('now')),
+        ('att-now', 'pt-1', '2026-03-01', 'in_analysis', NULL, datetime('now'), datetime('now')),
+        ('att-later', 'pt-1', '2026-03-10', 'released', NULL, datetime('now'), datetime('now')),
+        ('att-other', 'pt-2', '2026-02-28', 'released', NULL, datetime('now'), datetime('now'));
+
+      INSERT INTO exam_items (id, exam_id, catalog_item_id, name, result_value, released_at, created_at, updated_at)
+      VALUES
+        ('it-jan', 'att-jan', 'glicose', 'Glicose', '88', '2026-01-10 12:00:00', datetime('now'), datetime('now')),
+        ('it-feb', 'att-feb', 'glicose', 'Glicose', '92', '2026-02-05 12:00:00', datetime('now'), datetime('now')),
+        ('it-deleted', 'att-deleted', 'glicose', 'Glicose', '300', '2026-02-20 12:00:00', datetime('now'), datetime('now')),
+        ('it-pending', 'att-pending', 'glicose', 'Glicose', '250', NULL, datetime('now'), datetime('now')),
+        ('it-now', 'att-now', 'glicose', 'Glicose', NULL, NULL, datetime('now'), datetime('now')),
+        ('it-loose', 'att-now', NULL, 'Exame Avulso', NULL, NULL, datetime('now'), datetime('now')),
+        ('it-later', 'att-later', 'glicose', 'Glicose', '400', '2026-03-10 12:00:00', datetime('now'), datetime('now')),
+        ('it-other', 'att-other', 'glicose', 'Glicose', '500', '2026-02-28 12:00:00', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+#[tokio::test]
+async fn get_result_entry_context_loads_previous_released_value() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+  repo
+    .set_exam_delta_check(
+      SetExamDeltaCheckInput {
+        catalog_item_id: "glicose".to_string(),
+        rule: DeltaCheckRule {
+          absolute_threshold: Some(30.0),
+          percent_threshold: None,
+        },
+      },
+      None,
+    )
+    .await
+    .expect("delta check should be saved");
+
+  let context = repo
+    .get_result_entry_context("att-now".to_string())
+    .await
+    .expect("context should load");
+
+  let glicose = &context.items[0];
+  let previous = glicose
+    .previous_result
+    .as_ref()
+    .expect("previous result should be found");
+  assert_eq!(previous.attendance_id, "att-feb");
+  assert_eq!(previous.exam_date, "2026-02-05");
+  assert_eq!(previous.result_value, "92");
+  assert_eq!(glicose.delta_check.absolute_threshold, Some(30.0));
+
+  let loose = &context.items[1];
+  assert!(loose.previous_result.is_none());
+  assert_eq!(loose.delta_check, DeltaCheckRule::default());
+}
+
+#[tokio::test]
+async fn set_exam_delta_check_replaces_thresholds_with_audit() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  assert_eq!(
+    repo
+      .get_exam_delta_check("glicose".to_string())
+      .await
+      .expect("delta check should load"),
+    DeltaCheckRule::default()
+  );
+
+  let saved = repo
+    .set_exam_delta_check(
+      SetExamDeltaCheckInput {
+        catalog_item_id: "glicose".to_string(),
+        rule: DeltaCheckRule {
+          absolute_threshold: None,
+          percent_threshold: Some(50.0),
+        },
+      },
+      Some("usr-admin".to_string()),
+    )
+    .await
+    .expect("delta check should be saved");
+  assert_eq!(saved.percent_threshold, Some(50.0));
+  assert_eq!(
+    repo
+      .get_exam_delta_check("glicose".to_string())
+      .await
+      .expect("delta check should load"),
+    saved
+  );
+
+  let audit = sqlx::query(
+    "SELECT performed_by_user_id FROM audit_log WHERE entity_name = 'exam_catalog_items' AND entity_id = 'glicose'",
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("audit should be recorded");
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-admin")
+  );
+
+  let missing = repo
+    .set_exam_delta_check(
+      SetExamDeltaCheckInput {
+        catalog_item_id: "nao-existe".to_string(),
+        rule: DeltaCheckRule::default(),
+      },
+      None,
+    )
+    .await;
+  assert!(matches!(missing, Err(PatientRepositoryError::NotFound)));
+}
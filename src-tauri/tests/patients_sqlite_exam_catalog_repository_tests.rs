@@ -0,0 +1,171 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{
+      CreateAttendanceInput, CreateAttendanceItemInput, CreateExamCatalogItemInput,
+      DeactivateExamCatalogItemInput, UpdateExamCatalogItemInput,
+    },
+    errors::PatientRepositoryError,
+    ports::{ExamCatalogRepository, PatientRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+}
+
+#[tokio::test]
+async fn migration_seeds_the_previous_static_catalog() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let catalog = repo
+    .list_exam_catalog(false)
+    .await
+    .expect("catalog should load");
+
+  assert_eq!(catalog.len(), 6);
+  let glicose = catalog
+    .iter()
+    .find(|item| item.id == "glicose")
+    .expect("glicose should be seeded");
+  assert_eq!(glicose.category_title, "Bioquimica");
+  assert_eq!(glicose.price_cents, 1000);
+  assert!(glicose.is_active);
+}
+
+#[tokio::test]
+async fn create_update_and_deactivate_catalog_item() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let created = repo
+    .create_exam_catalog_item(
+      CreateExamCatalogItemInput {
+        name: "TSH".to_string(),
+        category_id: "imunologia".to_string(),
+        price_cents: 3500,
+        unit: Some("uUI/mL".to_string()),
+        method: None,
+        reference_range: None,
+      },
+      None,
+    )
+    .await
+    .expect("create should succeed");
+  assert_eq!(created.category_title, "Imunologia");
+
+  let updated = repo
+    .update_exam_catalog_item(
+      UpdateExamCatalogItemInput {
+        id: created.id.clone(),
+        name: "TSH Ultrassensivel".to_string(),
+        category_id: "imunologia".to_string(),
+        price_cents: 4000,
+        unit: Some("uUI/mL".to_string()),
+        method: Some("Quimioluminescencia".to_string()),
+        reference_range: None,
+      },
+      None,
+    )
+    .await
+    .expect("update should succeed");
+  assert_eq!(updated.name, "TSH Ultrassensivel");
+  assert_eq!(updated.price_cents, 4000);
+
+  let deactivated = repo
+    .deactivate_exam_catalog_item(
+      DeactivateExamCatalogItemInput {
+        id: created.id.clone(),
+      },
+      None,
+    )
+    .await
+    .expect("deactivate should succeed");
+  assert!(!deactivated.is_active);
+
+  let active = repo
+    .list_exam_catalog(false)
+    .await
+    .expect("catalog should load");
+  assert!(!active.iter().any(|item| item.id == created.id));
+  let all = repo
+    .list_exam_catalog(true)
+    .await
+    .expect("catalog should load");
+  assert!(all.iter().any(|item| item.id == created.id));
+}
+
+#[tokio::test]
+async fn create_catalog_item_with_unknown_category_returns_not_found() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let result = repo
+    .create_exam_catalog_item(
+      CreateExamCatalogItemInput {
+        name: "TSH".to_string(),
+        category_id: "missing".to_string(),
+        price_cents: 3500,
+        unit: None,
+        method: None,
+        reference_range: None,
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::NotFound)));
+}
+
+#[tokio::test]
+async fn create_attendance_resolves_catalog_item() {
+  let pool = setup_pool().await;
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to insert patient");
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let created = repo
+    .create_attendance(
+      CreateAttendanceInput {
+        patient_id: "pt-1".to_string(),
+        exam_date: "2026-02-14".to_string(),
+        requester_id: None,
+        status: None,
+        procedure_type: None,
+        delivered_to: None,
+        notes: None,
+        items: vec![CreateAttendanceItemInput {
+          catalog_item_id: Some("glicose".to_string()),
+          name: String::new(),
+          unit: None,
+          method: None,
+          reference_range: None,
+        }],
+      },
+      None,
+    )
+    .await
+    .expect("create attendance should succeed");
+
+  assert_eq!(created.items[0].name, "Glicose");
+  assert_eq!(created.items[0].unit.as_deref(), Some("mg/dL"));
+}
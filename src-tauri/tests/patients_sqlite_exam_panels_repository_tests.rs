@@ -0,0 +1,262 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{CreateAttendanceInput, CreateAttendanceItemInput, SetExamPanelAnalytesInput},
+    errors::PatientRepositoryError,
+    ports::{AttendanceRepository, ExamCatalogRepository, PatientRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+/// `ureia-creatinina` and `hemograma-completo` come from the seeded catalog.
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-admin', 'Administrador', '52998224725', 'admin', 'hash', 'admin', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO exam_catalog_items (id, category_id, name, price_cents, unit, method, reference_range, is_active, created_at, updated_at)
+      VALUES
+        ('ureia', 'bioquimica', 'Ureia', 900, 'mg/dL', 'Enzimatico', '15 - 45 mg/dL', TRUE, datetime('now'), datetime('now')),
+        ('creatinina', 'bioquimica', 'Creatinina', 900, 'mg/dL', 'Jaffe', '0,6 - 1,1 mg/dL', TRUE, datetime('now'), datetime('now')),
+        ('hemacias', 'hematologia', 'Hemacias', 0, 'milhoes/mm3', NULL, NULL, TRUE, datetime('now'), datetime('now')),
+        ('retired', 'bioquimica', 'Exame Retirado', 0, NULL, NULL, NULL, FALSE, datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn panel(panel_id: &str, analyte_ids: &[&str]) -> SetExamPanelAnalytesInput {
+  SetExamPanelAnalytesInput {
+    panel_id: panel_id.to_string(),
+    analyte_ids: analyte_ids.iter().map(|id| id.to_string()).collect(),
+  }
+}
+
+fn attendance(catalog_item_ids: &[&str]) -> CreateAttendanceInput {
+  CreateAttendanceInput {
+    patient_id: "pt-1".to_string(),
+    exam_date: "2026-02-14".to_string(),
+    requester_id: None,
+    status: None,
+    procedure_type: None,
+    delivered_to: None,
+    notes: None,
+    items: catalog_item_ids
+      .iter()
+      .map(|id| CreateAttendanceItemInput {
+        catalog_item_id: Some(id.to_string()),
+        name: String::new(),
+        unit: None,
+        method: None,
+        reference_range: None,
+      })
+      .collect(),
+  }
+}
+
+#[tokio::test]
+async fn set_exam_panel_analytes_replaces_analytes_in_order_with_audit() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  repo
+    .set_exam_panel_analytes(panel("ureia-creatinina", &["glicose"]), None)
+    .await
+    .expect("analytes should be saved");
+  let saved = repo
+    .set_exam_panel_analytes(
+      panel("ureia-creatinina", &["creatinina", "ureia"]),
+      Some("usr-admin".to_string()),
+    )
+    .await
+    .expect("analytes should be replaced");
+  assert_eq!(saved.analyte_ids, ["creatinina", "ureia"]);
+
+  let catalog = repo
+    .list_exam_catalog(false)
+    .await
+    .expect("catalog should load");
+  let find = |id: &str| {
+    catalog
+      .iter()
+      .find(|item| item.id == id)
+      .expect("item should be listed")
+  };
+  assert_eq!(
+    find("ureia-creatinina").analyte_ids,
+    ["creatinina", "ureia"]
+  );
+  assert!(find("glicose").analyte_ids.is_empty());
+
+  let audit = sqlx::query(
+    r#"
+    SELECT performed_by_user_id, before_json, after_json
+    FROM audit_log
+    WHERE entity_name = 'exam_catalog_items' AND entity_id = 'ureia-creatinina'
+    ORDER BY rowid DESC
+    "#,
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("audit should be recorded");
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-admin")
+  );
+  assert!(audit
+    .get::<String, _>("before_json")
+    .contains("\"glicose\""));
+  assert!(audit
+    .get::<String, _>("after_json")
+    .contains("\"creatinina\""));
+}
+
+#[tokio::test]
+async fn set_exam_panel_analytes_rejects_nesting_and_unknown_analytes() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+  repo
+    .set_exam_panel_analytes(panel("ureia-creatinina", &["ureia", "creatinina"]), None)
+    .await
+    .expect("analytes should be saved");
+
+  let panel_in_panel = repo
+    .set_exam_panel_analytes(panel("hemograma-completo", &["ureia-creatinina"]), None)
+    .await;
+  let analyte_as_panel = repo
+    .set_exam_panel_analytes(panel("ureia", &["glicose"]), None)
+    .await;
+  let unknown = repo
+    .set_exam_panel_analytes(panel("hemograma-completo", &["nao-existe"]), None)
+    .await;
+  let inactive = repo
+    .set_exam_panel_analytes(panel("hemograma-completo", &["retired"]), None)
+    .await;
+  let missing_panel = repo
+    .set_exam_panel_analytes(panel("nao-existe", &["hemacias"]), None)
+    .await;
+
+  assert!(matches!(
+    panel_in_panel,
+    Err(PatientRepositoryError::Conflict)
+  ));
+  assert!(matches!(
+    analyte_as_panel,
+    Err(PatientRepositoryError::Conflict)
+  ));
+  assert!(matches!(unknown, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(inactive, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(
+    missing_panel,
+    Err(PatientRepositoryError::NotFound)
+  ));
+}
+
+#[tokio::test]
+async fn create_attendance_expands_a_panel_into_one_item_per_analyte() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  repo
+    .set_exam_panel_analytes(panel("ureia-creatinina", &["ureia", "creatinina"]), None)
+    .await
+    .expect("analytes should be saved");
+
+  let created = repo
+    .create_attendance(attendance(&["glicose", "ureia-creatinina"]), None)
+    .await
+    .expect("create attendance should succeed");
+
+  let names: Vec<&str> = created
+    .items
+    .iter()
+    .map(|item| item.name.as_str())
+    .collect();
+  assert_eq!(names, ["Glicose", "Ureia", "Creatinina"]);
+  assert!(created.items[0].panel_id.is_none());
+  for analyte in &created.items[1..] {
+    assert_eq!(analyte.panel_id.as_deref(), Some("ureia-creatinina"));
+    assert_eq!(
+      analyte.panel_name.as_deref(),
+      Some("Bioquimica 2 (Ureia/Creatinina)")
+    );
+  }
+  assert_eq!(created.items[2].method.as_deref(), Some("Jaffe"));
+  assert_eq!(
+    created.items[2].reference_range.as_deref(),
+    Some("0,6 - 1,1 mg/dL")
+  );
+
+  let record = repo
+    .get_patient_record("pt-1".to_string(), false)
+    .await
+    .expect("record should load");
+  let record_names: Vec<&str> = record.entries[0]
+    .items
+    .iter()
+    .map(|item| item.name.as_str())
+    .collect();
+  assert_eq!(record_names, names);
+  assert_eq!(
+    record.entries[0].items[1].panel_id.as_deref(),
+    Some("ureia-creatinina")
+  );
+
+  let context = repo
+    .get_attendance_edit_context(created.exam_id.clone())
+    .await
+    .expect("context should load");
+  let panel_ids: Vec<Option<&str>> = context
+    .items
+    .iter()
+    .map(|item| item.panel_id.as_deref())
+    .collect();
+  assert_eq!(
+    panel_ids,
+    [None, Some("ureia-creatinina"), Some("ureia-creatinina")]
+  );
+}
+
+#[tokio::test]
+async fn create_attendance_skips_inactive_analytes_of_a_panel() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  repo
+    .set_exam_panel_analytes(panel("hemograma-completo", &["hemacias", "glicose"]), None)
+    .await
+    .expect("analytes should be saved");
+  sqlx::query("UPDATE exam_catalog_items SET is_active = FALSE WHERE id = 'glicose'")
+    .execute(&pool)
+    .await
+    .expect("analyte should be deactivated");
+
+  let created = repo
+    .create_attendance(attendance(&["hemograma-completo"]), None)
+    .await
+    .expect("create attendance should succeed");
+
+  assert_eq!(created.items.len(), 1);
+  assert_eq!(created.items[0].name, "Hemacias");
+  assert_eq!(
+    created.items[0].panel_name.as_deref(),
+    Some("Hemograma Completo")
+  );
+}
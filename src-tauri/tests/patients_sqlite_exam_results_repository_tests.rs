@@ -0,0 +1,103 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{EnterExamResultsInput, ExamItemResultInput},
+    errors::PatientRepositoryError,
+    ports::AttendanceRepository,
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'in_analysis', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-2', 'pt-1', '2026-02-18', 'in_analysis', '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+
+      INSERT INTO exam_items (id, exam_id, name, unit, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'Glicose', 'mg/dL', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-1', 'Colesterol Total', 'mg/dL', '2026-02-17 08:00:01', '2026-02-17 08:00:01'),
+        ('it-3', 'att-2', 'Triglicerideos', 'mg/dL', '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn result(exam_item_id: &str, value: &str, flag: Option<&str>) -> ExamItemResultInput {
+  ExamItemResultInput {
+    exam_item_id: exam_item_id.to_string(),
+    result_value: value.to_string(),
+    result_flag: flag.map(str::to_string),
+  }
+}
+
+#[tokio::test]
+async fn enter_exam_results_stores_values_and_bumps_updated_at() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let entry = repo
+    .enter_exam_results(
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "92", None), result("it-2", "240", Some("H"))],
+      },
+      None,
+    )
+    .await
+    .expect("results should be saved");
+
+  assert_eq!(entry.items.len(), 2);
+  assert_eq!(entry.items[0].result_value.as_deref(), Some("92"));
+  assert_eq!(entry.items[1].result_flag.as_deref(), Some("H"));
+
+  let row = sqlx::query("SELECT updated_at FROM exams WHERE id = 'att-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("exam should exist");
+  assert_ne!(row.get::<String, _>("updated_at"), "2026-02-17 08:00:00");
+}
+
+#[tokio::test]
+async fn enter_exam_results_rolls_back_when_item_belongs_to_other_attendance() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let result = repo
+    .enter_exam_results(
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![result("it-1", "92", None), result("it-3", "150", None)],
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::NotFound)));
+
+  let row = sqlx::query("SELECT result_value FROM exam_items WHERE id = 'it-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("item should exist");
+  assert_eq!(row.get::<Option<String>, _>("result_value"), None);
+}
@@ -1,8 +1,27 @@
 use laboratory_app_lib::{
-  domain::patients::{dto::CreatePatientInput, ports::PatientRepository},
+  domain::patients::{
+    dto::{CreatePatientInput, PatientPageQuery, UpdatePatientInput},
+    errors::PatientRepositoryError,
+    pagination::{PageCursor, PatientSort},
+    ports::PatientRepository,
+  },
   infra::repositories::patients_sqlite::PatientsSqliteRepository,
 };
-use sqlx::{sqlite::SqlitePoolOptions, Executor, SqlitePool};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+fn page_query(query: Option<&str>) -> PatientPageQuery {
+  PatientPageQuery {
+    query: query.map(str::to_string),
+    include_deleted: false,
+    sort: if query.is_some() {
+      PatientSort::Relevance
+    } else {
+      PatientSort::CreatedAt
+    },
+    after: None,
+    limit: 50,
+  }
+}
 
 async fn setup_pool() -> SqlitePool {
   let pool = SqlitePoolOptions::new()
@@ -24,13 +43,44 @@ async fn setup_pool() -> SqlitePool {
         address TEXT NOT NULL,
         cpf VARCHAR(14) NOT NULL UNIQUE,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        merged_into_patient_id TEXT,
+        merged_at DATETIME,
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
     .await
     .expect("failed to create patients table");
 
+  pool
+    .execute(
+      r#"
+      CREATE TABLE audit_log (
+        id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+        entity_name VARCHAR(50) NOT NULL,
+        entity_id TEXT NOT NULL,
+        action VARCHAR(10) NOT NULL,
+        performed_by_user_id TEXT,
+        performed_at DATETIME NOT NULL,
+        before_json TEXT,
+        after_json TEXT,
+        sequence INTEGER UNIQUE,
+        prev_hash TEXT,
+        entry_hash TEXT
+      );
+      "#,
+    )
+    .await
+    .expect("failed to create audit_log table");
+
+  pool
+    .execute(include_str!(
+      "../src/infra/db/migrations/0018_add_patient_search_index.sql"
+    ))
+    .await
+    .expect("failed to create patient search index");
   pool
 }
 
@@ -51,21 +101,26 @@ async fn list_returns_inserted_patients() {
   let repo = PatientsSqliteRepository::new(pool);
 
   repo
-    .insert(build_input("Maria Silva", "11111111111"))
+    .insert(build_input("Maria Silva", "11111111111"), None)
     .await
     .expect("insert 1 should succeed");
   repo
-    .insert(build_input("Joao Souza", "22222222222"))
+    .insert(build_input("Joao Souza", "22222222222"), None)
     .await
     .expect("insert 2 should succeed");
 
-  let listed = repo.list(None).await.expect("list should succeed");
+  let listed = repo
+    .list(page_query(None))
+    .await
+    .expect("list should succeed");
 
-  assert_eq!(listed.len(), 2);
-  assert!(!listed[0].birth_date.is_empty());
-  assert!(!listed[0].sex.is_empty());
-  assert!(!listed[0].phone.is_empty());
-  assert!(!listed[0].address.is_empty());
+  assert_eq!(listed.items.len(), 2);
+  assert_eq!(listed.total, 2);
+  assert!(listed.next_cursor.is_none());
+  assert!(!listed.items[0].birth_date.is_empty());
+  assert!(!listed.items[0].sex.is_empty());
+  assert!(!listed.items[0].phone.is_empty());
+  assert!(!listed.items[0].address.is_empty());
 }
 
 #[tokio::test]
@@ -74,25 +129,346 @@ async fn list_filters_by_name_and_cpf() {
   let repo = PatientsSqliteRepository::new(pool);
 
   repo
-    .insert(build_input("Maria Silva", "11111111111"))
+    .insert(build_input("Maria Silva", "11111111111"), None)
     .await
     .expect("insert 1 should succeed");
   repo
-    .insert(build_input("Joao Souza", "22222222222"))
+    .insert(build_input("Joao Souza", "22222222222"), None)
     .await
     .expect("insert 2 should succeed");
 
   let by_name = repo
-    .list(Some("maria".to_string()))
+    .list(page_query(Some("maria")))
     .await
     .expect("list by name should succeed");
   let by_cpf = repo
-    .list(Some("2222".to_string()))
+    .list(page_query(Some("2222")))
     .await
     .expect("list by cpf should succeed");
+  let by_masked_cpf = repo
+    .list(page_query(Some("222.222")))
+    .await
+    .expect("list by masked cpf should succeed");
+
+  assert_eq!(by_name.items.len(), 1);
+  assert_eq!(by_name.total, 1);
+  assert_eq!(by_name.items[0].full_name, "Maria Silva");
+  assert_eq!(by_cpf.items.len(), 1);
+  assert_eq!(by_cpf.items[0].cpf, "22222222222");
+  assert_eq!(by_masked_cpf.items.len(), 1);
+}
+
+#[tokio::test]
+async fn list_pages_by_name_with_a_cursor() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  for (name, cpf) in [
+    ("maria Silva", "11111111111"),
+    ("Joao Souza", "22222222222"),
+    ("Ana Costa", "33333333333"),
+  ] {
+    repo
+      .insert(build_input(name, cpf), None)
+      .await
+      .expect("insert should succeed");
+  }
+
+  let first = repo
+    .list(PatientPageQuery {
+      sort: PatientSort::Name,
+      limit: 2,
+      ..page_query(None)
+    })
+    .await
+    .expect("first page should succeed");
+  let cursor = first.next_cursor.clone().expect("expected a next page");
+  let second = repo
+    .list(PatientPageQuery {
+      sort: PatientSort::Name,
+      limit: 2,
+      after: PageCursor::decode(&cursor, "name", 2),
+      ..page_query(None)
+    })
+    .await
+    .expect("second page should succeed");
+
+  let first_names: Vec<&str> = first.items.iter().map(|p| p.full_name.as_str()).collect();
+  assert_eq!(first_names, vec!["Ana Costa", "Joao Souza"]);
+  assert_eq!(first.total, 3);
+  assert_eq!(second.items.len(), 1);
+  assert_eq!(second.items[0].full_name, "maria Silva");
+  assert!(second.next_cursor.is_none());
+  assert_eq!(second.total, 3);
+}
+
+#[tokio::test]
+async fn list_search_folds_accents_and_case() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  repo
+    .insert(build_input("JOÃO PEREIRA", "11111111111"), None)
+    .await
+    .expect("insert 1 should succeed");
+  repo
+    .insert(build_input("José Antônio", "22222222222"), None)
+    .await
+    .expect("insert 2 should succeed");
+
+  let joao = repo
+    .list(page_query(Some("joão")))
+    .await
+    .expect("search should succeed");
+  let jose = repo
+    .list(page_query(Some("Jose")))
+    .await
+    .expect("search should succeed");
+  let antonio = repo
+    .list(page_query(Some("ANTONIO")))
+    .await
+    .expect("search should succeed");
+
+  assert_eq!(joao.items.len(), 1);
+  assert_eq!(joao.items[0].full_name, "JOÃO PEREIRA");
+  assert_eq!(jose.items.len(), 1);
+  assert_eq!(jose.items[0].full_name, "José Antônio");
+  assert_eq!(antonio.items.len(), 1);
+}
+
+#[tokio::test]
+async fn list_search_ranks_name_prefix_matches_first() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  for (name, cpf) in [
+    ("Ana Maria Silvestre", "11111111111"),
+    ("Silvio Marques", "22222222222"),
+    ("Marcos Silva Marinho", "33333333333"),
+    ("Maria da Silva", "44444444444"),
+    ("Mariana Costa", "55555555555"),
+  ] {
+    repo
+      .insert(build_input(name, cpf), None)
+      .await
+      .expect("insert should succeed");
+  }
+
+  let found = repo
+    .list(page_query(Some("mar sil")))
+    .await
+    .expect("search should succeed");
+
+  let names: Vec<&str> = found.items.iter().map(|p| p.full_name.as_str()).collect();
+  assert_eq!(
+    names,
+    vec![
+      "Maria da Silva",
+      "Marcos Silva Marinho",
+      "Silvio Marques",
+      "Ana Maria Silvestre",
+    ]
+  );
+  assert_eq!(found.total, 4);
+
+  let first = repo
+    .list(PatientPageQuery {
+      limit: 3,
+      ..page_query(Some("mar sil"))
+    })
+    .await
+    .expect("first page should succeed");
+  let rest = repo
+    .list(PatientPageQuery {
+      after: first
+        .next_cursor
+        .as_deref()
+        .and_then(|cursor| PageCursor::decode(cursor, "relevance", 3)),
+      ..page_query(Some("mar sil"))
+    })
+    .await
+    .expect("second page should succeed");
+  assert_eq!(first.items.len(), 3);
+  assert_eq!(rest.items.len(), 1);
+  assert_eq!(rest.items[0].full_name, "Ana Maria Silvestre");
+}
+
+#[tokio::test]
+async fn list_search_index_follows_updates_phone_digits_and_legacy_code() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let created = repo
+    .insert(build_input("Maria Silva", "11111111111"), None)
+    .await
+    .expect("insert should succeed");
+  sqlx::query(
+    "UPDATE patients SET full_name = 'Beatriz Lima', phone = '(11) 98888-7777', legacy_code = 4321 WHERE id = ?1",
+  )
+  .bind(&created.id)
+  .execute(&pool)
+  .await
+  .expect("update should succeed");
+
+  let old_name = repo
+    .list(page_query(Some("maria")))
+    .await
+    .expect("search should succeed");
+  let new_name = repo
+    .list(page_query(Some("beatriz")))
+    .await
+    .expect("search should succeed");
+  let by_phone = repo
+    .list(page_query(Some("(11) 98888")))
+    .await
+    .expect("search should succeed");
+  let by_legacy_code = repo
+    .list(page_query(Some("4321")))
+    .await
+    .expect("search should succeed");
+  let punctuation = repo
+    .list(page_query(Some("--")))
+    .await
+    .expect("search should succeed");
+
+  assert!(old_name.items.is_empty());
+  assert_eq!(new_name.items.len(), 1);
+  assert_eq!(by_phone.items.len(), 1);
+  assert_eq!(by_legacy_code.items.len(), 1);
+  assert!(punctuation.items.is_empty());
+  assert_eq!(punctuation.total, 0);
+}
+
+#[tokio::test]
+async fn list_search_finds_cpf_and_phone_by_their_last_digits() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let created = repo
+    .insert(build_input("Maria Silva", "52998224725"), None)
+    .await
+    .expect("insert 1 should succeed");
+  let other = repo
+    .insert(build_input("Joana Costa", "11144477735"), None)
+    .await
+    .expect("insert 2 should succeed");
+  sqlx::query("UPDATE patients SET phone = '(21) 3333-4444' WHERE id = ?1")
+    .bind(&other.id)
+    .execute(&pool)
+    .await
+    .expect("update should succeed");
+
+  let by_cpf_suffix = repo
+    .list(page_query(Some("4725")))
+    .await
+    .expect("search should succeed");
+  let by_masked_cpf_suffix = repo
+    .list(page_query(Some("224-725")))
+    .await
+    .expect("search should succeed");
+  let by_phone_suffix = repo
+    .list(page_query(Some("988887777")))
+    .await
+    .expect("search should succeed");
+  let by_masked_phone_suffix = repo
+    .list(page_query(Some("3333-4444")))
+    .await
+    .expect("search should succeed");
+
+  assert_eq!(by_cpf_suffix.items.len(), 1);
+  assert_eq!(by_cpf_suffix.items[0].id, created.id);
+  assert_eq!(by_cpf_suffix.total, 1);
+  assert_eq!(by_masked_cpf_suffix.items.len(), 1);
+  assert_eq!(by_masked_cpf_suffix.items[0].id, created.id);
+  assert_eq!(by_phone_suffix.items.len(), 1);
+  assert_eq!(by_phone_suffix.items[0].id, created.id);
+  assert_eq!(by_masked_phone_suffix.items.len(), 1);
+  assert_eq!(by_masked_phone_suffix.items[0].id, other.id);
+}
+
+fn update_input(id: &str, expected_updated_at: &str, cpf: &str) -> UpdatePatientInput {
+  UpdatePatientInput {
+    id: id.to_string(),
+    full_name: "Maria Souza".to_string(),
+    cpf: cpf.to_string(),
+    birth_date: "1991-10-01T00:00:00".to_string(),
+    sex: "F".to_string(),
+    phone: "5511977776666".to_string(),
+    address: "Street 3".to_string(),
+    expected_updated_at: expected_updated_at.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn update_patient_rejects_stale_updated_at() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  let created = repo
+    .insert(build_input("Maria Silva", "11111111111"), None)
+    .await
+    .expect("insert should succeed");
+
+  let updated = repo
+    .update_patient(
+      update_input(&created.id, &created.updated_at, "11111111111"),
+      None,
+    )
+    .await
+    .expect("update should succeed");
+  let stale = repo
+    .update_patient(
+      update_input(&created.id, &created.updated_at, "11111111111"),
+      None,
+    )
+    .await;
+  let missing = repo
+    .update_patient(
+      update_input("pt-x", &updated.updated_at, "11111111111"),
+      None,
+    )
+    .await;
+
+  assert_eq!(updated.full_name, "Maria Souza");
+  assert_eq!(updated.phone, "5511977776666");
+  assert_eq!(updated.created_at, created.created_at);
+  assert_ne!(updated.updated_at, created.updated_at);
+  assert!(matches!(stale, Err(PatientRepositoryError::Conflict)));
+  assert!(matches!(missing, Err(PatientRepositoryError::NotFound)));
+
+  let audit = sqlx::query(
+    "SELECT before_json, after_json FROM audit_log WHERE entity_name = 'patients' AND action = 'update'",
+  )
+  .fetch_all(&pool)
+  .await
+  .expect("audit should load");
+  assert_eq!(audit.len(), 1);
+  assert!(audit[0]
+    .get::<String, _>("before_json")
+    .contains("Maria Silva"));
+  assert!(audit[0]
+    .get::<String, _>("after_json")
+    .contains("Maria Souza"));
+}
+
+#[tokio::test]
+async fn update_patient_rejects_cpf_of_another_patient() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+  repo
+    .insert(build_input("Maria Silva", "11111111111"), None)
+    .await
+    .expect("insert 1 should succeed");
+  let joao = repo
+    .insert(build_input("Joao Souza", "22222222222"), None)
+    .await
+    .expect("insert 2 should succeed");
+
+  let result = repo
+    .update_patient(
+      update_input(&joao.id, &joao.updated_at, "11111111111"),
+      None,
+    )
+    .await;
 
-  assert_eq!(by_name.len(), 1);
-  assert_eq!(by_name[0].full_name, "Maria Silva");
-  assert_eq!(by_cpf.len(), 1);
-  assert_eq!(by_cpf[0].cpf, "22222222222");
+  assert!(matches!(result, Err(PatientRepositoryError::Conflict)));
 }
@@ -0,0 +1,194 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{CreateAttendanceInput, MergePatientsInput, PatientPageQuery},
+    errors::PatientRepositoryError,
+    pagination::PatientSort,
+    ports::{PatientMergeRepository, PatientRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-1', 'Ana', '00000000000', 'ana', 'hash', 'reception', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES
+        ('pt-1', 'Maria Souza', '52998224725', '1991-10-01', 'F', '11999991234', 'Rua A', datetime('now'), datetime('now')),
+        ('pt-2', 'Maria Sousa', '12345678909', '1991-10-01', 'F', '', '', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'waiting', datetime('now'), datetime('now')),
+        ('att-2', 'pt-2', '2026-02-18', 'waiting', datetime('now'), datetime('now')),
+        ('att-3', 'pt-2', '2026-02-19', 'waiting', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn merge(surviving: &str, merged: &str) -> MergePatientsInput {
+  MergePatientsInput {
+    surviving_patient_id: surviving.to_string(),
+    merged_patient_id: merged.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn merge_patients_moves_attendances_and_tombstones_the_merged_record() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let result = repo
+    .merge_patients(merge("pt-1", "pt-2"), Some("usr-1".to_string()))
+    .await
+    .expect("merge should succeed");
+
+  assert_eq!(result.patient.id, "pt-1");
+  assert_eq!(result.merged_patient_id, "pt-2");
+  assert_eq!(result.moved_attendances, 2);
+
+  let record = repo
+    .get_patient_record("pt-1".to_string(), false)
+    .await
+    .expect("survivor record");
+  assert_eq!(record.entries.len(), 3);
+
+  let listed = repo
+    .list(PatientPageQuery {
+      query: None,
+      include_deleted: false,
+      sort: PatientSort::CreatedAt,
+      after: None,
+      limit: 50,
+    })
+    .await
+    .expect("list patients");
+  assert_eq!(listed.items.len(), 1);
+  assert_eq!(listed.total, 1);
+  assert_eq!(listed.items[0].id, "pt-1");
+
+  let tombstone =
+    sqlx::query("SELECT merged_into_patient_id, merged_at FROM patients WHERE id = 'pt-2'")
+      .fetch_one(&pool)
+      .await
+      .expect("merged patient row is kept");
+  assert_eq!(
+    tombstone
+      .get::<Option<String>, _>("merged_into_patient_id")
+      .as_deref(),
+    Some("pt-1")
+  );
+  assert!(tombstone.get::<Option<String>, _>("merged_at").is_some());
+
+  let audit = sqlx::query(
+    r#"
+    SELECT before_json, performed_by_user_id
+    FROM audit_log
+    WHERE entity_name = 'patients' AND entity_id = 'pt-2' AND action = 'delete'
+    "#,
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("merge should be audited");
+  let before = audit.get::<String, _>("before_json");
+  assert!(before.contains("Maria Sousa"));
+  assert!(before.contains("12345678909"));
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-1")
+  );
+
+  let moved_audits = sqlx::query(
+    "SELECT COUNT(*) AS total FROM audit_log WHERE entity_name = 'exams' AND action = 'update'",
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("count exam audits");
+  assert_eq!(moved_audits.get::<i64, _>("total"), 2);
+}
+
+#[tokio::test]
+async fn merged_patient_rejects_new_attendances_and_second_merge() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  repo
+    .merge_patients(merge("pt-1", "pt-2"), None)
+    .await
+    .expect("merge should succeed");
+
+  let attendance = repo
+    .create_attendance(
+      CreateAttendanceInput {
+        patient_id: "pt-2".to_string(),
+        exam_date: "2026-02-20".to_string(),
+        requester_id: None,
+        status: None,
+        procedure_type: None,
+        delivered_to: None,
+        notes: None,
+        items: Vec::new(),
+      },
+      None,
+    )
+    .await;
+  let again = repo.merge_patients(merge("pt-1", "pt-2"), None).await;
+  let into_merged = repo.merge_patients(merge("pt-2", "pt-1"), None).await;
+  let record = repo.get_patient_record("pt-2".to_string(), false).await;
+  let found = repo
+    .find_patient("pt-2".to_string())
+    .await
+    .expect("find patient");
+
+  assert!(matches!(attendance, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(again, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(into_merged, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(record, Err(PatientRepositoryError::NotFound)));
+  assert!(found.is_none());
+}
+
+#[tokio::test]
+async fn merge_patients_resolves_cpf_collisions() {
+  let pool = setup_pool().await;
+  pool
+    .execute(
+      r#"
+      INSERT INTO patient_cpf_collisions (patient_id, original_cpf, normalized_cpf, detected_at)
+      VALUES ('pt-2', '123.456.789-09', '12345678909', datetime('now'));
+      "#,
+    )
+    .await
+    .expect("seed collision");
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  repo
+    .merge_patients(merge("pt-1", "pt-2"), None)
+    .await
+    .expect("merge should succeed");
+
+  let row = sqlx::query("SELECT resolved_at FROM patient_cpf_collisions WHERE patient_id = 'pt-2'")
+    .fetch_one(&pool)
+    .await
+    .expect("collision row");
+  assert!(row.get::<Option<String>, _>("resolved_at").is_some());
+}
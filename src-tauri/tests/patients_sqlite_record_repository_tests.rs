@@ -3,7 +3,7 @@ use laboratory_app_lib::{
     dto::{CreateAttendanceInput, CreateAttendanceItemInput},
     ports::PatientRepository,
   },
-  infra::repositories::patients_sqlite::PatientsSqliteRepository,
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
 };
 use sqlx::{sqlite::SqlitePoolOptions, Executor, SqlitePool};
 
@@ -27,7 +27,11 @@ async fn setup_pool() -> SqlitePool {
         address TEXT NOT NULL,
         cpf VARCHAR(14) NOT NULL UNIQUE,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        merged_into_patient_id TEXT,
+        merged_at DATETIME,
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -60,7 +64,9 @@ async fn setup_pool() -> SqlitePool {
         delivered_to TEXT,
         notes TEXT,
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        deleted_at DATETIME,
+        deleted_by TEXT
       );
       "#,
     )
@@ -73,6 +79,7 @@ async fn setup_pool() -> SqlitePool {
       CREATE TABLE exam_items (
         id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
         exam_id TEXT NOT NULL,
+        catalog_item_id TEXT,
         name VARCHAR(150) NOT NULL,
         unit VARCHAR(20),
         method VARCHAR(100),
@@ -80,13 +87,56 @@ async fn setup_pool() -> SqlitePool {
         result_value TEXT,
         result_flag VARCHAR(20),
         created_at DATETIME NOT NULL CHECK(typeof(created_at) = 'text'),
-        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text')
+        updated_at DATETIME NOT NULL CHECK(typeof(updated_at) = 'text'),
+        released_at DATETIME,
+        released_by TEXT,
+        result_version INTEGER NOT NULL DEFAULT 1,
+        panel_id TEXT,
+        panel_name VARCHAR(150)
       );
       "#,
     )
     .await
     .expect("failed to create exam_items table");
 
+  pool
+    .execute(
+      r#"
+      CREATE TABLE attendance_status_history (
+        id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+        exam_id TEXT NOT NULL,
+        from_status VARCHAR(20),
+        to_status VARCHAR(20) NOT NULL,
+        changed_by_user_id TEXT,
+        changed_at DATETIME NOT NULL CHECK(typeof(changed_at) = 'text'),
+        note TEXT
+      );
+      "#,
+    )
+    .await
+    .expect("failed to create attendance_status_history table");
+
+
+  pool
+    .execute(
+      r#"
+      CREATE TABLE audit_log (
+        id TEXT PRIMARY KEY NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+        entity_name VARCHAR(50) NOT NULL,
+        entity_id TEXT NOT NULL,
+        action VARCHAR(10) NOT NULL,
+        performed_by_user_id TEXT,
+        performed_at DATETIME NOT NULL,
+        before_json TEXT,
+        after_json TEXT,
+        sequence INTEGER UNIQUE,
+        prev_hash TEXT,
+        entry_hash TEXT
+      );
+      "#,
+    )
+    .await
+    .expect("failed to create audit_log table");
   pool
 }
 
@@ -107,29 +157,34 @@ async fn create_attendance_and_get_record_work_together() {
   let repo = PatientsSqliteRepository::new(pool);
 
   let created = repo
-    .create_attendance(CreateAttendanceInput {
-      patient_id: "pt-1".to_string(),
-      exam_date: "2026-02-14".to_string(),
-      requester_id: None,
-      status: None,
-      procedure_type: None,
-      delivered_to: None,
-      notes: None,
-      items: vec![
-        CreateAttendanceItemInput {
-          name: "Glicose".to_string(),
-          unit: Some("mg/dL".to_string()),
-          method: None,
-          reference_range: Some("70-99".to_string()),
-        },
-        CreateAttendanceItemInput {
-          name: "Colesterol Total".to_string(),
-          unit: Some("mg/dL".to_string()),
-          method: None,
-          reference_range: Some("<190".to_string()),
-        },
-      ],
-    })
+    .create_attendance(
+      CreateAttendanceInput {
+        patient_id: "pt-1".to_string(),
+        exam_date: "2026-02-14".to_string(),
+        requester_id: None,
+        status: None,
+        procedure_type: None,
+        delivered_to: None,
+        notes: None,
+        items: vec![
+          CreateAttendanceItemInput {
+            catalog_item_id: None,
+            name: "Glicose".to_string(),
+            unit: Some("mg/dL".to_string()),
+            method: None,
+            reference_range: Some("70-99".to_string()),
+          },
+          CreateAttendanceItemInput {
+            catalog_item_id: None,
+            name: "Colesterol Total".to_string(),
+            unit: Some("mg/dL".to_string()),
+            method: None,
+            reference_range: Some("<190".to_string()),
+          },
+        ],
+      },
+      None,
+    )
     .await
     .expect("create attendance should succeed");
 
@@ -137,7 +192,7 @@ async fn create_attendance_and_get_record_work_together() {
   assert_eq!(created.items.len(), 2);
 
   let record = repo
-    .get_patient_record("pt-1".to_string())
+    .get_patient_record("pt-1".to_string(), false)
     .await
     .expect("get patient record should succeed");
 
@@ -149,10 +204,18 @@ async fn create_attendance_and_get_record_work_together() {
 
 #[tokio::test]
 async fn list_exam_catalog_returns_seed_items() {
-  let pool = setup_pool().await;
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+  run_migrations(&pool).await.expect("migrations should run");
   let repo = PatientsSqliteRepository::new(pool);
 
-  let catalog = repo.list_exam_catalog().await.expect("catalog should load");
+  let catalog = repo
+    .list_exam_catalog(false)
+    .await
+    .expect("catalog should load");
 
   assert!(!catalog.is_empty());
   assert!(catalog.iter().any(|item| item.id == "glicose"));
@@ -0,0 +1,131 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::SetExamReferenceRangesInput,
+    errors::PatientRepositoryError,
+    ports::{AttendanceRepository, ExamCatalogRepository},
+    reference_range::ReferenceRange,
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Joao Lima', '98765432100', '1980-05-20', 'M', '11999999999', 'Rua B', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES ('att-1', 'pt-1', '2026-02-17', 'in_analysis', datetime('now'), datetime('now'));
+
+      INSERT INTO exam_items (id, exam_id, catalog_item_id, name, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'glicose', 'Glicose', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-1', NULL, 'Exame Avulso', '2026-02-17 08:00:01', '2026-02-17 08:00:01');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn range(sex: Option<&str>, low: f64, high: f64) -> ReferenceRange {
+  ReferenceRange {
+    sex: sex.map(str::to_string),
+    age_min_years: Some(18),
+    age_max_years: None,
+    low: Some(low),
+    high: Some(high),
+    critical_low: None,
+    critical_high: None,
+    unit: Some("mg/dL".to_string()),
+    expected_text: None,
+  }
+}
+
+#[tokio::test]
+async fn migration_seeds_glicose_range() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  let ranges = repo
+    .list_exam_reference_ranges("glicose".to_string())
+    .await
+    .expect("ranges should load");
+
+  assert_eq!(ranges.len(), 1);
+  assert_eq!(ranges[0].low, Some(70.0));
+  assert_eq!(ranges[0].critical_high, Some(400.0));
+}
+
+#[tokio::test]
+async fn set_exam_reference_ranges_replaces_existing_ranges() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  let saved = repo
+    .set_exam_reference_ranges(
+      SetExamReferenceRangesInput {
+        catalog_item_id: "glicose".to_string(),
+        ranges: vec![range(Some("F"), 65.0, 95.0), range(Some("M"), 70.0, 105.0)],
+      },
+      None,
+    )
+    .await
+    .expect("ranges should be saved");
+
+  assert_eq!(saved.len(), 2);
+  assert_eq!(saved[1].sex.as_deref(), Some("M"));
+  assert_eq!(saved[1].age_min_years, Some(18));
+
+  let reloaded = repo
+    .list_exam_reference_ranges("glicose".to_string())
+    .await
+    .expect("ranges should load");
+  assert_eq!(reloaded, saved);
+}
+
+#[tokio::test]
+async fn set_exam_reference_ranges_requires_catalog_item() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  let result = repo
+    .set_exam_reference_ranges(
+      SetExamReferenceRangesInput {
+        catalog_item_id: "missing".to_string(),
+        ranges: vec![range(None, 1.0, 2.0)],
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::NotFound)));
+}
+
+#[tokio::test]
+async fn get_result_entry_context_loads_patient_and_item_ranges() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  let context = repo
+    .get_result_entry_context("att-1".to_string())
+    .await
+    .expect("context should load");
+
+  assert_eq!(context.status, "in_analysis");
+  assert_eq!(context.patient_sex, "M");
+  assert_eq!(context.patient_birth_date, "1980-05-20");
+  assert_eq!(context.items.len(), 2);
+  assert_eq!(context.items[0].catalog_item_id.as_deref(), Some("glicose"));
+  assert_eq!(context.items[0].reference_ranges.len(), 1);
+  assert!(context.items[1].reference_ranges.is_empty());
+}
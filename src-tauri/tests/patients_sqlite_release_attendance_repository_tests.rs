@@ -0,0 +1,166 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{AttendanceStatusChange, EnterExamResultsInput, ExamItemResultInput},
+    errors::PatientRepositoryError,
+    ports::{AttendanceRepository, PatientRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-bio', 'Beatriz Bioquimica', '52998224725', 'beatriz', 'hash', 'biochemist', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'awaiting_validation', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-2', 'pt-1', '2026-02-18', 'awaiting_validation', '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+
+      INSERT INTO exam_items (id, exam_id, name, unit, result_value, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'Glicose', 'mg/dL', '92', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-1', 'Colesterol Total', 'mg/dL', '180', '2026-02-17 08:00:01', '2026-02-17 08:00:01'),
+        ('it-3', 'att-2', 'Triglicerideos', 'mg/dL', NULL, '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn release(attendance_id: &str) -> AttendanceStatusChange {
+  AttendanceStatusChange {
+    attendance_id: attendance_id.to_string(),
+    from_status: "awaiting_validation".to_string(),
+    to_status: "released".to_string(),
+    changed_by_user_id: Some("usr-bio".to_string()),
+    note: None,
+  }
+}
+
+#[tokio::test]
+async fn release_attendance_signs_attendance_and_items() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let before = repo
+    .get_patient_record("pt-1".to_string(), false)
+    .await
+    .expect("record should load");
+  assert!(before
+    .entries
+    .iter()
+    .flat_map(|entry| &entry.items)
+    .all(|item| !item.report_available));
+
+  let released = repo
+    .release_attendance(release("att-1"))
+    .await
+    .expect("release should succeed");
+  assert_eq!(released.status, "released");
+
+  let exam = sqlx::query("SELECT released_at, released_by FROM exams WHERE id = 'att-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("exam should exist");
+  assert!(exam.get::<Option<String>, _>("released_at").is_some());
+  assert_eq!(
+    exam.get::<Option<String>, _>("released_by").as_deref(),
+    Some("usr-bio")
+  );
+
+  let signed = sqlx::query(
+    "SELECT COUNT(*) AS total FROM exam_items WHERE exam_id = 'att-1' AND released_by = 'usr-bio'",
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("items should exist");
+  assert_eq!(signed.get::<i64, _>("total"), 2);
+
+  let history = repo
+    .list_attendance_status_history("att-1".to_string())
+    .await
+    .expect("history should load");
+  assert_eq!(history[0].to_status, "released");
+  assert_eq!(history[0].changed_by_user_id.as_deref(), Some("usr-bio"));
+
+  let record = repo
+    .get_patient_record("pt-1".to_string(), false)
+    .await
+    .expect("record should load");
+  for entry in &record.entries {
+    let released = entry.exam_id == "att-1";
+    assert!(entry
+      .items
+      .iter()
+      .all(|item| item.report_available == released));
+  }
+}
+
+#[tokio::test]
+async fn release_attendance_refuses_items_without_result() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let result = repo.release_attendance(release("att-2")).await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::Conflict)));
+  let exam = sqlx::query("SELECT status, released_at FROM exams WHERE id = 'att-2'")
+    .fetch_one(&pool)
+    .await
+    .expect("exam should exist");
+  assert_eq!(exam.get::<String, _>("status"), "awaiting_validation");
+  assert_eq!(exam.get::<Option<String>, _>("released_at"), None);
+}
+
+#[tokio::test]
+async fn enter_exam_results_refuses_signed_items() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+  repo
+    .release_attendance(release("att-1"))
+    .await
+    .expect("release should succeed");
+
+  let result = repo
+    .enter_exam_results(
+      EnterExamResultsInput {
+        attendance_id: "att-1".to_string(),
+        items: vec![ExamItemResultInput {
+          exam_item_id: "it-1".to_string(),
+          result_value: "120".to_string(),
+          result_flag: None,
+        }],
+      },
+      None,
+    )
+    .await;
+
+  assert!(matches!(result, Err(PatientRepositoryError::Conflict)));
+  let item = sqlx::query("SELECT result_value FROM exam_items WHERE id = 'it-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("item should exist");
+  assert_eq!(
+    item.get::<Option<String>, _>("result_value").as_deref(),
+    Some("92")
+  );
+}
@@ -0,0 +1,289 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{
+      AttendanceQueueItemView, AttendanceQueuePageQuery, AttendanceStatusChange,
+      CreateAttendanceInput, PatientPageQuery,
+    },
+    errors::PatientRepositoryError,
+    pagination::{AttendanceSort, PageView, PatientSort},
+    ports::{AttendanceRepository, PatientRepository, RecordDeletionRepository},
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-1', 'Ana', '00000000000', 'ana', 'hash', 'admin', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES
+        ('pt-1', 'Maria Souza', '52998224725', '1991-10-01', 'F', '', '', '2026-02-01 08:00:00', '2026-02-01 08:00:00'),
+        ('pt-2', 'Joao Lima', '12345678909', '1985-03-12', 'M', '', '', '2026-02-02 08:00:00', '2026-02-02 08:00:00');
+
+      INSERT INTO exams (id, patient_id, exam_date, status, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'waiting', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-2', 'pt-1', '2026-02-18', 'waiting', '2026-02-18 08:00:00', '2026-02-18 08:00:00'),
+        ('att-3', 'pt-2', '2026-02-18', 'waiting', '2026-02-18 09:00:00', '2026-02-18 09:00:00');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn patients(query: Option<&str>, include_deleted: bool) -> PatientPageQuery {
+  PatientPageQuery {
+    query: query.map(str::to_string),
+    include_deleted,
+    sort: PatientSort::CreatedAt,
+    after: None,
+    limit: 50,
+  }
+}
+
+fn queue(include_deleted: bool) -> AttendanceQueuePageQuery {
+  AttendanceQueuePageQuery {
+    date: None,
+    status: None,
+    query: None,
+    include_deleted,
+    include_cancelled: false,
+    sort: AttendanceSort::ExamDate,
+    after: None,
+    limit: 50,
+  }
+}
+
+fn attendance_ids(page: &PageView<AttendanceQueueItemView>) -> Vec<&str> {
+  page
+    .items
+    .iter()
+    .map(|item| item.attendance_id.as_str())
+    .collect()
+}
+
+#[tokio::test]
+async fn deleted_patient_is_hidden_unless_requested() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let deleted = repo
+    .set_patient_deleted("pt-1".to_string(), true, Some("usr-1".to_string()))
+    .await
+    .expect("delete should succeed");
+  assert!(deleted.deleted_at.is_some());
+
+  let listed = repo
+    .list(patients(None, false))
+    .await
+    .expect("list patients");
+  let listed_all = repo
+    .list(patients(Some("maria"), true))
+    .await
+    .expect("list patients");
+  let found = repo
+    .find_patient("pt-1".to_string())
+    .await
+    .expect("find patient");
+  let record = repo.get_patient_record("pt-1".to_string(), false).await;
+  let record_all = repo
+    .get_patient_record("pt-1".to_string(), true)
+    .await
+    .expect("record with deleted");
+  let default_queue = repo
+    .list_attendance_queue(queue(false))
+    .await
+    .expect("queue");
+  let full_queue = repo
+    .list_attendance_queue(queue(true))
+    .await
+    .expect("queue");
+
+  assert_eq!(listed.items.len(), 1);
+  assert_eq!(listed.items[0].id, "pt-2");
+  assert_eq!(listed_all.items.len(), 1);
+  assert!(listed_all.items[0].deleted_at.is_some());
+  assert!(found.is_some_and(|patient| patient.deleted_at.is_some()));
+  assert!(matches!(record, Err(PatientRepositoryError::NotFound)));
+  assert!(record_all.patient.deleted_at.is_some());
+  assert_eq!(record_all.entries.len(), 2);
+  assert_eq!(attendance_ids(&default_queue), vec!["att-3"]);
+  assert_eq!(full_queue.items.len(), 3);
+  assert_eq!(full_queue.total, 3);
+
+  let row = sqlx::query("SELECT deleted_by FROM patients WHERE id = 'pt-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("patient row is kept");
+  assert_eq!(
+    row.get::<Option<String>, _>("deleted_by").as_deref(),
+    Some("usr-1")
+  );
+}
+
+#[tokio::test]
+async fn deleted_patient_rejects_changes_until_restored() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  repo
+    .set_patient_deleted("pt-1".to_string(), true, None)
+    .await
+    .expect("delete should succeed");
+
+  let new_attendance = CreateAttendanceInput {
+    patient_id: "pt-1".to_string(),
+    exam_date: "2026-02-20".to_string(),
+    requester_id: None,
+    status: None,
+    procedure_type: None,
+    delivered_to: None,
+    notes: None,
+    items: Vec::new(),
+  };
+  let while_deleted = repo.create_attendance(new_attendance.clone(), None).await;
+  let delete_twice = repo
+    .set_patient_deleted("pt-1".to_string(), true, None)
+    .await;
+
+  let restored = repo
+    .set_patient_deleted("pt-1".to_string(), false, Some("usr-1".to_string()))
+    .await
+    .expect("restore should succeed");
+  let after_restore = repo.create_attendance(new_attendance, None).await;
+  let restore_twice = repo
+    .set_patient_deleted("pt-1".to_string(), false, None)
+    .await;
+
+  assert!(matches!(
+    while_deleted,
+    Err(PatientRepositoryError::NotFound)
+  ));
+  assert!(matches!(
+    delete_twice,
+    Err(PatientRepositoryError::Conflict)
+  ));
+  assert!(restored.deleted_at.is_none());
+  assert!(after_restore.is_ok());
+  assert!(matches!(
+    restore_twice,
+    Err(PatientRepositoryError::Conflict)
+  ));
+
+  let actions: Vec<String> = sqlx::query(
+    r#"
+    SELECT action FROM audit_log
+    WHERE entity_name = 'patients' AND entity_id = 'pt-1'
+    ORDER BY sequence
+    "#,
+  )
+  .fetch_all(&pool)
+  .await
+  .expect("audit entries")
+  .iter()
+  .map(|row| row.get::<String, _>("action"))
+  .collect();
+  assert_eq!(actions, vec!["delete", "restore"]);
+}
+
+#[tokio::test]
+async fn deleted_attendance_leaves_queue_record_and_workflow() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let deleted = repo
+    .set_attendance_deleted("att-1".to_string(), true, Some("usr-1".to_string()))
+    .await
+    .expect("delete should succeed");
+  assert!(deleted.deleted_at.is_some());
+
+  let default_queue = repo
+    .list_attendance_queue(queue(false))
+    .await
+    .expect("queue");
+  let record = repo
+    .get_patient_record("pt-1".to_string(), false)
+    .await
+    .expect("record");
+  let record_all = repo
+    .get_patient_record("pt-1".to_string(), true)
+    .await
+    .expect("record with deleted");
+  let transition = repo
+    .change_attendance_status(AttendanceStatusChange {
+      attendance_id: "att-1".to_string(),
+      from_status: "waiting".to_string(),
+      to_status: "collected".to_string(),
+      changed_by_user_id: None,
+      note: None,
+    })
+    .await;
+  let results = repo.get_result_entry_context("att-1".to_string()).await;
+
+  assert_eq!(attendance_ids(&default_queue), vec!["att-3", "att-2"]);
+  assert_eq!(record.entries.len(), 1);
+  assert_eq!(record_all.entries.len(), 2);
+  assert!(record_all
+    .entries
+    .iter()
+    .any(|entry| entry.exam_id == "att-1" && entry.deleted_at.is_some()));
+  assert!(matches!(transition, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(results, Err(PatientRepositoryError::NotFound)));
+
+  let restored = repo
+    .set_attendance_deleted("att-1".to_string(), false, None)
+    .await
+    .expect("restore should succeed");
+  assert!(restored.deleted_at.is_none());
+  assert_eq!(
+    repo
+      .list_attendance_queue(queue(false))
+      .await
+      .expect("queue")
+      .total,
+    3
+  );
+
+  let audit = sqlx::query(
+    r#"
+    SELECT before_json, after_json FROM audit_log
+    WHERE entity_name = 'exams' AND entity_id = 'att-1' AND action = 'delete'
+    "#,
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("deletion should be audited");
+  assert!(audit.get::<String, _>("after_json").contains("usr-1"));
+}
+
+#[tokio::test]
+async fn soft_delete_reports_missing_rows() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool);
+
+  let patient = repo
+    .set_patient_deleted("pt-x".to_string(), true, None)
+    .await;
+  let attendance = repo
+    .set_attendance_deleted("att-x".to_string(), true, None)
+    .await;
+
+  assert!(matches!(patient, Err(PatientRepositoryError::NotFound)));
+  assert!(matches!(attendance, Err(PatientRepositoryError::NotFound)));
+}
@@ -0,0 +1,196 @@
+use laboratory_app_lib::{
+  domain::patients::{
+    dto::{AttendanceUpdate, CreateAttendanceItemInput},
+    errors::PatientRepositoryError,
+    ports::AttendanceRepository,
+  },
+  infra::{db::sqlite::run_migrations, repositories::patients_sqlite::PatientsSqliteRepository},
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-1', 'Rita Recepcao', '12345678900', 'rita', 'hash', 'reception', TRUE, datetime('now'), datetime('now'));
+
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '52998224725', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO requesters (id, name, created_at, updated_at)
+      VALUES ('rq-1', 'Dra. Ana Lima', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, exam_date, status, notes, created_at, updated_at)
+      VALUES
+        ('att-1', 'pt-1', '2026-02-17', 'waiting', 'Jejum', '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('att-2', 'pt-1', '2026-02-18', 'in_analysis', NULL, '2026-02-18 08:00:00', '2026-02-18 08:00:00');
+
+      INSERT INTO exam_items (id, exam_id, catalog_item_id, name, unit, result_value, created_at, updated_at)
+      VALUES
+        ('it-1', 'att-1', 'glicose', 'Glicose', 'mg/dL', NULL, '2026-02-17 08:00:00', '2026-02-17 08:00:00'),
+        ('it-2', 'att-1', 'colesterol-total', 'Colesterol Total', 'mg/dL', NULL, '2026-02-17 08:00:01', '2026-02-17 08:00:01'),
+        ('it-3', 'att-2', 'glicose', 'Glicose', 'mg/dL', '92', '2026-02-18 08:00:00', '2026-02-18 08:00:00'),
+        ('it-4', 'att-2', 'triglicerideos', 'Triglicerideos', 'mg/dL', NULL, '2026-02-18 08:00:01', '2026-02-18 08:00:01');
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn catalog_item(catalog_item_id: &str) -> CreateAttendanceItemInput {
+  CreateAttendanceItemInput {
+    catalog_item_id: Some(catalog_item_id.to_string()),
+    name: String::new(),
+    unit: None,
+    method: None,
+    reference_range: None,
+  }
+}
+
+fn update(attendance_id: &str, expected_status: &str) -> AttendanceUpdate {
+  AttendanceUpdate {
+    attendance_id: attendance_id.to_string(),
+    expected_status: expected_status.to_string(),
+    requester_id: None,
+    procedure_type: None,
+    delivered_to: None,
+    notes: None,
+    add_items: Vec::new(),
+    remove_item_ids: Vec::new(),
+  }
+}
+
+#[tokio::test]
+async fn update_attendance_replaces_fields_and_items_with_audit() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let entry = repo
+    .update_attendance(
+      AttendanceUpdate {
+        requester_id: Some("rq-1".to_string()),
+        delivered_to: Some("  Joao (filho) ".to_string()),
+        add_items: vec![catalog_item("hemograma-completo")],
+        remove_item_ids: vec!["it-2".to_string()],
+        ..update("att-1", "waiting")
+      },
+      Some("usr-1".to_string()),
+    )
+    .await
+    .expect("attendance should be updated");
+
+  assert_eq!(entry.requester_name.as_deref(), Some("Dra. Ana Lima"));
+  let names: Vec<&str> = entry.items.iter().map(|item| item.name.as_str()).collect();
+  assert_eq!(names, vec!["Glicose", "Hemograma Completo"]);
+
+  let exam = sqlx::query("SELECT delivered_to, notes, updated_at FROM exams WHERE id = 'att-1'")
+    .fetch_one(&pool)
+    .await
+    .expect("exam should exist");
+  assert_eq!(
+    exam.get::<Option<String>, _>("delivered_to").as_deref(),
+    Some("Joao (filho)")
+  );
+  assert_eq!(exam.get::<Option<String>, _>("notes"), None);
+  assert_ne!(exam.get::<String, _>("updated_at"), "2026-02-17 08:00:00");
+
+  let audit = sqlx::query(
+    r#"
+    SELECT action, performed_by_user_id, before_json, after_json
+    FROM audit_log
+    WHERE entity_name = 'exams' AND entity_id = 'att-1'
+    "#,
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("audit entry should exist");
+  assert_eq!(audit.get::<String, _>("action"), "update");
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-1")
+  );
+  let before = audit.get::<String, _>("before_json");
+  let after = audit.get::<String, _>("after_json");
+  assert!(before.contains("\"notes\":\"Jejum\"") && before.contains("Colesterol Total"));
+  assert!(after.contains("\"requester_id\":\"rq-1\"") && after.contains("Hemograma Completo"));
+  assert!(!after.contains("Colesterol Total"));
+}
+
+#[tokio::test]
+async fn update_attendance_rejects_stale_status_and_item_changes_after_results() {
+  let repo = PatientsSqliteRepository::new(setup_pool().await);
+
+  let stale = repo
+    .update_attendance(update("att-1", "collected"), None)
+    .await;
+  assert!(matches!(stale, Err(PatientRepositoryError::Conflict)));
+
+  let with_result = repo
+    .update_attendance(
+      AttendanceUpdate {
+        remove_item_ids: vec!["it-4".to_string()],
+        ..update("att-2", "in_analysis")
+      },
+      None,
+    )
+    .await;
+  assert!(matches!(with_result, Err(PatientRepositoryError::Conflict)));
+
+  let notes_only = repo
+    .update_attendance(
+      AttendanceUpdate {
+        notes: Some("Amostra recoletada".to_string()),
+        ..update("att-2", "in_analysis")
+      },
+      None,
+    )
+    .await
+    .expect("notes can change after results");
+  assert_eq!(notes_only.items.len(), 2);
+
+  let missing = repo
+    .update_attendance(update("att-404", "waiting"), None)
+    .await;
+  assert!(matches!(missing, Err(PatientRepositoryError::NotFound)));
+}
+
+#[tokio::test]
+async fn get_attendance_edit_context_reports_results_and_hides_deleted() {
+  let pool = setup_pool().await;
+  let repo = PatientsSqliteRepository::new(pool.clone());
+
+  let context = repo
+    .get_attendance_edit_context("att-2".to_string())
+    .await
+    .expect("context should load");
+  assert_eq!(context.status, "in_analysis");
+  assert_eq!(context.requester_id, None);
+  let flags: Vec<(&str, bool)> = context
+    .items
+    .iter()
+    .map(|item| (item.exam_item_id.as_str(), item.has_result))
+    .collect();
+  assert_eq!(flags, vec![("it-3", true), ("it-4", false)]);
+
+  pool
+    .execute("UPDATE exams SET deleted_at = datetime('now') WHERE id = 'att-1'")
+    .await
+    .expect("failed to delete attendance");
+  let deleted = repo.get_attendance_edit_context("att-1".to_string()).await;
+  assert!(matches!(deleted, Err(PatientRepositoryError::NotFound)));
+}
@@ -0,0 +1,214 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::update_patient::UpdatePatientUseCase,
+  domain::patients::{
+    dto::{
+      AttendanceQueueItemView, AttendanceQueuePageQuery, CompleteAttendanceInput,
+      CreateAttendanceInput, CreatePatientInput, ExamCatalogItemView, PatientRecordEntryView,
+      PatientRecordView, UpdatePatientInput,
+    },
+    entity::Patient,
+    errors::PatientRepositoryError,
+    ports::PatientRepository,
+  },
+  domain::users::dto::UserView,
+};
+
+fn user(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Ana".to_string(),
+    cpf: "00000000000".to_string(),
+    username: "ana".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+struct StubUpdateRepository {
+  result: Result<(), PatientRepositoryError>,
+  updates: Mutex<Vec<UpdatePatientInput>>,
+}
+
+impl StubUpdateRepository {
+  fn returning(result: Result<(), PatientRepositoryError>) -> Self {
+    Self {
+      result,
+      updates: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl PatientRepository for StubUpdateRepository {
+  async fn insert(
+    &self,
+    _input: CreatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list(
+    &self,
+    _query: laboratory_app_lib::domain::patients::dto::PatientPageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<Patient>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn update_patient(
+    &self,
+    input: UpdatePatientInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<Patient, PatientRepositoryError> {
+    self.updates.lock().unwrap().push(input.clone());
+    self.result.clone()?;
+    Ok(Patient {
+      id: input.id,
+      full_name: input.full_name,
+      cpf: input.cpf,
+      birth_date: input.birth_date,
+      sex: input.sex,
+      phone: input.phone,
+      address: input.address,
+      created_at: "2026-01-01 00:00:00".to_string(),
+      updated_at: "2026-01-02 09:30:00.120".to_string(),
+      deleted_at: None,
+    })
+  }
+
+  async fn find_patient(
+    &self,
+    _patient_id: String,
+  ) -> Result<Option<Patient>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_patient_record(
+    &self,
+    _patient_id: String,
+    _include_deleted: bool,
+  ) -> Result<PatientRecordView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_exam_catalog(
+    &self,
+    _include_inactive: bool,
+  ) -> Result<Vec<ExamCatalogItemView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn create_attendance(
+    &self,
+    _input: CreateAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_queue(
+    &self,
+    _input: AttendanceQueuePageQuery,
+  ) -> Result<
+    laboratory_app_lib::domain::patients::pagination::PageView<AttendanceQueueItemView>,
+    PatientRepositoryError,
+  > {
+    unimplemented!()
+  }
+
+  async fn complete_attendance(
+    &self,
+    _input: CompleteAttendanceInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn input(cpf: &str) -> UpdatePatientInput {
+  UpdatePatientInput {
+    id: " pt-1 ".to_string(),
+    full_name: "Maria da Silva".to_string(),
+    cpf: cpf.to_string(),
+    birth_date: "1990-01-01".to_string(),
+    sex: "F".to_string(),
+    phone: "11988887777".to_string(),
+    address: "Rua B".to_string(),
+    expected_updated_at: "2026-01-01 00:00:00".to_string(),
+  }
+}
+
+#[tokio::test]
+async fn update_patient_applies_creation_rules() {
+  let repo = Arc::new(StubUpdateRepository::returning(Ok(())));
+  let use_case = UpdatePatientUseCase::new(repo.clone());
+
+  let updated = use_case
+    .execute(&user("reception"), input("529.982.247-25"))
+    .await
+    .expect("expected success");
+  let invalid = use_case
+    .execute(&user("reception"), input("529.982.247-26"))
+    .await;
+
+  assert_eq!(updated.cpf, "52998224725");
+  assert_eq!(updated.full_name, "Maria da Silva");
+  assert!(matches!(
+    invalid,
+    Err(AppError::Validation { message: msg, .. }) if msg == "cpf check digits do not match"
+  ));
+  let updates = repo.updates.lock().unwrap();
+  assert_eq!(updates.len(), 1);
+  assert_eq!(updates[0].id, "pt-1");
+  assert_eq!(updates[0].expected_updated_at, "2026-01-01 00:00:00");
+}
+
+#[tokio::test]
+async fn update_patient_requires_expected_updated_at() {
+  let repo = Arc::new(StubUpdateRepository::returning(Ok(())));
+  let use_case = UpdatePatientUseCase::new(repo.clone());
+
+  let result = use_case
+    .execute(
+      &user("admin"),
+      UpdatePatientInput {
+        expected_updated_at: " ".to_string(),
+        ..input("52998224725")
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "expected_updated_at is required"
+  ));
+  assert!(repo.updates.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn update_patient_maps_stale_write_to_conflict() {
+  let use_case = UpdatePatientUseCase::new(Arc::new(StubUpdateRepository::returning(Err(
+    PatientRepositoryError::Conflict,
+  ))));
+
+  let result = use_case.execute(&user("admin"), input("52998224725")).await;
+
+  assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn update_patient_is_not_open_to_bench() {
+  let repo = Arc::new(StubUpdateRepository::returning(Ok(())));
+  let use_case = UpdatePatientUseCase::new(repo.clone());
+
+  let result = use_case.execute(&user("bench"), input("52998224725")).await;
+
+  assert!(matches!(result, Err(AppError::Forbidden(_))));
+  assert!(repo.updates.lock().unwrap().is_empty());
+}
@@ -0,0 +1,151 @@
+use laboratory_app_lib::{
+  domain::reports::{
+    dto::RecordPdfReportInput,
+    errors::{ReportRepositoryError, ReportStorageError},
+    ports::{ReportFileStore, ReportRepository},
+  },
+  infra::{
+    db::sqlite::run_migrations, reports::report_files::FsReportFileStore,
+    repositories::reports_sqlite::ReportsSqliteRepository,
+  },
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool).await.expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+
+      INSERT INTO requesters (id, name, created_at, updated_at) VALUES ('rq-1', 'Dra. Ana', datetime('now'), datetime('now'));
+
+      INSERT INTO exams (id, patient_id, requester_id, exam_date, status, created_at, updated_at)
+      VALUES ('att-1', 'pt-1', 'rq-1', '2026-02-17', 'released', datetime('now'), datetime('now'));
+
+      INSERT INTO exam_items (id, exam_id, name, unit, result_value, result_flag, created_at, updated_at)
+      VALUES ('it-1', 'att-1', 'Glicose', 'mg/dL', '120', 'H', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed data");
+
+  pool
+}
+
+fn record(version: i64) -> RecordPdfReportInput {
+  RecordPdfReportInput {
+    attendance_id: "att-1".to_string(),
+    report_version: version,
+    generated_by_user_id: None,
+    note: None,
+    amended: false,
+  }
+}
+
+#[tokio::test]
+async fn get_lab_report_source_loads_patient_entry_and_version() {
+  let repo = ReportsSqliteRepository::new(setup_pool().await);
+
+  let source = repo
+    .get_lab_report_source("att-1".to_string())
+    .await
+    .expect("source should load");
+
+  assert_eq!(source.patient.full_name, "Maria Souza");
+  assert_eq!(source.entry.requester_name.as_deref(), Some("Dra. Ana"));
+  assert_eq!(source.entry.items[0].result_flag.as_deref(), Some("H"));
+  assert_eq!(source.current_report_version, None);
+
+  repo.record_pdf_report(record(1)).await.expect("report should be recorded");
+  let source = repo
+    .get_lab_report_source("att-1".to_string())
+    .await
+    .expect("source should load");
+  assert_eq!(source.current_report_version, Some(1));
+}
+
+#[tokio::test]
+async fn get_lab_report_source_returns_not_found() {
+  let repo = ReportsSqliteRepository::new(setup_pool().await);
+
+  let result = repo.get_lab_report_source("missing".to_string()).await;
+
+  assert!(matches!(result, Err(ReportRepositoryError::NotFound)));
+}
+
+#[tokio::test]
+async fn record_pdf_report_bumps_version_and_rejects_stale_versions() {
+  let repo = ReportsSqliteRepository::new(setup_pool().await);
+
+  let first = repo.record_pdf_report(record(1)).await.expect("v1");
+  let second = repo
+    .record_pdf_report(RecordPdfReportInput {
+      note: Some("segunda via".to_string()),
+      ..record(2)
+    })
+    .await
+    .expect("v2");
+
+  assert_eq!(first.report_version, 1);
+  assert_eq!(second.id, first.id);
+  assert_eq!(second.report_version, 2);
+  assert_eq!(second.note.as_deref(), Some("segunda via"));
+  assert!(matches!(
+    repo.record_pdf_report(record(2)).await,
+    Err(ReportRepositoryError::Conflict)
+  ));
+  assert!(matches!(
+    repo.record_pdf_report(record(1)).await,
+    Err(ReportRepositoryError::Conflict)
+  ));
+}
+
+#[test]
+fn fs_report_file_store_writes_inside_its_directory() {
+  let dir = std::env::temp_dir().join(format!("laboratory-reports-{}", std::process::id()));
+  let store = FsReportFileStore::new(&dir);
+
+  let path = store.save("laudo-att-1-v1.pdf", b"%PDF-1.4").expect("file should be saved");
+
+  assert_eq!(std::fs::read(&path).expect("file should exist"), b"%PDF-1.4");
+  assert!(matches!(
+    store.save("../escape.pdf", b"x"),
+    Err(ReportStorageError::InvalidFileName(_))
+  ));
+
+  std::fs::remove_dir_all(&dir).expect("cleanup");
+}
+
+#[test]
+fn fs_report_file_store_promotes_or_discards_staged_files() {
+  let dir = std::env::temp_dir().join(format!("laboratory-staged-{}", std::process::id()));
+  let store = FsReportFileStore::new(&dir);
+
+  let first = store.stage("laudo-att-1-v2.pdf", b"first").expect("file should be staged");
+  let second = store.stage("laudo-att-1-v2.pdf", b"second").expect("file should be staged");
+  assert_ne!(first, second);
+  assert!(!dir.join("laudo-att-1-v2.pdf").exists());
+
+  let path = store
+    .promote(&first, "laudo-att-1-v2.pdf")
+    .expect("file should be promoted");
+  store.discard(&second);
+
+  assert_eq!(std::fs::read(&path).expect("file should exist"), b"first");
+  let names: Vec<String> = std::fs::read_dir(&dir)
+    .expect("dir should list")
+    .map(|entry| entry.expect("entry").file_name().to_string_lossy().into_owned())
+    .collect();
+  assert_eq!(names, ["laudo-att-1-v2.pdf"]);
+
+  std::fs::remove_dir_all(&dir).expect("cleanup");
+}
@@ -0,0 +1,205 @@
+use laboratory_app_lib::{
+  domain::requesters::{
+    dto::NewRequester, errors::RequesterRepositoryError, ports::RequesterRepository,
+    registry::CouncilRegistry,
+  },
+  infra::{
+    db::sqlite::run_migrations, repositories::requesters_sqlite::RequestersSqliteRepository,
+  },
+};
+use sqlx::{sqlite::SqlitePoolOptions, Executor, Row, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+    .execute(
+      r#"
+      INSERT INTO users (id, name, cpf, username, password_hash, role, is_active, created_at, updated_at)
+      VALUES ('usr-1', 'Rita Recepcao', '12345678900', 'rita', 'hash', 'reception', TRUE, datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed user");
+
+  pool
+}
+
+fn new_requester(name: &str, registry: Option<(&str, &str, &str)>) -> NewRequester {
+  NewRequester {
+    name: name.to_string(),
+    registry: registry.map(|(council_type, number, state)| CouncilRegistry {
+      council_type: council_type.to_string(),
+      number: number.to_string(),
+      state: state.to_string(),
+    }),
+  }
+}
+
+#[tokio::test]
+async fn insert_requester_enforces_unique_registry_only() {
+  let pool = setup_pool().await;
+  let repo = RequestersSqliteRepository::new(pool.clone());
+
+  let ana = repo
+    .insert_requester(
+      new_requester("Dra. Ana Lima", Some(("CRM", "12345", "SP"))),
+      Some("usr-1".to_string()),
+    )
+    .await
+    .expect("requester should be created");
+  assert_eq!(ana.council_type.as_deref(), Some("CRM"));
+  assert!(ana.is_active);
+
+  // Same name, different registry: homonyms are allowed.
+  repo
+    .insert_requester(
+      new_requester("Dra. Ana Lima", Some(("CRM", "12345", "RJ"))),
+      None,
+    )
+    .await
+    .expect("homonym with another registry should be created");
+  // Requesters without registry never collide.
+  for _ in 0..2 {
+    repo
+      .insert_requester(new_requester("Dr. Paulo", None), None)
+      .await
+      .expect("requester without registry should be created");
+  }
+
+  let duplicate = repo
+    .insert_requester(
+      new_requester("Ana Lima", Some(("CRM", "12345", "SP"))),
+      None,
+    )
+    .await;
+  assert!(matches!(duplicate, Err(RequesterRepositoryError::Conflict)));
+
+  let audit = sqlx::query(
+    "SELECT action, performed_by_user_id FROM audit_log WHERE entity_name = 'requesters' AND entity_id = ?1",
+  )
+  .bind(&ana.id)
+  .fetch_one(&pool)
+  .await
+  .expect("audit entry should exist");
+  assert_eq!(audit.get::<String, _>("action"), "create");
+  assert_eq!(
+    audit
+      .get::<Option<String>, _>("performed_by_user_id")
+      .as_deref(),
+    Some("usr-1")
+  );
+}
+
+#[tokio::test]
+async fn update_and_deactivate_requester() {
+  let repo = RequestersSqliteRepository::new(setup_pool().await);
+  let paulo = repo
+    .insert_requester(new_requester("Dr. Paulo", None), None)
+    .await
+    .expect("requester should be created");
+
+  let updated = repo
+    .update_requester(
+      paulo.id.clone(),
+      new_requester("Dr. Paulo Mendes", Some(("CRO", "777", "MG"))),
+      None,
+    )
+    .await
+    .expect("requester should be updated");
+  assert_eq!(updated.name, "Dr. Paulo Mendes");
+  assert_eq!(updated.council_number.as_deref(), Some("777"));
+
+  let inactive = repo
+    .set_requester_active(paulo.id.clone(), false, None)
+    .await
+    .expect("requester should be deactivated");
+  assert!(!inactive.is_active);
+
+  let active = repo.list_requesters(false).await.expect("list requesters");
+  assert!(active.is_empty());
+  let all = repo.list_requesters(true).await.expect("list requesters");
+  assert_eq!(all.len(), 1);
+
+  let found = repo
+    .find_requester(paulo.id.clone())
+    .await
+    .expect("find requester");
+  assert_eq!(found.map(|r| r.is_active), Some(false));
+
+  let missing = repo
+    .update_requester("nope".to_string(), new_requester("X", None), None)
+    .await;
+  assert!(matches!(missing, Err(RequesterRepositoryError::NotFound)));
+}
+
+#[tokio::test]
+async fn migration_keeps_existing_requesters_linked_to_exams() {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+  pool
+    .execute("PRAGMA foreign_keys = ON;")
+    .await
+    .expect("failed to enable foreign keys");
+  for migration in [
+    include_str!("../src/infra/db/migrations/0001_create_users.sql"),
+    include_str!("../src/infra/db/migrations/0002_create_patients.sql"),
+    include_str!("../src/infra/db/migrations/0003_create_requesters.sql"),
+    include_str!("../src/infra/db/migrations/0004_create_exams.sql"),
+  ] {
+    pool
+      .execute(migration)
+      .await
+      .expect("failed to run migration");
+  }
+  pool
+    .execute(
+      r#"
+      INSERT INTO patients (id, full_name, cpf, birth_date, sex, phone, address, created_at, updated_at)
+      VALUES ('pt-1', 'Maria Souza', '12345678900', '1991-10-01', 'F', '11999999999', 'Rua A', datetime('now'), datetime('now'));
+      INSERT INTO requesters (id, name, created_at) VALUES ('rq-1', 'Dra. Ana', datetime('now'));
+      INSERT INTO exams (id, patient_id, requester_id, exam_date, status, created_at, updated_at)
+      VALUES ('att-1', 'pt-1', 'rq-1', '2026-02-17', 'waiting', datetime('now'), datetime('now'));
+      "#,
+    )
+    .await
+    .expect("failed to seed legacy data");
+
+  pool
+    .execute(include_str!(
+      "../src/infra/db/migrations/0019_extend_requesters.sql"
+    ))
+    .await
+    .expect("failed to extend requesters");
+
+  let row = sqlx::query(
+    r#"
+    SELECT e.requester_id, r.name, r.council_number, r.is_active
+    FROM exams e JOIN requesters r ON r.id = e.requester_id
+    WHERE e.id = 'att-1'
+    "#,
+  )
+  .fetch_one(&pool)
+  .await
+  .expect("exam should still point at its requester");
+  assert_eq!(row.get::<String, _>("requester_id"), "rq-1");
+  assert_eq!(row.get::<String, _>("name"), "Dra. Ana");
+  assert_eq!(row.get::<Option<String>, _>("council_number"), None);
+  assert!(row.get::<bool, _>("is_active"));
+
+  let dangling = sqlx::query("UPDATE exams SET requester_id = 'rq-404' WHERE id = 'att-1'")
+    .execute(&pool)
+    .await;
+  assert!(dangling.is_err(), "foreign key should still be enforced");
+}
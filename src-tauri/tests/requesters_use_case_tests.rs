@@ -0,0 +1,412 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::requesters::{
+    create_requester::CreateRequesterUseCase, search_requesters::SearchRequestersUseCase,
+    update_requester::UpdateRequesterUseCase,
+  },
+  domain::{
+    requesters::{
+      dto::{
+        CreateRequesterInput, NewRequester, RequesterView, SearchRequestersInput,
+        UpdateRequesterInput,
+      },
+      errors::RequesterRepositoryError,
+      ports::RequesterRepository,
+    },
+    users::dto::UserView,
+  },
+};
+
+#[derive(Default)]
+struct StubRequesterRepository {
+  requesters: Mutex<Vec<RequesterView>>,
+}
+
+impl StubRequesterRepository {
+  fn with(requesters: Vec<RequesterView>) -> Self {
+    Self {
+      requesters: Mutex::new(requesters),
+    }
+  }
+}
+
+fn view_from(id: String, requester: NewRequester) -> RequesterView {
+  let registry = requester.registry;
+  RequesterView {
+    id,
+    name: requester.name,
+    council_type: registry.as_ref().map(|r| r.council_type.clone()),
+    council_number: registry.as_ref().map(|r| r.number.clone()),
+    council_state: registry.as_ref().map(|r| r.state.clone()),
+    is_active: true,
+    created_at: "2026-01-01 00:00:00".to_string(),
+    updated_at: "2026-01-01 00:00:00".to_string(),
+  }
+}
+
+fn same_registry(a: &RequesterView, b: &RequesterView) -> bool {
+  a.council_number.is_some()
+    && a.council_type == b.council_type
+    && a.council_number == b.council_number
+    && a.council_state == b.council_state
+}
+
+#[async_trait::async_trait]
+impl RequesterRepository for StubRequesterRepository {
+  async fn list_requesters(
+    &self,
+    include_inactive: bool,
+  ) -> Result<Vec<RequesterView>, RequesterRepositoryError> {
+    Ok(
+      self
+        .requesters
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|r| include_inactive || r.is_active)
+        .cloned()
+        .collect(),
+    )
+  }
+
+  async fn find_requester(
+    &self,
+    requester_id: String,
+  ) -> Result<Option<RequesterView>, RequesterRepositoryError> {
+    Ok(
+      self
+        .requesters
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|r| r.id == requester_id)
+        .cloned(),
+    )
+  }
+
+  async fn insert_requester(
+    &self,
+    requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    let mut requesters = self.requesters.lock().unwrap();
+    let view = view_from(format!("rq-{}", requesters.len() + 1), requester);
+    if requesters.iter().any(|r| same_registry(r, &view)) {
+      return Err(RequesterRepositoryError::Conflict);
+    }
+    requesters.push(view.clone());
+    Ok(view)
+  }
+
+  async fn update_requester(
+    &self,
+    requester_id: String,
+    requester: NewRequester,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    let mut requesters = self.requesters.lock().unwrap();
+    let view = view_from(requester_id.clone(), requester);
+    let existing = requesters
+      .iter_mut()
+      .find(|r| r.id == requester_id)
+      .ok_or(RequesterRepositoryError::NotFound)?;
+    *existing = view.clone();
+    Ok(view)
+  }
+
+  async fn set_requester_active(
+    &self,
+    _requester_id: String,
+    _is_active: bool,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<RequesterView, RequesterRepositoryError> {
+    unimplemented!()
+  }
+}
+
+fn actor(role: &str) -> UserView {
+  UserView {
+    id: "usr-1".to_string(),
+    name: "Rita Recepcao".to_string(),
+    cpf: "12345678900".to_string(),
+    username: "rita".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+fn create_input(
+  name: &str,
+  council_type: Option<&str>,
+  number: Option<&str>,
+  state: Option<&str>,
+) -> CreateRequesterInput {
+  CreateRequesterInput {
+    name: name.to_string(),
+    council_type: council_type.map(str::to_string),
+    council_number: number.map(str::to_string),
+    council_state: state.map(str::to_string),
+  }
+}
+
+fn requester(
+  id: &str,
+  name: &str,
+  registry: Option<(&str, &str, &str)>,
+  created_at: &str,
+) -> RequesterView {
+  RequesterView {
+    id: id.to_string(),
+    name: name.to_string(),
+    council_type: registry.map(|(t, _, _)| t.to_string()),
+    council_number: registry.map(|(_, n, _)| n.to_string()),
+    council_state: registry.map(|(_, _, s)| s.to_string()),
+    is_active: true,
+    created_at: created_at.to_string(),
+    updated_at: created_at.to_string(),
+  }
+}
+
+fn search_input(query: &str) -> SearchRequestersInput {
+  SearchRequestersInput {
+    query: Some(query.to_string()),
+    limit: None,
+  }
+}
+
+fn ids(requesters: &[RequesterView]) -> Vec<&str> {
+  requesters.iter().map(|r| r.id.as_str()).collect()
+}
+
+#[tokio::test]
+async fn create_requester_stores_canonical_registry() {
+  let use_case = CreateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+
+  let created = use_case
+    .execute(
+      &actor("reception"),
+      create_input(" Dra. Ana Lima ", Some("crm"), Some("012.345"), Some("sp")),
+    )
+    .await
+    .expect("expected success");
+
+  assert_eq!(created.name, "Dra. Ana Lima");
+  assert_eq!(created.council_type.as_deref(), Some("CRM"));
+  assert_eq!(created.council_number.as_deref(), Some("12345"));
+  assert_eq!(created.council_state.as_deref(), Some("SP"));
+}
+
+#[tokio::test]
+async fn create_requester_accepts_missing_registry_but_not_a_partial_one() {
+  let use_case = CreateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+
+  let without = use_case
+    .execute(
+      &actor("reception"),
+      create_input("Dr. Paulo", None, Some(" "), None),
+    )
+    .await
+    .expect("blank registry is allowed");
+  assert_eq!(without.council_number, None);
+
+  let partial = use_case
+    .execute(
+      &actor("reception"),
+      create_input("Dr. Paulo", Some("CRM"), Some("123"), None),
+    )
+    .await;
+  assert!(matches!(
+    partial,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "council_type, council_number and council_state must be filled together"
+  ));
+}
+
+#[tokio::test]
+async fn create_requester_rejects_invalid_fields() {
+  let use_case = CreateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+  let reception = actor("reception");
+
+  let cases = [
+    (create_input(" ", None, None, None), "name is required"),
+    (
+      create_input("Dr. Paulo", Some("CRX"), Some("123"), Some("SP")),
+      "council_type must be one of CRM, CRO, CRBM, CRF, COREN, CRN, CRMV, CRP, CREFITO",
+    ),
+    (
+      create_input("Dr. Paulo", Some("CRM"), Some("123"), Some("XX")),
+      "council_state must be a Brazilian state code (UF)",
+    ),
+    (
+      create_input("Dr. Paulo", Some("CRM"), Some("12a"), Some("SP")),
+      "council_number must contain only digits, '.' and '-'",
+    ),
+    (
+      create_input("Dr. Paulo", Some("CRM"), Some("000"), Some("SP")),
+      "council_number must have between 1 and 10 significant digits",
+    ),
+  ];
+
+  for (input, expected) in cases {
+    let result = use_case.execute(&reception, input).await;
+    assert!(
+      matches!(&result, Err(AppError::Validation { message: msg, .. }) if msg == expected),
+      "expected {expected:?}, got {result:?}"
+    );
+  }
+}
+
+#[tokio::test]
+async fn create_requester_reports_duplicate_registry_as_conflict() {
+  let use_case = CreateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+  let reception = actor("reception");
+
+  use_case
+    .execute(
+      &reception,
+      create_input("Dra. Ana Lima", Some("CRM"), Some("12345"), Some("SP")),
+    )
+    .await
+    .expect("first requester should be created");
+  let duplicate = use_case
+    .execute(
+      &reception,
+      create_input("Ana Lima", Some("CRM"), Some("12.345"), Some("SP")),
+    )
+    .await;
+
+  assert!(matches!(
+    duplicate,
+    Err(AppError::Conflict(msg))
+      if msg == "another requester is already registered with this council registration"
+  ));
+}
+
+#[tokio::test]
+async fn create_requester_requires_register_permission() {
+  let use_case = CreateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+
+  let result = use_case
+    .execute(&actor("bench"), create_input("Dr. Paulo", None, None, None))
+    .await;
+
+  assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn update_requester_reports_unknown_id() {
+  let use_case = UpdateRequesterUseCase::new(Arc::new(StubRequesterRepository::default()));
+
+  let result = use_case
+    .execute(
+      &actor("reception"),
+      UpdateRequesterInput {
+        id: "rq-404".to_string(),
+        name: "Dr. Paulo".to_string(),
+        council_type: None,
+        council_number: None,
+        council_state: None,
+      },
+    )
+    .await;
+
+  assert!(matches!(result, Err(AppError::NotFound(msg)) if msg == "requester not found"));
+}
+
+#[tokio::test]
+async fn search_requesters_ranks_prefix_matches_and_hides_duplicates() {
+  let repo = Arc::new(StubRequesterRepository::with(vec![
+    requester("rq-1", "Carlos Souza", None, "2025-01-01 10:00:00"),
+    requester(
+      "rq-2",
+      "Dr. Carlos Souza",
+      Some(("CRM", "1234", "SP")),
+      "2026-01-01 10:00:00",
+    ),
+    requester("rq-3", "Carla Mendes", None, "2026-02-01 10:00:00"),
+    requester("rq-4", "CARLA MENDES", None, "2025-06-01 10:00:00"),
+    requester(
+      "rq-5",
+      "Ana Carla Lima",
+      Some(("CRM", "999", "RJ")),
+      "2026-01-01 10:00:00",
+    ),
+    requester("rq-6", "Bruno Alves", None, "2026-01-01 10:00:00"),
+  ]));
+  let use_case = SearchRequestersUseCase::new(repo);
+
+  let found = use_case
+    .execute(&actor("bench"), search_input("carl"))
+    .await
+    .expect("expected success");
+
+  assert_eq!(ids(&found), vec!["rq-4", "rq-2", "rq-5"]);
+}
+
+#[tokio::test]
+async fn search_requesters_matches_registry_tokens_and_skips_inactive() {
+  let mut retired = requester(
+    "rq-3",
+    "Joao Pereira",
+    Some(("CRM", "12999", "SP")),
+    "2026-01-01 10:00:00",
+  );
+  retired.is_active = false;
+  let repo = Arc::new(StubRequesterRepository::with(vec![
+    requester(
+      "rq-1",
+      "Dra. Ana Lima",
+      Some(("CRM", "12345", "SP")),
+      "2026-01-01 10:00:00",
+    ),
+    requester(
+      "rq-2",
+      "Ana Lúcia Prado",
+      Some(("CRO", "12345", "RJ")),
+      "2026-01-01 10:00:00",
+    ),
+    retired,
+  ]));
+  let use_case = SearchRequestersUseCase::new(repo);
+  let viewer = actor("bench");
+
+  let by_number = use_case
+    .execute(&viewer, search_input("12.3"))
+    .await
+    .expect("expected success");
+  assert_eq!(ids(&by_number), vec!["rq-1", "rq-2"]);
+
+  let by_council = use_case
+    .execute(&viewer, search_input("crm 12"))
+    .await
+    .expect("expected success");
+  assert_eq!(ids(&by_council), vec!["rq-1"]);
+
+  let by_accent = use_case
+    .execute(&viewer, search_input("dra ana lu"))
+    .await
+    .expect("expected success");
+  assert_eq!(ids(&by_accent), vec!["rq-2"]);
+}
+
+#[tokio::test]
+async fn search_requesters_validates_limit() {
+  let use_case = SearchRequestersUseCase::new(Arc::new(StubRequesterRepository::default()));
+
+  let result = use_case
+    .execute(
+      &actor("bench"),
+      SearchRequestersInput {
+        query: None,
+        limit: Some(51),
+      },
+    )
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Validation { message: msg, .. }) if msg == "limit must be between 1 and 50"
+  ));
+}
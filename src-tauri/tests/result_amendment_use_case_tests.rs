@@ -0,0 +1,449 @@
+use std::sync::{Arc, Mutex};
+
+use laboratory_app_lib::{
+  app::error::AppError,
+  application::patients::amend_result::AmendResultUseCase,
+  domain::{
+    patients::{
+      delta_check::DeltaCheckRule,
+      dto::{
+        AmendResultInput, AttendanceEditContext, AttendanceQueueItemView, AttendanceStatusChange,
+        AttendanceStatusHistoryView, AttendanceUpdate, EnterExamResultsInput,
+        PatientRecordEntryView, PatientRecordExamItemView, PatientView, ResultEntryContext,
+        ResultEntryItemContext, ResultHistoryView,
+      },
+      errors::PatientRepositoryError,
+      ports::AttendanceRepository,
+      reference_range::ReferenceRange,
+    },
+    reports::{
+      dto::{LabReportSource, PdfReportView, RecordPdfReportInput},
+      errors::{ReportRepositoryError, ReportStorageError},
+      ports::{LabReportRenderer, ReportFileStore, ReportRepository},
+    },
+    users::dto::UserView,
+  },
+};
+
+fn actor(role: &str) -> UserView {
+  UserView {
+    id: "usr-bio".to_string(),
+    name: "Beatriz Bioquimica".to_string(),
+    cpf: "52998224725".to_string(),
+    username: "beatriz".to_string(),
+    role: role.to_string(),
+    is_active: true,
+  }
+}
+
+struct StubAttendanceRepository {
+  status: String,
+  amended: Mutex<Vec<AmendResultInput>>,
+}
+
+impl StubAttendanceRepository {
+  fn new(status: &str) -> Self {
+    Self {
+      status: status.to_string(),
+      amended: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl AttendanceRepository for StubAttendanceRepository {
+  async fn get_attendance(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn change_attendance_status(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn cancel_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn release_attendance(
+    &self,
+    _change: AttendanceStatusChange,
+  ) -> Result<AttendanceQueueItemView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn list_attendance_status_history(
+    &self,
+    _attendance_id: String,
+  ) -> Result<Vec<AttendanceStatusHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_result_entry_context(
+    &self,
+    attendance_id: String,
+  ) -> Result<ResultEntryContext, PatientRepositoryError> {
+    if attendance_id != "att-1" {
+      return Err(PatientRepositoryError::NotFound);
+    }
+    Ok(ResultEntryContext {
+      attendance_id,
+      status: self.status.clone(),
+      exam_date: "2026-02-14".to_string(),
+      patient_sex: "F".to_string(),
+      patient_birth_date: "1991-10-01".to_string(),
+      items: vec![ResultEntryItemContext {
+        exam_item_id: "it-1".to_string(),
+        catalog_item_id: Some("glicose".to_string()),
+        reference_ranges: vec![ReferenceRange {
+          sex: None,
+          age_min_years: None,
+          age_max_years: None,
+          low: Some(70.0),
+          high: Some(99.0),
+          critical_low: None,
+          critical_high: None,
+          unit: Some("mg/dL".to_string()),
+          expected_text: None,
+        }],
+        delta_check: DeltaCheckRule::default(),
+        previous_result: None,
+      }],
+    })
+  }
+
+  async fn enter_exam_results(
+    &self,
+    _input: EnterExamResultsInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn amend_result(
+    &self,
+    input: AmendResultInput,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    let entry = PatientRecordEntryView {
+      exam_id: input.attendance_id.clone(),
+      exam_date: "2026-02-14".to_string(),
+      status: self.status.clone(),
+      requester_name: None,
+      items: vec![PatientRecordExamItemView {
+        exam_item_id: input.exam_item_id.clone(),
+        name: "Glicose".to_string(),
+        unit: Some("mg/dL".to_string()),
+        method: None,
+        reference_range: None,
+        result_value: Some(input.result_value.clone()),
+        result_flag: input.result_flag.clone(),
+        report_available: true,
+        result_version: 2,
+        panel_id: None,
+        panel_name: None,
+      }],
+      deleted_at: None,
+    };
+    self.amended.lock().unwrap().push(input);
+    Ok(entry)
+  }
+
+  async fn list_result_history(
+    &self,
+    _exam_item_id: String,
+  ) -> Result<Vec<ResultHistoryView>, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn get_attendance_edit_context(
+    &self,
+    _attendance_id: String,
+  ) -> Result<AttendanceEditContext, PatientRepositoryError> {
+    unimplemented!()
+  }
+
+  async fn update_attendance(
+    &self,
+    _update: AttendanceUpdate,
+    _performed_by_user_id: Option<String>,
+  ) -> Result<PatientRecordEntryView, PatientRepositoryError> {
+    unimplemented!()
+  }
+}
+
+struct StubReportRepository {
+  current_report_version: Option<i64>,
+  recorded: Mutex<Vec<RecordPdfReportInput>>,
+}
+
+impl StubReportRepository {
+  fn new(current_report_version: Option<i64>) -> Self {
+    Self {
+      current_report_version,
+      recorded: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl ReportRepository for StubReportRepository {
+  async fn get_lab_report_source(
+    &self,
+    attendance_id: String,
+  ) -> Result<LabReportSource, ReportRepositoryError> {
+    Ok(LabReportSource {
+      patient: PatientView {
+        id: "pt-1".to_string(),
+        full_name: "Maria Souza".to_string(),
+        cpf: "12345678900".to_string(),
+        birth_date: "1991-10-01".to_string(),
+        sex: "F".to_string(),
+        phone: "11999999999".to_string(),
+        address: "Rua A".to_string(),
+        created_at: "2026-01-01T00:00:00".to_string(),
+        updated_at: "2026-01-01T00:00:00".to_string(),
+        deleted_at: None,
+      },
+      entry: PatientRecordEntryView {
+        exam_id: attendance_id,
+        exam_date: "2026-02-14".to_string(),
+        status: "released".to_string(),
+        requester_name: None,
+        items: vec![],
+        deleted_at: None,
+      },
+      current_report_version: self.current_report_version,
+      amended: true,
+    })
+  }
+
+  async fn record_pdf_report(
+    &self,
+    input: RecordPdfReportInput,
+  ) -> Result<PdfReportView, ReportRepositoryError> {
+    let view = PdfReportView {
+      id: "rep-2".to_string(),
+      attendance_id: input.attendance_id.clone(),
+      report_version: input.report_version,
+      generated_by_user_id: input.generated_by_user_id.clone(),
+      generated_at: "2026-02-15 09:00:00".to_string(),
+      note: input.note.clone(),
+      is_amended: input.amended,
+    };
+    self.recorded.lock().unwrap().push(input);
+    Ok(view)
+  }
+}
+
+struct StubRenderer;
+
+impl LabReportRenderer for StubRenderer {
+  fn render(&self, source: &LabReportSource, report_version: i64) -> Vec<u8> {
+    format!("{} v{report_version}", source.patient.full_name).into_bytes()
+  }
+}
+
+#[derive(Default)]
+struct StubFileStore {
+  fail: bool,
+  saved: Mutex<Vec<String>>,
+}
+
+impl ReportFileStore for StubFileStore {
+  fn save(&self, file_name: &str, _content: &[u8]) -> Result<String, ReportStorageError> {
+    Ok(format!("/reports/{file_name}"))
+  }
+
+  fn stage(&self, file_name: &str, _content: &[u8]) -> Result<String, ReportStorageError> {
+    if self.fail {
+      return Err(ReportStorageError::Io("disk full".to_string()));
+    }
+    Ok(format!("{file_name}.tmp"))
+  }
+
+  fn promote(&self, _staged_name: &str, file_name: &str) -> Result<String, ReportStorageError> {
+    self.saved.lock().unwrap().push(file_name.to_string());
+    Ok(format!("/reports/{file_name}"))
+  }
+
+  fn discard(&self, _staged_name: &str) {}
+}
+
+struct Fixture {
+  repo: Arc<StubAttendanceRepository>,
+  reports: Arc<StubReportRepository>,
+  files: Arc<StubFileStore>,
+  use_case: AmendResultUseCase,
+}
+
+fn setup(status: &str, current_report_version: Option<i64>) -> Fixture {
+  setup_with_files(status, current_report_version, StubFileStore::default())
+}
+
+fn setup_with_files(
+  status: &str,
+  current_report_version: Option<i64>,
+  files: StubFileStore,
+) -> Fixture {
+  let repo = Arc::new(StubAttendanceRepository::new(status));
+  let reports = Arc::new(StubReportRepository::new(current_report_version));
+  let files = Arc::new(files);
+  let use_case = AmendResultUseCase::new(
+    repo.clone(),
+    reports.clone(),
+    Arc::new(StubRenderer),
+    files.clone(),
+  );
+  Fixture {
+    repo,
+    reports,
+    files,
+    use_case,
+  }
+}
+
+fn input(value: &str, reason: &str) -> AmendResultInput {
+  AmendResultInput {
+    attendance_id: "att-1".to_string(),
+    exam_item_id: "it-1".to_string(),
+    result_value: value.to_string(),
+    result_flag: None,
+    reason: reason.to_string(),
+  }
+}
+
+#[tokio::test]
+async fn amend_result_saves_value_and_reissues_existing_report() {
+  let fixture = setup("released", Some(1));
+
+  let amended = fixture
+    .use_case
+    .execute(&actor("biochemist"), input("120", "  erro de digitacao  "))
+    .await
+    .expect("expected success");
+
+  assert_eq!(amended.entry.items[0].result_version, 2);
+  let saved = fixture.repo.amended.lock().unwrap();
+  assert_eq!(saved[0].reason, "erro de digitacao");
+  assert_eq!(saved[0].result_flag.as_deref(), Some("H"));
+
+  let report = amended.report.expect("report should be reissued");
+  assert_eq!(report.report.report_version, 2);
+  assert!(report.report.is_amended);
+  assert_eq!(
+    report.report.note.as_deref(),
+    Some("Retificado: erro de digitacao")
+  );
+  assert!(amended.report_error.is_none());
+  assert_eq!(
+    fixture.files.saved.lock().unwrap().as_slice(),
+    ["laudo-att-1-v2.pdf".to_string()]
+  );
+}
+
+#[tokio::test]
+async fn amend_result_keeps_amendment_when_report_store_fails() {
+  let fixture = setup_with_files(
+    "released",
+    Some(1),
+    StubFileStore {
+      fail: true,
+      ..StubFileStore::default()
+    },
+  );
+
+  let amended = fixture
+    .use_case
+    .execute(&actor("biochemist"), input("120", "erro de digitacao"))
+    .await
+    .expect("amendment should be returned even without the laudo");
+
+  assert_eq!(amended.entry.items[0].result_value.as_deref(), Some("120"));
+  assert_eq!(fixture.repo.amended.lock().unwrap().len(), 1);
+  assert!(amended.report.is_none());
+  assert!(fixture.reports.recorded.lock().unwrap().is_empty());
+  let warning = amended
+    .report_error
+    .expect("report failure should be reported");
+  assert!(warning.starts_with("result amended, but the retificado laudo could not be issued"));
+}
+
+#[tokio::test]
+async fn amend_result_without_report_does_not_issue_one() {
+  let fixture = setup("released", None);
+
+  let amended = fixture
+    .use_case
+    .execute(&actor("biochemist"), input("95", "amostra reprocessada"))
+    .await
+    .expect("expected success");
+
+  assert!(amended.report.is_none());
+  assert_eq!(fixture.repo.amended.lock().unwrap().len(), 1);
+  assert!(fixture.reports.recorded.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn amend_result_requires_reason_and_release() {
+  let released = setup("released", None);
+  let in_analysis = setup("in_analysis", None);
+
+  let no_reason = released
+    .use_case
+    .execute(&actor("biochemist"), input("120", "   "))
+    .await;
+  let not_released = in_analysis
+    .use_case
+    .execute(&actor("biochemist"), input("120", "erro de digitacao"))
+    .await;
+  let foreign_item = released
+    .use_case
+    .execute(
+      &actor("biochemist"),
+      AmendResultInput {
+        exam_item_id: "it-9".to_string(),
+        ..input("120", "erro de digitacao")
+      },
+    )
+    .await;
+
+  assert!(
+    matches!(no_reason, Err(AppError::Validation { message: msg, .. }) if msg == "reason is required")
+  );
+  assert!(matches!(
+    not_released,
+    Err(AppError::Validation { message: msg, .. })
+      if msg == "results can only be amended after release; attendance is in_analysis"
+  ));
+  assert!(matches!(
+    foreign_item,
+    Err(AppError::Validation { message: msg, .. }) if msg == "exam_item_id it-9 does not belong to attendance"
+  ));
+  assert!(released.repo.amended.lock().unwrap().is_empty());
+  assert!(in_analysis.repo.amended.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn amend_result_requires_release_permission() {
+  let fixture = setup("released", Some(1));
+
+  let result = fixture
+    .use_case
+    .execute(&actor("bench"), input("120", "erro de digitacao"))
+    .await;
+
+  assert!(matches!(
+    result,
+    Err(AppError::Forbidden(msg)) if msg == "role bench is not allowed to release_results"
+  ));
+  assert!(fixture.repo.amended.lock().unwrap().is_empty());
+}
@@ -0,0 +1,121 @@
+use laboratory_app_lib::{
+  domain::users::{
+    dto::NewUser,
+    errors::UserRepositoryError,
+    ports::{PasswordHasher, UserRepository},
+  },
+  infra::{
+    db::sqlite::run_migrations, repositories::users_sqlite::UsersSqliteRepository,
+    security::argon2_hasher::Argon2PasswordHasher,
+  },
+};
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn setup_pool() -> SqlitePool {
+  let pool = SqlitePoolOptions::new()
+    .max_connections(1)
+    .connect("sqlite::memory:")
+    .await
+    .expect("failed to create sqlite in-memory pool");
+
+  run_migrations(&pool)
+    .await
+    .expect("failed to run migrations");
+
+  pool
+}
+
+fn new_user(username: &str, cpf: &str) -> NewUser {
+  NewUser {
+    name: "Ana Admin".to_string(),
+    cpf: cpf.to_string(),
+    username: username.to_string(),
+    password_hash: "$argon2id$stub".to_string(),
+    role: "admin".to_string(),
+  }
+}
+
+#[tokio::test]
+async fn insert_first_user_only_works_on_empty_table() {
+  let repo = UsersSqliteRepository::new(setup_pool().await);
+
+  assert_eq!(repo.count_users().await.expect("count"), 0);
+
+  let admin = repo
+    .insert_first_user(new_user("ana", "12345678900"))
+    .await
+    .expect("first user should be created");
+  assert!(admin.is_active);
+  assert_eq!(admin.role, "admin");
+  assert_eq!(repo.count_users().await.expect("count"), 1);
+
+  let second = repo.insert_first_user(new_user("bia", "98765432100")).await;
+  assert!(matches!(second, Err(UserRepositoryError::Conflict)));
+}
+
+#[tokio::test]
+async fn find_credentials_by_username_returns_hash() {
+  let repo = UsersSqliteRepository::new(setup_pool().await);
+  repo
+    .insert_first_user(new_user("ana", "12345678900"))
+    .await
+    .expect("first user should be created");
+
+  let found = repo
+    .find_credentials_by_username("ana".to_string())
+    .await
+    .expect("query should succeed")
+    .expect("user should exist");
+  let missing = repo
+    .find_credentials_by_username("bia".to_string())
+    .await
+    .expect("query should succeed");
+
+  assert_eq!(found.user.username, "ana");
+  assert_eq!(found.password_hash, "$argon2id$stub");
+  assert!(missing.is_none());
+}
+
+#[test]
+fn argon2_hasher_round_trips_and_salts_each_hash() {
+  let hasher = Argon2PasswordHasher::new();
+
+  let first = hasher.hash("s3cret-pass").expect("hash");
+  let second = hasher.hash("s3cret-pass").expect("hash");
+
+  assert!(first.starts_with("$argon2id$"));
+  assert_ne!(first, second);
+  assert!(hasher.verify("s3cret-pass", &first));
+  assert!(!hasher.verify("wrong-pass", &first));
+  assert!(!hasher.verify("s3cret-pass", "not a phc string"));
+}
+
+#[tokio::test]
+async fn insert_user_lists_and_toggles_active() {
+  let repo = UsersSqliteRepository::new(setup_pool().await);
+  repo
+    .insert_first_user(new_user("ana", "12345678900"))
+    .await
+    .expect("first user should be created");
+
+  let bia = repo
+    .insert_user(new_user("bia", "98765432100"), None)
+    .await
+    .expect("second user should be created");
+  let duplicate = repo.insert_user(new_user("bia", "11122233344"), None).await;
+  assert!(matches!(duplicate, Err(UserRepositoryError::Conflict)));
+
+  let inactive = repo
+    .set_user_active(bia.id.clone(), false, None)
+    .await
+    .expect("user should be deactivated");
+  assert!(!inactive.is_active);
+
+  let users = repo.list_users().await.expect("list users");
+  let names: Vec<_> = users.iter().map(|u| u.username.as_str()).collect();
+  assert_eq!(names.len(), 2);
+  assert!(names.contains(&"bia"));
+
+  let missing = repo.set_user_active("nope".to_string(), true, None).await;
+  assert!(matches!(missing, Err(UserRepositoryError::NotFound)));
+}
@@ -1,10 +1,17 @@
 import { Routes } from "@angular/router";
 import { LayoutComponent } from './layout/layout.component';
+import { authGuard } from './core/guards/auth.guard';
 
 export const routes: Routes = [
+  {
+    path: 'login',
+    loadComponent: () =>
+      import('./pages/login/login.component').then((m) => m.LoginComponent),
+  },
   {
     path: '',
     component: LayoutComponent,
+    canActivate: [authGuard],
    children: [
       {
         path: '',
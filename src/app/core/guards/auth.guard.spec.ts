@@ -0,0 +1,50 @@
+import { TestBed } from '@angular/core/testing';
+import { ActivatedRouteSnapshot, Router, RouterStateSnapshot, UrlTree } from '@angular/router';
+
+import { authGuard } from './auth.guard';
+import { AuthApiService, UserView } from '../services/auth-api.service';
+
+describe('authGuard', () => {
+  let auth: jasmine.SpyObj<AuthApiService>;
+
+  const user: UserView = {
+    id: 'usr-1',
+    name: 'Administrador',
+    cpf: '52998224725',
+    username: 'admin',
+    role: 'admin',
+    is_active: true,
+  };
+
+  beforeEach(() => {
+    auth = jasmine.createSpyObj<AuthApiService>('AuthApiService', ['currentUser']);
+    TestBed.configureTestingModule({
+      providers: [{ provide: AuthApiService, useValue: auth }],
+    });
+  });
+
+  function runGuard(): Promise<boolean | UrlTree> {
+    return TestBed.runInInjectionContext(
+      () =>
+        authGuard(
+          {} as ActivatedRouteSnapshot,
+          {} as RouterStateSnapshot
+        ) as Promise<boolean | UrlTree>
+    );
+  }
+
+  it('lets the navigation through when a session is open', async () => {
+    auth.currentUser.and.resolveTo(user);
+
+    expect(await runGuard()).toBeTrue();
+  });
+
+  it('redirects to login without a session', async () => {
+    auth.currentUser.and.resolveTo(null);
+
+    const result = await runGuard();
+
+    const router = TestBed.inject(Router);
+    expect(router.serializeUrl(result as UrlTree)).toBe('/login');
+  });
+});
@@ -0,0 +1,13 @@
+import { inject } from '@angular/core';
+import { CanActivateFn, Router } from '@angular/router';
+
+import { AuthApiService } from '../services/auth-api.service';
+
+// Todos os comandos exigem sessao aberta; sem ela, vai para /login (que tambem trata o primeiro uso).
+export const authGuard: CanActivateFn = async () => {
+  const auth = inject(AuthApiService);
+  const router = inject(Router);
+
+  const user = await auth.currentUser().catch(() => null);
+  return user ? true : router.createUrlTree(['/login']);
+};
@@ -0,0 +1,44 @@
+import { Injectable } from '@angular/core';
+import { invoke } from '@tauri-apps/api/core';
+
+export type ListAuditLogInput = {
+  entity_name?: string | null;
+  entity_id?: string | null;
+  performed_by_user_id?: string | null;
+  from_date?: string | null;
+  to_date?: string | null;
+  limit?: number | null;
+};
+
+export type AuditEntryView = {
+  id: string;
+  entity_name: string;
+  entity_id: string;
+  action: 'create' | 'update' | 'delete';
+  performed_by_user_id: string | null;
+  performed_at: string;
+  before_json: string | null;
+  after_json: string | null;
+};
+
+export type AuditChainVerificationView = {
+  is_valid: boolean;
+  checked_entries: number;
+  first_broken_entry_id: string | null;
+  first_broken_sequence: number | null;
+  reason: string | null;
+  // Anote a cada inspecao: remocoes no fim da cadeia so aparecem comparando este valor.
+  last_entry_hash: string | null;
+};
+
+@Injectable({ providedIn: 'root' })
+export class AuditApiService {
+  // Ex.: quem alterou um resultado -> { entity_name: 'exam_items', entity_id: itemId }.
+  listAuditLog(input: ListAuditLogInput = {}): Promise<AuditEntryView[]> {
+    return invoke<AuditEntryView[]>('list_audit_log', { input });
+  }
+
+  verifyAuditChain(): Promise<AuditChainVerificationView> {
+    return invoke<AuditChainVerificationView>('verify_audit_chain');
+  }
+}
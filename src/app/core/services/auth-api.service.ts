@@ -0,0 +1,75 @@
+import { Injectable } from '@angular/core';
+import { invoke } from '@tauri-apps/api/core';
+
+export type UserRole = 'admin' | 'reception' | 'bench' | 'biochemist';
+
+export type LoginInput = {
+  username: string;
+  password: string;
+};
+
+export type CreateInitialAdminInput = {
+  name: string;
+  cpf: string;
+  username: string;
+  password: string;
+};
+
+export type CreateUserInput = {
+  name: string;
+  cpf: string;
+  username: string;
+  password: string;
+  role: UserRole;
+};
+
+export type SetUserActiveInput = {
+  user_id: string;
+  is_active: boolean;
+};
+
+export type UserView = {
+  id: string;
+  name: string;
+  cpf: string;
+  username: string;
+  role: UserRole;
+  is_active: boolean;
+};
+
+@Injectable({ providedIn: 'root' })
+export class AuthApiService {
+  login(input: LoginInput): Promise<UserView> {
+    return invoke<UserView>('login', { input });
+  }
+
+  logout(): Promise<void> {
+    return invoke<void>('logout');
+  }
+
+  currentUser(): Promise<UserView | null> {
+    return invoke<UserView | null>('current_user');
+  }
+
+  needsInitialSetup(): Promise<boolean> {
+    return invoke<boolean>('needs_initial_setup');
+  }
+
+  // Primeiro uso: cria o admin e ja abre a sessao com ele.
+  createInitialAdmin(input: CreateInitialAdminInput): Promise<UserView> {
+    return invoke<UserView>('create_initial_admin', { input });
+  }
+
+  // Gestao de usuarios: somente admin; outros perfis recebem erro Forbidden.
+  listUsers(): Promise<UserView[]> {
+    return invoke<UserView[]>('list_users');
+  }
+
+  createUser(input: CreateUserInput): Promise<UserView> {
+    return invoke<UserView>('create_user', { input });
+  }
+
+  setUserActive(input: SetUserActiveInput): Promise<UserView> {
+    return invoke<UserView>('set_user_active', { input });
+  }
+}
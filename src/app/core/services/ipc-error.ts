@@ -0,0 +1,25 @@
+// Erro rejeitado por todos os comandos IPC (ver src-tauri/src/interface/ipc/error.rs).
+export type IpcErrorCode =
+  | 'validation'
+  | 'not_found'
+  | 'conflict'
+  | 'forbidden'
+  | 'database'
+  | 'unexpected';
+
+export type IpcError = {
+  code: IpcErrorCode;
+  message: string;
+  // Campo do formulario a destacar (ex.: 'cpf'); so em erros de validacao.
+  field: string | null;
+};
+
+export function isIpcError(error: unknown): error is IpcError {
+  return (
+    !!error &&
+    typeof error === 'object' &&
+    'code' in error &&
+    'message' in error &&
+    typeof (error as { message?: unknown }).message === 'string'
+  );
+}
@@ -1,7 +1,10 @@
 import { Injectable } from '@angular/core';
 import { invoke } from '@tauri-apps/api/core';
 
+import { PageView } from './patients-api.service';
+
 export interface CreateAttendanceItemInputDto {
+  catalog_item_id?: string;
   name: string;
   unit?: string;
   method?: string;
@@ -19,12 +22,53 @@ export interface CreateAttendanceInputDto {
   items: CreateAttendanceItemInputDto[];
 }
 
+// Substitui solicitante, procedimento, entrega e observacoes (em branco limpa o campo).
+// Itens so podem entrar/sair enquanto nenhum resultado foi lancado.
+export interface UpdateAttendanceInputDto {
+  attendance_id: string;
+  requester_id?: string;
+  procedure_type?: string;
+  delivered_to?: string;
+  notes?: string;
+  add_items?: CreateAttendanceItemInputDto[];
+  remove_item_ids?: string[];
+}
+
 export interface ExamCatalogItemDto {
   id: string;
   name: string;
   category_id: string;
   category_title: string;
   price_cents: number;
+  unit?: string;
+  method?: string;
+  reference_range?: string;
+  is_active: boolean;
+  // Analitos ativos de um painel (ex.: Hemograma Completo), na ordem do laudo; vazio para exame simples.
+  analyte_ids: string[];
+}
+
+export interface ExamCategoryDto {
+  id: string;
+  title: string;
+}
+
+export interface CreateExamCategoryInputDto {
+  id: string;
+  title: string;
+}
+
+export interface CreateExamCatalogItemInputDto {
+  name: string;
+  category_id: string;
+  price_cents: number;
+  unit?: string;
+  method?: string;
+  reference_range?: string;
+}
+
+export interface UpdateExamCatalogItemInputDto extends CreateExamCatalogItemInputDto {
+  id: string;
 }
 
 export interface PatientRecordExamItemDto {
@@ -35,7 +79,13 @@ export interface PatientRecordExamItemDto {
   reference_range?: string;
   result_value?: string;
   result_flag?: string;
+  // true so depois da liberacao assinada (release_attendance), nao apenas com resultado digitado.
   report_available: boolean;
+  // Comeca em 1 e cresce a cada retificacao (amend_result); versoes anteriores em listResultHistory.
+  result_version: number;
+  // Painel em que o item foi pedido; itens do mesmo painel aparecem agrupados sob panel_name.
+  panel_id?: string;
+  panel_name?: string;
 }
 
 export interface PatientRecordEntryDto {
@@ -44,6 +94,8 @@ export interface PatientRecordEntryDto {
   status: string;
   requester_name?: string;
   items: PatientRecordExamItemDto[];
+  // Preenchido apenas quando o prontuario foi aberto com includeDeleted.
+  deleted_at: string | null;
 }
 
 export interface PatientRecordDto {
@@ -57,6 +109,7 @@ export interface PatientRecordDto {
     address: string;
     created_at: string;
     updated_at: string;
+    deleted_at: string | null;
   };
   entries: PatientRecordEntryDto[];
 }
@@ -70,39 +123,322 @@ export interface AttendanceQueueItemDto {
   status: string;
   exam_names: string[];
   updated_at: string;
+  deleted_at: string | null;
 }
 
 export interface CompleteAttendanceInputDto {
   attendance_id: string;
 }
 
+export interface AttendanceDeletionInputDto {
+  attendance_id: string;
+}
+
+export interface PdfReportDto {
+  id: string;
+  attendance_id: string;
+  report_version: number;
+  generated_by_user_id: string | null;
+  generated_at: string;
+  note: string | null;
+  // true quando o laudo foi reemitido depois de uma retificacao de resultado.
+  is_amended: boolean;
+}
+
+export interface GeneratedLabReportDto {
+  report: PdfReportDto;
+  file_path: string;
+}
+
+export interface ReferenceRangeDto {
+  sex: 'F' | 'M' | null;
+  age_min_years: number | null;
+  age_max_years: number | null;
+  low: number | null;
+  high: number | null;
+  critical_low: number | null;
+  critical_high: number | null;
+  unit: string | null;
+  expected_text: string | null;
+}
+
+export interface ExamItemResultInputDto {
+  exam_item_id: string;
+  result_value: string;
+  result_flag?: string;
+}
+
+export interface EnterExamResultsInputDto {
+  attendance_id: string;
+  items: ExamItemResultInputDto[];
+}
+
+// Limites do delta check por exame do catalogo; null desliga o limite.
+export interface DeltaCheckRuleDto {
+  absolute_threshold: number | null;
+  percent_threshold: number | null;
+}
+
+// Comparacao com o ultimo resultado liberado do mesmo exame do paciente.
+export interface DeltaCheckDto {
+  exam_item_id: string;
+  previous_attendance_id: string;
+  previous_exam_date: string;
+  previous_value: string;
+  current_value: string;
+  absolute_change: number | null;
+  percent_change: number | null;
+  exceeds_threshold: boolean;
+}
+
+export interface EnterExamResultsDto {
+  entry: PatientRecordEntryDto;
+  delta_checks: DeltaCheckDto[];
+}
+
+export type AttendanceSortDto = 'exam_date' | 'created_at' | 'name';
+
+export type AttendanceStatusDto =
+  | 'waiting'
+  | 'collected'
+  | 'in_analysis'
+  | 'awaiting_validation'
+  | 'released'
+  | 'delivered'
+  | 'cancelled';
+
+// Motivo obrigatorio (ate 500 caracteres); recusado depois da liberacao dos resultados.
+export interface CancelAttendanceInputDto {
+  attendance_id: string;
+  reason: string;
+}
+
+// Liberacao tecnica: o bioquimico assina com a propria senha; todos os itens precisam de resultado.
+export interface ReleaseAttendanceInputDto {
+  attendance_id: string;
+  password: string;
+  note?: string;
+}
+
+// Retificacao de resultado ja liberado: motivo obrigatorio; o valor anterior vai para o historico.
+export interface AmendResultInputDto {
+  attendance_id: string;
+  exam_item_id: string;
+  result_value: string;
+  result_flag?: string;
+  reason: string;
+}
+
+// report vem preenchido quando ja existia laudo e uma nova versao "retificado" foi emitida.
+export interface AmendResultDto {
+  entry: PatientRecordEntryDto;
+  report: GeneratedLabReportDto | null;
+  // Falha ao reemitir o laudo; o resultado ja foi retificado.
+  report_error: string | null;
+}
+
+export interface ResultHistoryDto {
+  id: string;
+  exam_item_id: string;
+  result_version: number;
+  result_value?: string;
+  result_flag?: string;
+  reason: string;
+  amended_by_user_id?: string;
+  amended_at: string;
+}
+
+export type CriticalAlertContactMethodDto = 'phone' | 'in_person' | 'email' | 'message';
+
+// Valor critico (LL/HH) a ser comunicado ao solicitante; bloqueia a liberacao ate ser confirmado.
+export interface CriticalAlertDto {
+  id: string;
+  attendance_id: string;
+  exam_item_id: string;
+  patient_id: string;
+  patient_name: string;
+  exam_name: string;
+  result_value: string;
+  result_flag: string;
+  unit?: string;
+  requester_name?: string;
+  detected_at: string;
+  detected_by_user_id?: string;
+  // Preenchidos pela confirmacao: quem foi avisado, como e por qual usuario.
+  acknowledged_at?: string;
+  acknowledged_by_user_id?: string;
+  contact_name?: string;
+  contact_method?: CriticalAlertContactMethodDto;
+  note?: string;
+}
+
+export interface AcknowledgeCriticalAlertInputDto {
+  alert_id: string;
+  contact_name: string;
+  contact_method: CriticalAlertContactMethodDto;
+  note?: string;
+}
+
+export interface TransitionAttendanceInputDto {
+  attendance_id: string;
+  to_status: AttendanceStatusDto;
+  note?: string;
+}
+
+export interface AttendanceStatusHistoryDto {
+  id: string;
+  attendance_id: string;
+  from_status?: AttendanceStatusDto;
+  to_status: AttendanceStatusDto;
+  changed_by_user_id?: string;
+  changed_at: string;
+  note?: string;
+}
+
 @Injectable({ providedIn: 'root' })
 export class PatientRecordApiService {
-  getPatientRecord(patientId: string): Promise<PatientRecordDto> {
-    return invoke<PatientRecordDto>('get_patient_record', { patientId });
+  // includeDeleted (somente admin) traz tambem paciente/atendimentos excluidos.
+  getPatientRecord(patientId: string, includeDeleted = false): Promise<PatientRecordDto> {
+    return invoke<PatientRecordDto>('get_patient_record', { patientId, includeDeleted });
+  }
+
+  listExamCatalog(includeInactive = false): Promise<ExamCatalogItemDto[]> {
+    return invoke<ExamCatalogItemDto[]>('list_exam_catalog', { includeInactive });
+  }
+
+  listExamCategories(): Promise<ExamCategoryDto[]> {
+    return invoke<ExamCategoryDto[]>('list_exam_categories');
+  }
+
+  createExamCategory(input: CreateExamCategoryInputDto): Promise<ExamCategoryDto> {
+    return invoke<ExamCategoryDto>('create_exam_category', { input });
   }
 
-  listExamCatalog(): Promise<ExamCatalogItemDto[]> {
-    return invoke<ExamCatalogItemDto[]>('list_exam_catalog');
+  createExamCatalogItem(input: CreateExamCatalogItemInputDto): Promise<ExamCatalogItemDto> {
+    return invoke<ExamCatalogItemDto>('create_exam_catalog_item', { input });
+  }
+
+  updateExamCatalogItem(input: UpdateExamCatalogItemInputDto): Promise<ExamCatalogItemDto> {
+    return invoke<ExamCatalogItemDto>('update_exam_catalog_item', { input });
+  }
+
+  deactivateExamCatalogItem(id: string): Promise<ExamCatalogItemDto> {
+    return invoke<ExamCatalogItemDto>('deactivate_exam_catalog_item', { input: { id } });
+  }
+
+  listExamReferenceRanges(catalogItemId: string): Promise<ReferenceRangeDto[]> {
+    return invoke<ReferenceRangeDto[]>('list_exam_reference_ranges', { catalogItemId });
+  }
+
+  setExamReferenceRanges(catalogItemId: string, ranges: ReferenceRangeDto[]): Promise<ReferenceRangeDto[]> {
+    return invoke<ReferenceRangeDto[]>('set_exam_reference_ranges', {
+      input: { catalog_item_id: catalogItemId, ranges },
+    });
+  }
+
+  getExamDeltaCheck(catalogItemId: string): Promise<DeltaCheckRuleDto> {
+    return invoke<DeltaCheckRuleDto>('get_exam_delta_check', { catalogItemId });
+  }
+
+  setExamDeltaCheck(catalogItemId: string, rule: DeltaCheckRuleDto): Promise<DeltaCheckRuleDto> {
+    return invoke<DeltaCheckRuleDto>('set_exam_delta_check', {
+      input: { catalog_item_id: catalogItemId, rule },
+    });
+  }
+
+  // Lista vazia volta o painel a exame simples; atendimentos ja criados mantem seus itens.
+  setExamPanelAnalytes(panelId: string, analyteIds: string[]): Promise<ExamCatalogItemDto> {
+    return invoke<ExamCatalogItemDto>('set_exam_panel_analytes', {
+      input: { panel_id: panelId, analyte_ids: analyteIds },
+    });
   }
 
   createAttendance(input: CreateAttendanceInputDto): Promise<PatientRecordEntryDto> {
     return invoke<PatientRecordEntryDto>('create_attendance', { input });
   }
 
+  updateAttendance(input: UpdateAttendanceInputDto): Promise<PatientRecordEntryDto> {
+    return invoke<PatientRecordEntryDto>('update_attendance', { input });
+  }
+
+  // Pagina sobre atendimentos; sort padrao 'exam_date' (mais recentes primeiro), limit padrao 50.
+  // Cancelados ficam fora, a menos que includeCancelled ou status 'cancelled' seja informado.
   listAttendanceQueue(params?: {
     date?: string;
-    status?: 'waiting' | 'completed';
+    status?: AttendanceStatusDto;
     query?: string;
-  }): Promise<AttendanceQueueItemDto[]> {
-    return invoke<AttendanceQueueItemDto[]>('list_attendance_queue', {
-      date: params?.date,
-      status: params?.status,
-      query: params?.query,
+    includeDeleted?: boolean;
+    includeCancelled?: boolean;
+    sort?: AttendanceSortDto;
+    cursor?: string;
+    limit?: number;
+  }): Promise<PageView<AttendanceQueueItemDto>> {
+    return invoke<PageView<AttendanceQueueItemDto>>('list_attendance_queue', {
+      input: {
+        date: params?.date,
+        status: params?.status,
+        query: params?.query,
+        include_deleted: params?.includeDeleted,
+        include_cancelled: params?.includeCancelled,
+        sort: params?.sort,
+        cursor: params?.cursor,
+        limit: params?.limit,
+      },
     });
   }
 
   completeAttendance(input: CompleteAttendanceInputDto): Promise<AttendanceQueueItemDto> {
     return invoke<AttendanceQueueItemDto>('complete_attendance', { input });
   }
+
+  // Exclusao logica (somente admin): o atendimento some da fila e do prontuario, mas continua no banco.
+  deleteAttendance(input: AttendanceDeletionInputDto): Promise<AttendanceQueueItemDto> {
+    return invoke<AttendanceQueueItemDto>('delete_attendance', { input });
+  }
+
+  restoreAttendance(input: AttendanceDeletionInputDto): Promise<AttendanceQueueItemDto> {
+    return invoke<AttendanceQueueItemDto>('restore_attendance', { input });
+  }
+
+  cancelAttendance(input: CancelAttendanceInputDto): Promise<AttendanceQueueItemDto> {
+    return invoke<AttendanceQueueItemDto>('cancel_attendance', { input });
+  }
+
+  transitionAttendance(input: TransitionAttendanceInputDto): Promise<AttendanceQueueItemDto> {
+    return invoke<AttendanceQueueItemDto>('transition_attendance', { input });
+  }
+
+  enterExamResults(input: EnterExamResultsInputDto): Promise<EnterExamResultsDto> {
+    return invoke<EnterExamResultsDto>('enter_exam_results', { input });
+  }
+
+  releaseAttendance(input: ReleaseAttendanceInputDto): Promise<AttendanceQueueItemDto> {
+    return invoke<AttendanceQueueItemDto>('release_attendance', { input });
+  }
+
+  amendResult(input: AmendResultInputDto): Promise<AmendResultDto> {
+    return invoke<AmendResultDto>('amend_result', { input });
+  }
+
+  listResultHistory(examItemId: string): Promise<ResultHistoryDto[]> {
+    return invoke<ResultHistoryDto[]>('list_result_history', { examItemId });
+  }
+
+  listPendingCriticalAlerts(): Promise<CriticalAlertDto[]> {
+    return invoke<CriticalAlertDto[]>('list_pending_critical_alerts');
+  }
+
+  acknowledgeCriticalAlert(input: AcknowledgeCriticalAlertInputDto): Promise<CriticalAlertDto> {
+    return invoke<CriticalAlertDto>('acknowledge_critical_alert', { input });
+  }
+
+  generateLabReport(attendanceId: string, note?: string): Promise<GeneratedLabReportDto> {
+    return invoke<GeneratedLabReportDto>('generate_lab_report', {
+      input: { attendance_id: attendanceId, note: note ?? null },
+    });
+  }
+
+  listAttendanceStatusHistory(attendanceId: string): Promise<AttendanceStatusHistoryDto[]> {
+    return invoke<AttendanceStatusHistoryDto[]>('list_attendance_status_history', { attendanceId });
+  }
 }
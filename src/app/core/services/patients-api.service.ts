@@ -3,6 +3,7 @@ import { invoke } from '@tauri-apps/api/core';
 
 export type CreatePatientInput = {
   full_name: string;
+  // Com ou sem mascara; o backend valida os digitos verificadores.
   cpf: string;
   birth_date: string;
   sex: string;
@@ -10,9 +11,16 @@ export type CreatePatientInput = {
   address: string;
 };
 
+export type UpdatePatientInput = CreatePatientInput & {
+  id: string;
+  // updated_at do PatientView exibido; se o paciente mudou desde entao, o backend retorna 'conflict'.
+  expected_updated_at: string;
+};
+
 export type PatientView = {
   id: string;
   full_name: string;
+  // Somente digitos (ex.: '52998224725'); aplicar a mascara na exibicao.
   cpf: string;
   birth_date: string;
   sex: string;
@@ -20,6 +28,78 @@ export type PatientView = {
   address: string;
   created_at: string;
   updated_at: string;
+  // Preenchido so para pacientes excluidos (listados com includeDeleted).
+  deleted_at: string | null;
+};
+
+// Pagina de uma listagem paginada; next_cursor e null na ultima pagina e total conta todas as paginas.
+export type PageView<T> = {
+  items: T[];
+  next_cursor: string | null;
+  total: number;
+};
+
+// Maior limit aceito pelas listagens paginadas.
+export const MAX_PAGE_LIMIT = 200;
+
+// Segue next_cursor ate a ultima pagina, para telas que precisam da listagem inteira (contagens, filtros locais).
+export async function fetchAllPages<T>(
+  fetchPage: (cursor: string | undefined) => Promise<PageView<T>>
+): Promise<T[]> {
+  const items: T[] = [];
+  let cursor: string | undefined;
+  do {
+    const page = await fetchPage(cursor);
+    items.push(...page.items);
+    cursor = page.next_cursor ?? undefined;
+  } while (cursor);
+  return items;
+}
+
+export type PatientSort = 'created_at' | 'name' | 'relevance';
+
+export type ListPatientsInput = {
+  // Busca sem acento/maiusculas por prefixo de cada palavra (nome, CPF, telefone, codigo legado).
+  query?: string;
+  // Exige perfil admin.
+  include_deleted?: boolean;
+  // Padrao 'relevance' com query (melhores resultados primeiro) e 'created_at' sem query.
+  sort?: PatientSort;
+  // next_cursor da pagina anterior, obtido com o mesmo sort.
+  cursor?: string;
+  // 1 a 200; padrao 50.
+  limit?: number;
+};
+
+export type PatientDeletionInput = {
+  patient_id: string;
+};
+
+export type DuplicateReason = 'similar_name' | 'same_birth_date' | 'same_phone';
+
+export type FindDuplicatePatientsInput = {
+  // Somente pares que envolvem este paciente; omitido, varre todos os cadastros ativos.
+  patient_id?: string;
+  // 0 a 100; padrao 60.
+  min_score?: number;
+};
+
+export type DuplicatePatientsView = {
+  patient: PatientView;
+  duplicate: PatientView;
+  score: number;
+  reasons: DuplicateReason[];
+};
+
+export type MergePatientsInput = {
+  surviving_patient_id: string;
+  merged_patient_id: string;
+};
+
+export type MergePatientsResultView = {
+  patient: PatientView;
+  merged_patient_id: string;
+  moved_attendances: number;
 };
 
 @Injectable({ providedIn: 'root' })
@@ -29,11 +109,30 @@ export class PatientsApiService {
     return invoke<PatientView>('create_patient', { input });
   }
 
-  listPatients(query?: string): Promise<PatientView[]> {
-    const normalized = query?.trim();
-    if (normalized) {
-      return invoke<PatientView[]>('list_patients', { query: normalized });
-    }
-    return invoke<PatientView[]>('list_patients');
+  updatePatient(input: UpdatePatientInput): Promise<PatientView> {
+    return invoke<PatientView>('update_patient', { input });
+  }
+
+  listPatients(input: ListPatientsInput = {}): Promise<PageView<PatientView>> {
+    const query = input.query?.trim() || undefined;
+    return invoke<PageView<PatientView>>('list_patients', { input: { ...input, query } });
+  }
+
+  // Exclusao logica (somente admin): o cadastro e os atendimentos somem das listagens, sem apagar nada.
+  deletePatient(input: PatientDeletionInput): Promise<PatientView> {
+    return invoke<PatientView>('delete_patient', { input });
+  }
+
+  restorePatient(input: PatientDeletionInput): Promise<PatientView> {
+    return invoke<PatientView>('restore_patient', { input });
+  }
+
+  findDuplicatePatients(input: FindDuplicatePatientsInput = {}): Promise<DuplicatePatientsView[]> {
+    return invoke<DuplicatePatientsView[]>('find_duplicate_patients', { input });
+  }
+
+  // Os atendimentos do cadastro unificado passam para o que fica; o unificado some das listagens.
+  mergePatients(input: MergePatientsInput): Promise<MergePatientsResultView> {
+    return invoke<MergePatientsResultView>('merge_patients', { input });
   }
 }
@@ -0,0 +1,70 @@
+import { Injectable } from '@angular/core';
+import { invoke } from '@tauri-apps/api/core';
+
+export type CouncilType =
+  | 'CRM'
+  | 'CRO'
+  | 'CRBM'
+  | 'CRF'
+  | 'COREN'
+  | 'CRN'
+  | 'CRMV'
+  | 'CRP'
+  | 'CREFITO';
+
+// Conselho, numero e UF vao juntos ou ficam todos vazios.
+export type CreateRequesterInput = {
+  name: string;
+  council_type?: CouncilType | null;
+  council_number?: string | null;
+  council_state?: string | null;
+};
+
+export type UpdateRequesterInput = CreateRequesterInput & {
+  id: string;
+};
+
+export type SetRequesterActiveInput = {
+  requester_id: string;
+  is_active: boolean;
+};
+
+export type SearchRequestersInput = {
+  query?: string | null;
+  limit?: number | null;
+};
+
+export type RequesterView = {
+  id: string;
+  name: string;
+  council_type: CouncilType | null;
+  council_number: string | null;
+  council_state: string | null;
+  is_active: boolean;
+  created_at: string;
+  updated_at: string;
+};
+
+@Injectable({ providedIn: 'root' })
+export class RequestersApiService {
+  listRequesters(includeInactive = false): Promise<RequesterView[]> {
+    return invoke<RequesterView[]>('list_requesters', { includeInactive });
+  }
+
+  // Autocomplete do atendimento: so solicitantes ativos, sem cadastros repetidos.
+  searchRequesters(input: SearchRequestersInput): Promise<RequesterView[]> {
+    return invoke<RequesterView[]>('search_requesters', { input });
+  }
+
+  createRequester(input: CreateRequesterInput): Promise<RequesterView> {
+    return invoke<RequesterView>('create_requester', { input });
+  }
+
+  updateRequester(input: UpdateRequesterInput): Promise<RequesterView> {
+    return invoke<RequesterView>('update_requester', { input });
+  }
+
+  setRequesterActive(input: SetRequesterActiveInput): Promise<RequesterView> {
+    return invoke<RequesterView>('set_requester_active', { input });
+  }
+}
@@ -31,6 +31,7 @@ class AttendanceQueueServiceMock {
       exams: ['GLICOSE'],
       urgency: 'normal',
       status: 'waiting',
+      stage: 'waiting',
       scheduledAt: '2026-02-13T08:00:00',
     },
     {
@@ -40,6 +41,7 @@ class AttendanceQueueServiceMock {
       exams: ['BETA HCG'],
       urgency: 'urgent',
       status: 'done',
+      stage: 'collected',
       scheduledAt: '2026-02-13T09:00:00',
       completedAt: '2026-02-13T10:00:00',
     },
@@ -49,6 +51,7 @@ class AttendanceQueueServiceMock {
   completeAttendance = jasmine.createSpy('completeAttendance').and.resolveTo({
     ...this.list[0],
     status: 'done',
+    stage: 'collected',
     completedAt: '2026-02-13T12:00:00',
   } satisfies AttendanceItem);
 
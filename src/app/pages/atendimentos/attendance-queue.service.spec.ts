@@ -17,6 +17,7 @@ describe('AttendanceQueueService', () => {
       exams: ['GLICOSE'],
       urgency: 'normal',
       status: 'waiting',
+      stage: 'waiting',
       scheduledAt: `${date}T08:00:00`,
     },
     {
@@ -26,6 +27,7 @@ describe('AttendanceQueueService', () => {
       exams: ['BETA HCG'],
       urgency: 'urgent',
       status: 'done',
+      stage: 'collected',
       scheduledAt: `${date}T09:00:00`,
       completedAt: `${date}T10:00:00`,
     },
@@ -36,6 +38,7 @@ describe('AttendanceQueueService', () => {
       exams: ['COLESTEROL'],
       urgency: 'emergency',
       status: 'waiting',
+      stage: 'waiting',
       scheduledAt: `${otherDate}T11:00:00`,
     },
   ];
@@ -49,18 +52,23 @@ describe('AttendanceQueueService', () => {
   });
 
   it('loads queue from backend and maps to attendance items', async () => {
-    api.listAttendanceQueue.and.resolveTo([
-      {
-        attendance_id: 'att-1',
-        patient_id: 'pt-1',
-        patient_name: 'Maria',
-        patient_cpf: '12345678900',
-        exam_date: '2026-02-13',
-        status: 'waiting',
-        exam_names: ['Glicose'],
-        updated_at: '2026-02-13T08:00:00',
-      },
-    ]);
+    api.listAttendanceQueue.and.resolveTo({
+      items: [
+        {
+          attendance_id: 'att-1',
+          patient_id: 'pt-1',
+          patient_name: 'Maria',
+          patient_cpf: '12345678900',
+          exam_date: '2026-02-13',
+          status: 'waiting',
+          exam_names: ['Glicose'],
+          updated_at: '2026-02-13T08:00:00',
+          deleted_at: null,
+        },
+      ],
+      next_cursor: null,
+      total: 1,
+    });
 
     const loaded = await service.loadQueue({
       date: '2026-02-13',
@@ -70,6 +78,8 @@ describe('AttendanceQueueService', () => {
     expect(api.listAttendanceQueue).toHaveBeenCalledWith({
       date: '2026-02-13',
       query: 'maria',
+      cursor: undefined,
+      limit: 200,
     });
     expect(loaded.length).toBe(1);
     expect(loaded[0].patientName).toBe('Maria');
@@ -83,15 +93,92 @@ describe('AttendanceQueueService', () => {
       patient_name: 'Maria',
       patient_cpf: '12345678900',
       exam_date: '2026-02-13',
-      status: 'completed',
+      status: 'collected',
       exam_names: ['Glicose'],
       updated_at: '2026-02-13T10:00:00',
+      deleted_at: null,
     });
 
     const completed = await service.completeAttendance('att-1');
 
     expect(api.completeAttendance).toHaveBeenCalledWith({ attendance_id: 'att-1' });
     expect(completed.status).toBe('done');
+    expect(completed.stage).toBe('collected');
+    expect(completed.completedAt).toBe('2026-02-13T10:00:00');
+  });
+
+  it('maps every status after collection to done and keeps cancelled out of both tabs', async () => {
+    const queueItem = (status: string) => ({
+      attendance_id: `att-${status}`,
+      patient_id: 'pt-1',
+      patient_name: 'Maria',
+      patient_cpf: '12345678900',
+      exam_date: '2026-02-13',
+      status,
+      exam_names: ['Glicose'],
+      updated_at: '2026-02-13T10:00:00',
+      deleted_at: null,
+    });
+    const statuses = [
+      'waiting',
+      'collected',
+      'in_analysis',
+      'awaiting_validation',
+      'released',
+      'delivered',
+      'cancelled',
+    ];
+    api.listAttendanceQueue.and.resolveTo({
+      items: statuses.map(queueItem),
+      next_cursor: null,
+      total: statuses.length,
+    });
+
+    const loaded = await service.loadQueue({ date: '2026-02-13' });
+
+    expect(loaded.map((item) => item.status)).toEqual([
+      'waiting',
+      'done',
+      'done',
+      'done',
+      'done',
+      'done',
+      'cancelled',
+    ]);
+    expect(loaded[0].completedAt).toBeUndefined();
+    expect(loaded[4].completedAt).toBe('2026-02-13T10:00:00');
+    expect(loaded[6].completedAt).toBeUndefined();
+    expect(service.countByTab(loaded, '2026-02-13')).toEqual({ scheduled: 1, completed: 5 });
+  });
+
+  it('follows next_cursor until the last page so tab counts cover every attendance', async () => {
+    const queueItem = (id: string, status: string) => ({
+      attendance_id: id,
+      patient_id: 'pt-1',
+      patient_name: 'Maria',
+      patient_cpf: '12345678900',
+      exam_date: '2026-02-13',
+      status,
+      exam_names: ['Glicose'],
+      updated_at: '2026-02-13T10:00:00',
+      deleted_at: null,
+    });
+    api.listAttendanceQueue.and.callFake(async (params) =>
+      params?.cursor === 'page-2'
+        ? { items: [queueItem('att-3', 'released')], next_cursor: null, total: 3 }
+        : {
+            items: [queueItem('att-1', 'waiting'), queueItem('att-2', 'collected')],
+            next_cursor: 'page-2',
+            total: 3,
+          }
+    );
+
+    const loaded = await service.loadQueue({ date: '2026-02-13' });
+
+    expect(api.listAttendanceQueue).toHaveBeenCalledTimes(2);
+    expect(api.listAttendanceQueue.calls.mostRecent().args[0]?.cursor).toBe('page-2');
+    expect(loaded.map((item) => item.id)).toEqual(['att-1', 'att-2', 'att-3']);
+    expect(service.countByTab(loaded, '2026-02-13')).toEqual({ scheduled: 1, completed: 2 });
   });
 
   it('filters by tab correctly', () => {
@@ -2,10 +2,13 @@ import { Injectable } from '@angular/core';
 
 import {
   AttendanceQueueItemDto,
+  AttendanceStatusDto,
   PatientRecordApiService,
 } from '../../core/services/patient-record-api.service';
+import { MAX_PAGE_LIMIT, fetchAllPages } from '../../core/services/patients-api.service';
 import {
   AttendanceItem,
+  AttendanceStatus,
   AttendanceTab,
   AttendanceTabCounts,
 } from './models/attendance-queue.model';
@@ -20,12 +23,17 @@ export class AttendanceQueueService {
   }): Promise<readonly AttendanceItem[]> {
     const query = params.query?.trim() || undefined;
 
-    const list = await this.api.listAttendanceQueue({
-      date: params.date,
-      query,
-    });
+    // Todas as paginas: as contagens das abas saem da lista carregada.
+    const items = await fetchAllPages((cursor) =>
+      this.api.listAttendanceQueue({
+        date: params.date,
+        query,
+        cursor,
+        limit: MAX_PAGE_LIMIT,
+      })
+    );
 
-    return list.map(mapQueueItemToModel);
+    return items.map(mapQueueItemToModel);
   }
 
   async completeAttendance(id: string): Promise<AttendanceItem> {
@@ -66,19 +74,35 @@ export class AttendanceQueueService {
   }
 }
 
+// Tudo depois da coleta conta como realizado na fila.
+const DONE_STATUSES: readonly string[] = [
+  'collected',
+  'in_analysis',
+  'awaiting_validation',
+  'released',
+  'delivered',
+];
+
 function mapQueueItemToModel(item: AttendanceQueueItemDto): AttendanceItem {
+  const status = mapStatus(item.status);
   return {
     id: item.attendance_id,
     patientName: item.patient_name,
     protocol: item.attendance_id,
     exams: item.exam_names,
     urgency: 'normal',
-    status: item.status === 'completed' ? 'done' : 'waiting',
+    status,
+    stage: item.status as AttendanceStatusDto,
     scheduledAt: ensureDateTime(item.exam_date),
-    completedAt: item.status === 'completed' ? item.updated_at : undefined,
+    completedAt: status === 'done' ? item.updated_at : undefined,
   };
 }
 
+function mapStatus(status: string): AttendanceStatus {
+  if (status === 'cancelled') return 'cancelled';
+  return DONE_STATUSES.includes(status) ? 'done' : 'waiting';
+}
+
 function ensureDateTime(dateOrDateTime: string): string {
   const value = String(dateOrDateTime ?? '').trim();
   if (!value) {
@@ -15,6 +15,7 @@ describe('AtendimentosTableComponent', () => {
       exams: ['GLICOSE'],
       urgency: 'normal',
       status: 'waiting',
+      stage: 'waiting',
       scheduledAt: '2026-02-13T08:00:00',
     },
   ];
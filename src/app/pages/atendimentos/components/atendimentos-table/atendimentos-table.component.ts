@@ -1,7 +1,18 @@
 import { ChangeDetectionStrategy, Component, EventEmitter, Input, Output } from '@angular/core';
 
+import { AttendanceStatusDto } from '../../../../core/services/patient-record-api.service';
 import { AttendanceItem, AttendanceTab } from '../../models/attendance-queue.model';
 
+const STAGE_LABELS: Record<AttendanceStatusDto, string> = {
+  waiting: 'AGUARDANDO',
+  collected: 'COLETADO',
+  in_analysis: 'EM ANALISE',
+  awaiting_validation: 'AGUARDANDO VALIDACAO',
+  released: 'LIBERADO',
+  delivered: 'ENTREGUE',
+  cancelled: 'CANCELADO',
+};
+
 @Component({
   selector: 'app-atendimentos-table',
   standalone: true,
@@ -24,6 +35,6 @@ export class AtendimentosTableComponent {
   }
 
   statusLabel(item: AttendanceItem): string {
-    return item.status === 'waiting' ? 'AGUARDANDO' : 'REALIZADO';
+    return STAGE_LABELS[item.stage] ?? 'REALIZADO';
   }
 }
@@ -1,6 +1,9 @@
 export type AttendanceTab = 'scheduled' | 'completed';
 
-export type AttendanceStatus = 'waiting' | 'done';
+import { AttendanceStatusDto } from '../../../core/services/patient-record-api.service';
+
+// Aba do atendimento: 'done' cobre tudo depois da coleta; cancelados nao entram em nenhuma aba.
+export type AttendanceStatus = 'waiting' | 'done' | 'cancelled';
 
 export type AttendanceUrgency = 'normal' | 'urgent' | 'emergency';
 
@@ -11,6 +14,8 @@ export interface AttendanceItem {
   exams: readonly string[];
   urgency: AttendanceUrgency;
   status: AttendanceStatus;
+  // Status do backend, para o rotulo da linha (COLETADO, EM ANALISE, LIBERADO...).
+  stage: AttendanceStatusDto;
   scheduledAt: string;
   completedAt?: string;
 }
@@ -0,0 +1,92 @@
+import { ChangeDetectionStrategy, Component, OnInit, inject, signal } from '@angular/core';
+import { NonNullableFormBuilder, ReactiveFormsModule, Validators } from '@angular/forms';
+import { Router } from '@angular/router';
+
+import { AuthApiService } from '../../core/services/auth-api.service';
+import { isIpcError } from '../../core/services/ipc-error';
+
+// 'setup' enquanto nao existe nenhum usuario: o primeiro cadastro vira o admin.
+type LoginMode = 'loading' | 'login' | 'setup';
+
+@Component({
+  selector: 'app-login',
+  standalone: true,
+  imports: [ReactiveFormsModule],
+  templateUrl: './login.component.html',
+  styleUrl: './login.component.scss',
+  changeDetection: ChangeDetectionStrategy.OnPush,
+})
+export class LoginComponent implements OnInit {
+  private readonly auth = inject(AuthApiService);
+  private readonly router = inject(Router);
+  private readonly fb = inject(NonNullableFormBuilder);
+
+  readonly mode = signal<LoginMode>('loading');
+  readonly submitting = signal(false);
+  readonly error = signal<string | null>(null);
+
+  readonly loginForm = this.fb.group({
+    username: ['', [Validators.required]],
+    password: ['', [Validators.required]],
+  });
+
+  readonly setupForm = this.fb.group({
+    name: ['', [Validators.required]],
+    cpf: ['', [Validators.required]],
+    username: ['', [Validators.required]],
+    password: ['', [Validators.required, Validators.minLength(8)]],
+  });
+
+  async ngOnInit(): Promise<void> {
+    try {
+      const needsSetup = await this.auth.needsInitialSetup();
+      this.mode.set(needsSetup ? 'setup' : 'login');
+    } catch (error) {
+      this.error.set(normalizeError(error));
+      this.mode.set('login');
+    }
+  }
+
+  async login(): Promise<void> {
+    if (this.loginForm.invalid) {
+      this.loginForm.markAllAsTouched();
+      return;
+    }
+    await this.submit(() => this.auth.login(this.loginForm.getRawValue()));
+  }
+
+  async createInitialAdmin(): Promise<void> {
+    if (this.setupForm.invalid) {
+      this.setupForm.markAllAsTouched();
+      return;
+    }
+    const value = this.setupForm.getRawValue();
+    await this.submit(() =>
+      this.auth.createInitialAdmin({ ...value, cpf: value.cpf.replace(/\D/g, '') })
+    );
+  }
+
+  // Login e primeiro admin abrem a sessao no backend; depois disso as demais telas liberam.
+  private async submit(openSession: () => Promise<unknown>): Promise<void> {
+    this.submitting.set(true);
+    this.error.set(null);
+    try {
+      await openSession();
+      await this.router.navigateByUrl('/');
+    } catch (error) {
+      this.error.set(normalizeError(error));
+    } finally {
+      this.submitting.set(false);
+    }
+  }
+}
+
+function normalizeError(error: unknown): string {
+  if (isIpcError(error) && error.message.trim()) {
+    return error.message;
+  }
+  if (typeof error === 'string' && error.trim()) {
+    return error;
+  }
+  return 'Nao foi possivel entrar. Tente novamente.';
+}
@@ -8,6 +8,8 @@ export interface PatientRecordExam {
   protocol: string;
   status: PatientRecordExamStatus;
   reportAvailable: boolean;
+  // Painel em que o exame foi pedido; analitos do mesmo painel vem em sequencia.
+  panelName?: string;
 }
 
 export interface PatientRecordEntry {
@@ -26,12 +26,13 @@ describe('PatientRecordService', () => {
         address: 'Rua A',
         created_at: '2026-01-01T00:00:00',
         updated_at: '2026-01-02T00:00:00',
+        deleted_at: null,
       },
       entries: [
         {
           exam_id: 'ex-1',
           exam_date: '2026-02-14',
-          status: 'completed',
+          status: 'released',
           requester_name: 'Dr. Silva',
           items: [
             {
@@ -40,6 +41,7 @@ describe('PatientRecordService', () => {
               report_available: true,
             },
           ],
+          deleted_at: null,
         },
       ],
     });
@@ -60,6 +62,8 @@ describe('PatientRecordService', () => {
         category_id: 'bioquimica',
         category_title: 'Bioquimica',
         price_cents: 1000,
+        is_active: true,
+        analyte_ids: [],
       },
     ]);
     api.createAttendance.and.resolveTo({
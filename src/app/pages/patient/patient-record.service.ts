@@ -37,7 +37,7 @@ export class PatientRecordService {
         throw new Error('Exame invalido para criacao do atendimento.');
       }
 
-      return { name: exam.name };
+      return { catalog_item_id: exam.id, name: exam.name };
     });
 
     const input: CreateAttendanceInputDto = {
@@ -90,11 +90,13 @@ function mapItemToExam(
     protocol: item.exam_item_id.slice(0, 8).toUpperCase(),
     status: mapStatus(status),
     reportAvailable: item.report_available,
+    panelName: item.panel_name,
   };
 }
 
 function mapStatus(status: string): PatientRecordExamStatus {
-  return status.toLowerCase() === 'completed' ? 'completed' : 'pending';
+  const normalized = status.toLowerCase();
+  return normalized === 'released' || normalized === 'delivered' ? 'completed' : 'pending';
 }
 
 function toBrazilianDate(value: string): string {
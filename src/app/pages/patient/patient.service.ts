@@ -2,16 +2,24 @@ import { Injectable } from '@angular/core';
 import { Observable, from } from 'rxjs';
 import { map, timeout } from 'rxjs/operators';
 import { Patient } from './models/patient.model';
-import { PatientView, PatientsApiService } from '../../core/services/patients-api.service';
+import {
+  MAX_PAGE_LIMIT,
+  PatientView,
+  PatientsApiService,
+  fetchAllPages,
+} from '../../core/services/patients-api.service';
 
 @Injectable({ providedIn: 'root' })
 export class PatientService {
   constructor(private readonly patientsApi: PatientsApiService) {}
 
   listPatients(query?: string): Observable<readonly Patient[]> {
-    return from(this.patientsApi.listPatients(query)).pipe(
+    const items = fetchAllPages((cursor) =>
+      this.patientsApi.listPatients({ query, cursor, limit: MAX_PAGE_LIMIT })
+    );
+    return from(items).pipe(
       timeout(8000),
-      map((items) => items.map(mapPatientViewToUiModel))
+      map((views) => views.map(mapPatientViewToUiModel))
     );
   }
 }